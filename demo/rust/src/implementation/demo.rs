@@ -21,25 +21,25 @@ use super::*;
 
 pub struct Demo {
     emit: DemoEmitter,
+    file_system_tree: FileSystemTree,
     fibonacci: Fibonacci,
     fibonacci_list: FibonacciList,
-    file_system_tree: FileSystemTree,
     processes: Processes,
     time_series: TimeSeries
 }
 
 impl DemoTrait for Demo {
     fn new(emit: DemoEmitter,
+        file_system_tree: FileSystemTree,
         fibonacci: Fibonacci,
         fibonacci_list: FibonacciList,
-        file_system_tree: FileSystemTree,
         processes: Processes,
         time_series: TimeSeries) -> Self {
         Demo {
             emit: emit,
+            file_system_tree: file_system_tree,
             fibonacci: fibonacci,
             fibonacci_list: fibonacci_list,
-            file_system_tree: file_system_tree,
             processes: processes,
             time_series: time_series
         }
@@ -47,6 +47,12 @@ impl DemoTrait for Demo {
     fn emit(&self) -> &DemoEmitter {
         &self.emit
     }
+    fn file_system_tree(&self) -> &FileSystemTree {
+        &self.file_system_tree
+    }
+    fn file_system_tree_mut(&mut self) -> &mut FileSystemTree {
+        &mut self.file_system_tree
+    }
     fn fibonacci(&self) -> &Fibonacci {
         &self.fibonacci
     }
@@ -59,12 +65,6 @@ impl DemoTrait for Demo {
     fn fibonacci_list_mut(&mut self) -> &mut FibonacciList {
         &mut self.fibonacci_list
     }
-    fn file_system_tree(&self) -> &FileSystemTree {
-        &self.file_system_tree
-    }
-    fn file_system_tree_mut(&mut self) -> &mut FileSystemTree {
-        &mut self.file_system_tree
-    }
     fn processes(&self) -> &Processes {
         &self.processes
     }
@@ -16,41 +16,52 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::thread;
 use interface::*;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 fn fibonacci(input: u32) -> usize {
+    fibonacci_until(input, || false).unwrap_or(0)
+}
+
+// Returns None when `cancelled` returns true before the number is known.
+fn fibonacci_until<F: Fn() -> bool>(input: u32, cancelled: F) -> Option<usize> {
     if input <= 1 {
-        return input as usize;
+        return Some(input as usize);
     }
     let mut i = 0;
     let mut sum = 0;
     let mut last = 0;
     let mut cur = 1;
     while i < input - 1 {
+        if i % 0x10000 == 0 && cancelled() {
+            return None;
+        }
         sum = last + cur;
         last = cur;
         cur = sum;
         i += 1;
     }
-    sum
+    Some(sum)
 }
 
 pub struct Fibonacci {
     emit: FibonacciEmitter,
     input: u32,
     result: Arc<AtomicUsize>,
+    // the calculations are joined when the object is deleted
+    workers: Workers,
 }
 
 impl FibonacciTrait for Fibonacci {
     fn new(emit: FibonacciEmitter) -> Fibonacci {
+        let workers = Workers::new(emit.cancel_token());
         Fibonacci {
             emit: emit,
             input: 0,
             result: Arc::new(AtomicUsize::new(0)),
+            workers: workers,
         }
     }
     fn emit(&self) -> &FibonacciEmitter {
@@ -66,10 +77,13 @@ impl FibonacciTrait for Fibonacci {
         let result = self.result.clone();
         result.swap(0, Ordering::SeqCst);
         emit.result_changed();
-        thread::spawn(move || {
-            let r = fibonacci(value);
-            result.swap(r, Ordering::SeqCst);
-            emit.result_changed();
+        // the token is cancelled when the object is deleted, so dropping
+        // the workers does not wait for a long calculation
+        self.workers.spawn(move |token| {
+            if let Some(r) = fibonacci_until(value, || token.is_cancelled()) {
+                result.swap(r, Ordering::SeqCst);
+                emit.result_changed();
+            }
         });
     }
     fn result(&self) -> u64 {
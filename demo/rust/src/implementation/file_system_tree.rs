@@ -69,9 +69,14 @@ impl Item for DirEntry {
     fn retrieve(id: usize, parents: Vec<&DirEntry>, q: Incoming<Self>, emit: FileSystemTreeEmitter) {
         let mut v = Vec::new();
         let path: PathBuf = parents.into_iter().map(|e| &e.name).collect();
+        let token = emit.cancel_token();
         thread::spawn(move || {
             if let Ok(it) = read_dir(&path) {
                 for i in it.filter_map(|v| v.ok()) {
+                    if token.is_cancelled() {
+                        // the tree is gone
+                        return;
+                    }
                     let de = DirEntry {
                         name: i.file_name(),
                         metadata: i.metadata().ok(),
@@ -21,7 +21,6 @@ use sysinfo::*;
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
 use libc::pid_t;
-use std::thread;
 use std::time::Duration;
 use std::sync::mpsc::{channel, Sender, Receiver, RecvTimeoutError};
 
@@ -50,7 +49,9 @@ pub struct Processes {
     p: ProcessTree,
     incoming: Arc<Mutex<Option<ProcessTree>>>,
     active: bool,
-    channel: Sender<ChangeState>
+    channel: Sender<ChangeState>,
+    // the update thread is joined when the object is deleted
+    workers: Workers,
 }
 
 fn check_process_hierarchy(parent: Option<pid_t>, processes: &HashMap<pid_t, Process>) {
@@ -127,13 +128,14 @@ fn update() -> ProcessTree {
 }
 
 fn update_thread(
+    workers: &mut Workers,
     emit: ProcessesEmitter,
     incoming: Arc<Mutex<Option<ProcessTree>>>,
     mut active: bool,
     status_channel: Receiver<ChangeState>,
 ) {
-    thread::spawn(move || {
-        loop {
+    workers.spawn(move |token| {
+        while !token.is_cancelled() {
             let timeout = if active {
                 *incoming.lock().unwrap() = Some(update());
                 emit.new_data_ready(None);
@@ -323,15 +325,16 @@ fn sync_tree(
 impl ProcessesTrait for Processes {
     fn new(emit: ProcessesEmitter, model: ProcessesTree) -> Processes {
         let (tx, rx) = channel();
-        let p = Processes {
+        let mut p = Processes {
             emit: emit.clone(),
             model: model,
             p: ProcessTree::default(),
             incoming: Arc::new(Mutex::new(None)),
             active: false,
             channel: tx,
+            workers: Workers::new(emit.cancel_token()),
         };
-        update_thread(emit, p.incoming.clone(), p.active, rx);
+        update_thread(&mut p.workers, emit, p.incoming.clone(), p.active, rx);
         p
     }
     fn emit(&self) -> &ProcessesEmitter {
@@ -429,6 +432,7 @@ impl ProcessesTrait for Processes {
 
 impl Drop for Processes {
     fn drop(&mut self) {
-        self.channel.send(ChangeState::Quit).expect("Process thread died.");
+        // the thread may have stopped already, its token was cancelled
+        let _ = self.channel.send(ChangeState::Quit);
     }
 }
@@ -1,12 +1,19 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
-
-use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
 
 use implementation::*;
 
@@ -42,8 +49,7 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
@@ -66,51 +72,268 @@ pub struct QModelIndex {
 }
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// Cancelled when the QObject of an emitter is deleted. Worker threads can
+/// check it, wait on it or await it to stop in time.
+#[derive(Clone)]
+pub struct CancelToken {
+    state: Arc<CancelState>,
+}
+
+struct CancelState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+    condvar: Condvar,
+}
+
+impl Default for CancelToken {
+    fn default() -> CancelToken {
+        CancelToken {
+            state: Arc::new(CancelState {
+                cancelled: AtomicBool::new(false),
+                wakers: Mutex::new(Vec::new()),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+}
+
+impl CancelToken {
+    fn cancel(&self) {
+        let wakers: Vec<Waker> = {
+            let mut wakers = self.state.wakers.lock().unwrap();
+            self.state.cancelled.store(true, Ordering::SeqCst);
+            self.state.condvar.notify_all();
+            wakers.drain(..).collect()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+    /// Sleep until the token is cancelled or `timeout` has passed. Returns
+    /// `true` when the token is cancelled.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let wakers = self.state.wakers.lock().unwrap();
+        let _ = self.state.condvar
+            .wait_timeout_while(wakers, timeout, |_| !self.is_cancelled())
+            .unwrap();
+        self.is_cancelled()
+    }
+    /// A future that is ready when the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled { token: self.clone() }
+    }
+    // Keep `waker` to wake it when the token is cancelled. Returns `true`
+    // when the token already is.
+    fn register(&self, waker: &Waker) -> bool {
+        let mut wakers = self.state.wakers.lock().unwrap();
+        if self.is_cancelled() {
+            return true;
+        }
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+        false
+    }
+}
+
+/// The future of `CancelToken::cancelled`.
+pub struct Cancelled {
+    token: CancelToken,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.token.register(cx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Worker threads that are cancelled and joined when this is dropped. Keep
+/// it in a field of the Rust object and its threads end with the object.
+pub struct Workers {
+    token: CancelToken,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Workers {
+    pub fn new(token: CancelToken) -> Workers {
+        Workers {
+            token: token,
+            threads: Vec::new(),
+        }
+    }
+    /// Run `f` in a new thread. `f` should return soon after the token that
+    /// it gets is cancelled.
+    pub fn spawn<F: FnOnce(CancelToken) + Send + 'static>(&mut self, f: F) {
+        self.threads.retain(|t| !t.is_finished());
+        let token = self.token.clone();
+        self.threads.push(thread::spawn(move || f(token)));
+    }
+}
+
+impl Drop for Workers {
+    fn drop(&mut self) {
+        self.token.cancel();
+        for t in self.threads.drain(..) {
+            let _ = t.join();
+        }
+    }
+}
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xa48d9cc2b855f6ad
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct DemoQObject {}
 
 #[derive(Clone)]
 pub struct DemoEmitter {
-    qobject: Arc<Mutex<*const DemoQObject>>,
+    pub(crate) qobject: Arc<AtomicPtr<DemoQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const DemoQObject, *mut c_void),
 }
 
-unsafe impl Send for DemoEmitter {}
-
 impl DemoEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const DemoQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by demo_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Demo) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
     }
 }
 
 pub trait DemoTrait {
     fn new(emit: DemoEmitter,
+        file_system_tree: FileSystemTree,
         fibonacci: Fibonacci,
         fibonacci_list: FibonacciList,
-        file_system_tree: FileSystemTree,
         processes: Processes,
         time_series: TimeSeries) -> Self;
     fn emit(&self) -> &DemoEmitter;
+    fn file_system_tree(&self) -> &FileSystemTree;
+    fn file_system_tree_mut(&mut self) -> &mut FileSystemTree;
     fn fibonacci(&self) -> &Fibonacci;
     fn fibonacci_mut(&mut self) -> &mut Fibonacci;
     fn fibonacci_list(&self) -> &FibonacciList;
     fn fibonacci_list_mut(&mut self) -> &mut FibonacciList;
-    fn file_system_tree(&self) -> &FileSystemTree;
-    fn file_system_tree_mut(&mut self) -> &mut FileSystemTree;
     fn processes(&self) -> &Processes;
     fn processes_mut(&mut self) -> &mut Processes;
     fn time_series(&self) -> &TimeSeries;
@@ -120,10 +343,24 @@ pub trait DemoTrait {
 #[no_mangle]
 pub extern "C" fn demo_new(
     demo: *mut DemoQObject,
+    demo_run_on_gui_thread: fn(*const DemoQObject, *mut c_void),
+    file_system_tree: *mut FileSystemTreeQObject,
+    file_system_tree_run_on_gui_thread: fn(*const FileSystemTreeQObject, *mut c_void),
+    path_changed: fn(*const FileSystemTreeQObject),
+    file_system_tree_new_data_ready: fn(*const FileSystemTreeQObject, item: usize, valid: bool),
+    file_system_tree_data_changed: fn(*const FileSystemTreeQObject, usize, usize),
+    file_system_tree_begin_reset_model: fn(*const FileSystemTreeQObject),
+    file_system_tree_end_reset_model: fn(*const FileSystemTreeQObject),
+    file_system_tree_begin_insert_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
+    file_system_tree_end_insert_rows: fn(*const FileSystemTreeQObject),
+    file_system_tree_begin_remove_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
+    file_system_tree_end_remove_rows: fn(*const FileSystemTreeQObject),
     fibonacci: *mut FibonacciQObject,
+    fibonacci_run_on_gui_thread: fn(*const FibonacciQObject, *mut c_void),
     input_changed: fn(*const FibonacciQObject),
     result_changed: fn(*const FibonacciQObject),
     fibonacci_list: *mut FibonacciListQObject,
+    fibonacci_list_run_on_gui_thread: fn(*const FibonacciListQObject, *mut c_void),
     fibonacci_list_new_data_ready: fn(*const FibonacciListQObject),
     fibonacci_list_data_changed: fn(*const FibonacciListQObject, usize, usize),
     fibonacci_list_begin_reset_model: fn(*const FibonacciListQObject),
@@ -132,17 +369,8 @@ pub extern "C" fn demo_new(
     fibonacci_list_end_insert_rows: fn(*const FibonacciListQObject),
     fibonacci_list_begin_remove_rows: fn(*const FibonacciListQObject, usize, usize),
     fibonacci_list_end_remove_rows: fn(*const FibonacciListQObject),
-    file_system_tree: *mut FileSystemTreeQObject,
-    path_changed: fn(*const FileSystemTreeQObject),
-    file_system_tree_new_data_ready: fn(*const FileSystemTreeQObject, item: usize, valid: bool),
-    file_system_tree_data_changed: fn(*const FileSystemTreeQObject, usize, usize),
-    file_system_tree_begin_reset_model: fn(*const FileSystemTreeQObject),
-    file_system_tree_end_reset_model: fn(*const FileSystemTreeQObject),
-    file_system_tree_begin_insert_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
-    file_system_tree_end_insert_rows: fn(*const FileSystemTreeQObject),
-    file_system_tree_begin_remove_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
-    file_system_tree_end_remove_rows: fn(*const FileSystemTreeQObject),
     processes: *mut ProcessesQObject,
+    processes_run_on_gui_thread: fn(*const ProcessesQObject, *mut c_void),
     active_changed: fn(*const ProcessesQObject),
     processes_new_data_ready: fn(*const ProcessesQObject, item: usize, valid: bool),
     processes_data_changed: fn(*const ProcessesQObject, usize, usize),
@@ -153,6 +381,7 @@ pub extern "C" fn demo_new(
     processes_begin_remove_rows: fn(*const ProcessesQObject, item: usize, valid: bool, usize, usize),
     processes_end_remove_rows: fn(*const ProcessesQObject),
     time_series: *mut TimeSeriesQObject,
+    time_series_run_on_gui_thread: fn(*const TimeSeriesQObject, *mut c_void),
     time_series_new_data_ready: fn(*const TimeSeriesQObject),
     time_series_data_changed: fn(*const TimeSeriesQObject, usize, usize),
     time_series_begin_reset_model: fn(*const TimeSeriesQObject),
@@ -162,14 +391,39 @@ pub extern "C" fn demo_new(
     time_series_begin_remove_rows: fn(*const TimeSeriesQObject, usize, usize),
     time_series_end_remove_rows: fn(*const TimeSeriesQObject),
 ) -> *mut Demo {
+    let file_system_tree_emit = FileSystemTreeEmitter {
+        qobject: Arc::new(AtomicPtr::new(file_system_tree)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: file_system_tree_run_on_gui_thread,
+        path_changed: path_changed,
+        new_data_ready: file_system_tree_new_data_ready,
+    };
+    let model = FileSystemTreeTree {
+        qobject: file_system_tree,
+        data_changed: file_system_tree_data_changed,
+        begin_reset_model: file_system_tree_begin_reset_model,
+        end_reset_model: file_system_tree_end_reset_model,
+        begin_insert_rows: file_system_tree_begin_insert_rows,
+        end_insert_rows: file_system_tree_end_insert_rows,
+        begin_remove_rows: file_system_tree_begin_remove_rows,
+        end_remove_rows: file_system_tree_end_remove_rows,
+    };
+    let d_file_system_tree = FileSystemTree::new(file_system_tree_emit, model);
     let fibonacci_emit = FibonacciEmitter {
-        qobject: Arc::new(Mutex::new(fibonacci)),
+        qobject: Arc::new(AtomicPtr::new(fibonacci)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: fibonacci_run_on_gui_thread,
         input_changed: input_changed,
         result_changed: result_changed,
     };
     let d_fibonacci = Fibonacci::new(fibonacci_emit);
     let fibonacci_list_emit = FibonacciListEmitter {
-        qobject: Arc::new(Mutex::new(fibonacci_list)),
+        qobject: Arc::new(AtomicPtr::new(fibonacci_list)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: fibonacci_list_run_on_gui_thread,
         new_data_ready: fibonacci_list_new_data_ready,
     };
     let model = FibonacciListList {
@@ -183,24 +437,11 @@ pub extern "C" fn demo_new(
         end_remove_rows: fibonacci_list_end_remove_rows,
     };
     let d_fibonacci_list = FibonacciList::new(fibonacci_list_emit, model);
-    let file_system_tree_emit = FileSystemTreeEmitter {
-        qobject: Arc::new(Mutex::new(file_system_tree)),
-        path_changed: path_changed,
-        new_data_ready: file_system_tree_new_data_ready,
-    };
-    let model = FileSystemTreeTree {
-        qobject: file_system_tree,
-        data_changed: file_system_tree_data_changed,
-        begin_reset_model: file_system_tree_begin_reset_model,
-        end_reset_model: file_system_tree_end_reset_model,
-        begin_insert_rows: file_system_tree_begin_insert_rows,
-        end_insert_rows: file_system_tree_end_insert_rows,
-        begin_remove_rows: file_system_tree_begin_remove_rows,
-        end_remove_rows: file_system_tree_end_remove_rows,
-    };
-    let d_file_system_tree = FileSystemTree::new(file_system_tree_emit, model);
     let processes_emit = ProcessesEmitter {
-        qobject: Arc::new(Mutex::new(processes)),
+        qobject: Arc::new(AtomicPtr::new(processes)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: processes_run_on_gui_thread,
         active_changed: active_changed,
         new_data_ready: processes_new_data_ready,
     };
@@ -216,7 +457,10 @@ pub extern "C" fn demo_new(
     };
     let d_processes = Processes::new(processes_emit, model);
     let time_series_emit = TimeSeriesEmitter {
-        qobject: Arc::new(Mutex::new(time_series)),
+        qobject: Arc::new(AtomicPtr::new(time_series)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: time_series_run_on_gui_thread,
         new_data_ready: time_series_new_data_ready,
     };
     let model = TimeSeriesList {
@@ -231,12 +475,15 @@ pub extern "C" fn demo_new(
     };
     let d_time_series = TimeSeries::new(time_series_emit, model);
     let demo_emit = DemoEmitter {
-        qobject: Arc::new(Mutex::new(demo)),
+        qobject: Arc::new(AtomicPtr::new(demo)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: demo_run_on_gui_thread,
     };
     let d_demo = Demo::new(demo_emit,
+        d_file_system_tree,
         d_fibonacci,
         d_fibonacci_list,
-        d_file_system_tree,
         d_processes,
         d_time_series);
     Box::into_raw(Box::new(d_demo))
@@ -244,17 +491,24 @@ pub extern "C" fn demo_new(
 
 #[no_mangle]
 pub unsafe extern "C" fn demo_free(ptr: *mut Demo) {
-    Box::from_raw(ptr).emit().clear();
+    let o = Box::from_raw(ptr);
+    o.emit().clear();
+    o.file_system_tree().emit().clear();
+    o.fibonacci().emit().clear();
+    o.fibonacci_list().emit().clear();
+    o.processes().emit().clear();
+    o.time_series().emit().clear();
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn demo_fibonacci_get(ptr: *mut Demo) -> *mut Fibonacci {
-    (&mut *ptr).fibonacci_mut()
+pub unsafe extern "C" fn demo_run_task(ptr: *mut Demo, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Demo) + Send>);
+    f(&mut *ptr);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn demo_fibonacci_list_get(ptr: *mut Demo) -> *mut FibonacciList {
-    (&mut *ptr).fibonacci_list_mut()
+pub unsafe extern "C" fn demo_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Demo) + Send>));
 }
 
 #[no_mangle]
@@ -262,6 +516,16 @@ pub unsafe extern "C" fn demo_file_system_tree_get(ptr: *mut Demo) -> *mut FileS
     (&mut *ptr).file_system_tree_mut()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn demo_fibonacci_get(ptr: *mut Demo) -> *mut Fibonacci {
+    (&mut *ptr).fibonacci_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn demo_fibonacci_list_get(ptr: *mut Demo) -> *mut FibonacciList {
+    (&mut *ptr).fibonacci_list_mut()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn demo_processes_get(ptr: *mut Demo) -> *mut Processes {
     (&mut *ptr).processes_mut()
@@ -272,112 +536,165 @@ pub unsafe extern "C" fn demo_time_series_get(ptr: *mut Demo) -> *mut TimeSeries
     (&mut *ptr).time_series_mut()
 }
 
-pub struct FibonacciQObject {}
-
-#[derive(Clone)]
-pub struct FibonacciEmitter {
-    qobject: Arc<Mutex<*const FibonacciQObject>>,
-    input_changed: fn(*const FibonacciQObject),
-    result_changed: fn(*const FibonacciQObject),
-}
-
-unsafe impl Send for FibonacciEmitter {}
-
-impl FibonacciEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+/// Owns a Demo for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct DemoFixture {
+    pub ptr: *mut Demo,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl DemoFixture {
+    pub fn new() -> DemoFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = demo_new(
+            q as *mut DemoQObject,
+            |_, task| unsafe { demo_drop_task(task) },
+            q as *mut FileSystemTreeQObject,
+            |_, task| unsafe { file_system_tree_drop_task(task) },
+            |q| TestQObject::emit(q, "pathChanged"),
+            |q, _, _| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+            q as *mut FibonacciQObject,
+            |_, task| unsafe { fibonacci_drop_task(task) },
+            |q| TestQObject::emit(q, "inputChanged"),
+            |q| TestQObject::emit(q, "resultChanged"),
+            q as *mut FibonacciListQObject,
+            |_, task| unsafe { fibonacci_list_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+            q as *mut ProcessesQObject,
+            |_, task| unsafe { processes_drop_task(task) },
+            |q| TestQObject::emit(q, "activeChanged"),
+            |q, _, _| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+            q as *mut TimeSeriesQObject,
+            |_, task| unsafe { time_series_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        DemoFixture { ptr: ptr, qobject: qobject }
     }
-    pub fn input_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.input_changed)(ptr);
-        }
+    pub fn get(&self) -> &Demo {
+        unsafe { &*self.ptr }
     }
-    pub fn result_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.result_changed)(ptr);
+    pub fn get_mut(&mut self) -> &mut Demo {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { demo_free(self.ptr) };
+            self.ptr = null_mut();
         }
     }
 }
 
-pub trait FibonacciTrait {
-    fn new(emit: FibonacciEmitter) -> Self;
-    fn emit(&self) -> &FibonacciEmitter;
-    fn input(&self) -> u32;
-    fn set_input(&mut self, value: u32);
-    fn result(&self) -> u64;
-}
-
-#[no_mangle]
-pub extern "C" fn fibonacci_new(
-    fibonacci: *mut FibonacciQObject,
-    input_changed: fn(*const FibonacciQObject),
-    result_changed: fn(*const FibonacciQObject),
-) -> *mut Fibonacci {
-    let fibonacci_emit = FibonacciEmitter {
-        qobject: Arc::new(Mutex::new(fibonacci)),
-        input_changed: input_changed,
-        result_changed: result_changed,
-    };
-    let d_fibonacci = Fibonacci::new(fibonacci_emit);
-    Box::into_raw(Box::new(d_fibonacci))
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn fibonacci_free(ptr: *mut Fibonacci) {
-    Box::from_raw(ptr).emit().clear();
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn fibonacci_input_get(ptr: *const Fibonacci) -> u32 {
-    (&*ptr).input()
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn fibonacci_input_set(ptr: *mut Fibonacci, v: u32) {
-    (&mut *ptr).set_input(v);
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn fibonacci_result_get(ptr: *const Fibonacci) -> u64 {
-    (&*ptr).result()
+#[cfg(test)]
+impl Drop for DemoFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
 
-pub struct FibonacciListQObject {}
+pub struct FileSystemTreeQObject {}
 
 #[derive(Clone)]
-pub struct FibonacciListEmitter {
-    qobject: Arc<Mutex<*const FibonacciListQObject>>,
-    new_data_ready: fn(*const FibonacciListQObject),
+pub struct FileSystemTreeEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<FileSystemTreeQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const FileSystemTreeQObject, *mut c_void),
+    pub(crate) path_changed: fn(*const FileSystemTreeQObject),
+    pub(crate) new_data_ready: fn(*const FileSystemTreeQObject, item: usize, valid: bool),
 }
 
-unsafe impl Send for FibonacciListEmitter {}
-
-impl FibonacciListEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+impl FileSystemTreeEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const FileSystemTreeQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by file_system_tree_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut FileSystemTree) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    pub fn path_changed(&self) {
+        self.with_qobject(|ptr| (self.path_changed)(ptr));
+    }
+    pub fn new_data_ready(&self, item: Option<usize>) {
+        self.with_qobject(|ptr| {
+            (self.new_data_ready)(ptr, item.unwrap_or(13), item.is_some());
+        });
+    }
 }
 
-pub struct FibonacciListList {
-    qobject: *const FibonacciListQObject,
-    data_changed: fn(*const FibonacciListQObject, usize, usize),
-    begin_reset_model: fn(*const FibonacciListQObject),
-    end_reset_model: fn(*const FibonacciListQObject),
-    begin_insert_rows: fn(*const FibonacciListQObject, usize, usize),
-    end_insert_rows: fn(*const FibonacciListQObject),
-    begin_remove_rows: fn(*const FibonacciListQObject, usize, usize),
-    end_remove_rows: fn(*const FibonacciListQObject),
+pub struct FileSystemTreeTree {
+    pub(crate) qobject: *const FileSystemTreeQObject,
+    pub(crate) data_changed: fn(*const FileSystemTreeQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const FileSystemTreeQObject),
+    pub(crate) end_reset_model: fn(*const FileSystemTreeQObject),
+    pub(crate) begin_insert_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_insert_rows: fn(*const FileSystemTreeQObject),
+    pub(crate) begin_remove_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_remove_rows: fn(*const FileSystemTreeQObject),
 }
 
-impl FibonacciListList {
+impl FileSystemTreeTree {
     pub fn data_changed(&self, first: usize, last: usize) {
         (self.data_changed)(self.qobject, first, last);
     }
@@ -387,152 +704,525 @@ impl FibonacciListList {
     pub fn end_reset_model(&self) {
         (self.end_reset_model)(self.qobject);
     }
-    pub fn begin_insert_rows(&self, first: usize, last: usize) {
-        (self.begin_insert_rows)(self.qobject, first, last);
+    pub fn begin_insert_rows(&self, item: Option<usize>, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, item.unwrap_or(13), item.is_some(), first, last);
     }
     pub fn end_insert_rows(&self) {
         (self.end_insert_rows)(self.qobject);
     }
-    pub fn begin_remove_rows(&self, first: usize, last: usize) {
-        (self.begin_remove_rows)(self.qobject, first, last);
+    pub fn begin_remove_rows(&self, item: Option<usize>, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, item.unwrap_or(13), item.is_some(), first, last);
     }
     pub fn end_remove_rows(&self) {
         (self.end_remove_rows)(self.qobject);
     }
 }
 
-pub trait FibonacciListTrait {
-    fn new(emit: FibonacciListEmitter, model: FibonacciListList) -> Self;
-    fn emit(&self) -> &FibonacciListEmitter;
-    fn row_count(&self) -> usize;
-    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
-    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
-    fn can_fetch_more(&self) -> bool {
+pub trait FileSystemTreeTrait {
+    fn new(emit: FileSystemTreeEmitter, model: FileSystemTreeTree) -> Self;
+    fn emit(&self) -> &FileSystemTreeEmitter;
+    fn path(&self) -> Option<&str>;
+    fn set_path(&mut self, value: Option<String>);
+    fn row_count(&self, Option<usize>) -> usize;
+    fn can_fetch_more(&self, Option<usize>) -> bool {
         false
     }
-    fn fetch_more(&mut self) {}
+    fn fetch_more(&mut self, Option<usize>) {}
     fn sort(&mut self, u8, SortOrder) {}
-    fn fibonacci_number(&self, item: usize) -> u64;
-    fn row(&self, item: usize) -> u64;
-}
-
-#[no_mangle]
-pub extern "C" fn fibonacci_list_new(
-    fibonacci_list: *mut FibonacciListQObject,
-    fibonacci_list_new_data_ready: fn(*const FibonacciListQObject),
-    fibonacci_list_data_changed: fn(*const FibonacciListQObject, usize, usize),
-    fibonacci_list_begin_reset_model: fn(*const FibonacciListQObject),
-    fibonacci_list_end_reset_model: fn(*const FibonacciListQObject),
-    fibonacci_list_begin_insert_rows: fn(*const FibonacciListQObject, usize, usize),
-    fibonacci_list_end_insert_rows: fn(*const FibonacciListQObject),
-    fibonacci_list_begin_remove_rows: fn(*const FibonacciListQObject, usize, usize),
-    fibonacci_list_end_remove_rows: fn(*const FibonacciListQObject),
-) -> *mut FibonacciList {
-    let fibonacci_list_emit = FibonacciListEmitter {
-        qobject: Arc::new(Mutex::new(fibonacci_list)),
-        new_data_ready: fibonacci_list_new_data_ready,
+    fn index(&self, item: Option<usize>, row: usize) -> usize;
+    fn parent(&self, item: usize) -> Option<usize>;
+    fn row(&self, item: usize) -> usize;
+    fn file_name(&self, item: usize) -> String;
+    fn file_icon(&self, item: usize) -> &[u8];
+    fn file_size(&self, item: usize) -> Option<u64>;
+    fn file_path(&self, item: usize) -> Option<String>;
+    fn file_permissions(&self, item: usize) -> i32;
+    fn file_type(&self, item: usize) -> i32;
+}
+
+#[no_mangle]
+pub extern "C" fn file_system_tree_new(
+    file_system_tree: *mut FileSystemTreeQObject,
+    file_system_tree_run_on_gui_thread: fn(*const FileSystemTreeQObject, *mut c_void),
+    path_changed: fn(*const FileSystemTreeQObject),
+    file_system_tree_new_data_ready: fn(*const FileSystemTreeQObject, item: usize, valid: bool),
+    file_system_tree_data_changed: fn(*const FileSystemTreeQObject, usize, usize),
+    file_system_tree_begin_reset_model: fn(*const FileSystemTreeQObject),
+    file_system_tree_end_reset_model: fn(*const FileSystemTreeQObject),
+    file_system_tree_begin_insert_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
+    file_system_tree_end_insert_rows: fn(*const FileSystemTreeQObject),
+    file_system_tree_begin_remove_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
+    file_system_tree_end_remove_rows: fn(*const FileSystemTreeQObject),
+) -> *mut FileSystemTree {
+    let file_system_tree_emit = FileSystemTreeEmitter {
+        qobject: Arc::new(AtomicPtr::new(file_system_tree)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: file_system_tree_run_on_gui_thread,
+        path_changed: path_changed,
+        new_data_ready: file_system_tree_new_data_ready,
     };
-    let model = FibonacciListList {
-        qobject: fibonacci_list,
-        data_changed: fibonacci_list_data_changed,
-        begin_reset_model: fibonacci_list_begin_reset_model,
-        end_reset_model: fibonacci_list_end_reset_model,
-        begin_insert_rows: fibonacci_list_begin_insert_rows,
-        end_insert_rows: fibonacci_list_end_insert_rows,
-        begin_remove_rows: fibonacci_list_begin_remove_rows,
-        end_remove_rows: fibonacci_list_end_remove_rows,
+    let model = FileSystemTreeTree {
+        qobject: file_system_tree,
+        data_changed: file_system_tree_data_changed,
+        begin_reset_model: file_system_tree_begin_reset_model,
+        end_reset_model: file_system_tree_end_reset_model,
+        begin_insert_rows: file_system_tree_begin_insert_rows,
+        end_insert_rows: file_system_tree_end_insert_rows,
+        begin_remove_rows: file_system_tree_begin_remove_rows,
+        end_remove_rows: file_system_tree_end_remove_rows,
     };
-    let d_fibonacci_list = FibonacciList::new(fibonacci_list_emit, model);
-    Box::into_raw(Box::new(d_fibonacci_list))
+    let d_file_system_tree = FileSystemTree::new(file_system_tree_emit, model);
+    Box::into_raw(Box::new(d_file_system_tree))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_free(ptr: *mut FibonacciList) {
+pub unsafe extern "C" fn file_system_tree_free(ptr: *mut FileSystemTree) {
     Box::from_raw(ptr).emit().clear();
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_row_count(ptr: *const FibonacciList) -> c_int {
-    to_c_int((&*ptr).row_count())
+pub unsafe extern "C" fn file_system_tree_run_task(ptr: *mut FileSystemTree, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut FileSystemTree) + Send>);
+    f(&mut *ptr);
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_insert_rows(ptr: *mut FibonacciList, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+pub unsafe extern "C" fn file_system_tree_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut FileSystemTree) + Send>));
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_remove_rows(ptr: *mut FibonacciList, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+pub extern "C" fn file_system_tree_path_get(
+    ptr: *const FileSystemTree,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.path();
+    if let Some(v) = v {
+        let s: *const c_char = v.as_ptr() as (*const c_char);
+        set(p, s, to_c_int(v.len()));
+    }
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_can_fetch_more(ptr: *const FibonacciList) -> bool {
-    (&*ptr).can_fetch_more()
+pub extern "C" fn file_system_tree_path_set(ptr: *mut FileSystemTree, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let mut s = String::new();
+    set_string_from_utf16(&mut s, v, len);
+    o.set_path(Some(s));
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_fetch_more(ptr: *mut FibonacciList) {
-    (&mut *ptr).fetch_more()
+pub extern "C" fn file_system_tree_path_set_none(ptr: *mut FileSystemTree) {
+    let o = unsafe { &mut *ptr };
+    o.set_path(None);
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn fibonacci_list_sort(
-    ptr: *mut FibonacciList,
+pub unsafe extern "C" fn file_system_tree_row_count(
+    ptr: *const FileSystemTree,
+    item: usize,
+    valid: bool,
+) -> c_int {
+    to_c_int(if valid {
+        (&*ptr).row_count(Some(item))
+    } else {
+        (&*ptr).row_count(None)
+    })
+}
+#[no_mangle]
+pub unsafe extern "C" fn file_system_tree_can_fetch_more(
+    ptr: *const FileSystemTree,
+    item: usize,
+    valid: bool,
+) -> bool {
+    if valid {
+        (&*ptr).can_fetch_more(Some(item))
+    } else {
+        (&*ptr).can_fetch_more(None)
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn file_system_tree_fetch_more(ptr: *mut FileSystemTree, item: usize, valid: bool) {
+    if valid {
+        (&mut *ptr).fetch_more(Some(item))
+    } else {
+        (&mut *ptr).fetch_more(None)
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn file_system_tree_sort(
+    ptr: *mut FileSystemTree,
     column: u8,
-    order: SortOrder,
+    order: SortOrder
 ) {
     (&mut *ptr).sort(column, order)
 }
+#[no_mangle]
+pub unsafe extern "C" fn file_system_tree_index(
+    ptr: *const FileSystemTree,
+    item: usize,
+    valid: bool,
+    row: c_int,
+) -> usize {
+    if !valid {
+        (&*ptr).index(None, to_usize(row))
+    } else {
+        (&*ptr).index(Some(item), to_usize(row))
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn file_system_tree_parent(ptr: *const FileSystemTree, index: usize) -> QModelIndex {
+    if let Some(parent) = (&*ptr).parent(index) {
+        QModelIndex {
+            row: to_c_int((&*ptr).row(parent)),
+            internal_id: parent,
+        }
+    } else {
+        QModelIndex {
+            row: -1,
+            internal_id: 0,
+        }
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn file_system_tree_row(ptr: *const FileSystemTree, item: usize) -> c_int {
+    to_c_int((&*ptr).row(item))
+}
 
 #[no_mangle]
-pub extern "C" fn fibonacci_list_data_fibonacci_number(ptr: *const FibonacciList, row: c_int) -> u64 {
+pub extern "C" fn file_system_tree_data_file_name(
+    ptr: *const FileSystemTree, item: usize,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
     let o = unsafe { &*ptr };
-    o.fibonacci_number(to_usize(row)).into()
+    let data = o.file_name(item);
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
 }
 
 #[no_mangle]
-pub extern "C" fn fibonacci_list_data_row(ptr: *const FibonacciList, row: c_int) -> u64 {
+pub extern "C" fn file_system_tree_data_file_icon(
+    ptr: *const FileSystemTree, item: usize,
+    d: *mut QByteArray,
+    set: fn(*mut QByteArray, *const c_char, len: c_int),
+) {
     let o = unsafe { &*ptr };
-    o.row(to_usize(row)).into()
+    let data = o.file_icon(item);
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
 }
 
-pub struct FileSystemTreeQObject {}
+#[no_mangle]
+pub extern "C" fn file_system_tree_data_file_size(ptr: *const FileSystemTree, item: usize) -> COption<u64> {
+    let o = unsafe { &*ptr };
+    o.file_size(item).into()
+}
 
-#[derive(Clone)]
-pub struct FileSystemTreeEmitter {
-    qobject: Arc<Mutex<*const FileSystemTreeQObject>>,
-    path_changed: fn(*const FileSystemTreeQObject),
-    new_data_ready: fn(*const FileSystemTreeQObject, item: usize, valid: bool),
+#[no_mangle]
+pub extern "C" fn file_system_tree_data_file_path(
+    ptr: *const FileSystemTree, item: usize,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.file_path(item);
+    if let Some(data) = data {
+        let s: *const c_char = data.as_ptr() as (*const c_char);
+        set(d, s, to_c_int(data.len()));
+    }
 }
 
-unsafe impl Send for FileSystemTreeEmitter {}
+#[no_mangle]
+pub extern "C" fn file_system_tree_data_file_permissions(ptr: *const FileSystemTree, item: usize) -> i32 {
+    let o = unsafe { &*ptr };
+    o.file_permissions(item).into()
+}
 
-impl FileSystemTreeEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+#[no_mangle]
+pub extern "C" fn file_system_tree_data_file_type(ptr: *const FileSystemTree, item: usize) -> i32 {
+    let o = unsafe { &*ptr };
+    o.file_type(item).into()
+}
+
+/// Owns a FileSystemTree for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct FileSystemTreeFixture {
+    pub ptr: *mut FileSystemTree,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl FileSystemTreeFixture {
+    pub fn new() -> FileSystemTreeFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = file_system_tree_new(
+            q as *mut FileSystemTreeQObject,
+            |_, task| unsafe { file_system_tree_drop_task(task) },
+            |q| TestQObject::emit(q, "pathChanged"),
+            |q, _, _| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        FileSystemTreeFixture { ptr: ptr, qobject: qobject }
     }
-    pub fn path_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    pub fn get(&self) -> &FileSystemTree {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut FileSystemTree {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { file_system_tree_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for FileSystemTreeFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct FibonacciQObject {}
+
+#[derive(Clone)]
+pub struct FibonacciEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<FibonacciQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const FibonacciQObject, *mut c_void),
+    pub(crate) input_changed: fn(*const FibonacciQObject),
+    pub(crate) result_changed: fn(*const FibonacciQObject),
+}
+
+impl FibonacciEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const FibonacciQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.path_changed)(ptr);
+            f(ptr);
         }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
     }
-    pub fn new_data_ready(&self, item: Option<usize>) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Called by fibonacci_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Fibonacci) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    pub fn input_changed(&self) {
+        self.with_qobject(|ptr| (self.input_changed)(ptr));
+    }
+    pub fn result_changed(&self) {
+        self.with_qobject(|ptr| (self.result_changed)(ptr));
+    }
+}
+
+pub trait FibonacciTrait {
+    fn new(emit: FibonacciEmitter) -> Self;
+    fn emit(&self) -> &FibonacciEmitter;
+    fn input(&self) -> u32;
+    fn set_input(&mut self, value: u32);
+    fn result(&self) -> u64;
+}
+
+#[no_mangle]
+pub extern "C" fn fibonacci_new(
+    fibonacci: *mut FibonacciQObject,
+    fibonacci_run_on_gui_thread: fn(*const FibonacciQObject, *mut c_void),
+    input_changed: fn(*const FibonacciQObject),
+    result_changed: fn(*const FibonacciQObject),
+) -> *mut Fibonacci {
+    let fibonacci_emit = FibonacciEmitter {
+        qobject: Arc::new(AtomicPtr::new(fibonacci)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: fibonacci_run_on_gui_thread,
+        input_changed: input_changed,
+        result_changed: result_changed,
+    };
+    let d_fibonacci = Fibonacci::new(fibonacci_emit);
+    Box::into_raw(Box::new(d_fibonacci))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fibonacci_free(ptr: *mut Fibonacci) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fibonacci_run_task(ptr: *mut Fibonacci, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Fibonacci) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fibonacci_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Fibonacci) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fibonacci_input_get(ptr: *const Fibonacci) -> u32 {
+    (&*ptr).input()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fibonacci_input_set(ptr: *mut Fibonacci, v: u32) {
+    (&mut *ptr).set_input(v);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fibonacci_result_get(ptr: *const Fibonacci) -> u64 {
+    (&*ptr).result()
+}
+
+/// Owns a Fibonacci for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct FibonacciFixture {
+    pub ptr: *mut Fibonacci,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl FibonacciFixture {
+    pub fn new() -> FibonacciFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = fibonacci_new(
+            q as *mut FibonacciQObject,
+            |_, task| unsafe { fibonacci_drop_task(task) },
+            |q| TestQObject::emit(q, "inputChanged"),
+            |q| TestQObject::emit(q, "resultChanged"),
+        );
+        FibonacciFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Fibonacci {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Fibonacci {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { fibonacci_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for FibonacciFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct FibonacciListQObject {}
+
+#[derive(Clone)]
+pub struct FibonacciListEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<FibonacciListQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const FibonacciListQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const FibonacciListQObject),
+}
+
+impl FibonacciListEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const FibonacciListQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.new_data_ready)(ptr, item.unwrap_or(13), item.is_some());
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by fibonacci_list_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut FibonacciList) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
 }
 
-pub struct FileSystemTreeTree {
-    qobject: *const FileSystemTreeQObject,
-    data_changed: fn(*const FileSystemTreeQObject, usize, usize),
-    begin_reset_model: fn(*const FileSystemTreeQObject),
-    end_reset_model: fn(*const FileSystemTreeQObject),
-    begin_insert_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
-    end_insert_rows: fn(*const FileSystemTreeQObject),
-    begin_remove_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
-    end_remove_rows: fn(*const FileSystemTreeQObject),
+pub struct FibonacciListList {
+    pub(crate) qobject: *const FibonacciListQObject,
+    pub(crate) data_changed: fn(*const FibonacciListQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const FibonacciListQObject),
+    pub(crate) end_reset_model: fn(*const FibonacciListQObject),
+    pub(crate) begin_insert_rows: fn(*const FibonacciListQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const FibonacciListQObject),
+    pub(crate) begin_remove_rows: fn(*const FibonacciListQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const FibonacciListQObject),
 }
 
-impl FileSystemTreeTree {
+impl FibonacciListList {
     pub fn data_changed(&self, first: usize, last: usize) {
         (self.data_changed)(self.qobject, first, last);
     }
@@ -542,273 +1232,250 @@ impl FileSystemTreeTree {
     pub fn end_reset_model(&self) {
         (self.end_reset_model)(self.qobject);
     }
-    pub fn begin_insert_rows(&self, item: Option<usize>, first: usize, last: usize) {
-        (self.begin_insert_rows)(self.qobject, item.unwrap_or(13), item.is_some(), first, last);
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
     }
     pub fn end_insert_rows(&self) {
         (self.end_insert_rows)(self.qobject);
     }
-    pub fn begin_remove_rows(&self, item: Option<usize>, first: usize, last: usize) {
-        (self.begin_remove_rows)(self.qobject, item.unwrap_or(13), item.is_some(), first, last);
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
     }
     pub fn end_remove_rows(&self) {
         (self.end_remove_rows)(self.qobject);
     }
 }
 
-pub trait FileSystemTreeTrait {
-    fn new(emit: FileSystemTreeEmitter, model: FileSystemTreeTree) -> Self;
-    fn emit(&self) -> &FileSystemTreeEmitter;
-    fn path(&self) -> Option<&str>;
-    fn set_path(&mut self, value: Option<String>);
-    fn row_count(&self, Option<usize>) -> usize;
-    fn can_fetch_more(&self, Option<usize>) -> bool {
+pub trait FibonacciListTrait {
+    fn new(emit: FibonacciListEmitter, model: FibonacciListList) -> Self;
+    fn emit(&self) -> &FibonacciListEmitter;
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
         false
     }
-    fn fetch_more(&mut self, Option<usize>) {}
+    fn fetch_more(&mut self) {}
     fn sort(&mut self, u8, SortOrder) {}
-    fn index(&self, item: Option<usize>, row: usize) -> usize;
-    fn parent(&self, item: usize) -> Option<usize>;
-    fn row(&self, item: usize) -> usize;
-    fn file_icon(&self, item: usize) -> &[u8];
-    fn file_name(&self, item: usize) -> String;
-    fn file_path(&self, item: usize) -> Option<String>;
-    fn file_permissions(&self, item: usize) -> i32;
-    fn file_size(&self, item: usize) -> Option<u64>;
-    fn file_type(&self, item: usize) -> i32;
+    fn row(&self, item: usize) -> u64;
+    fn fibonacci_number(&self, item: usize) -> u64;
 }
 
 #[no_mangle]
-pub extern "C" fn file_system_tree_new(
-    file_system_tree: *mut FileSystemTreeQObject,
-    path_changed: fn(*const FileSystemTreeQObject),
-    file_system_tree_new_data_ready: fn(*const FileSystemTreeQObject, item: usize, valid: bool),
-    file_system_tree_data_changed: fn(*const FileSystemTreeQObject, usize, usize),
-    file_system_tree_begin_reset_model: fn(*const FileSystemTreeQObject),
-    file_system_tree_end_reset_model: fn(*const FileSystemTreeQObject),
-    file_system_tree_begin_insert_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
-    file_system_tree_end_insert_rows: fn(*const FileSystemTreeQObject),
-    file_system_tree_begin_remove_rows: fn(*const FileSystemTreeQObject, item: usize, valid: bool, usize, usize),
-    file_system_tree_end_remove_rows: fn(*const FileSystemTreeQObject),
-) -> *mut FileSystemTree {
-    let file_system_tree_emit = FileSystemTreeEmitter {
-        qobject: Arc::new(Mutex::new(file_system_tree)),
-        path_changed: path_changed,
-        new_data_ready: file_system_tree_new_data_ready,
-    };
-    let model = FileSystemTreeTree {
-        qobject: file_system_tree,
-        data_changed: file_system_tree_data_changed,
-        begin_reset_model: file_system_tree_begin_reset_model,
-        end_reset_model: file_system_tree_end_reset_model,
-        begin_insert_rows: file_system_tree_begin_insert_rows,
-        end_insert_rows: file_system_tree_end_insert_rows,
-        begin_remove_rows: file_system_tree_begin_remove_rows,
-        end_remove_rows: file_system_tree_end_remove_rows,
+pub extern "C" fn fibonacci_list_new(
+    fibonacci_list: *mut FibonacciListQObject,
+    fibonacci_list_run_on_gui_thread: fn(*const FibonacciListQObject, *mut c_void),
+    fibonacci_list_new_data_ready: fn(*const FibonacciListQObject),
+    fibonacci_list_data_changed: fn(*const FibonacciListQObject, usize, usize),
+    fibonacci_list_begin_reset_model: fn(*const FibonacciListQObject),
+    fibonacci_list_end_reset_model: fn(*const FibonacciListQObject),
+    fibonacci_list_begin_insert_rows: fn(*const FibonacciListQObject, usize, usize),
+    fibonacci_list_end_insert_rows: fn(*const FibonacciListQObject),
+    fibonacci_list_begin_remove_rows: fn(*const FibonacciListQObject, usize, usize),
+    fibonacci_list_end_remove_rows: fn(*const FibonacciListQObject),
+) -> *mut FibonacciList {
+    let fibonacci_list_emit = FibonacciListEmitter {
+        qobject: Arc::new(AtomicPtr::new(fibonacci_list)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: fibonacci_list_run_on_gui_thread,
+        new_data_ready: fibonacci_list_new_data_ready,
     };
-    let d_file_system_tree = FileSystemTree::new(file_system_tree_emit, model);
-    Box::into_raw(Box::new(d_file_system_tree))
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn file_system_tree_free(ptr: *mut FileSystemTree) {
-    Box::from_raw(ptr).emit().clear();
-}
-
-#[no_mangle]
-pub extern "C" fn file_system_tree_path_get(
-    ptr: *const FileSystemTree,
-    p: *mut QString,
-    set: fn(*mut QString, *const c_char, c_int),
-) {
-    let o = unsafe { &*ptr };
-    let v = o.path();
-    if let Some(v) = v {
-        let s: *const c_char = v.as_ptr() as (*const c_char);
-        set(p, s, to_c_int(v.len()));
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn file_system_tree_path_set(ptr: *mut FileSystemTree, v: *const c_ushort, len: c_int) {
-    let o = unsafe { &mut *ptr };
-    let mut s = String::new();
-    set_string_from_utf16(&mut s, v, len);
-    o.set_path(Some(s));
+    let model = FibonacciListList {
+        qobject: fibonacci_list,
+        data_changed: fibonacci_list_data_changed,
+        begin_reset_model: fibonacci_list_begin_reset_model,
+        end_reset_model: fibonacci_list_end_reset_model,
+        begin_insert_rows: fibonacci_list_begin_insert_rows,
+        end_insert_rows: fibonacci_list_end_insert_rows,
+        begin_remove_rows: fibonacci_list_begin_remove_rows,
+        end_remove_rows: fibonacci_list_end_remove_rows,
+    };
+    let d_fibonacci_list = FibonacciList::new(fibonacci_list_emit, model);
+    Box::into_raw(Box::new(d_fibonacci_list))
 }
 
 #[no_mangle]
-pub extern "C" fn file_system_tree_path_set_none(ptr: *mut FileSystemTree) {
-    let o = unsafe { &mut *ptr };
-    o.set_path(None);
+pub unsafe extern "C" fn fibonacci_list_free(ptr: *mut FibonacciList) {
+    Box::from_raw(ptr).emit().clear();
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn file_system_tree_row_count(
-    ptr: *const FileSystemTree,
-    item: usize,
-    valid: bool,
-) -> c_int {
-    to_c_int(if valid {
-        (&*ptr).row_count(Some(item))
-    } else {
-        (&*ptr).row_count(None)
-    })
-}
-#[no_mangle]
-pub unsafe extern "C" fn file_system_tree_can_fetch_more(
-    ptr: *const FileSystemTree,
-    item: usize,
-    valid: bool,
-) -> bool {
-    if valid {
-        (&*ptr).can_fetch_more(Some(item))
-    } else {
-        (&*ptr).can_fetch_more(None)
-    }
+pub unsafe extern "C" fn fibonacci_list_run_task(ptr: *mut FibonacciList, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut FibonacciList) + Send>);
+    f(&mut *ptr);
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn file_system_tree_fetch_more(ptr: *mut FileSystemTree, item: usize, valid: bool) {
-    if valid {
-        (&mut *ptr).fetch_more(Some(item))
-    } else {
-        (&mut *ptr).fetch_more(None)
-    }
+pub unsafe extern "C" fn fibonacci_list_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut FibonacciList) + Send>));
 }
+
 #[no_mangle]
-pub unsafe extern "C" fn file_system_tree_sort(
-    ptr: *mut FileSystemTree,
-    column: u8,
-    order: SortOrder
-) {
-    (&mut *ptr).sort(column, order)
+pub unsafe extern "C" fn fibonacci_list_row_count(ptr: *const FibonacciList) -> c_int {
+    to_c_int((&*ptr).row_count())
 }
 #[no_mangle]
-pub unsafe extern "C" fn file_system_tree_index(
-    ptr: *const FileSystemTree,
-    item: usize,
-    valid: bool,
-    row: c_int,
-) -> usize {
-    if !valid {
-        (&*ptr).index(None, to_usize(row))
-    } else {
-        (&*ptr).index(Some(item), to_usize(row))
-    }
+pub unsafe extern "C" fn fibonacci_list_insert_rows(ptr: *mut FibonacciList, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
-pub unsafe extern "C" fn file_system_tree_parent(ptr: *const FileSystemTree, index: usize) -> QModelIndex {
-    if let Some(parent) = (&*ptr).parent(index) {
-        QModelIndex {
-            row: to_c_int((&*ptr).row(parent)),
-            internal_id: parent,
-        }
-    } else {
-        QModelIndex {
-            row: -1,
-            internal_id: 0,
-        }
-    }
+pub unsafe extern "C" fn fibonacci_list_remove_rows(ptr: *mut FibonacciList, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
-pub unsafe extern "C" fn file_system_tree_row(ptr: *const FileSystemTree, item: usize) -> c_int {
-    to_c_int((&*ptr).row(item))
+pub unsafe extern "C" fn fibonacci_list_can_fetch_more(ptr: *const FibonacciList) -> bool {
+    (&*ptr).can_fetch_more()
 }
-
 #[no_mangle]
-pub extern "C" fn file_system_tree_data_file_icon(
-    ptr: *const FileSystemTree, item: usize,
-    d: *mut QByteArray,
-    set: fn(*mut QByteArray, *const c_char, len: c_int),
-) {
-    let o = unsafe { &*ptr };
-    let data = o.file_icon(item);
-    let s: *const c_char = data.as_ptr() as (*const c_char);
-    set(d, s, to_c_int(data.len()));
+pub unsafe extern "C" fn fibonacci_list_fetch_more(ptr: *mut FibonacciList) {
+    (&mut *ptr).fetch_more()
 }
-
 #[no_mangle]
-pub extern "C" fn file_system_tree_data_file_name(
-    ptr: *const FileSystemTree, item: usize,
-    d: *mut QString,
-    set: fn(*mut QString, *const c_char, len: c_int),
+pub unsafe extern "C" fn fibonacci_list_sort(
+    ptr: *mut FibonacciList,
+    column: u8,
+    order: SortOrder,
 ) {
-    let o = unsafe { &*ptr };
-    let data = o.file_name(item);
-    let s: *const c_char = data.as_ptr() as (*const c_char);
-    set(d, s, to_c_int(data.len()));
+    (&mut *ptr).sort(column, order)
 }
 
 #[no_mangle]
-pub extern "C" fn file_system_tree_data_file_path(
-    ptr: *const FileSystemTree, item: usize,
-    d: *mut QString,
-    set: fn(*mut QString, *const c_char, len: c_int),
-) {
+pub extern "C" fn fibonacci_list_data_row(ptr: *const FibonacciList, row: c_int) -> u64 {
     let o = unsafe { &*ptr };
-    let data = o.file_path(item);
-    if let Some(data) = data {
-        let s: *const c_char = data.as_ptr() as (*const c_char);
-        set(d, s, to_c_int(data.len()));
-    }
+    o.row(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub extern "C" fn file_system_tree_data_file_permissions(ptr: *const FileSystemTree, item: usize) -> i32 {
+pub extern "C" fn fibonacci_list_data_fibonacci_number(ptr: *const FibonacciList, row: c_int) -> u64 {
     let o = unsafe { &*ptr };
-    o.file_permissions(item).into()
+    o.fibonacci_number(to_usize(row)).into()
 }
 
-#[no_mangle]
-pub extern "C" fn file_system_tree_data_file_size(ptr: *const FileSystemTree, item: usize) -> COption<u64> {
-    let o = unsafe { &*ptr };
-    o.file_size(item).into()
+/// Owns a FibonacciList for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct FibonacciListFixture {
+    pub ptr: *mut FibonacciList,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl FibonacciListFixture {
+    pub fn new() -> FibonacciListFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = fibonacci_list_new(
+            q as *mut FibonacciListQObject,
+            |_, task| unsafe { fibonacci_list_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        FibonacciListFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &FibonacciList {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut FibonacciList {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { fibonacci_list_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn file_system_tree_data_file_type(ptr: *const FileSystemTree, item: usize) -> i32 {
-    let o = unsafe { &*ptr };
-    o.file_type(item).into()
+#[cfg(test)]
+impl Drop for FibonacciListFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
 
 pub struct ProcessesQObject {}
 
 #[derive(Clone)]
 pub struct ProcessesEmitter {
-    qobject: Arc<Mutex<*const ProcessesQObject>>,
-    active_changed: fn(*const ProcessesQObject),
-    new_data_ready: fn(*const ProcessesQObject, item: usize, valid: bool),
+    pub(crate) qobject: Arc<AtomicPtr<ProcessesQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const ProcessesQObject, *mut c_void),
+    pub(crate) active_changed: fn(*const ProcessesQObject),
+    pub(crate) new_data_ready: fn(*const ProcessesQObject, item: usize, valid: bool),
 }
 
-unsafe impl Send for ProcessesEmitter {}
-
 impl ProcessesEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn active_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const ProcessesQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.active_changed)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by processes_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Processes) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    pub fn active_changed(&self) {
+        self.with_qobject(|ptr| (self.active_changed)(ptr));
+    }
     pub fn new_data_ready(&self, item: Option<usize>) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
+        self.with_qobject(|ptr| {
             (self.new_data_ready)(ptr, item.unwrap_or(13), item.is_some());
-        }
+        });
     }
 }
 
 pub struct ProcessesTree {
-    qobject: *const ProcessesQObject,
-    data_changed: fn(*const ProcessesQObject, usize, usize),
-    begin_reset_model: fn(*const ProcessesQObject),
-    end_reset_model: fn(*const ProcessesQObject),
-    begin_insert_rows: fn(*const ProcessesQObject, item: usize, valid: bool, usize, usize),
-    end_insert_rows: fn(*const ProcessesQObject),
-    begin_remove_rows: fn(*const ProcessesQObject, item: usize, valid: bool, usize, usize),
-    end_remove_rows: fn(*const ProcessesQObject),
+    pub(crate) qobject: *const ProcessesQObject,
+    pub(crate) data_changed: fn(*const ProcessesQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const ProcessesQObject),
+    pub(crate) end_reset_model: fn(*const ProcessesQObject),
+    pub(crate) begin_insert_rows: fn(*const ProcessesQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_insert_rows: fn(*const ProcessesQObject),
+    pub(crate) begin_remove_rows: fn(*const ProcessesQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_remove_rows: fn(*const ProcessesQObject),
 }
 
 impl ProcessesTree {
@@ -849,18 +1516,19 @@ pub trait ProcessesTrait {
     fn index(&self, item: Option<usize>, row: usize) -> usize;
     fn parent(&self, item: usize) -> Option<usize>;
     fn row(&self, item: usize) -> usize;
-    fn cmd(&self, item: usize) -> String;
-    fn cpu_percentage(&self, item: usize) -> u8;
+    fn pid(&self, item: usize) -> u32;
+    fn name(&self, item: usize) -> &str;
     fn cpu_usage(&self, item: usize) -> f32;
     fn memory(&self, item: usize) -> u64;
-    fn name(&self, item: usize) -> &str;
-    fn pid(&self, item: usize) -> u32;
     fn uid(&self, item: usize) -> u32;
+    fn cpu_percentage(&self, item: usize) -> u8;
+    fn cmd(&self, item: usize) -> String;
 }
 
 #[no_mangle]
 pub extern "C" fn processes_new(
     processes: *mut ProcessesQObject,
+    processes_run_on_gui_thread: fn(*const ProcessesQObject, *mut c_void),
     active_changed: fn(*const ProcessesQObject),
     processes_new_data_ready: fn(*const ProcessesQObject, item: usize, valid: bool),
     processes_data_changed: fn(*const ProcessesQObject, usize, usize),
@@ -872,7 +1540,10 @@ pub extern "C" fn processes_new(
     processes_end_remove_rows: fn(*const ProcessesQObject),
 ) -> *mut Processes {
     let processes_emit = ProcessesEmitter {
-        qobject: Arc::new(Mutex::new(processes)),
+        qobject: Arc::new(AtomicPtr::new(processes)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: processes_run_on_gui_thread,
         active_changed: active_changed,
         new_data_ready: processes_new_data_ready,
     };
@@ -895,6 +1566,17 @@ pub unsafe extern "C" fn processes_free(ptr: *mut Processes) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn processes_run_task(ptr: *mut Processes, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Processes) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn processes_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Processes) + Send>));
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn processes_active_get(ptr: *const Processes) -> bool {
     (&*ptr).active()
@@ -978,23 +1660,23 @@ pub unsafe extern "C" fn processes_row(ptr: *const Processes, item: usize) -> c_
 }
 
 #[no_mangle]
-pub extern "C" fn processes_data_cmd(
+pub extern "C" fn processes_data_pid(ptr: *const Processes, item: usize) -> u32 {
+    let o = unsafe { &*ptr };
+    o.pid(item).into()
+}
+
+#[no_mangle]
+pub extern "C" fn processes_data_name(
     ptr: *const Processes, item: usize,
     d: *mut QString,
     set: fn(*mut QString, *const c_char, len: c_int),
 ) {
     let o = unsafe { &*ptr };
-    let data = o.cmd(item);
+    let data = o.name(item);
     let s: *const c_char = data.as_ptr() as (*const c_char);
     set(d, s, to_c_int(data.len()));
 }
 
-#[no_mangle]
-pub extern "C" fn processes_data_cpu_percentage(ptr: *const Processes, item: usize) -> u8 {
-    let o = unsafe { &*ptr };
-    o.cpu_percentage(item).into()
-}
-
 #[no_mangle]
 pub extern "C" fn processes_data_cpu_usage(ptr: *const Processes, item: usize) -> f32 {
     let o = unsafe { &*ptr };
@@ -1008,60 +1690,142 @@ pub extern "C" fn processes_data_memory(ptr: *const Processes, item: usize) -> u
 }
 
 #[no_mangle]
-pub extern "C" fn processes_data_name(
+pub extern "C" fn processes_data_uid(ptr: *const Processes, item: usize) -> u32 {
+    let o = unsafe { &*ptr };
+    o.uid(item).into()
+}
+
+#[no_mangle]
+pub extern "C" fn processes_data_cpu_percentage(ptr: *const Processes, item: usize) -> u8 {
+    let o = unsafe { &*ptr };
+    o.cpu_percentage(item).into()
+}
+
+#[no_mangle]
+pub extern "C" fn processes_data_cmd(
     ptr: *const Processes, item: usize,
     d: *mut QString,
     set: fn(*mut QString, *const c_char, len: c_int),
 ) {
     let o = unsafe { &*ptr };
-    let data = o.name(item);
+    let data = o.cmd(item);
     let s: *const c_char = data.as_ptr() as (*const c_char);
     set(d, s, to_c_int(data.len()));
 }
 
-#[no_mangle]
-pub extern "C" fn processes_data_pid(ptr: *const Processes, item: usize) -> u32 {
-    let o = unsafe { &*ptr };
-    o.pid(item).into()
+/// Owns a Processes for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct ProcessesFixture {
+    pub ptr: *mut Processes,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl ProcessesFixture {
+    pub fn new() -> ProcessesFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = processes_new(
+            q as *mut ProcessesQObject,
+            |_, task| unsafe { processes_drop_task(task) },
+            |q| TestQObject::emit(q, "activeChanged"),
+            |q, _, _| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        ProcessesFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Processes {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Processes {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { processes_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn processes_data_uid(ptr: *const Processes, item: usize) -> u32 {
-    let o = unsafe { &*ptr };
-    o.uid(item).into()
+#[cfg(test)]
+impl Drop for ProcessesFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
 
 pub struct TimeSeriesQObject {}
 
 #[derive(Clone)]
 pub struct TimeSeriesEmitter {
-    qobject: Arc<Mutex<*const TimeSeriesQObject>>,
-    new_data_ready: fn(*const TimeSeriesQObject),
+    pub(crate) qobject: Arc<AtomicPtr<TimeSeriesQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const TimeSeriesQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const TimeSeriesQObject),
 }
 
-unsafe impl Send for TimeSeriesEmitter {}
-
 impl TimeSeriesEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const TimeSeriesQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by time_series_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut TimeSeries) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
 }
 
 pub struct TimeSeriesList {
-    qobject: *const TimeSeriesQObject,
-    data_changed: fn(*const TimeSeriesQObject, usize, usize),
-    begin_reset_model: fn(*const TimeSeriesQObject),
-    end_reset_model: fn(*const TimeSeriesQObject),
-    begin_insert_rows: fn(*const TimeSeriesQObject, usize, usize),
-    end_insert_rows: fn(*const TimeSeriesQObject),
-    begin_remove_rows: fn(*const TimeSeriesQObject, usize, usize),
-    end_remove_rows: fn(*const TimeSeriesQObject),
+    pub(crate) qobject: *const TimeSeriesQObject,
+    pub(crate) data_changed: fn(*const TimeSeriesQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const TimeSeriesQObject),
+    pub(crate) end_reset_model: fn(*const TimeSeriesQObject),
+    pub(crate) begin_insert_rows: fn(*const TimeSeriesQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const TimeSeriesQObject),
+    pub(crate) begin_remove_rows: fn(*const TimeSeriesQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const TimeSeriesQObject),
 }
 
 impl TimeSeriesList {
@@ -1099,17 +1863,18 @@ pub trait TimeSeriesTrait {
     }
     fn fetch_more(&mut self) {}
     fn sort(&mut self, u8, SortOrder) {}
-    fn cos(&self, item: usize) -> f32;
-    fn set_cos(&mut self, item: usize, f32) -> bool;
-    fn sin(&self, item: usize) -> f32;
-    fn set_sin(&mut self, item: usize, f32) -> bool;
     fn time(&self, item: usize) -> f32;
     fn set_time(&mut self, item: usize, f32) -> bool;
+    fn sin(&self, item: usize) -> f32;
+    fn set_sin(&mut self, item: usize, f32) -> bool;
+    fn cos(&self, item: usize) -> f32;
+    fn set_cos(&mut self, item: usize, f32) -> bool;
 }
 
 #[no_mangle]
 pub extern "C" fn time_series_new(
     time_series: *mut TimeSeriesQObject,
+    time_series_run_on_gui_thread: fn(*const TimeSeriesQObject, *mut c_void),
     time_series_new_data_ready: fn(*const TimeSeriesQObject),
     time_series_data_changed: fn(*const TimeSeriesQObject, usize, usize),
     time_series_begin_reset_model: fn(*const TimeSeriesQObject),
@@ -1120,7 +1885,10 @@ pub extern "C" fn time_series_new(
     time_series_end_remove_rows: fn(*const TimeSeriesQObject),
 ) -> *mut TimeSeries {
     let time_series_emit = TimeSeriesEmitter {
-        qobject: Arc::new(Mutex::new(time_series)),
+        qobject: Arc::new(AtomicPtr::new(time_series)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: time_series_run_on_gui_thread,
         new_data_ready: time_series_new_data_ready,
     };
     let model = TimeSeriesList {
@@ -1142,17 +1910,34 @@ pub unsafe extern "C" fn time_series_free(ptr: *mut TimeSeries) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn time_series_run_task(ptr: *mut TimeSeries, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut TimeSeries) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn time_series_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut TimeSeries) + Send>));
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn time_series_row_count(ptr: *const TimeSeries) -> c_int {
     to_c_int((&*ptr).row_count())
 }
 #[no_mangle]
 pub unsafe extern "C" fn time_series_insert_rows(ptr: *mut TimeSeries, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn time_series_remove_rows(ptr: *mut TimeSeries, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn time_series_can_fetch_more(ptr: *const TimeSeries) -> bool {
@@ -1172,17 +1957,17 @@ pub unsafe extern "C" fn time_series_sort(
 }
 
 #[no_mangle]
-pub extern "C" fn time_series_data_cos(ptr: *const TimeSeries, row: c_int) -> f32 {
+pub extern "C" fn time_series_data_time(ptr: *const TimeSeries, row: c_int) -> f32 {
     let o = unsafe { &*ptr };
-    o.cos(to_usize(row)).into()
+    o.time(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn time_series_set_data_cos(
+pub unsafe extern "C" fn time_series_set_data_time(
     ptr: *mut TimeSeries, row: c_int,
     v: f32,
 ) -> bool {
-    (&mut *ptr).set_cos(to_usize(row), v)
+    (&mut *ptr).set_time(to_usize(row), v)
 }
 
 #[no_mangle]
@@ -1200,15 +1985,64 @@ pub unsafe extern "C" fn time_series_set_data_sin(
 }
 
 #[no_mangle]
-pub extern "C" fn time_series_data_time(ptr: *const TimeSeries, row: c_int) -> f32 {
+pub extern "C" fn time_series_data_cos(ptr: *const TimeSeries, row: c_int) -> f32 {
     let o = unsafe { &*ptr };
-    o.time(to_usize(row)).into()
+    o.cos(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn time_series_set_data_time(
+pub unsafe extern "C" fn time_series_set_data_cos(
     ptr: *mut TimeSeries, row: c_int,
     v: f32,
 ) -> bool {
-    (&mut *ptr).set_time(to_usize(row), v)
+    (&mut *ptr).set_cos(to_usize(row), v)
+}
+
+/// Owns a TimeSeries for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct TimeSeriesFixture {
+    pub ptr: *mut TimeSeries,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl TimeSeriesFixture {
+    pub fn new() -> TimeSeriesFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = time_series_new(
+            q as *mut TimeSeriesQObject,
+            |_, task| unsafe { time_series_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        TimeSeriesFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &TimeSeries {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut TimeSeries {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { time_series_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TimeSeriesFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
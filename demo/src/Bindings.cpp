@@ -1,7 +1,36 @@
 /* generated by rust_qt_binding_generator */
 #include "Bindings.h"
+#include <QtQml/qqml.h>
+#include <QQmlEngine>
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     struct option_quintptr {
     public:
@@ -51,6 +80,10 @@ namespace {
     inline QVariant cleanNullQVariant(const QVariant& v) {
         return (v.isNull()) ?QVariant() :v;
     }
+    inline void fileSystemTreePathChanged(FileSystemTree* o)
+    {
+        emit o->pathChanged();
+    }
     inline void fibonacciInputChanged(Fibonacci* o)
     {
         emit o->inputChanged();
@@ -59,25 +92,13 @@ namespace {
     {
         emit o->resultChanged();
     }
-    inline void fileSystemTreePathChanged(FileSystemTree* o)
-    {
-        emit o->pathChanged();
-    }
     inline void processesActiveChanged(Processes* o)
     {
         emit o->activeChanged();
     }
 }
 extern "C" {
-    Demo::Private* demo_new(Demo*, Fibonacci*, void (*)(Fibonacci*), void (*)(Fibonacci*), FibonacciList*,
-        void (*)(const FibonacciList*),
-        void (*)(FibonacciList*, quintptr, quintptr),
-        void (*)(FibonacciList*),
-        void (*)(FibonacciList*),
-        void (*)(FibonacciList*, int, int),
-        void (*)(FibonacciList*),
-        void (*)(FibonacciList*, int, int),
-        void (*)(FibonacciList*), FileSystemTree*, void (*)(FileSystemTree*),
+    Demo::Private* demo_new(Demo*, void (*)(Demo*, void*), FileSystemTree*, void (*)(FileSystemTree*, void*), void (*)(FileSystemTree*),
         void (*)(const FileSystemTree*, quintptr, bool),
         void (*)(FileSystemTree*, quintptr, quintptr),
         void (*)(FileSystemTree*),
@@ -85,7 +106,15 @@ extern "C" {
         void (*)(FileSystemTree*, option_quintptr, int, int),
         void (*)(FileSystemTree*),
         void (*)(FileSystemTree*, option_quintptr, int, int),
-        void (*)(FileSystemTree*), Processes*, void (*)(Processes*),
+        void (*)(FileSystemTree*), Fibonacci*, void (*)(Fibonacci*, void*), void (*)(Fibonacci*), void (*)(Fibonacci*), FibonacciList*, void (*)(FibonacciList*, void*),
+        void (*)(const FibonacciList*),
+        void (*)(FibonacciList*, quintptr, quintptr),
+        void (*)(FibonacciList*),
+        void (*)(FibonacciList*),
+        void (*)(FibonacciList*, int, int),
+        void (*)(FibonacciList*),
+        void (*)(FibonacciList*, int, int),
+        void (*)(FibonacciList*), Processes*, void (*)(Processes*, void*), void (*)(Processes*),
         void (*)(const Processes*, quintptr, bool),
         void (*)(Processes*, quintptr, quintptr),
         void (*)(Processes*),
@@ -93,7 +122,7 @@ extern "C" {
         void (*)(Processes*, option_quintptr, int, int),
         void (*)(Processes*),
         void (*)(Processes*, option_quintptr, int, int),
-        void (*)(Processes*), TimeSeries*,
+        void (*)(Processes*), TimeSeries*, void (*)(TimeSeries*, void*),
         void (*)(const TimeSeries*),
         void (*)(TimeSeries*, quintptr, quintptr),
         void (*)(TimeSeries*),
@@ -103,125 +132,194 @@ extern "C" {
         void (*)(TimeSeries*, int, int),
         void (*)(TimeSeries*));
     void demo_free(Demo::Private*);
+    void demo_run_task(Demo::Private*, void*);
+    void demo_drop_task(void*);
+    FileSystemTree::Private* demo_file_system_tree_get(const Demo::Private*);
     Fibonacci::Private* demo_fibonacci_get(const Demo::Private*);
     FibonacciList::Private* demo_fibonacci_list_get(const Demo::Private*);
-    FileSystemTree::Private* demo_file_system_tree_get(const Demo::Private*);
     Processes::Private* demo_processes_get(const Demo::Private*);
     TimeSeries::Private* demo_time_series_get(const Demo::Private*);
 };
 
 extern "C" {
-    Fibonacci::Private* fibonacci_new(Fibonacci*, void (*)(Fibonacci*), void (*)(Fibonacci*));
-    void fibonacci_free(Fibonacci::Private*);
-    quint32 fibonacci_input_get(const Fibonacci::Private*);
-    void fibonacci_input_set(Fibonacci::Private*, quint32);
-    quint64 fibonacci_result_get(const Fibonacci::Private*);
-};
-
-extern "C" {
-    quint64 fibonacci_list_data_fibonacci_number(const FibonacciList::Private*, int);
-    quint64 fibonacci_list_data_row(const FibonacciList::Private*, int);
-    void fibonacci_list_sort(FibonacciList::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+    void file_system_tree_data_file_name(const FileSystemTree::Private*, quintptr, QString*, qstring_set);
+    void file_system_tree_data_file_icon(const FileSystemTree::Private*, quintptr, QByteArray*, qbytearray_set);
+    option_quint64 file_system_tree_data_file_size(const FileSystemTree::Private*, quintptr);
+    void file_system_tree_data_file_path(const FileSystemTree::Private*, quintptr, QString*, qstring_set);
+    qint32 file_system_tree_data_file_permissions(const FileSystemTree::Private*, quintptr);
+    qint32 file_system_tree_data_file_type(const FileSystemTree::Private*, quintptr);
+    void file_system_tree_sort(FileSystemTree::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
-    int fibonacci_list_row_count(const FibonacciList::Private*);
-    bool fibonacci_list_insert_rows(FibonacciList::Private*, int, int);
-    bool fibonacci_list_remove_rows(FibonacciList::Private*, int, int);
-    bool fibonacci_list_can_fetch_more(const FibonacciList::Private*);
-    void fibonacci_list_fetch_more(FibonacciList::Private*);
+    int file_system_tree_row_count(const FileSystemTree::Private*, quintptr, bool);
+    bool file_system_tree_can_fetch_more(const FileSystemTree::Private*, quintptr, bool);
+    void file_system_tree_fetch_more(FileSystemTree::Private*, quintptr, bool);
+    quintptr file_system_tree_index(const FileSystemTree::Private*, quintptr, bool, int);
+    qmodelindex_t file_system_tree_parent(const FileSystemTree::Private*, quintptr);
+    int file_system_tree_row(const FileSystemTree::Private*, quintptr);
 }
-int FibonacciList::columnCount(const QModelIndex &parent) const
+int FileSystemTree::columnCount(const QModelIndex &) const
 {
-    return (parent.isValid()) ? 0 : 2;
+    return 5;
 }
 
-bool FibonacciList::hasChildren(const QModelIndex &parent) const
+bool FileSystemTree::hasChildren(const QModelIndex &parent) const
 {
     return rowCount(parent) > 0;
 }
 
-int FibonacciList::rowCount(const QModelIndex &parent) const
+int FileSystemTree::rowCount(const QModelIndex &parent) const
 {
-    return (parent.isValid()) ? 0 : fibonacci_list_row_count(m_d);
+    if (parent.isValid() && parent.column() != 0) {
+        return 0;
+    }
+    return file_system_tree_row_count(m_d, parent.internalId(), parent.isValid());
 }
 
-bool FibonacciList::insertRows(int row, int count, const QModelIndex &)
+bool FileSystemTree::insertRows(int, int, const QModelIndex &)
 {
-    return fibonacci_list_insert_rows(m_d, row, count);
+    return false; // not supported yet
 }
 
-bool FibonacciList::removeRows(int row, int count, const QModelIndex &)
+bool FileSystemTree::removeRows(int, int, const QModelIndex &)
 {
-    return fibonacci_list_remove_rows(m_d, row, count);
+    return false; // not supported yet
 }
 
-QModelIndex FibonacciList::index(int row, int column, const QModelIndex &parent) const
+QModelIndex FileSystemTree::index(int row, int column, const QModelIndex &parent) const
 {
-    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 2) {
-        return createIndex(row, column, (quintptr)row);
+    if (row < 0 || column < 0 || column >= 5) {
+        return QModelIndex();
     }
-    return QModelIndex();
+    if (parent.isValid() && parent.column() != 0) {
+        return QModelIndex();
+    }
+    if (row >= rowCount(parent)) {
+        return QModelIndex();
+    }
+    const quintptr id = file_system_tree_index(m_d, parent.internalId(), parent.isValid(), row);
+    return createIndex(row, column, id);
 }
 
-QModelIndex FibonacciList::parent(const QModelIndex &) const
+QModelIndex FileSystemTree::parent(const QModelIndex &index) const
 {
-    return QModelIndex();
+    if (!index.isValid()) {
+        return QModelIndex();
+    }
+    const qmodelindex_t parent = file_system_tree_parent(m_d, index.internalId());
+    return parent.row >= 0 ?createIndex(parent.row, 0, parent.id) :QModelIndex();
 }
 
-bool FibonacciList::canFetchMore(const QModelIndex &parent) const
+bool FileSystemTree::canFetchMore(const QModelIndex &parent) const
 {
-    return (parent.isValid()) ? 0 : fibonacci_list_can_fetch_more(m_d);
+    if (parent.isValid() && parent.column() != 0) {
+        return false;
+    }
+    return file_system_tree_can_fetch_more(m_d, parent.internalId(), parent.isValid());
 }
 
-void FibonacciList::fetchMore(const QModelIndex &parent)
+void FileSystemTree::fetchMore(const QModelIndex &parent)
 {
-    if (!parent.isValid()) {
-        fibonacci_list_fetch_more(m_d);
-    }
+    file_system_tree_fetch_more(m_d, parent.internalId(), parent.isValid());
 }
 
-void FibonacciList::sort(int column, Qt::SortOrder order)
+void FileSystemTree::sort(int column, Qt::SortOrder order)
 {
-    fibonacci_list_sort(m_d, column, order);
+    file_system_tree_sort(m_d, column, order);
 }
-Qt::ItemFlags FibonacciList::flags(const QModelIndex &i) const
+Qt::ItemFlags FileSystemTree::flags(const QModelIndex &i) const
 {
     auto flags = QAbstractItemModel::flags(i);
     return flags;
 }
 
-quint64 FibonacciList::fibonacciNumber(int row) const
+QString FileSystemTree::fileName(const QModelIndex& index) const
 {
-    return fibonacci_list_data_fibonacci_number(m_d, row);
+    QString s;
+    file_system_tree_data_file_name(m_d, index.internalId(), &s, set_qstring);
+    return s;
 }
 
-quint64 FibonacciList::row(int row) const
+QByteArray FileSystemTree::fileIcon(const QModelIndex& index) const
 {
-    return fibonacci_list_data_row(m_d, row);
+    QByteArray b;
+    file_system_tree_data_file_icon(m_d, index.internalId(), &b, set_qbytearray);
+    return b;
 }
 
-QVariant FibonacciList::data(const QModelIndex &index, int role) const
+QVariant FileSystemTree::fileSize(const QModelIndex& index) const
+{
+    QVariant v;
+    v = file_system_tree_data_file_size(m_d, index.internalId());
+    return v;
+}
+
+QString FileSystemTree::filePath(const QModelIndex& index) const
+{
+    QString s;
+    file_system_tree_data_file_path(m_d, index.internalId(), &s, set_qstring);
+    return s;
+}
+
+qint32 FileSystemTree::filePermissions(const QModelIndex& index) const
+{
+    return file_system_tree_data_file_permissions(m_d, index.internalId());
+}
+
+qint32 FileSystemTree::fileType(const QModelIndex& index) const
+{
+    return file_system_tree_data_file_type(m_d, index.internalId());
+}
+
+QVariant FileSystemTree::data(const QModelIndex &index, int role) const
 {
     Q_ASSERT(rowCount(index.parent()) > index.row());
     switch (index.column()) {
     case 0:
         switch (role) {
-        case Qt::UserRole + 0:
-            return QVariant::fromValue(fibonacciNumber(index.row()));
         case Qt::DisplayRole:
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(fileName(index));
+        case Qt::DecorationRole:
         case Qt::UserRole + 1:
-            return QVariant::fromValue(row(index.row()));
+            return QVariant::fromValue(fileIcon(index));
+        case Qt::UserRole + 2:
+            return fileSize(index);
+        case Qt::UserRole + 3:
+            return cleanNullQVariant(QVariant::fromValue(filePath(index)));
+        case Qt::UserRole + 4:
+            return QVariant::fromValue(filePermissions(index));
+        case Qt::UserRole + 5:
+            return QVariant::fromValue(fileType(index));
         }
     case 1:
         switch (role) {
         case Qt::DisplayRole:
-        case Qt::UserRole + 0:
-            return QVariant::fromValue(fibonacciNumber(index.row()));
+        case Qt::UserRole + 2:
+            return fileSize(index);
+        }
+    case 2:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::UserRole + 3:
+            return cleanNullQVariant(QVariant::fromValue(filePath(index)));
+        }
+    case 3:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::UserRole + 4:
+            return QVariant::fromValue(filePermissions(index));
+        }
+    case 4:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::UserRole + 5:
+            return QVariant::fromValue(fileType(index));
         }
     }
     return QVariant();
 }
 
-int FibonacciList::role(const char* name) const {
+int FileSystemTree::role(const char* name) const {
     auto names = roleNames();
     auto i = names.constBegin();
     while (i != names.constEnd()) {
@@ -232,13 +330,17 @@ int FibonacciList::role(const char* name) const {
     }
     return -1;
 }
-QHash<int, QByteArray> FibonacciList::roleNames() const {
+QHash<int, QByteArray> FileSystemTree::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
-    names.insert(Qt::UserRole + 0, "fibonacciNumber");
-    names.insert(Qt::UserRole + 1, "row");
+    names.insert(Qt::UserRole + 0, "fileName");
+    names.insert(Qt::UserRole + 1, "fileIcon");
+    names.insert(Qt::UserRole + 2, "fileSize");
+    names.insert(Qt::UserRole + 3, "filePath");
+    names.insert(Qt::UserRole + 4, "filePermissions");
+    names.insert(Qt::UserRole + 5, "fileType");
     return names;
 }
-QVariant FibonacciList::headerData(int section, Qt::Orientation orientation, int role) const
+QVariant FileSystemTree::headerData(int section, Qt::Orientation orientation, int role) const
 {
     if (orientation != Qt::Horizontal) {
         return QVariant();
@@ -246,7 +348,7 @@ QVariant FibonacciList::headerData(int section, Qt::Orientation orientation, int
     return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
 }
 
-bool FibonacciList::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+bool FileSystemTree::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
 {
     if (orientation != Qt::Horizontal) {
         return false;
@@ -256,197 +358,137 @@ bool FibonacciList::setHeaderData(int section, Qt::Orientation orientation, cons
 }
 
 extern "C" {
-    FibonacciList::Private* fibonacci_list_new(FibonacciList*,
-        void (*)(const FibonacciList*),
-        void (*)(FibonacciList*, quintptr, quintptr),
-        void (*)(FibonacciList*),
-        void (*)(FibonacciList*),
-        void (*)(FibonacciList*, int, int),
-        void (*)(FibonacciList*),
-        void (*)(FibonacciList*, int, int),
-        void (*)(FibonacciList*));
-    void fibonacci_list_free(FibonacciList::Private*);
+    FileSystemTree::Private* file_system_tree_new(FileSystemTree*, void (*)(FileSystemTree*, void*), void (*)(FileSystemTree*),
+        void (*)(const FileSystemTree*, quintptr, bool),
+        void (*)(FileSystemTree*, quintptr, quintptr),
+        void (*)(FileSystemTree*),
+        void (*)(FileSystemTree*),
+        void (*)(FileSystemTree*, option_quintptr, int, int),
+        void (*)(FileSystemTree*),
+        void (*)(FileSystemTree*, option_quintptr, int, int),
+        void (*)(FileSystemTree*));
+    void file_system_tree_free(FileSystemTree::Private*);
+    void file_system_tree_run_task(FileSystemTree::Private*, void*);
+    void file_system_tree_drop_task(void*);
+    void file_system_tree_path_get(const FileSystemTree::Private*, QString*, qstring_set);
+    void file_system_tree_path_set(FileSystemTree::Private*, const ushort *str, int len);
+    void file_system_tree_path_set_none(FileSystemTree::Private*);
 };
 
 extern "C" {
-    void file_system_tree_data_file_icon(const FileSystemTree::Private*, quintptr, QByteArray*, qbytearray_set);
-    void file_system_tree_data_file_name(const FileSystemTree::Private*, quintptr, QString*, qstring_set);
-    void file_system_tree_data_file_path(const FileSystemTree::Private*, quintptr, QString*, qstring_set);
-    qint32 file_system_tree_data_file_permissions(const FileSystemTree::Private*, quintptr);
-    option_quint64 file_system_tree_data_file_size(const FileSystemTree::Private*, quintptr);
-    qint32 file_system_tree_data_file_type(const FileSystemTree::Private*, quintptr);
-    void file_system_tree_sort(FileSystemTree::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+    Fibonacci::Private* fibonacci_new(Fibonacci*, void (*)(Fibonacci*, void*), void (*)(Fibonacci*), void (*)(Fibonacci*));
+    void fibonacci_free(Fibonacci::Private*);
+    void fibonacci_run_task(Fibonacci::Private*, void*);
+    void fibonacci_drop_task(void*);
+    quint32 fibonacci_input_get(const Fibonacci::Private*);
+    void fibonacci_input_set(Fibonacci::Private*, quint32);
+    quint64 fibonacci_result_get(const Fibonacci::Private*);
+};
 
-    int file_system_tree_row_count(const FileSystemTree::Private*, quintptr, bool);
-    bool file_system_tree_can_fetch_more(const FileSystemTree::Private*, quintptr, bool);
-    void file_system_tree_fetch_more(FileSystemTree::Private*, quintptr, bool);
-    quintptr file_system_tree_index(const FileSystemTree::Private*, quintptr, bool, int);
-    qmodelindex_t file_system_tree_parent(const FileSystemTree::Private*, quintptr);
-    int file_system_tree_row(const FileSystemTree::Private*, quintptr);
-}
-int FileSystemTree::columnCount(const QModelIndex &) const
-{
-    return 5;
-}
+extern "C" {
+    quint64 fibonacci_list_data_row(const FibonacciList::Private*, int);
+    quint64 fibonacci_list_data_fibonacci_number(const FibonacciList::Private*, int);
+    void fibonacci_list_sort(FibonacciList::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
-bool FileSystemTree::hasChildren(const QModelIndex &parent) const
-{
-    return rowCount(parent) > 0;
+    int fibonacci_list_row_count(const FibonacciList::Private*);
+    bool fibonacci_list_insert_rows(FibonacciList::Private*, int, int);
+    bool fibonacci_list_remove_rows(FibonacciList::Private*, int, int);
+    bool fibonacci_list_can_fetch_more(const FibonacciList::Private*);
+    void fibonacci_list_fetch_more(FibonacciList::Private*);
 }
-
-int FileSystemTree::rowCount(const QModelIndex &parent) const
+int FibonacciList::columnCount(const QModelIndex &parent) const
 {
-    if (parent.isValid() && parent.column() != 0) {
-        return 0;
-    }
-    return file_system_tree_row_count(m_d, parent.internalId(), parent.isValid());
+    return (parent.isValid()) ? 0 : 2;
 }
 
-bool FileSystemTree::insertRows(int, int, const QModelIndex &)
+bool FibonacciList::hasChildren(const QModelIndex &parent) const
 {
-    return false; // not supported yet
+    return rowCount(parent) > 0;
 }
 
-bool FileSystemTree::removeRows(int, int, const QModelIndex &)
+int FibonacciList::rowCount(const QModelIndex &parent) const
 {
-    return false; // not supported yet
+    return (parent.isValid()) ? 0 : fibonacci_list_row_count(m_d);
 }
 
-QModelIndex FileSystemTree::index(int row, int column, const QModelIndex &parent) const
+bool FibonacciList::insertRows(int row, int count, const QModelIndex &)
 {
-    if (row < 0 || column < 0 || column >= 5) {
-        return QModelIndex();
-    }
-    if (parent.isValid() && parent.column() != 0) {
-        return QModelIndex();
-    }
-    if (row >= rowCount(parent)) {
-        return QModelIndex();
-    }
-    const quintptr id = file_system_tree_index(m_d, parent.internalId(), parent.isValid(), row);
-    return createIndex(row, column, id);
+    return fibonacci_list_insert_rows(m_d, row, count);
 }
 
-QModelIndex FileSystemTree::parent(const QModelIndex &index) const
+bool FibonacciList::removeRows(int row, int count, const QModelIndex &)
 {
-    if (!index.isValid()) {
-        return QModelIndex();
-    }
-    const qmodelindex_t parent = file_system_tree_parent(m_d, index.internalId());
-    return parent.row >= 0 ?createIndex(parent.row, 0, parent.id) :QModelIndex();
+    return fibonacci_list_remove_rows(m_d, row, count);
 }
 
-bool FileSystemTree::canFetchMore(const QModelIndex &parent) const
+QModelIndex FibonacciList::index(int row, int column, const QModelIndex &parent) const
 {
-    if (parent.isValid() && parent.column() != 0) {
-        return false;
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 2) {
+        return createIndex(row, column, (quintptr)row);
     }
-    return file_system_tree_can_fetch_more(m_d, parent.internalId(), parent.isValid());
-}
-
-void FileSystemTree::fetchMore(const QModelIndex &parent)
-{
-    file_system_tree_fetch_more(m_d, parent.internalId(), parent.isValid());
-}
-
-void FileSystemTree::sort(int column, Qt::SortOrder order)
-{
-    file_system_tree_sort(m_d, column, order);
-}
-Qt::ItemFlags FileSystemTree::flags(const QModelIndex &i) const
-{
-    auto flags = QAbstractItemModel::flags(i);
-    return flags;
+    return QModelIndex();
 }
 
-QByteArray FileSystemTree::fileIcon(const QModelIndex& index) const
+QModelIndex FibonacciList::parent(const QModelIndex &) const
 {
-    QByteArray b;
-    file_system_tree_data_file_icon(m_d, index.internalId(), &b, set_qbytearray);
-    return b;
+    return QModelIndex();
 }
 
-QString FileSystemTree::fileName(const QModelIndex& index) const
+bool FibonacciList::canFetchMore(const QModelIndex &parent) const
 {
-    QString s;
-    file_system_tree_data_file_name(m_d, index.internalId(), &s, set_qstring);
-    return s;
+    return (parent.isValid()) ? 0 : fibonacci_list_can_fetch_more(m_d);
 }
 
-QString FileSystemTree::filePath(const QModelIndex& index) const
+void FibonacciList::fetchMore(const QModelIndex &parent)
 {
-    QString s;
-    file_system_tree_data_file_path(m_d, index.internalId(), &s, set_qstring);
-    return s;
+    if (!parent.isValid()) {
+        fibonacci_list_fetch_more(m_d);
+    }
 }
 
-qint32 FileSystemTree::filePermissions(const QModelIndex& index) const
+void FibonacciList::sort(int column, Qt::SortOrder order)
 {
-    return file_system_tree_data_file_permissions(m_d, index.internalId());
+    fibonacci_list_sort(m_d, column, order);
+}
+Qt::ItemFlags FibonacciList::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    return flags;
 }
 
-QVariant FileSystemTree::fileSize(const QModelIndex& index) const
+quint64 FibonacciList::row(int row) const
 {
-    QVariant v;
-    v = file_system_tree_data_file_size(m_d, index.internalId());
-    return v;
+    return fibonacci_list_data_row(m_d, row);
 }
 
-qint32 FileSystemTree::fileType(const QModelIndex& index) const
+quint64 FibonacciList::fibonacciNumber(int row) const
 {
-    return file_system_tree_data_file_type(m_d, index.internalId());
+    return fibonacci_list_data_fibonacci_number(m_d, row);
 }
 
-QVariant FileSystemTree::data(const QModelIndex &index, int role) const
+QVariant FibonacciList::data(const QModelIndex &index, int role) const
 {
     Q_ASSERT(rowCount(index.parent()) > index.row());
     switch (index.column()) {
     case 0:
         switch (role) {
-        case Qt::DecorationRole:
-        case Qt::UserRole + 0:
-            return QVariant::fromValue(fileIcon(index));
         case Qt::DisplayRole:
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(row(index.row()));
         case Qt::UserRole + 1:
-            return QVariant::fromValue(fileName(index));
-        case Qt::UserRole + 2:
-            return cleanNullQVariant(QVariant::fromValue(filePath(index)));
-        case Qt::UserRole + 3:
-            return QVariant::fromValue(filePermissions(index));
-        case Qt::UserRole + 4:
-            return fileSize(index);
-        case Qt::UserRole + 5:
-            return QVariant::fromValue(fileType(index));
+            return QVariant::fromValue(fibonacciNumber(index.row()));
         }
     case 1:
         switch (role) {
         case Qt::DisplayRole:
-        case Qt::UserRole + 4:
-            return fileSize(index);
-        }
-    case 2:
-        switch (role) {
-        case Qt::DisplayRole:
-        case Qt::UserRole + 2:
-            return cleanNullQVariant(QVariant::fromValue(filePath(index)));
-        }
-    case 3:
-        switch (role) {
-        case Qt::DisplayRole:
-        case Qt::UserRole + 3:
-            return QVariant::fromValue(filePermissions(index));
-        }
-    case 4:
-        switch (role) {
-        case Qt::DisplayRole:
-        case Qt::UserRole + 5:
-            return QVariant::fromValue(fileType(index));
+        case Qt::UserRole + 1:
+            return QVariant::fromValue(fibonacciNumber(index.row()));
         }
     }
     return QVariant();
 }
 
-int FileSystemTree::role(const char* name) const {
+int FibonacciList::role(const char* name) const {
     auto names = roleNames();
     auto i = names.constBegin();
     while (i != names.constEnd()) {
@@ -457,17 +499,13 @@ int FileSystemTree::role(const char* name) const {
     }
     return -1;
 }
-QHash<int, QByteArray> FileSystemTree::roleNames() const {
+QHash<int, QByteArray> FibonacciList::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
-    names.insert(Qt::UserRole + 0, "fileIcon");
-    names.insert(Qt::UserRole + 1, "fileName");
-    names.insert(Qt::UserRole + 2, "filePath");
-    names.insert(Qt::UserRole + 3, "filePermissions");
-    names.insert(Qt::UserRole + 4, "fileSize");
-    names.insert(Qt::UserRole + 5, "fileType");
+    names.insert(Qt::UserRole + 0, "row");
+    names.insert(Qt::UserRole + 1, "fibonacciNumber");
     return names;
 }
-QVariant FileSystemTree::headerData(int section, Qt::Orientation orientation, int role) const
+QVariant FibonacciList::headerData(int section, Qt::Orientation orientation, int role) const
 {
     if (orientation != Qt::Horizontal) {
         return QVariant();
@@ -475,7 +513,7 @@ QVariant FileSystemTree::headerData(int section, Qt::Orientation orientation, in
     return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
 }
 
-bool FileSystemTree::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+bool FibonacciList::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
 {
     if (orientation != Qt::Horizontal) {
         return false;
@@ -485,29 +523,28 @@ bool FileSystemTree::setHeaderData(int section, Qt::Orientation orientation, con
 }
 
 extern "C" {
-    FileSystemTree::Private* file_system_tree_new(FileSystemTree*, void (*)(FileSystemTree*),
-        void (*)(const FileSystemTree*, quintptr, bool),
-        void (*)(FileSystemTree*, quintptr, quintptr),
-        void (*)(FileSystemTree*),
-        void (*)(FileSystemTree*),
-        void (*)(FileSystemTree*, option_quintptr, int, int),
-        void (*)(FileSystemTree*),
-        void (*)(FileSystemTree*, option_quintptr, int, int),
-        void (*)(FileSystemTree*));
-    void file_system_tree_free(FileSystemTree::Private*);
-    void file_system_tree_path_get(const FileSystemTree::Private*, QString*, qstring_set);
-    void file_system_tree_path_set(FileSystemTree::Private*, const ushort *str, int len);
-    void file_system_tree_path_set_none(FileSystemTree::Private*);
+    FibonacciList::Private* fibonacci_list_new(FibonacciList*, void (*)(FibonacciList*, void*),
+        void (*)(const FibonacciList*),
+        void (*)(FibonacciList*, quintptr, quintptr),
+        void (*)(FibonacciList*),
+        void (*)(FibonacciList*),
+        void (*)(FibonacciList*, int, int),
+        void (*)(FibonacciList*),
+        void (*)(FibonacciList*, int, int),
+        void (*)(FibonacciList*));
+    void fibonacci_list_free(FibonacciList::Private*);
+    void fibonacci_list_run_task(FibonacciList::Private*, void*);
+    void fibonacci_list_drop_task(void*);
 };
 
 extern "C" {
-    void processes_data_cmd(const Processes::Private*, quintptr, QString*, qstring_set);
-    quint8 processes_data_cpu_percentage(const Processes::Private*, quintptr);
+    quint32 processes_data_pid(const Processes::Private*, quintptr);
+    void processes_data_name(const Processes::Private*, quintptr, QString*, qstring_set);
     float processes_data_cpu_usage(const Processes::Private*, quintptr);
     quint64 processes_data_memory(const Processes::Private*, quintptr);
-    void processes_data_name(const Processes::Private*, quintptr, QString*, qstring_set);
-    quint32 processes_data_pid(const Processes::Private*, quintptr);
     quint32 processes_data_uid(const Processes::Private*, quintptr);
+    quint8 processes_data_cpu_percentage(const Processes::Private*, quintptr);
+    void processes_data_cmd(const Processes::Private*, quintptr, QString*, qstring_set);
     void processes_sort(Processes::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
     int processes_row_count(const Processes::Private*, quintptr, bool);
@@ -592,16 +629,16 @@ Qt::ItemFlags Processes::flags(const QModelIndex &i) const
     return flags;
 }
 
-QString Processes::cmd(const QModelIndex& index) const
+quint32 Processes::pid(const QModelIndex& index) const
 {
-    QString s;
-    processes_data_cmd(m_d, index.internalId(), &s, set_qstring);
-    return s;
+    return processes_data_pid(m_d, index.internalId());
 }
 
-quint8 Processes::cpuPercentage(const QModelIndex& index) const
+QString Processes::name(const QModelIndex& index) const
 {
-    return processes_data_cpu_percentage(m_d, index.internalId());
+    QString s;
+    processes_data_name(m_d, index.internalId(), &s, set_qstring);
+    return s;
 }
 
 float Processes::cpuUsage(const QModelIndex& index) const
@@ -614,21 +651,21 @@ quint64 Processes::memory(const QModelIndex& index) const
     return processes_data_memory(m_d, index.internalId());
 }
 
-QString Processes::name(const QModelIndex& index) const
+quint32 Processes::uid(const QModelIndex& index) const
 {
-    QString s;
-    processes_data_name(m_d, index.internalId(), &s, set_qstring);
-    return s;
+    return processes_data_uid(m_d, index.internalId());
 }
 
-quint32 Processes::pid(const QModelIndex& index) const
+quint8 Processes::cpuPercentage(const QModelIndex& index) const
 {
-    return processes_data_pid(m_d, index.internalId());
+    return processes_data_cpu_percentage(m_d, index.internalId());
 }
 
-quint32 Processes::uid(const QModelIndex& index) const
+QString Processes::cmd(const QModelIndex& index) const
 {
-    return processes_data_uid(m_d, index.internalId());
+    QString s;
+    processes_data_cmd(m_d, index.internalId(), &s, set_qstring);
+    return s;
 }
 
 QVariant Processes::data(const QModelIndex &index, int role) const
@@ -637,22 +674,22 @@ QVariant Processes::data(const QModelIndex &index, int role) const
     switch (index.column()) {
     case 0:
         switch (role) {
+        case Qt::ToolTipRole:
         case Qt::UserRole + 0:
-            return QVariant::fromValue(cmd(index));
+            return QVariant::fromValue(pid(index));
+        case Qt::DisplayRole:
         case Qt::UserRole + 1:
-            return QVariant::fromValue(cpuPercentage(index));
+            return QVariant::fromValue(name(index));
         case Qt::UserRole + 2:
             return QVariant::fromValue(cpuUsage(index));
         case Qt::UserRole + 3:
             return QVariant::fromValue(memory(index));
-        case Qt::DisplayRole:
         case Qt::UserRole + 4:
-            return QVariant::fromValue(name(index));
-        case Qt::ToolTipRole:
+            return QVariant::fromValue(uid(index));
         case Qt::UserRole + 5:
-            return QVariant::fromValue(pid(index));
+            return QVariant::fromValue(cpuPercentage(index));
         case Qt::UserRole + 6:
-            return QVariant::fromValue(uid(index));
+            return QVariant::fromValue(cmd(index));
         }
     case 1:
         switch (role) {
@@ -683,13 +720,13 @@ int Processes::role(const char* name) const {
 }
 QHash<int, QByteArray> Processes::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
-    names.insert(Qt::UserRole + 0, "cmd");
-    names.insert(Qt::UserRole + 1, "cpuPercentage");
+    names.insert(Qt::UserRole + 0, "pid");
+    names.insert(Qt::UserRole + 1, "name");
     names.insert(Qt::UserRole + 2, "cpuUsage");
     names.insert(Qt::UserRole + 3, "memory");
-    names.insert(Qt::UserRole + 4, "name");
-    names.insert(Qt::UserRole + 5, "pid");
-    names.insert(Qt::UserRole + 6, "uid");
+    names.insert(Qt::UserRole + 4, "uid");
+    names.insert(Qt::UserRole + 5, "cpuPercentage");
+    names.insert(Qt::UserRole + 6, "cmd");
     return names;
 }
 QVariant Processes::headerData(int section, Qt::Orientation orientation, int role) const
@@ -710,7 +747,7 @@ bool Processes::setHeaderData(int section, Qt::Orientation orientation, const QV
 }
 
 extern "C" {
-    Processes::Private* processes_new(Processes*, void (*)(Processes*),
+    Processes::Private* processes_new(Processes*, void (*)(Processes*, void*), void (*)(Processes*),
         void (*)(const Processes*, quintptr, bool),
         void (*)(Processes*, quintptr, quintptr),
         void (*)(Processes*),
@@ -720,17 +757,19 @@ extern "C" {
         void (*)(Processes*, option_quintptr, int, int),
         void (*)(Processes*));
     void processes_free(Processes::Private*);
+    void processes_run_task(Processes::Private*, void*);
+    void processes_drop_task(void*);
     bool processes_active_get(const Processes::Private*);
     void processes_active_set(Processes::Private*, bool);
 };
 
 extern "C" {
-    float time_series_data_cos(const TimeSeries::Private*, int);
-    bool time_series_set_data_cos(TimeSeries::Private*, int, float);
-    float time_series_data_sin(const TimeSeries::Private*, int);
-    bool time_series_set_data_sin(TimeSeries::Private*, int, float);
     float time_series_data_time(const TimeSeries::Private*, int);
     bool time_series_set_data_time(TimeSeries::Private*, int, float);
+    float time_series_data_sin(const TimeSeries::Private*, int);
+    bool time_series_set_data_sin(TimeSeries::Private*, int, float);
+    float time_series_data_cos(const TimeSeries::Private*, int);
+    bool time_series_set_data_cos(TimeSeries::Private*, int, float);
     void time_series_sort(TimeSeries::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
     int time_series_row_count(const TimeSeries::Private*);
@@ -808,15 +847,15 @@ Qt::ItemFlags TimeSeries::flags(const QModelIndex &i) const
     return flags;
 }
 
-float TimeSeries::cos(int row) const
+float TimeSeries::time(int row) const
 {
-    return time_series_data_cos(m_d, row);
+    return time_series_data_time(m_d, row);
 }
 
-bool TimeSeries::setCos(int row, float value)
+bool TimeSeries::setTime(int row, float value)
 {
     bool set = false;
-    set = time_series_set_data_cos(m_d, row, value);
+    set = time_series_set_data_time(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -840,15 +879,15 @@ bool TimeSeries::setSin(int row, float value)
     return set;
 }
 
-float TimeSeries::time(int row) const
+float TimeSeries::cos(int row) const
 {
-    return time_series_data_time(m_d, row);
+    return time_series_data_cos(m_d, row);
 }
 
-bool TimeSeries::setTime(int row, float value)
+bool TimeSeries::setCos(int row, float value)
 {
     bool set = false;
-    set = time_series_set_data_time(m_d, row, value);
+    set = time_series_set_data_cos(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -862,14 +901,14 @@ QVariant TimeSeries::data(const QModelIndex &index, int role) const
     switch (index.column()) {
     case 0:
         switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
         case Qt::UserRole + 0:
-            return QVariant::fromValue(cos(index.row()));
+            return QVariant::fromValue(time(index.row()));
         case Qt::UserRole + 1:
             return QVariant::fromValue(sin(index.row()));
-        case Qt::DisplayRole:
-        case Qt::EditRole:
         case Qt::UserRole + 2:
-            return QVariant::fromValue(time(index.row()));
+            return QVariant::fromValue(cos(index.row()));
         }
     case 1:
         switch (role) {
@@ -882,7 +921,7 @@ QVariant TimeSeries::data(const QModelIndex &index, int role) const
         switch (role) {
         case Qt::DisplayRole:
         case Qt::EditRole:
-        case Qt::UserRole + 0:
+        case Qt::UserRole + 2:
             return QVariant::fromValue(cos(index.row()));
         }
     }
@@ -902,9 +941,9 @@ int TimeSeries::role(const char* name) const {
 }
 QHash<int, QByteArray> TimeSeries::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
-    names.insert(Qt::UserRole + 0, "cos");
+    names.insert(Qt::UserRole + 0, "time");
     names.insert(Qt::UserRole + 1, "sin");
-    names.insert(Qt::UserRole + 2, "time");
+    names.insert(Qt::UserRole + 2, "cos");
     return names;
 }
 QVariant TimeSeries::headerData(int section, Qt::Orientation orientation, int role) const
@@ -927,9 +966,9 @@ bool TimeSeries::setHeaderData(int section, Qt::Orientation orientation, const Q
 bool TimeSeries::setData(const QModelIndex &index, const QVariant &value, int role)
 {
     if (index.column() == 0) {
-        if (role == Qt::UserRole + 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
             if (value.canConvert(qMetaTypeId<float>())) {
-                return setCos(index.row(), value.value<float>());
+                return setTime(index.row(), value.value<float>());
             }
         }
         if (role == Qt::UserRole + 1) {
@@ -937,9 +976,9 @@ bool TimeSeries::setData(const QModelIndex &index, const QVariant &value, int ro
                 return setSin(index.row(), value.value<float>());
             }
         }
-        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 2) {
+        if (role == Qt::UserRole + 2) {
             if (value.canConvert(qMetaTypeId<float>())) {
-                return setTime(index.row(), value.value<float>());
+                return setCos(index.row(), value.value<float>());
             }
         }
     }
@@ -951,7 +990,7 @@ bool TimeSeries::setData(const QModelIndex &index, const QVariant &value, int ro
         }
     }
     if (index.column() == 2) {
-        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 2) {
             if (value.canConvert(qMetaTypeId<float>())) {
                 return setCos(index.row(), value.value<float>());
             }
@@ -961,7 +1000,7 @@ bool TimeSeries::setData(const QModelIndex &index, const QVariant &value, int ro
 }
 
 extern "C" {
-    TimeSeries::Private* time_series_new(TimeSeries*,
+    TimeSeries::Private* time_series_new(TimeSeries*, void (*)(TimeSeries*, void*),
         void (*)(const TimeSeries*),
         void (*)(TimeSeries*, quintptr, quintptr),
         void (*)(TimeSeries*),
@@ -971,56 +1010,49 @@ extern "C" {
         void (*)(TimeSeries*, int, int),
         void (*)(TimeSeries*));
     void time_series_free(TimeSeries::Private*);
+    void time_series_run_task(TimeSeries::Private*, void*);
+    void time_series_drop_task(void*);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
 };
 
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xa48d9cc2b855f6ad)) {
+            qFatal("Bindings.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Demo::Demo(bool /*owned*/, QObject *parent):
     QObject(parent),
+    m_fileSystemTree(new FileSystemTree(false, this)),
     m_fibonacci(new Fibonacci(false, this)),
     m_fibonacciList(new FibonacciList(false, this)),
-    m_fileSystemTree(new FileSystemTree(false, this)),
     m_processes(new Processes(false, this)),
     m_timeSeries(new TimeSeries(false, this)),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 Demo::Demo(QObject *parent):
     QObject(parent),
+    m_fileSystemTree(new FileSystemTree(false, this)),
     m_fibonacci(new Fibonacci(false, this)),
     m_fibonacciList(new FibonacciList(false, this)),
-    m_fileSystemTree(new FileSystemTree(false, this)),
     m_processes(new Processes(false, this)),
     m_timeSeries(new TimeSeries(false, this)),
-    m_d(demo_new(this, m_fibonacci,
-        fibonacciInputChanged,
-        fibonacciResultChanged, m_fibonacciList,
-        [](const FibonacciList* o) {
-            emit o->newDataReady(QModelIndex());
-        },
-        [](FibonacciList* o, quintptr first, quintptr last) {
-            o->dataChanged(o->createIndex(first, 0, first),
-                       o->createIndex(last, 1, last));
-        },
-        [](FibonacciList* o) {
-            o->beginResetModel();
-        },
-        [](FibonacciList* o) {
-            o->endResetModel();
-        },
-        [](FibonacciList* o, int first, int last) {
-            o->beginInsertRows(QModelIndex(), first, last);
-        },
-        [](FibonacciList* o) {
-            o->endInsertRows();
-        },
-        [](FibonacciList* o, int first, int last) {
-            o->beginRemoveRows(QModelIndex(), first, last);
-        },
-        [](FibonacciList* o) {
-            o->endRemoveRows();
-        }
-, m_fileSystemTree,
+    m_d((checkBindingHash(), demo_new(this,
+        Demo::postTask, m_fileSystemTree,
+        FileSystemTree::postTask,
         fileSystemTreePathChanged,
         [](const FileSystemTree* o, quintptr id, bool valid) {
             if (valid) {
@@ -1042,29 +1074,60 @@ Demo::Demo(QObject *parent):
         [](FileSystemTree* o) {
             o->endResetModel();
         },
-        [](FileSystemTree* o, option_quintptr id, int first, int last) {
-            if (id.some) {
-                int row = file_system_tree_row(o->m_d, id.value);
-                o->beginInsertRows(o->createIndex(row, 0, id.value), first, last);
-            } else {
-                o->beginInsertRows(QModelIndex(), first, last);
-            }
+        [](FileSystemTree* o, option_quintptr id, int first, int last) {
+            if (id.some) {
+                int row = file_system_tree_row(o->m_d, id.value);
+                o->beginInsertRows(o->createIndex(row, 0, id.value), first, last);
+            } else {
+                o->beginInsertRows(QModelIndex(), first, last);
+            }
+        },
+        [](FileSystemTree* o) {
+            o->endInsertRows();
+        },
+        [](FileSystemTree* o, option_quintptr id, int first, int last) {
+            if (id.some) {
+                int row = file_system_tree_row(o->m_d, id.value);
+                o->beginRemoveRows(o->createIndex(row, 0, id.value), first, last);
+            } else {
+                o->beginRemoveRows(QModelIndex(), first, last);
+            }
+        },
+        [](FileSystemTree* o) {
+            o->endRemoveRows();
+        }
+, m_fibonacci,
+        Fibonacci::postTask,
+        fibonacciInputChanged,
+        fibonacciResultChanged, m_fibonacciList,
+        FibonacciList::postTask,
+        [](const FibonacciList* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](FibonacciList* o, quintptr first, quintptr last) {
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 1, last));
+        },
+        [](FibonacciList* o) {
+            o->beginResetModel();
+        },
+        [](FibonacciList* o) {
+            o->endResetModel();
+        },
+        [](FibonacciList* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
         },
-        [](FileSystemTree* o) {
+        [](FibonacciList* o) {
             o->endInsertRows();
         },
-        [](FileSystemTree* o, option_quintptr id, int first, int last) {
-            if (id.some) {
-                int row = file_system_tree_row(o->m_d, id.value);
-                o->beginRemoveRows(o->createIndex(row, 0, id.value), first, last);
-            } else {
-                o->beginRemoveRows(QModelIndex(), first, last);
-            }
+        [](FibonacciList* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
         },
-        [](FileSystemTree* o) {
+        [](FibonacciList* o) {
             o->endRemoveRows();
         }
 , m_processes,
+        Processes::postTask,
         processesActiveChanged,
         [](const Processes* o, quintptr id, bool valid) {
             if (valid) {
@@ -1109,6 +1172,7 @@ Demo::Demo(QObject *parent):
             o->endRemoveRows();
         }
 , m_timeSeries,
+        TimeSeries::postTask,
         [](const TimeSeries* o) {
             emit o->newDataReady(QModelIndex());
         },
@@ -1134,20 +1198,20 @@ Demo::Demo(QObject *parent):
         [](TimeSeries* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
+    m_fileSystemTree->m_d = demo_file_system_tree_get(m_d);
     m_fibonacci->m_d = demo_fibonacci_get(m_d);
     m_fibonacciList->m_d = demo_fibonacci_list_get(m_d);
-    m_fileSystemTree->m_d = demo_file_system_tree_get(m_d);
     m_processes->m_d = demo_processes_get(m_d);
     m_timeSeries->m_d = demo_time_series_get(m_d);
-    connect(this->m_fibonacciList, &FibonacciList::newDataReady, this->m_fibonacciList, [this](const QModelIndex& i) {
-        this->m_fibonacciList->fetchMore(i);
-    }, Qt::QueuedConnection);
     connect(this->m_fileSystemTree, &FileSystemTree::newDataReady, this->m_fileSystemTree, [this](const QModelIndex& i) {
         this->m_fileSystemTree->fetchMore(i);
     }, Qt::QueuedConnection);
+    connect(this->m_fibonacciList, &FibonacciList::newDataReady, this->m_fibonacciList, [this](const QModelIndex& i) {
+        this->m_fibonacciList->fetchMore(i);
+    }, Qt::QueuedConnection);
     connect(this->m_processes, &Processes::newDataReady, this->m_processes, [this](const QModelIndex& i) {
         this->m_processes->fetchMore(i);
     }, Qt::QueuedConnection);
@@ -1161,6 +1225,28 @@ Demo::~Demo() {
         demo_free(m_d);
     }
 }
+
+bool Demo::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        demo_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Demo::postTask(Demo* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, demo_drop_task));
+}
+const FileSystemTree* Demo::fileSystemTree() const
+{
+    return m_fileSystemTree;
+}
+FileSystemTree* Demo::fileSystemTree()
+{
+    return m_fileSystemTree;
+}
 const Fibonacci* Demo::fibonacci() const
 {
     return m_fibonacci;
@@ -1177,14 +1263,6 @@ FibonacciList* Demo::fibonacciList()
 {
     return m_fibonacciList;
 }
-const FileSystemTree* Demo::fileSystemTree() const
-{
-    return m_fileSystemTree;
-}
-FileSystemTree* Demo::fileSystemTree()
-{
-    return m_fileSystemTree;
-}
 const Processes* Demo::processes() const
 {
     return m_processes;
@@ -1201,103 +1279,19 @@ TimeSeries* Demo::timeSeries()
 {
     return m_timeSeries;
 }
-Fibonacci::Fibonacci(bool /*owned*/, QObject *parent):
-    QObject(parent),
-    m_d(0),
-    m_ownsPrivate(false)
-{
-}
-
-Fibonacci::Fibonacci(QObject *parent):
-    QObject(parent),
-    m_d(fibonacci_new(this,
-        fibonacciInputChanged,
-        fibonacciResultChanged)),
-    m_ownsPrivate(true)
-{
-}
-
-Fibonacci::~Fibonacci() {
-    if (m_ownsPrivate) {
-        fibonacci_free(m_d);
-    }
-}
-quint32 Fibonacci::input() const
-{
-    return fibonacci_input_get(m_d);
-}
-void Fibonacci::setInput(quint32 v) {
-    fibonacci_input_set(m_d, v);
-}
-quint64 Fibonacci::result() const
-{
-    return fibonacci_result_get(m_d);
-}
-FibonacciList::FibonacciList(bool /*owned*/, QObject *parent):
-    QAbstractItemModel(parent),
-    m_d(0),
-    m_ownsPrivate(false)
-{
-    initHeaderData();
-}
-
-FibonacciList::FibonacciList(QObject *parent):
-    QAbstractItemModel(parent),
-    m_d(fibonacci_list_new(this,
-        [](const FibonacciList* o) {
-            emit o->newDataReady(QModelIndex());
-        },
-        [](FibonacciList* o, quintptr first, quintptr last) {
-            o->dataChanged(o->createIndex(first, 0, first),
-                       o->createIndex(last, 1, last));
-        },
-        [](FibonacciList* o) {
-            o->beginResetModel();
-        },
-        [](FibonacciList* o) {
-            o->endResetModel();
-        },
-        [](FibonacciList* o, int first, int last) {
-            o->beginInsertRows(QModelIndex(), first, last);
-        },
-        [](FibonacciList* o) {
-            o->endInsertRows();
-        },
-        [](FibonacciList* o, int first, int last) {
-            o->beginRemoveRows(QModelIndex(), first, last);
-        },
-        [](FibonacciList* o) {
-            o->endRemoveRows();
-        }
-)),
-    m_ownsPrivate(true)
-{
-    connect(this, &FibonacciList::newDataReady, this, [this](const QModelIndex& i) {
-        this->fetchMore(i);
-    }, Qt::QueuedConnection);
-    initHeaderData();
-}
-
-FibonacciList::~FibonacciList() {
-    if (m_ownsPrivate) {
-        fibonacci_list_free(m_d);
-    }
-}
-void FibonacciList::initHeaderData() {
-    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("row"));
-    m_headerData.insert(qMakePair(1, Qt::DisplayRole), QVariant("fibonacciNumber"));
-}
 FileSystemTree::FileSystemTree(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
 FileSystemTree::FileSystemTree(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(file_system_tree_new(this,
+    m_d((checkBindingHash(), file_system_tree_new(this,
+        FileSystemTree::postTask,
         fileSystemTreePathChanged,
         [](const FileSystemTree* o, quintptr id, bool valid) {
             if (valid) {
@@ -1341,7 +1335,7 @@ FileSystemTree::FileSystemTree(QObject *parent):
         [](FileSystemTree* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
     connect(this, &FileSystemTree::newDataReady, this, [this](const QModelIndex& i) {
@@ -1355,6 +1349,20 @@ FileSystemTree::~FileSystemTree() {
         file_system_tree_free(m_d);
     }
 }
+
+bool FileSystemTree::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        file_system_tree_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void FileSystemTree::postTask(FileSystemTree* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, file_system_tree_drop_task));
+}
 void FileSystemTree::initHeaderData() {
     m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("fileName"));
     m_headerData.insert(qMakePair(1, Qt::DisplayRole), QVariant("fileSize"));
@@ -1375,17 +1383,137 @@ void FileSystemTree::setPath(const QString& v) {
     file_system_tree_path_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
     }
 }
+Fibonacci::Fibonacci(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Fibonacci::Fibonacci(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), fibonacci_new(this,
+        Fibonacci::postTask,
+        fibonacciInputChanged,
+        fibonacciResultChanged))),
+    m_ownsPrivate(true)
+{
+}
+
+Fibonacci::~Fibonacci() {
+    if (m_ownsPrivate) {
+        fibonacci_free(m_d);
+    }
+}
+
+bool Fibonacci::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        fibonacci_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Fibonacci::postTask(Fibonacci* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, fibonacci_drop_task));
+}
+quint32 Fibonacci::input() const
+{
+    return fibonacci_input_get(m_d);
+}
+void Fibonacci::setInput(quint32 v) {
+    fibonacci_input_set(m_d, v);
+}
+quint64 Fibonacci::result() const
+{
+    return fibonacci_result_get(m_d);
+}
+FibonacciList::FibonacciList(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+FibonacciList::FibonacciList(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), fibonacci_list_new(this,
+        FibonacciList::postTask,
+        [](const FibonacciList* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](FibonacciList* o, quintptr first, quintptr last) {
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 1, last));
+        },
+        [](FibonacciList* o) {
+            o->beginResetModel();
+        },
+        [](FibonacciList* o) {
+            o->endResetModel();
+        },
+        [](FibonacciList* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](FibonacciList* o) {
+            o->endInsertRows();
+        },
+        [](FibonacciList* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](FibonacciList* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &FibonacciList::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+FibonacciList::~FibonacciList() {
+    if (m_ownsPrivate) {
+        fibonacci_list_free(m_d);
+    }
+}
+
+bool FibonacciList::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        fibonacci_list_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void FibonacciList::postTask(FibonacciList* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, fibonacci_list_drop_task));
+}
+void FibonacciList::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("row"));
+    m_headerData.insert(qMakePair(1, Qt::DisplayRole), QVariant("fibonacciNumber"));
+}
 Processes::Processes(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
 Processes::Processes(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(processes_new(this,
+    m_d((checkBindingHash(), processes_new(this,
+        Processes::postTask,
         processesActiveChanged,
         [](const Processes* o, quintptr id, bool valid) {
             if (valid) {
@@ -1429,7 +1557,7 @@ Processes::Processes(QObject *parent):
         [](Processes* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
     connect(this, &Processes::newDataReady, this, [this](const QModelIndex& i) {
@@ -1443,6 +1571,20 @@ Processes::~Processes() {
         processes_free(m_d);
     }
 }
+
+bool Processes::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        processes_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Processes::postTask(Processes* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, processes_drop_task));
+}
 void Processes::initHeaderData() {
     m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("name"));
     m_headerData.insert(qMakePair(1, Qt::DisplayRole), QVariant("cpuUsage"));
@@ -1460,12 +1602,14 @@ TimeSeries::TimeSeries(bool /*owned*/, QObject *parent):
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
 TimeSeries::TimeSeries(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(time_series_new(this,
+    m_d((checkBindingHash(), time_series_new(this,
+        TimeSeries::postTask,
         [](const TimeSeries* o) {
             emit o->newDataReady(QModelIndex());
         },
@@ -1491,7 +1635,7 @@ TimeSeries::TimeSeries(QObject *parent):
         [](TimeSeries* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
     connect(this, &TimeSeries::newDataReady, this, [this](const QModelIndex& i) {
@@ -1505,8 +1649,33 @@ TimeSeries::~TimeSeries() {
         time_series_free(m_d);
     }
 }
+
+bool TimeSeries::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        time_series_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void TimeSeries::postTask(TimeSeries* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, time_series_drop_task));
+}
 void TimeSeries::initHeaderData() {
     m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("time"));
     m_headerData.insert(qMakePair(1, Qt::DisplayRole), QVariant("sin"));
     m_headerData.insert(qMakePair(2, Qt::DisplayRole), QVariant("cos"));
 }
+
+void registerQmlTypes()
+{
+    const char* uri = "rust";
+    qmlRegisterType<Demo>(uri, 1, 0, "Demo");
+    qmlRegisterType<FileSystemTree>(uri, 1, 0, "FileSystemTree");
+    qmlRegisterType<Fibonacci>(uri, 1, 0, "Fibonacci");
+    qmlRegisterType<FibonacciList>(uri, 1, 0, "FibonacciList");
+    qmlRegisterType<Processes>(uri, 1, 0, "Processes");
+    qmlRegisterType<TimeSeries>(uri, 1, 0, "TimeSeries");
+}
@@ -6,9 +6,9 @@
 #include <QAbstractItemModel>
 
 class Demo;
+class FileSystemTree;
 class Fibonacci;
 class FibonacciList;
-class FileSystemTree;
 class Processes;
 class TimeSeries;
 
@@ -17,77 +17,91 @@ class Demo : public QObject
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Demo for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Demo* create(QObject* parent) { return new Demo(false, parent); }
+        static Private*& d(Demo* o) { return o->m_d; }
+        static Private* d(const Demo* o) { return o->m_d; }
+        static void postTask(Demo* o, void* task) { Demo::postTask(o, task); }
+    };
 private:
+    FileSystemTree* const m_fileSystemTree;
     Fibonacci* const m_fibonacci;
     FibonacciList* const m_fibonacciList;
-    FileSystemTree* const m_fileSystemTree;
     Processes* const m_processes;
     TimeSeries* const m_timeSeries;
     Private * m_d;
     bool m_ownsPrivate;
+    Q_PROPERTY(FileSystemTree* fileSystemTree READ fileSystemTree NOTIFY fileSystemTreeChanged FINAL)
     Q_PROPERTY(Fibonacci* fibonacci READ fibonacci NOTIFY fibonacciChanged FINAL)
     Q_PROPERTY(FibonacciList* fibonacciList READ fibonacciList NOTIFY fibonacciListChanged FINAL)
-    Q_PROPERTY(FileSystemTree* fileSystemTree READ fileSystemTree NOTIFY fileSystemTreeChanged FINAL)
     Q_PROPERTY(Processes* processes READ processes NOTIFY processesChanged FINAL)
     Q_PROPERTY(TimeSeries* timeSeries READ timeSeries NOTIFY timeSeriesChanged FINAL)
     explicit Demo(bool owned, QObject *parent);
 public:
     explicit Demo(QObject *parent = nullptr);
     ~Demo();
+    const FileSystemTree* fileSystemTree() const;
+    FileSystemTree* fileSystemTree();
     const Fibonacci* fibonacci() const;
     Fibonacci* fibonacci();
     const FibonacciList* fibonacciList() const;
     FibonacciList* fibonacciList();
-    const FileSystemTree* fileSystemTree() const;
-    FileSystemTree* fileSystemTree();
     const Processes* processes() const;
     Processes* processes();
     const TimeSeries* timeSeries() const;
     TimeSeries* timeSeries();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Demo* o, void* task);
 signals:
+    void fileSystemTreeChanged();
     void fibonacciChanged();
     void fibonacciListChanged();
-    void fileSystemTreeChanged();
     void processesChanged();
     void timeSeriesChanged();
 };
 
-class Fibonacci : public QObject
-{
-    Q_OBJECT
-    friend class Demo;
-public:
-    class Private;
-private:
-    Private * m_d;
-    bool m_ownsPrivate;
-    Q_PROPERTY(quint32 input READ input WRITE setInput NOTIFY inputChanged FINAL)
-    Q_PROPERTY(quint64 result READ result NOTIFY resultChanged FINAL)
-    explicit Fibonacci(bool owned, QObject *parent);
-public:
-    explicit Fibonacci(QObject *parent = nullptr);
-    ~Fibonacci();
-    quint32 input() const;
-    void setInput(quint32 v);
-    quint64 result() const;
-signals:
-    void inputChanged();
-    void resultChanged();
-};
-
-class FibonacciList : public QAbstractItemModel
+class FileSystemTree : public QAbstractItemModel
 {
     Q_OBJECT
     friend class Demo;
 public:
     class Private;
+    // constructs and connects FileSystemTree for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static FileSystemTree* create(QObject* parent) { return new FileSystemTree(false, parent); }
+        static Private*& d(FileSystemTree* o) { return o->m_d; }
+        static Private* d(const FileSystemTree* o) { return o->m_d; }
+        static void postTask(FileSystemTree* o, void* task) { FileSystemTree::postTask(o, task); }
+        static QModelIndex createIndex(const FileSystemTree* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(FileSystemTree* o) { o->beginResetModel(); }
+        static void endResetModel(FileSystemTree* o) { o->endResetModel(); }
+        static void beginInsertRows(FileSystemTree* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(FileSystemTree* o) { o->endInsertRows(); }
+        static void beginRemoveRows(FileSystemTree* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(FileSystemTree* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
-    explicit FibonacciList(bool owned, QObject *parent);
+    Q_PROPERTY(QString path READ path WRITE setPath NOTIFY pathChanged FINAL)
+    explicit FileSystemTree(bool owned, QObject *parent);
 public:
-    explicit FibonacciList(QObject *parent = nullptr);
-    ~FibonacciList();
+    explicit FileSystemTree(QObject *parent = nullptr);
+    ~FileSystemTree();
+    QString path() const;
+    void setPath(const QString& v);
 
     int columnCount(const QModelIndex &parent = QModelIndex()) const override;
     QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
@@ -105,8 +119,12 @@ public:
     bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
     Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
-    Q_INVOKABLE quint64 fibonacciNumber(int row) const;
-    Q_INVOKABLE quint64 row(int row) const;
+    Q_INVOKABLE QString fileName(const QModelIndex& index) const;
+    Q_INVOKABLE QByteArray fileIcon(const QModelIndex& index) const;
+    Q_INVOKABLE QVariant fileSize(const QModelIndex& index) const;
+    Q_INVOKABLE QString filePath(const QModelIndex& index) const;
+    Q_INVOKABLE qint32 filePermissions(const QModelIndex& index) const;
+    Q_INVOKABLE qint32 fileType(const QModelIndex& index) const;
 
 signals:
     // new data is ready to be made available to the model with fetchMore()
@@ -114,25 +132,85 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(FileSystemTree* o, void* task);
 signals:
+    void pathChanged();
 };
 
-class FileSystemTree : public QAbstractItemModel
+class Fibonacci : public QObject
 {
     Q_OBJECT
     friend class Demo;
 public:
     class Private;
+    // constructs and connects Fibonacci for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Fibonacci* create(QObject* parent) { return new Fibonacci(false, parent); }
+        static Private*& d(Fibonacci* o) { return o->m_d; }
+        static Private* d(const Fibonacci* o) { return o->m_d; }
+        static void postTask(Fibonacci* o, void* task) { Fibonacci::postTask(o, task); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
-    Q_PROPERTY(QString path READ path WRITE setPath NOTIFY pathChanged FINAL)
-    explicit FileSystemTree(bool owned, QObject *parent);
+    Q_PROPERTY(quint32 input READ input WRITE setInput NOTIFY inputChanged FINAL)
+    Q_PROPERTY(quint64 result READ result NOTIFY resultChanged FINAL)
+    explicit Fibonacci(bool owned, QObject *parent);
 public:
-    explicit FileSystemTree(QObject *parent = nullptr);
-    ~FileSystemTree();
-    QString path() const;
-    void setPath(const QString& v);
+    explicit Fibonacci(QObject *parent = nullptr);
+    ~Fibonacci();
+    quint32 input() const;
+    void setInput(quint32 v);
+    quint64 result() const;
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Fibonacci* o, void* task);
+signals:
+    void inputChanged();
+    void resultChanged();
+};
+
+class FibonacciList : public QAbstractItemModel
+{
+    Q_OBJECT
+    friend class Demo;
+public:
+    class Private;
+    // constructs and connects FibonacciList for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static FibonacciList* create(QObject* parent) { return new FibonacciList(false, parent); }
+        static Private*& d(FibonacciList* o) { return o->m_d; }
+        static Private* d(const FibonacciList* o) { return o->m_d; }
+        static void postTask(FibonacciList* o, void* task) { FibonacciList::postTask(o, task); }
+        static QModelIndex createIndex(const FibonacciList* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(FibonacciList* o) { o->beginResetModel(); }
+        static void endResetModel(FibonacciList* o) { o->endResetModel(); }
+        static void beginInsertRows(FibonacciList* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(FibonacciList* o) { o->endInsertRows(); }
+        static void beginRemoveRows(FibonacciList* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(FibonacciList* o) { o->endRemoveRows(); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit FibonacciList(bool owned, QObject *parent);
+public:
+    explicit FibonacciList(QObject *parent = nullptr);
+    ~FibonacciList();
 
     int columnCount(const QModelIndex &parent = QModelIndex()) const override;
     QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
@@ -150,12 +228,8 @@ public:
     bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
     Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
-    Q_INVOKABLE QByteArray fileIcon(const QModelIndex& index) const;
-    Q_INVOKABLE QString fileName(const QModelIndex& index) const;
-    Q_INVOKABLE QString filePath(const QModelIndex& index) const;
-    Q_INVOKABLE qint32 filePermissions(const QModelIndex& index) const;
-    Q_INVOKABLE QVariant fileSize(const QModelIndex& index) const;
-    Q_INVOKABLE qint32 fileType(const QModelIndex& index) const;
+    Q_INVOKABLE quint64 row(int row) const;
+    Q_INVOKABLE quint64 fibonacciNumber(int row) const;
 
 signals:
     // new data is ready to be made available to the model with fetchMore()
@@ -163,8 +237,12 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(FibonacciList* o, void* task);
 signals:
-    void pathChanged();
 };
 
 class Processes : public QAbstractItemModel
@@ -173,6 +251,27 @@ class Processes : public QAbstractItemModel
     friend class Demo;
 public:
     class Private;
+    // constructs and connects Processes for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Processes* create(QObject* parent) { return new Processes(false, parent); }
+        static Private*& d(Processes* o) { return o->m_d; }
+        static Private* d(const Processes* o) { return o->m_d; }
+        static void postTask(Processes* o, void* task) { Processes::postTask(o, task); }
+        static QModelIndex createIndex(const Processes* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Processes* o) { o->beginResetModel(); }
+        static void endResetModel(Processes* o) { o->endResetModel(); }
+        static void beginInsertRows(Processes* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Processes* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Processes* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Processes* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -200,13 +299,13 @@ public:
     bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
     Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
-    Q_INVOKABLE QString cmd(const QModelIndex& index) const;
-    Q_INVOKABLE quint8 cpuPercentage(const QModelIndex& index) const;
+    Q_INVOKABLE quint32 pid(const QModelIndex& index) const;
+    Q_INVOKABLE QString name(const QModelIndex& index) const;
     Q_INVOKABLE float cpuUsage(const QModelIndex& index) const;
     Q_INVOKABLE quint64 memory(const QModelIndex& index) const;
-    Q_INVOKABLE QString name(const QModelIndex& index) const;
-    Q_INVOKABLE quint32 pid(const QModelIndex& index) const;
     Q_INVOKABLE quint32 uid(const QModelIndex& index) const;
+    Q_INVOKABLE quint8 cpuPercentage(const QModelIndex& index) const;
+    Q_INVOKABLE QString cmd(const QModelIndex& index) const;
 
 signals:
     // new data is ready to be made available to the model with fetchMore()
@@ -214,6 +313,11 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Processes* o, void* task);
 signals:
     void activeChanged();
 };
@@ -224,6 +328,27 @@ class TimeSeries : public QAbstractItemModel
     friend class Demo;
 public:
     class Private;
+    // constructs and connects TimeSeries for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static TimeSeries* create(QObject* parent) { return new TimeSeries(false, parent); }
+        static Private*& d(TimeSeries* o) { return o->m_d; }
+        static Private* d(const TimeSeries* o) { return o->m_d; }
+        static void postTask(TimeSeries* o, void* task) { TimeSeries::postTask(o, task); }
+        static QModelIndex createIndex(const TimeSeries* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(TimeSeries* o) { o->beginResetModel(); }
+        static void endResetModel(TimeSeries* o) { o->endResetModel(); }
+        static void beginInsertRows(TimeSeries* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(TimeSeries* o) { o->endInsertRows(); }
+        static void beginRemoveRows(TimeSeries* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(TimeSeries* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -249,12 +374,12 @@ public:
     Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
-    Q_INVOKABLE float cos(int row) const;
-    Q_INVOKABLE bool setCos(int row, float value);
-    Q_INVOKABLE float sin(int row) const;
-    Q_INVOKABLE bool setSin(int row, float value);
     Q_INVOKABLE float time(int row) const;
     Q_INVOKABLE bool setTime(int row, float value);
+    Q_INVOKABLE float sin(int row) const;
+    Q_INVOKABLE bool setSin(int row, float value);
+    Q_INVOKABLE float cos(int row) const;
+    Q_INVOKABLE bool setCos(int row, float value);
 
 signals:
     // new data is ready to be made available to the model with fetchMore()
@@ -262,6 +387,14 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(TimeSeries* o, void* task);
 signals:
 };
+
+// register all types with QML under the module rust 1.0
+void registerQmlTypes();
 #endif // BINDINGS_H
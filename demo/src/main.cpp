@@ -408,8 +408,7 @@ int main (int argc, char *argv[])
 
 #ifdef QT_QUICK_LIB
     qmlRegisterType<QSortFilterProxyModel>("org.qtproject.example", 1, 0, "SortFilterProxyModel");
-    qmlRegisterType<Fibonacci>("rust", 1, 0, "Fibonacci");
-    qmlRegisterType<FibonacciList>("rust", 1, 0, "FibonacciList");
+    registerQmlTypes();
 #endif
 
     QCommandLineParser parser;
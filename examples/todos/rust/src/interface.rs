@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -42,8 +44,7 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
@@ -63,67 +64,154 @@ pub struct QModelIndex {
 }
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xaa4d1e27c1effc7f
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct TodosQObject {}
 
 #[derive(Clone)]
 pub struct TodosEmitter {
-    qobject: Arc<Mutex<*const TodosQObject>>,
-    active_count_changed: fn(*const TodosQObject),
-    count_changed: fn(*const TodosQObject),
-    new_data_ready: fn(*const TodosQObject),
+    pub(crate) qobject: Arc<AtomicPtr<TodosQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const TodosQObject, *mut c_void),
+    pub(crate) count_changed: fn(*const TodosQObject),
+    pub(crate) active_count_changed: fn(*const TodosQObject),
+    pub(crate) new_data_ready: fn(*const TodosQObject),
 }
 
-unsafe impl Send for TodosEmitter {}
-
 impl TodosEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn active_count_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const TodosQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.active_count_changed)(ptr);
+            f(ptr);
         }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
     }
-    pub fn count_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.count_changed)(ptr);
+    // Called by todos_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Todos) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn count_changed(&self) {
+        self.with_qobject(|ptr| (self.count_changed)(ptr));
+    }
+    pub fn active_count_changed(&self) {
+        self.with_qobject(|ptr| (self.active_count_changed)(ptr));
+    }
     pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
-        }
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
     }
 }
 
 pub struct TodosList {
-    qobject: *const TodosQObject,
-    data_changed: fn(*const TodosQObject, usize, usize),
-    begin_reset_model: fn(*const TodosQObject),
-    end_reset_model: fn(*const TodosQObject),
-    begin_insert_rows: fn(*const TodosQObject, usize, usize),
-    end_insert_rows: fn(*const TodosQObject),
-    begin_remove_rows: fn(*const TodosQObject, usize, usize),
-    end_remove_rows: fn(*const TodosQObject),
+    pub(crate) qobject: *const TodosQObject,
+    pub(crate) data_changed: fn(*const TodosQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const TodosQObject),
+    pub(crate) end_reset_model: fn(*const TodosQObject),
+    pub(crate) begin_insert_rows: fn(*const TodosQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const TodosQObject),
+    pub(crate) begin_remove_rows: fn(*const TodosQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const TodosQObject),
 }
 
 impl TodosList {
@@ -153,12 +241,12 @@ impl TodosList {
 pub trait TodosTrait {
     fn new(emit: TodosEmitter, model: TodosList) -> Self;
     fn emit(&self) -> &TodosEmitter;
-    fn active_count(&self) -> u64;
     fn count(&self) -> u64;
+    fn active_count(&self) -> u64;
     fn add(&mut self, description: String) -> ();
-    fn clear_completed(&mut self) -> ();
     fn remove(&mut self, index: u64) -> bool;
     fn set_all(&mut self, completed: bool) -> ();
+    fn clear_completed(&mut self) -> ();
     fn row_count(&self) -> usize;
     fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
     fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
@@ -176,8 +264,9 @@ pub trait TodosTrait {
 #[no_mangle]
 pub extern "C" fn todos_new(
     todos: *mut TodosQObject,
-    active_count_changed: fn(*const TodosQObject),
+    todos_run_on_gui_thread: fn(*const TodosQObject, *mut c_void),
     count_changed: fn(*const TodosQObject),
+    active_count_changed: fn(*const TodosQObject),
     todos_new_data_ready: fn(*const TodosQObject),
     todos_data_changed: fn(*const TodosQObject, usize, usize),
     todos_begin_reset_model: fn(*const TodosQObject),
@@ -188,9 +277,11 @@ pub extern "C" fn todos_new(
     todos_end_remove_rows: fn(*const TodosQObject),
 ) -> *mut Todos {
     let todos_emit = TodosEmitter {
-        qobject: Arc::new(Mutex::new(todos)),
-        active_count_changed: active_count_changed,
+        qobject: Arc::new(AtomicPtr::new(todos)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: todos_run_on_gui_thread,
         count_changed: count_changed,
+        active_count_changed: active_count_changed,
         new_data_ready: todos_new_data_ready,
     };
     let model = TodosList {
@@ -213,8 +304,14 @@ pub unsafe extern "C" fn todos_free(ptr: *mut Todos) {
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn todos_active_count_get(ptr: *const Todos) -> u64 {
-    (&*ptr).active_count()
+pub unsafe extern "C" fn todos_run_task(ptr: *mut Todos, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Todos) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn todos_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Todos) + Send>));
 }
 
 #[no_mangle]
@@ -222,6 +319,11 @@ pub unsafe extern "C" fn todos_count_get(ptr: *const Todos) -> u64 {
     (&*ptr).count()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn todos_active_count_get(ptr: *const Todos) -> u64 {
+    (&*ptr).active_count()
+}
+
 #[no_mangle]
 pub extern "C" fn todos_add(ptr: *mut Todos, description_str: *const c_ushort, description_len: c_int) -> () {
     let mut description = String::new();
@@ -232,23 +334,23 @@ pub extern "C" fn todos_add(ptr: *mut Todos, description_str: *const c_ushort, d
 }
 
 #[no_mangle]
-pub extern "C" fn todos_clear_completed(ptr: *mut Todos) -> () {
+pub extern "C" fn todos_remove(ptr: *mut Todos, index: u64) -> bool {
     let o = unsafe { &mut *ptr };
-    let r = o.clear_completed();
+    let r = o.remove(index);
     r
 }
 
 #[no_mangle]
-pub extern "C" fn todos_remove(ptr: *mut Todos, index: u64) -> bool {
+pub extern "C" fn todos_set_all(ptr: *mut Todos, completed: bool) -> () {
     let o = unsafe { &mut *ptr };
-    let r = o.remove(index);
+    let r = o.set_all(completed);
     r
 }
 
 #[no_mangle]
-pub extern "C" fn todos_set_all(ptr: *mut Todos, completed: bool) -> () {
+pub extern "C" fn todos_clear_completed(ptr: *mut Todos) -> () {
     let o = unsafe { &mut *ptr };
-    let r = o.set_all(completed);
+    let r = o.clear_completed();
     r
 }
 
@@ -258,11 +360,17 @@ pub unsafe extern "C" fn todos_row_count(ptr: *const Todos) -> c_int {
 }
 #[no_mangle]
 pub unsafe extern "C" fn todos_insert_rows(ptr: *mut Todos, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn todos_remove_rows(ptr: *mut Todos, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn todos_can_fetch_more(ptr: *const Todos) -> bool {
@@ -317,3 +425,54 @@ pub extern "C" fn todos_set_data_description(
     set_string_from_utf16(&mut v, s, len);
     o.set_description(to_usize(row), v)
 }
+
+/// Owns a Todos for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct TodosFixture {
+    pub ptr: *mut Todos,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl TodosFixture {
+    pub fn new() -> TodosFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = todos_new(
+            q as *mut TodosQObject,
+            |_, task| unsafe { todos_drop_task(task) },
+            |q| TestQObject::emit(q, "countChanged"),
+            |q| TestQObject::emit(q, "activeCountChanged"),
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        TodosFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Todos {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Todos {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { todos_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TodosFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
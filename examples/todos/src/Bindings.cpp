@@ -1,7 +1,36 @@
 /* generated by rust_qt_binding_generator */
 #include "Bindings.h"
+#include <QtQml/qqml.h>
+#include <QQmlEngine>
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     struct option_quintptr {
     public:
@@ -28,14 +57,14 @@ namespace {
     inline QVariant cleanNullQVariant(const QVariant& v) {
         return (v.isNull()) ?QVariant() :v;
     }
-    inline void todosActiveCountChanged(Todos* o)
-    {
-        emit o->activeCountChanged();
-    }
     inline void todosCountChanged(Todos* o)
     {
         emit o->countChanged();
     }
+    inline void todosActiveCountChanged(Todos* o)
+    {
+        emit o->activeCountChanged();
+    }
 }
 extern "C" {
     bool todos_data_completed(const Todos::Private*, int);
@@ -203,7 +232,7 @@ bool Todos::setData(const QModelIndex &index, const QVariant &value, int role)
 }
 
 extern "C" {
-    Todos::Private* todos_new(Todos*, void (*)(Todos*), void (*)(Todos*),
+    Todos::Private* todos_new(Todos*, void (*)(Todos*, void*), void (*)(Todos*), void (*)(Todos*),
         void (*)(const Todos*),
         void (*)(Todos*, quintptr, quintptr),
         void (*)(Todos*),
@@ -213,27 +242,47 @@ extern "C" {
         void (*)(Todos*, int, int),
         void (*)(Todos*));
     void todos_free(Todos::Private*);
-    quint64 todos_active_count_get(const Todos::Private*);
+    void todos_run_task(Todos::Private*, void*);
+    void todos_drop_task(void*);
     quint64 todos_count_get(const Todos::Private*);
+    quint64 todos_active_count_get(const Todos::Private*);
     void todos_add(Todos::Private*, const ushort*, int);
-    void todos_clear_completed(Todos::Private*);
     bool todos_remove(Todos::Private*, quint64);
     void todos_set_all(Todos::Private*, bool);
+    void todos_clear_completed(Todos::Private*);
 };
 
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xaa4d1e27c1effc7f)) {
+            qFatal("Bindings.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Todos::Todos(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
 Todos::Todos(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(todos_new(this,
-        todosActiveCountChanged,
+    m_d((checkBindingHash(), todos_new(this,
+        Todos::postTask,
         todosCountChanged,
+        todosActiveCountChanged,
         [](const Todos* o) {
             emit o->newDataReady(QModelIndex());
         },
@@ -259,7 +308,7 @@ Todos::Todos(QObject *parent):
         [](Todos* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
     connect(this, &Todos::newDataReady, this, [this](const QModelIndex& i) {
@@ -273,23 +322,33 @@ Todos::~Todos() {
         todos_free(m_d);
     }
 }
-void Todos::initHeaderData() {
+
+bool Todos::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        todos_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
 }
-quint64 Todos::activeCount() const
+
+void Todos::postTask(Todos* o, void* task)
 {
-    return todos_active_count_get(m_d);
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, todos_drop_task));
+}
+void Todos::initHeaderData() {
 }
 quint64 Todos::count() const
 {
     return todos_count_get(m_d);
 }
-void Todos::add(const QString& description)
+quint64 Todos::activeCount() const
 {
-    return todos_add(m_d, description.utf16(), description.size());
+    return todos_active_count_get(m_d);
 }
-void Todos::clearCompleted()
+void Todos::add(const QString& description)
 {
-    return todos_clear_completed(m_d);
+    return todos_add(m_d, description.utf16(), description.size());
 }
 bool Todos::remove(quint64 index)
 {
@@ -299,3 +358,13 @@ void Todos::setAll(bool completed)
 {
     return todos_set_all(m_d, completed);
 }
+void Todos::clearCompleted()
+{
+    return todos_clear_completed(m_d);
+}
+
+void registerQmlTypes()
+{
+    const char* uri = "RustCode";
+    qmlRegisterType<Todos>(uri, 1, 0, "Todos");
+}
@@ -12,21 +12,42 @@ class Todos : public QAbstractItemModel
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Todos for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Todos* create(QObject* parent) { return new Todos(false, parent); }
+        static Private*& d(Todos* o) { return o->m_d; }
+        static Private* d(const Todos* o) { return o->m_d; }
+        static void postTask(Todos* o, void* task) { Todos::postTask(o, task); }
+        static QModelIndex createIndex(const Todos* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Todos* o) { o->beginResetModel(); }
+        static void endResetModel(Todos* o) { o->endResetModel(); }
+        static void beginInsertRows(Todos* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Todos* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Todos* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Todos* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
-    Q_PROPERTY(quint64 activeCount READ activeCount NOTIFY activeCountChanged FINAL)
     Q_PROPERTY(quint64 count READ count NOTIFY countChanged FINAL)
+    Q_PROPERTY(quint64 activeCount READ activeCount NOTIFY activeCountChanged FINAL)
     explicit Todos(bool owned, QObject *parent);
 public:
     explicit Todos(QObject *parent = nullptr);
     ~Todos();
-    quint64 activeCount() const;
     quint64 count() const;
+    quint64 activeCount() const;
     Q_INVOKABLE void add(const QString& description);
-    Q_INVOKABLE void clearCompleted();
     Q_INVOKABLE bool remove(quint64 index);
     Q_INVOKABLE void setAll(bool completed);
+    Q_INVOKABLE void clearCompleted();
 
     int columnCount(const QModelIndex &parent = QModelIndex()) const override;
     QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
@@ -55,8 +76,16 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Todos* o, void* task);
 signals:
-    void activeCountChanged();
     void countChanged();
+    void activeCountChanged();
 };
+
+// register all types with QML under the module RustCode 1.0
+void registerQmlTypes();
 #endif // BINDINGS_H
@@ -1,13 +1,12 @@
 #include "Bindings.h"
 
-#include <QtQml/qqml.h>
 #include <QApplication>
 #include <QQmlApplicationEngine>
 
 int main(int argc, char *argv[])
 {
     QApplication app(argc, argv);
-    qmlRegisterType<Todos>("RustCode", 1, 0, "Todos");
+    registerQmlTypes();
 
     QQmlApplicationEngine engine;
     engine.load(QUrl(QStringLiteral("qrc:/main.qml")));
@@ -0,0 +1,95 @@
+// Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; either version 2 of
+// the License or (at your option) version 3 or any later version
+// accepted by the membership of KDE e.V. (or its successor approved
+// by the membership of KDE e.V.), which shall act as a proxy
+// defined in Section 14 of version 3 of the license.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{Error, Outputs};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn qmake_query(qmake: &Path, var: &str) -> Option<PathBuf> {
+    let output = Command::new(qmake).args(["-query", var]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8(output.stdout).ok()?;
+    Some(PathBuf::from(value.trim()))
+}
+
+/// Compile the generated C++ code and link it with Qt.
+///
+/// The code is compiled as C++11 and linked with Qt 5, or as C++17 and
+/// linked with Qt 6 when the configuration has `"qtVersion": 6`.
+///
+/// `qmake` should belong to that Qt version. It is taken from the environment
+/// variable `QMAKE` or from `PATH`. If it cannot be found, nothing is compiled
+/// and `Ok(false)` is returned.
+pub fn compile(outputs: &Outputs) -> Result<bool, Error> {
+    println!("cargo:rerun-if-env-changed=QMAKE");
+    let qmake = env::var_os("QMAKE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("qmake"));
+    let headers = match qmake_query(&qmake, "QT_INSTALL_HEADERS") {
+        Some(headers) => headers,
+        None => return Ok(false),
+    };
+    let libs = qmake_query(&qmake, "QT_INSTALL_LIBS")
+        .ok_or_else(|| Error::Generator("qmake does not know QT_INSTALL_LIBS".into()))?;
+    let bins = qmake_query(&qmake, "QT_HOST_BINS")
+        .or_else(|| qmake_query(&qmake, "QT_INSTALL_BINS"))
+        .ok_or_else(|| Error::Generator("qmake does not know QT_HOST_BINS".into()))?;
+
+    let moc_cpp = outputs.cpp.with_file_name(format!(
+        "moc_{}",
+        outputs.cpp.file_name().unwrap().to_string_lossy()
+    ));
+    let output = Command::new(bins.join("moc"))
+        .arg(&outputs.header)
+        .arg("-o")
+        .arg(&moc_cpp)
+        .output()?;
+    if !output.status.success() {
+        return Err(Error::Generator(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+
+    let (std, qt) = if outputs.qt_version >= 6 {
+        ("-std=c++17", "Qt6")
+    } else {
+        ("-std=c++11", "Qt5")
+    };
+    let modules = ["QtCore", "QtGui", "QtQml"];
+    let mut build = cc::Build::new();
+    build
+        .cpp(true)
+        .flag_if_supported(std)
+        .flag_if_supported("-fPIC")
+        .include(&headers)
+        .file(&outputs.cpp)
+        .file(&moc_cpp);
+    for module in &modules {
+        build.include(headers.join(module));
+    }
+    build.compile("rust_qt_binding_generator_bindings");
+
+    println!("cargo:rustc-link-search={}", libs.display());
+    for module in &modules {
+        println!("cargo:rustc-link-lib={}", module.replace("Qt", qt));
+    }
+    Ok(true)
+}
@@ -0,0 +1,394 @@
+// Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU General Public License as
+// published by the Free Software Foundation; either version 2 of
+// the License or (at your option) version 3 or any later version
+// accepted by the membership of KDE e.V. (or its successor approved
+// by the membership of KDE e.V.), which shall act as a proxy
+// defined in Section 14 of version 3 of the license.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Run `rust_qt_binding_generator` from a `build.rs` file.
+//!
+//! ```no_run
+//! // in build.rs
+//! let outputs = rust_qt_binding_generator::generate("bindings.json").unwrap();
+//! ```
+//!
+//! The generated Rust code can then be included in the crate. The file is
+//! named after `rust.interfaceModule`; a path like `crate::ui::bindings` is
+//! written to `ui/bindings.rs`:
+//!
+//! ```ignore
+//! #[allow(unknown_lints, clippy::all)]
+//! pub mod interface {
+//!     include!(concat!(env!("OUT_DIR"), "/interface.rs"));
+//! }
+//! ```
+//!
+//! The generator executable is looked up in the environment variable
+//! `RUST_QT_BINDING_GENERATOR` and then in `PATH`.
+
+use serde_json::Value;
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[cfg(feature = "compile")]
+mod compile;
+#[cfg(feature = "compile")]
+pub use crate::compile::compile;
+
+/// The files that were generated.
+#[derive(Debug, Clone)]
+pub struct Outputs {
+    /// The Rust interface, ready for `include!`. It is named after
+    /// `rust.interfaceModule`.
+    pub interface: PathBuf,
+    /// The generated C++ source file.
+    pub cpp: PathBuf,
+    /// The generated C++ header file.
+    pub header: PathBuf,
+    /// The Qt major version that the C++ code is written for, from
+    /// `cpp.qtVersion`.
+    pub qt_version: u32,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing a file failed.
+    Io(io::Error),
+    /// The configuration is not valid JSON.
+    Json(serde_json::Error),
+    /// The configuration lacks a required key.
+    Configuration(String),
+    /// The generator could not be started.
+    GeneratorNotFound(PathBuf, io::Error),
+    /// The generator reported an error.
+    Generator(String),
+    /// `OUT_DIR` is not set, so this is not running in a build script.
+    NoOutDir,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Json(e) => write!(f, "invalid configuration: {}", e),
+            Error::Configuration(e) => write!(f, "invalid configuration: {}", e),
+            Error::GeneratorNotFound(p, e) => write!(
+                f,
+                "cannot run {}: {}; set RUST_QT_BINDING_GENERATOR",
+                p.display(),
+                e
+            ),
+            Error::Generator(e) => write!(f, "rust_qt_binding_generator failed: {}", e),
+            Error::NoOutDir => write!(f, "OUT_DIR is not set"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+fn generator() -> PathBuf {
+    env::var_os("RUST_QT_BINDING_GENERATOR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("rust_qt_binding_generator"))
+}
+
+/// Generate the bindings for `config` into `OUT_DIR`.
+///
+/// Cargo is told to run the build script again when the configuration
+/// changes.
+pub fn generate<P: AsRef<Path>>(config: P) -> Result<Outputs, Error> {
+    let out_dir = env::var_os("OUT_DIR").ok_or(Error::NoOutDir)?;
+    println!("cargo:rerun-if-env-changed=RUST_QT_BINDING_GENERATOR");
+    generate_into(config.as_ref(), Path::new(&out_dir))
+}
+
+/// Generate the bindings for `config` into `out_dir`.
+pub fn generate_into(config: &Path, out_dir: &Path) -> Result<Outputs, Error> {
+    let gen_dir = out_dir.join("rust_qt_binding_generator");
+    fs::create_dir_all(gen_dir.join("src"))?;
+    let (gen_config, json, outputs) = write_config(config, out_dir, &mut Vec::new())?;
+
+    let generator = generator();
+    let output = Command::new(&generator)
+        .arg(&gen_config)
+        .output()
+        .map_err(|e| Error::GeneratorNotFound(generator.clone(), e))?;
+    if !output.status.success() {
+        return Err(Error::Generator(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+    let interface = fs::read_to_string(
+        gen_dir
+            .join("src")
+            .join(module_file(module(&json, "interfaceModule")?)),
+    )?;
+    if let Some(dir) = outputs.interface.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&outputs.interface, strip_inner_attributes(&interface))?;
+    Ok(outputs)
+}
+
+/// Write a copy of `config` and of the configurations that it imports with
+/// all output paths pointing into `out_dir`.
+fn write_config(
+    config: &Path,
+    out_dir: &Path,
+    stack: &mut Vec<PathBuf>,
+) -> Result<(PathBuf, Value, Outputs), Error> {
+    println!("cargo:rerun-if-changed={}", config.display());
+    let canonical = config.canonicalize()?;
+    if stack.contains(&canonical) {
+        return Err(Error::Configuration(format!(
+            "{} imports itself",
+            config.display()
+        )));
+    }
+    stack.push(canonical);
+    let mut json: Value = serde_json::from_slice(&fs::read(config)?)?;
+    let base = config.parent().unwrap_or_else(|| Path::new(""));
+    // indexing a missing key would insert a null, which the generator rejects
+    if let Some(imports) = json.get_mut("imports").and_then(Value::as_array_mut) {
+        for import in imports.iter_mut() {
+            let path = import
+                .as_str()
+                .ok_or_else(|| Error::Configuration("an import is not a string".into()))?;
+            let (path, _, _) = write_config(&base.join(path), out_dir, stack)?;
+            *import = Value::from(path.to_string_lossy().into_owned());
+        }
+    }
+    stack.pop();
+    let (json, outputs) = redirect(json, out_dir)?;
+    let name = config
+        .file_name()
+        .ok_or_else(|| Error::Configuration("the configuration has no file name".into()))?;
+    let gen_config = out_dir.join("rust_qt_binding_generator").join(name);
+    fs::write(&gen_config, serde_json::to_vec_pretty(&json)?)?;
+    Ok((gen_config, json, outputs))
+}
+
+fn module<'a>(json: &'a Value, key: &str) -> Result<&'a str, Error> {
+    json["rust"][key]
+        .as_str()
+        .ok_or_else(|| Error::Configuration(format!("rust.{} is missing", key)))
+}
+
+/// The file of a module, relative to `src`. A path like `crate::ui::bindings`
+/// becomes `ui/bindings.rs`.
+fn module_file(module: &str) -> PathBuf {
+    let module = module.strip_prefix("crate::").unwrap_or(module);
+    let mut path: PathBuf = module.split("::").collect();
+    path.set_extension("rs");
+    path
+}
+
+/// Point all output paths of the configuration to `out_dir`.
+fn redirect(mut json: Value, out_dir: &Path) -> Result<(Value, Outputs), Error> {
+    let cpp_file = json["cppFile"]
+        .as_str()
+        .ok_or_else(|| Error::Configuration("cppFile is missing".into()))?;
+    let cpp_name = Path::new(cpp_file)
+        .file_name()
+        .ok_or_else(|| Error::Configuration("cppFile is empty".into()))?;
+    let cpp = out_dir.join(cpp_name);
+    let header = cpp.with_extension("h");
+    let interface = out_dir.join(module_file(module(&json, "interfaceModule")?));
+    let qt_version = json["cpp"]["qtVersion"].as_u64().unwrap_or(5) as u32;
+    json["cppFile"] = Value::from(cpp.to_string_lossy().into_owned());
+    json["rust"]["dir"] = Value::from(
+        out_dir
+            .join("rust_qt_binding_generator")
+            .to_string_lossy()
+            .into_owned(),
+    );
+    let outputs = Outputs {
+        interface,
+        cpp,
+        header,
+        qt_version,
+    };
+    Ok((json, outputs))
+}
+
+/// Inner attributes like `#![allow(unknown_lints)]` are not allowed in a file
+/// that is used with `include!`.
+fn strip_inner_attributes(source: &str) -> String {
+    source
+        .lines()
+        .filter(|l| !l.starts_with("#!["))
+        .map(|l| format!("{}\n", l))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redirect_outputs() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "cppFile": "src/Bindings.cpp",
+                "rust": {
+                    "dir": "rust",
+                    "interfaceModule": "interface",
+                    "implementationModule": "implementation"
+                },
+                "objects": {}
+            }"#,
+        )
+        .unwrap();
+        let (json, outputs) = redirect(json, Path::new("/out")).unwrap();
+        assert_eq!(json["cppFile"], "/out/Bindings.cpp");
+        assert_eq!(json["rust"]["dir"], "/out/rust_qt_binding_generator");
+        assert_eq!(outputs.header, Path::new("/out/Bindings.h"));
+        assert_eq!(outputs.interface, Path::new("/out/interface.rs"));
+        assert_eq!(outputs.qt_version, 5);
+    }
+
+    #[test]
+    fn redirect_qt6() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "cppFile": "Bindings.cpp",
+                "cpp": { "qtVersion": 6 },
+                "rust": { "interfaceModule": "interface" }
+            }"#,
+        )
+        .unwrap();
+        let (_, outputs) = redirect(json, Path::new("/out")).unwrap();
+        assert_eq!(outputs.qt_version, 6);
+    }
+
+    #[test]
+    fn redirect_without_cpp_file() {
+        let json: Value = serde_json::from_str(r#"{ "rust": {} }"#).unwrap();
+        assert!(redirect(json, Path::new("/out")).is_err());
+    }
+
+    #[test]
+    fn write_config_with_imports() {
+        let dir = env::temp_dir().join(format!("rqbg-imports-{}", std::process::id()));
+        let out = dir.join("out");
+        fs::create_dir_all(out.join("rust_qt_binding_generator")).unwrap();
+        let rust = r#""rust": { "dir": "rust", "interfaceModule": "#;
+        fs::write(
+            dir.join("people.json"),
+            format!(r#"{{ "cppFile": "src/People.cpp", {}"people" }} }}"#, rust),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("library.json"),
+            format!(
+                r#"{{ "imports": ["people.json"], "cppFile": "src/Library.cpp", {}"library" }} }}"#,
+                rust
+            ),
+        )
+        .unwrap();
+        let (path, json, outputs) =
+            write_config(&dir.join("library.json"), &out, &mut Vec::new()).unwrap();
+        let people = out.join("rust_qt_binding_generator").join("people.json");
+        assert_eq!(path, out.join("rust_qt_binding_generator").join("library.json"));
+        assert_eq!(json["imports"][0], people.to_str().unwrap());
+        assert_eq!(outputs.interface, out.join("library.rs"));
+        let people: Value = serde_json::from_slice(&fs::read(people).unwrap()).unwrap();
+        assert_eq!(people["cppFile"], out.join("People.cpp").to_str().unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn write_config_without_imports() {
+        let dir = env::temp_dir().join(format!("rqbg-no-imports-{}", std::process::id()));
+        let out = dir.join("out");
+        fs::create_dir_all(out.join("rust_qt_binding_generator")).unwrap();
+        fs::write(
+            dir.join("object.json"),
+            r#"{ "cppFile": "src/Object.cpp", "rust": { "dir": "rust", "interfaceModule": "object" } }"#,
+        )
+        .unwrap();
+        let (path, json, _) =
+            write_config(&dir.join("object.json"), &out, &mut Vec::new()).unwrap();
+        assert!(json.get("imports").is_none());
+        let written: Value = serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+        assert!(written.get("imports").is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn write_config_keeps_order() {
+        let dir = env::temp_dir().join(format!("rqbg-order-{}", std::process::id()));
+        let out = dir.join("out");
+        fs::create_dir_all(out.join("rust_qt_binding_generator")).unwrap();
+        fs::write(
+            dir.join("order.json"),
+            r#"{
+                "rust": { "dir": "rust", "interfaceModule": "order" },
+                "cppFile": "src/Order.cpp",
+                "objects": {
+                    "Zebra": { "type": "Object", "properties": { "b": {}, "a": {} } },
+                    "Apple": { "type": "Object", "properties": {} }
+                }
+            }"#,
+        )
+        .unwrap();
+        let (path, _, _) = write_config(&dir.join("order.json"), &out, &mut Vec::new()).unwrap();
+        let written = fs::read_to_string(path).unwrap();
+        // the keys are written in the order of the file, not sorted
+        let keys = ["rust", "cppFile", "Zebra", "b", "a", "Apple"];
+        let order: Vec<usize> = keys
+            .iter()
+            .map(|key| written.find(&format!("\"{}\"", key)).unwrap())
+            .collect();
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(order, sorted);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn module_paths() {
+        assert_eq!(module_file("interface"), Path::new("interface.rs"));
+        assert_eq!(
+            module_file("crate::ui::bindings"),
+            Path::new("ui/bindings.rs")
+        );
+    }
+
+    #[test]
+    fn strip_attributes() {
+        let source = "/* generated */\n#![allow(unknown_lints)]\nuse std::slice;\n";
+        assert_eq!(
+            strip_inner_attributes(source),
+            "/* generated */\nuse std::slice;\n"
+        );
+    }
+}
@@ -62,6 +62,38 @@ QString cGetType(const BindingTypeProperties& type) {
     return type.name + "*, " + type.name.toLower() + "_set";
 }
 
+void cppDoc(QTextStream& h, const QString& indent, const QString& doc,
+        const QList<Argument>& args = QList<Argument>()) {
+    QStringList lines;
+    if (!doc.isEmpty()) {
+        lines = doc.split('\n');
+    }
+    for (auto a: args) {
+        if (!a.doc.isEmpty()) {
+            lines.append(QString("@param %1 %2").arg(a.name, a.doc));
+        }
+    }
+    if (lines.isEmpty()) {
+        return;
+    }
+    h << indent << "/**\n";
+    for (auto line: lines) {
+        // a */ in the text would end the comment
+        line.replace("*/", "*\\/");
+        h << indent << " *" << (line.isEmpty() ? "" : " " + line) << "\n";
+    }
+    h << indent << " */\n";
+}
+
+// Check if a QVariant can be converted to a type.
+QString canConvert(const QString& value, const QString& type,
+        const Configuration& conf) {
+    if (conf.qtVersion >= 6) {
+        return QString("%1.canConvert(QMetaType::fromType<%2>())").arg(value, type);
+    }
+    return QString("%1.canConvert(qMetaTypeId<%2>())").arg(value, type);
+}
+
 bool modelIsWritable(const Object& o) {
     bool write = false;
     for (auto ip: o.itemProperties) {
@@ -98,14 +130,17 @@ void writeHeaderItemModel(QTextStream& h, const Object& o) {
         if (r == "QVariant" || ip.type.isComplex()) {
             rw = "const " + r + "&";
         }
+        cppDoc(h, "    ", ip.doc);
         if (o.type == ObjectType::List) {
             h << QString("    Q_INVOKABLE %2 %1(int row) const;\n").arg(ip.name, r);
             if (ip.write) {
+                cppDoc(h, "    ", ip.doc);
                 h << QString("    Q_INVOKABLE bool set%1(int row, %2 value);\n").arg(upperInitial(ip.name), rw);
             }
         } else {
             h << QString("    Q_INVOKABLE %2 %1(const QModelIndex& index) const;\n").arg(ip.name, r);
             if (ip.write) {
+                cppDoc(h, "    ", ip.doc);
                 h << QString("    Q_INVOKABLE bool set%1(const QModelIndex& index, %2 value);\n").arg(upperInitial(ip.name), rw);
             }
         }
@@ -118,6 +153,21 @@ private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
 )";
+    if (o.bulk) {
+        h << QString(R"(    // the item properties of a block of rows, fetched from Rust at once and
+    // guarded by m_cacheMutex because Rust may drop rows from any thread
+    mutable QMutex m_cacheMutex;
+    mutable int m_cacheFirst = 0;
+    mutable int m_cacheRows = 0;
+    mutable QVector<QVariant> m_cache;
+    // counts the drops, so that rows which change while they are fetched
+    // are not kept
+    mutable quint32 m_cacheGeneration = 0;
+    QVariant cached(int row, int property) const;
+    static void dropCachedRows(%1* o, quintptr first, quintptr last);
+    static void dropCache(%1* o);
+)").arg(o.name);
+    }
 }
 
 bool isColumnWrite(const Object& o, int col) {
@@ -130,8 +180,8 @@ bool isColumnWrite(const Object& o, int col) {
 }
 
 void writeModelGetterSetter(QTextStream& cpp, const QString& index,
-        const ItemProperty& ip, const Object& o) {
-    const QString lcname(snakeCase(o.name));
+        const ItemProperty& ip, const Object& o, const Configuration& conf) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
     QString idx = index;
 
     // getter
@@ -193,7 +243,8 @@ void writeModelGetterSetter(QTextStream& cpp, const QString& index,
         cpp << "    } else {\n";
     }
     if (ip.optional && !ip.type.isComplex()) {
-        cpp << QString("    if (!value.canConvert(qMetaTypeId<%1>())) {\n        return false;\n    }\n").arg(ip.type.name);
+        cpp << QString("    if (!%1) {\n        return false;\n    }\n")
+            .arg(canConvert("value", ip.type.name, conf));
         cpp << QString("    set = %1_set_data_%2(m_d%3, value.value<%4>());")
             .arg(lcname, snakeCase(ip.name), idx, ip.type.name) << endl;
     } else {
@@ -211,7 +262,17 @@ void writeModelGetterSetter(QTextStream& cpp, const QString& index,
     if (ip.optional) {
         cpp << "    }\n";
     }
-    if (o.type == ObjectType::List) {
+    if (o.bulk) {
+        cpp << R"(    if (set) {
+        dropCachedRows(this, row, row);
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+)";
+    } else if (o.type == ObjectType::List) {
         cpp << R"(    if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -231,8 +292,83 @@ void writeModelGetterSetter(QTextStream& cpp, const QString& index,
     }
 }
 
-void writeCppModel(QTextStream& cpp, const Object& o) {
-    const QString lcname(snakeCase(o.name));
+// The rows of a bulk list are fetched in blocks of this size.
+const int bulkRows = 256;
+
+void writeCppCache(QTextStream& cpp, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    const QString n = QString::number(o.itemProperties.size());
+    cpp << QString(R"(QVariant %1::cached(int row, int property) const
+{
+    QMutexLocker lock(&m_cacheMutex);
+    if (row >= m_cacheFirst && row < m_cacheFirst + m_cacheRows) {
+        return m_cache.at((row - m_cacheFirst) * %3 + property);
+    }
+    // Rust is called without the lock, so that its threads can drop rows
+    // meanwhile
+    const quint32 generation = m_cacheGeneration;
+    lock.unlock();
+    const int first = row - row % %2;
+    const int count = qMin(%2, rowCount() - first);
+)").arg(o.name, QString::number(bulkRows), n);
+    QString args;
+    for (auto ip: o.itemProperties) {
+        const QString type = ip.type.isComplex() ? ip.type.name : cppSetType(ip);
+        cpp << QString("    QVector<%1> %2Rows(count);\n").arg(type, ip.name);
+        args += QString(", %1Rows.data()").arg(ip.name);
+        if (ip.type.isComplex()) {
+            args += QString(", set_%1_rows").arg(ip.type.name.toLower());
+        }
+    }
+    cpp << QString("    %1_data_rows(m_d, first, count%2);\n").arg(lcname, args);
+    cpp << QString("    QVector<QVariant> cache(count * %1);\n").arg(n);
+    cpp << "    for (int i = 0; i < count; ++i) {\n";
+    for (int i = 0; i < o.itemProperties.size(); ++i) {
+        auto ip = o.itemProperties[i];
+        QString value;
+        if (ip.optional && !ip.type.isComplex()) {
+            value = QString("%1Rows[i]").arg(ip.name);
+        } else if (ip.optional) {
+            value = QString("cleanNullQVariant(QVariant::fromValue(%1Rows[i]))").arg(ip.name);
+        } else {
+            value = QString("QVariant::fromValue(%1Rows[i])").arg(ip.name);
+        }
+        cpp << QString("        cache[i * %1 + %2] = %3;\n").arg(n, QString::number(i), value);
+    }
+    cpp << QString(R"(    }
+    const QVariant value = cache.at((row - first) * %2 + property);
+    lock.relock();
+    // rows that were dropped during the fetch are fetched again
+    if (generation == m_cacheGeneration) {
+        m_cache.swap(cache);
+        m_cacheFirst = first;
+        m_cacheRows = count;
+    }
+    return value;
+}
+
+void %1::dropCachedRows(%1* o, quintptr first, quintptr last)
+{
+    QMutexLocker lock(&o->m_cacheMutex);
+    ++o->m_cacheGeneration;
+    if (first < quintptr(o->m_cacheFirst + o->m_cacheRows)
+            && last >= quintptr(o->m_cacheFirst)) {
+        o->m_cacheRows = 0;
+    }
+}
+
+void %1::dropCache(%1* o)
+{
+    QMutexLocker lock(&o->m_cacheMutex);
+    ++o->m_cacheGeneration;
+    o->m_cacheRows = 0;
+}
+
+)").arg(o.name, n);
+}
+
+void writeCppModel(QTextStream& cpp, const Object& o, const Configuration& conf) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
     QString indexDecl = ", int";
     QString index = ", index.row()";
     if (o.type == ObjectType::Tree) {
@@ -266,6 +402,19 @@ void writeCppModel(QTextStream& cpp, const Object& o) {
             }
         }
     }
+    if (o.bulk) {
+        // an array for each item property, complex values are passed to a
+        // setter for all rows at once
+        cpp << QString("    void %2_data_rows(const %1::Private*, int first, int count").arg(o.name, lcname);
+        for (auto ip: o.itemProperties) {
+            if (ip.type.isComplex()) {
+                cpp << QString(", %1*, %2_rows_set").arg(ip.type.name, ip.type.name.toLower());
+            } else {
+                cpp << QString(", %1*").arg(cppSetType(ip));
+            }
+        }
+        cpp << ");\n";
+    }
     cpp << QString("    void %2_sort(%1::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);\n").arg(o.name, lcname);
     if (o.type == ObjectType::List) {
         cpp << QString(R"(
@@ -418,7 +567,7 @@ Qt::ItemFlags %1::flags(const QModelIndex &i) const
     }
     cpp << "    return flags;\n}\n\n";
     for (auto ip: o.itemProperties) {
-        writeModelGetterSetter(cpp, index, ip, o);
+        writeModelGetterSetter(cpp, index, ip, o, conf);
     }
     cpp << QString(R"(QVariant %1::data(const QModelIndex &index, int role) const
 {
@@ -441,7 +590,9 @@ Qt::ItemFlags %1::flags(const QModelIndex &i) const
             }
             cpp << QString("        case Qt::UserRole + %1:\n").arg(i);
             auto ii = (o.type == ObjectType::List) ?".row()" :"";
-            if (ip.optional && !ip.type.isComplex()) {
+            if (o.bulk) {
+                cpp << QString("            return cached(index.row(), %1);\n").arg(i);
+            } else if (ip.optional && !ip.type.isComplex()) {
                 cpp << QString("            return %1(index%2);\n").arg(ip.name, ii);
             } else if (ip.optional) {
                 cpp << QString("            return cleanNullQVariant(QVariant::fromValue(%1(index%2)));\n").arg(ip.name, ii);
@@ -452,6 +603,9 @@ Qt::ItemFlags %1::flags(const QModelIndex &i) const
         cpp << "        }\n";
     }
     cpp << "    }\n    return QVariant();\n}\n\n";
+    if (o.bulk) {
+        writeCppCache(cpp, o);
+    }
     cpp << "int " << o.name << "::role(const char* name) const {\n";
     cpp << "    auto names = roleNames();\n";
     cpp << "    auto i = names.constBegin();\n";
@@ -519,7 +673,8 @@ bool %1::setHeaderData(int section, Qt::Orientation orientation, const QVariant
                     if (ip.optional) {
                         pre = "!value.isValid() || value.isNull() ||";
                     }
-                    cpp << QString("            if (%2value.canConvert(qMetaTypeId<%1>())) {\n").arg(ip.type.name, pre);
+                    cpp << QString("            if (%1%2) {\n")
+                        .arg(pre, canConvert("value", ip.type.name, conf));
                     cpp << QString("                return set%1(index%2, value.value<%3>());\n").arg(upperInitial(ip.name), ii, ip.type.name);
                     cpp << QString("            }\n");
                 }
@@ -531,21 +686,93 @@ bool %1::setHeaderData(int section, Qt::Orientation orientation, const QVariant
     }
 }
 
+// The functions with which the generated code constructs and connects an
+// object from outside of its class: from the anonymous namespace of its file
+// and from the files that import it, which the class does not know.
+void writeHeaderBinding(QTextStream& h, const Object& o) {
+    h << QString(R"(    // constructs and connects %1 for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static %1* create(QObject* parent) { return new %1(false, parent); }
+        static Private*& d(%1* o) { return o->m_d; }
+        static Private* d(const %1* o) { return o->m_d; }
+        static void postTask(%1* o, void* task) { %1::postTask(o, task); }
+)").arg(o.name);
+    if (o.hasCoalesced()) {
+        h << QString(R"(        static void emitLater(%1* o, int signal) { %1::emitLater(o, signal); }
+)").arg(o.name);
+    }
+    if (o.hasConstants()) {
+        h << QString(R"(        static void readConstants(%1* o) { o->readConstants(); }
+)").arg(o.name);
+    }
+    if (o.type != ObjectType::Object) {
+        h << QString(R"(        static QModelIndex createIndex(const %1* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(%1* o) { o->beginResetModel(); }
+        static void endResetModel(%1* o) { o->endResetModel(); }
+        static void beginInsertRows(%1* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(%1* o) { o->endInsertRows(); }
+        static void beginRemoveRows(%1* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(%1* o) { o->endRemoveRows(); }
+)").arg(o.name);
+    }
+    if (o.coalesce) {
+        h << QString(R"(        static void dataChangedLater(%1* o, quintptr first, quintptr last) {
+            %1::dataChangedLater(o, first, last);
+        }
+        static void flushDataChanged(%1* o) { %1::flushDataChanged(o); }
+)").arg(o.name);
+    }
+    if (o.bulk) {
+        h << QString(R"(        static void dropCachedRows(%1* o, quintptr first, quintptr last) {
+            %1::dropCachedRows(o, first, last);
+        }
+        static void dropCache(%1* o) { %1::dropCache(o); }
+)").arg(o.name);
+    }
+    h << "    };\n";
+}
+
 void writeHeaderObject(QTextStream& h, const Object& o, const Configuration& conf) {
-    h << QString(R"(
-class %1 : public %3
+    h << "\n";
+    cppDoc(h, "", o.doc);
+    h << QString(R"(class %1 : public %3
 {
     Q_OBJEC%2
 )").arg(o.name, "T", baseType(o));
+    if (conf.qtVersion >= 6 && !conf.qmlUri.isEmpty()) {
+        h << "    QML_ELEMENT\n";
+        if (o.singleton) {
+            h << "    QML_SINGLETON\n";
+        }
+    }
     for (auto object: conf.objects) {
         if (object.containsObject() && o.name != object.name) {
             h << "    friend class " << object.name << ";\n";
         }
     }
-    h << R"(public:
-    class Private;
-private:
-)";
+    h << "public:\n    class Private;\n";
+    writeHeaderBinding(h, o);
+    // the change signals of bindable properties are declared before the
+    // properties that emit them
+    QString bindables;
+    for (auto p: o.properties) {
+        if (p.bindable) {
+            if (bindables.isEmpty()) {
+                h << "signals:\n";
+            }
+            h << "    void " << p.name << "Changed();\n";
+            bindables += QString("    Q_OBJECT_BINDABLE_PROPERTY(%1, %2, m_%3, &%1::%3Changed)\n")
+                .arg(o.name, p.type.name, p.name);
+        }
+    }
+    h << "private:\n" << bindables;
     for (auto p: o.properties) {
         if (p.type.type == BindingType::Object) {
             h << "    " << p.type.name << "* const m_" << p.name << ";\n";
@@ -554,26 +781,68 @@ private:
     h << R"(    Private * m_d;
     bool m_ownsPrivate;
 )";
+    if (o.hasConstants()) {
+        h << "    // the values of the const properties, read once from Rust\n";
+        for (auto p: o.properties) {
+            if (p.constant && p.type.type != BindingType::Object) {
+                const QString t = p.optional && !p.type.isComplex()
+                    ? "QVariant" : p.type.name;
+                h << "    " << t << " m_" << p.name << ";\n";
+            }
+        }
+        h << "    void readConstants();\n";
+    }
     for (auto p: o.properties) {
         bool obj = p.type.type == BindingType::Object;
         auto t = p.type.name;
         if (p.optional && !p.type.isComplex()) {
             t = "QVariant";
         }
-        h << QString("    Q_PROPERTY(%1 %2 READ %2 %3NOTIFY %2Changed FINAL)")
-                .arg(t + (obj ?"*" :""),
-                     p.name,
-                     p.write ? writeProperty(p.name) :"")
+        QString access;
+        if (p.write) {
+            access += writeProperty(p.name);
+        }
+        if (p.reset) {
+            access += "RESET reset" + upperInitial(p.name) + " ";
+        }
+        if (p.constant) {
+            access += "CONSTANT ";
+        } else {
+            access += "NOTIFY " + p.name + "Changed ";
+        }
+        if (p.bindable) {
+            access += "BINDABLE bindable" + upperInitial(p.name) + " ";
+        }
+        h << QString("    Q_PROPERTY(%1 %2 READ %2 %3FINAL)")
+                .arg(t + (obj ?"*" :""), p.name, access)
              << endl;
     }
-    h << QString(R"(    explicit %1(bool owned, QObject *parent);
+    if (o.singleton) {
+        h << QString(R"(    explicit %1(bool owned, QObject *parent);
+    explicit %1(QObject *parent = nullptr);
+    // the instance is never deleted
+    ~%1();
+public:
+    // the one instance of %1, it lives as long as the program
+    static %1* instance();
+)").arg(o.name);
+        if (conf.qtVersion >= 6 && !conf.qmlUri.isEmpty()) {
+            h << QString(R"(    // called by QML to get the instance
+    static %1* create(QQmlEngine*, QJSEngine*);
+)").arg(o.name);
+        }
+    } else {
+        h << QString(R"(    explicit %1(bool owned, QObject *parent);
 public:
     explicit %1(QObject *parent = nullptr);
     ~%1();
 )").arg(o.name);
+    }
     for (auto p: o.properties) {
+        cppDoc(h, "    ", p.doc);
         if (p.type.type == BindingType::Object) {
             h << "    const " << p.type.name << "* " << p.name << "() const;" << endl;
+            cppDoc(h, "    ", p.doc);
             h << "    " << p.type.name << "* " << p.name << "();" << endl;
         } else {
             auto t = p.type.name;
@@ -583,12 +852,28 @@ public:
                 t2 = "const QVariant&";
             }
             h << "    " << t << " " << p.name << "() const;" << endl;
+            if (p.borrowed) {
+                h << "    // C++ only: wraps the buffer of Rust without a copy and is valid"
+                    << endl << "    // until " << p.name << " changes" << endl;
+                h << "    " << t << " " << p.name << "View() const;" << endl;
+            }
+            if (p.bindable) {
+                cppDoc(h, "    ", p.doc);
+                h << "    QBindable<" << t << "> bindable" << upperInitial(p.name)
+                    << "();" << endl;
+            }
             if (p.write) {
+                cppDoc(h, "    ", p.doc);
                 h << "    void set" << upperInitial(p.name) << "(" << t2 << " v);" << endl;
             }
+            if (p.reset) {
+                cppDoc(h, "    ", p.doc);
+                h << "    void reset" << upperInitial(p.name) << "();" << endl;
+            }
         }
     }
     for (auto f: o.functions) {
+        cppDoc(h, "    ", f.doc, f.args);
         h << "    Q_INVOKABLE " << f.type.name << " " << f.name << "(";
         for (auto a = f.args.begin(); a < f.args.end(); a++) {
             if (a != f.args.begin()) {
@@ -602,20 +887,57 @@ public:
     if (baseType(o) == "QAbstractItemModel") {
         writeHeaderItemModel(h, o);
     }
+    if (o.hasCoalesced()) {
+        h << QString(R"(protected:
+    // runs the closures that Rust sends with run_on_gui_thread and delivers
+    // the coalesced signals
+    bool event(QEvent* e) override;
+private:
+    static void postTask(%1* o, void* task);
+    // a bit for each coalesced signal that waits for delivery
+    QAtomicInt m_pendingSignals;
+    static void emitLater(%1* o, int signal);
+)").arg(o.name);
+        if (o.coalesce) {
+            h << QString(R"(    // the rows that changed since dataChanged was last emitted, guarded by
+    // m_dirtyMutex because Rust may report them from any thread
+    QMutex m_dirtyMutex;
+    bool m_dirty = false;
+    quintptr m_dirtyFirst = 0;
+    quintptr m_dirtyLast = 0;
+    static void dataChangedLater(%1* o, quintptr first, quintptr last);
+    static void flushDataChanged(%1* o);
+)").arg(o.name);
+        }
+    } else {
+        h << QString(R"(protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(%1* o, void* task);
+)").arg(o.name);
+    }
     h << "signals:" << endl;
     for (auto p: o.properties) {
-        h << "    void " << p.name << "Changed();" << endl;
+        if (!p.constant && !p.bindable) {
+            h << "    void " << p.name << "Changed();" << endl;
+        }
     }
     h << "};" << endl;
 }
 
 void constructorArgsDecl(QTextStream& cpp, const Object& o, const Configuration& conf) {
     cpp << o.name << "*";
+    cpp << QString(", void (*)(%1*, void*)").arg(o.name);
     for (auto p: o.properties) {
         if (p.type.type == BindingType::Object) {
             cpp << QString(", ");
             constructorArgsDecl(cpp, conf.findObject(p.type.name), conf);
-        } else {
+        } else if (p.bindable && p.type.isComplex()) {
+            cpp << QString(", void (*)(%1*, const char*, int)").arg(o.name);
+        } else if (p.bindable) {
+            cpp << QString(", void (*)(%1*, %2)").arg(o.name, p.type.name);
+        } else if (!p.constant) {
             cpp << QString(", void (*)(%1*)").arg(o.name);
         }
     }
@@ -647,94 +969,200 @@ QString changedF(const Object& o, const Property& p) {
     return lowerInitial(o.name) + upperInitial(p.name) + "Changed";
 }
 
-void constructorArgs(QTextStream& cpp, const QString& prefix, const Object& o, const Configuration& conf) {
-    const QString lcname(snakeCase(o.name));
+// The bit in m_pendingSignals for the coalesced property 'p'. Bit 0 is the
+// dataChanged signal of a coalesced list.
+int coalescedSignal(const Object& o, const Property& p) {
+    int signal = 1;
+    for (auto q: o.properties) {
+        if (q.name == p.name) {
+            break;
+        }
+        if (q.coalesce) {
+            signal += 1;
+        }
+    }
+    return signal;
+}
+
+// The function that stores a value from Rust in a bindable property.
+QString updateF(const Object& o, const Property& p) {
+    return lowerInitial(o.name) + upperInitial(p.name) + "Update";
+}
+
+// The expression for the object property 'p' of 'self', an object of type
+// 'o'. An empty 'self' is the object whose constructor is written. Imported
+// objects are reached via their public getters.
+QString childObject(const QString& self, const Object& o, const Property& p, const Configuration& conf) {
+    if (self.isEmpty()) {
+        return "m_" + p.name;
+    }
+    if (conf.isImported(o.name)) {
+        return self + "->" + p.name + "()";
+    }
+    return self + "->m_" + p.name;
+}
+
+// The expression for the Private pointer of 'self', an object of type 'o'.
+QString privateOf(const QString& self, const Object& o, const Configuration& conf) {
+    if (self.isEmpty()) {
+        return "m_d";
+    }
+    if (conf.isImported(o.name)) {
+        return o.name + "::Binding::d(" + self + ")";
+    }
+    return self + "->m_d";
+}
+
+// A call in a lambda of the protected member 'f' of 'o', which the lambda
+// gets as 'o'. Imported classes do not know the classes that use them as
+// properties, so they are reached via their Binding.
+QString callMember(const Object& o, const QString& f, const QString& args,
+        const Configuration& conf) {
+    if (conf.isImported(o.name)) {
+        return QString("%1::Binding::%2(o%3)")
+            .arg(o.name, f, args.isEmpty() ? "" : ", " + args);
+    }
+    return QString("o->%1(%2)").arg(f, args);
+}
+
+// A call in a lambda of the private static member 'f' of 'o'.
+QString callStatic(const Object& o, const QString& f, const QString& args,
+        const Configuration& conf) {
+    return QString("%1::%2%3(o%4)").arg(o.name,
+        conf.isImported(o.name) ? "Binding::" : "", f,
+        args.isEmpty() ? "" : ", " + args);
+}
+
+void constructorArgs(QTextStream& cpp, const QString& self, const Object& o, const Configuration& conf) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    cpp << ",\n        " << o.name
+        << (conf.isImported(o.name) ? "::Binding::postTask" : "::postTask");
     for (const Property& p: o.properties) {
         if (p.type.type == BindingType::Object) {
-            cpp << ", " << prefix << "m_" << p.name;
-            constructorArgs(cpp, "m_" + p.name + "->",
-                    conf.findObject(p.type.name), conf);
-        } else {
+            const QString child = childObject(self, o, p, conf);
+            cpp << ", " << child;
+            constructorArgs(cpp, child, conf.findObject(p.type.name), conf);
+        } else if (p.bindable) {
+            cpp << ",\n        " << updateF(o, p);
+        } else if (!p.constant) {
             cpp << ",\n        " << changedF(o, p);
         }
     }
+    const auto call = [&o, &conf](const QString& f, const QString& args) {
+        return callMember(o, f, args, conf);
+    };
+    const QString d = privateOf("o", o, conf);
+    QString lambdas;
     if (o.type == ObjectType::List) {
-        cpp << QString(R"(,
+        // the changed rows of a coalesced list are merged until the rows move
+        QString changed = QString("o->dataChanged(%1,\n                       %2);")
+            .arg(call("createIndex", "first, 0, first"),
+                call("createIndex", QString("last, %1, last").arg(o.columnCount - 1)));
+        QString flush;
+        if (o.coalesce) {
+            changed = callStatic(o, "dataChangedLater", "first, last", conf) + ";";
+            flush = callStatic(o, "flushDataChanged", "", conf) + ";\n            ";
+        }
+        // the cached rows of a bulk list are dropped when they change or move
+        QString drop;
+        if (o.bulk) {
+            changed = callStatic(o, "dropCachedRows", "first, last", conf)
+                + ";\n            " + changed;
+            drop = callStatic(o, "dropCache", "", conf) + ";\n            ";
+        }
+        lambdas = QString(R"(,
         [](const %1* o) {
             emit o->newDataReady(QModelIndex());
         },
         [](%1* o, quintptr first, quintptr last) {
-            o->dataChanged(o->createIndex(first, 0, first),
-                       o->createIndex(last, %2, last));
+            %2
         },
         [](%1* o) {
-            o->beginResetModel();
+            %3%4;
         },
         [](%1* o) {
-            o->endResetModel();
+            %5;
         },
         [](%1* o, int first, int last) {
-            o->beginInsertRows(QModelIndex(), first, last);
+            %3%6;
         },
         [](%1* o) {
-            o->endInsertRows();
+            %7;
         },
         [](%1* o, int first, int last) {
-            o->beginRemoveRows(QModelIndex(), first, last);
+            %3%8;
         },
         [](%1* o) {
-            o->endRemoveRows();
+            %9;
         }
-)").arg(o.name,     QString::number(o.columnCount - 1));
+)").arg(o.name, changed, flush,
+            call("beginResetModel", ""), drop + call("endResetModel", ""),
+            call("beginInsertRows", "QModelIndex(), first, last"),
+            drop + call("endInsertRows", ""),
+            call("beginRemoveRows", "QModelIndex(), first, last"),
+            drop + call("endRemoveRows", ""));
     }
     if (o.type == ObjectType::Tree) {
-        cpp << QString(R"(,
+        lambdas = QString(R"(,
         [](const %1* o, quintptr id, bool valid) {
             if (valid) {
-                int row = %2_row(o->m_d, id);
-                emit o->newDataReady(o->createIndex(row, 0, id));
+                int row = %2_row(%3, id);
+                emit o->newDataReady(%4);
             } else {
                 emit o->newDataReady(QModelIndex());
             }
         },
         [](%1* o, quintptr first, quintptr last) {
-            quintptr frow = %2_row(o->m_d, first);
-            quintptr lrow = %2_row(o->m_d, first);
-            o->dataChanged(o->createIndex(frow, 0, first),
-                       o->createIndex(lrow, %3, last));
+            quintptr frow = %2_row(%3, first);
+            quintptr lrow = %2_row(%3, first);
+            o->dataChanged(%5,
+                       %6);
         },
         [](%1* o) {
-            o->beginResetModel();
+            %7;
         },
         [](%1* o) {
-            o->endResetModel();
+            %8;
         },
-        [](%1* o, option_quintptr id, int first, int last) {
+)").arg(o.name, lcname, d, call("createIndex", "row, 0, id"),
+            call("createIndex", "frow, 0, first"),
+            call("createIndex", QString("lrow, %1, last").arg(o.columnCount - 1)),
+            call("beginResetModel", ""), call("endResetModel", ""));
+        const QString parent = call("createIndex", "row, 0, id.value");
+        lambdas += QString(R"(        [](%1* o, option_quintptr id, int first, int last) {
             if (id.some) {
-                int row = %2_row(o->m_d, id.value);
-                o->beginInsertRows(o->createIndex(row, 0, id.value), first, last);
+                int row = %2_row(%3, id.value);
+                %4;
             } else {
-                o->beginInsertRows(QModelIndex(), first, last);
+                %5;
             }
         },
         [](%1* o) {
-            o->endInsertRows();
+            %6;
         },
         [](%1* o, option_quintptr id, int first, int last) {
             if (id.some) {
-                int row = %2_row(o->m_d, id.value);
-                o->beginRemoveRows(o->createIndex(row, 0, id.value), first, last);
+                int row = %2_row(%3, id.value);
+                %7;
             } else {
-                o->beginRemoveRows(QModelIndex(), first, last);
+                %8;
             }
         },
         [](%1* o) {
-            o->endRemoveRows();
+            %9;
         }
-)").arg(o.name, lcname, QString::number(o.columnCount - 1));
-    }
+)").arg(o.name, lcname, d,
+            call("beginInsertRows", parent + ", first, last"),
+            call("beginInsertRows", "QModelIndex(), first, last"),
+            call("endInsertRows", ""),
+            call("beginRemoveRows", parent + ", first, last"),
+            call("beginRemoveRows", "QModelIndex(), first, last"),
+            call("endRemoveRows", ""));
+    }
+    cpp << lambdas;
 }
 
+
 void writeFunctionCDecl(QTextStream& cpp, const Function& f, const QString& lcname, const Object& o) {
     const QString lc(snakeCase(f.name));
     cpp << "    ";
@@ -768,17 +1196,26 @@ void writeFunctionCDecl(QTextStream& cpp, const Function& f, const QString& lcna
 }
 
 void writeObjectCDecl(QTextStream& cpp, const Object& o, const Configuration& conf) {
-    const QString lcname(snakeCase(o.name));
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
     cpp << QString("    %1::Private* %2_new(").arg(o.name, lcname);
     constructorArgsDecl(cpp, o, conf);
     cpp << ");" << endl;
     cpp << QString("    void %2_free(%1::Private*);").arg(o.name, lcname)
         << endl;
+    cpp << QString("    void %2_run_task(%1::Private*, void*);").arg(o.name, lcname)
+        << endl;
+    cpp << QString("    void %1_drop_task(void*);").arg(lcname) << endl;
     for (const Property& p: o.properties) {
         const QString base = QString("%1_%2").arg(lcname, snakeCase(p.name));
         if (p.type.type == BindingType::Object) {
             cpp << QString("    %3::Private* %2_get(const %1::Private*);")
                 .arg(o.name, base, p.type.name) << endl;
+        } else if (p.bindable) {
+            // the value is pushed by Rust, there is no getter
+        } else if (p.borrowed) {
+            cpp << QString("    const %3* %2_get(const %1::Private*, int*);")
+                .arg(o.name, base, p.type.name == "QString" ? "ushort" : "char")
+                << endl;
         } else if (p.type.isComplex()) {
             cpp << QString("    void %2_get(const %1::Private*, %3);")
                 .arg(o.name, base, cGetType(p.type)) << endl;
@@ -803,6 +1240,10 @@ void writeObjectCDecl(QTextStream& cpp, const Object& o, const Configuration& co
                     .arg(o.name, base) << endl;
             }
         }
+        if (p.reset) {
+            cpp << QString("    void %2_reset(%1::Private*);")
+                .arg(o.name, base) << endl;
+        }
     }
 
     for (const Function& f: o.functions) {
@@ -810,6 +1251,26 @@ void writeObjectCDecl(QTextStream& cpp, const Object& o, const Configuration& co
     }
 }
 
+// The functions of an imported object that are called when it is
+// constructed as a property of an object in this configuration.
+void writeImportedCDecl(QTextStream& cpp, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    QString decl;
+    if (o.type == ObjectType::Tree) {
+        decl += QString("    int %2_row(const %1::Private*, quintptr);\n")
+            .arg(o.name, lcname);
+    }
+    for (const Property& p: o.properties) {
+        if (p.type.type == BindingType::Object) {
+            decl += QString("    %3::Private* %2_%4_get(const %1::Private*);\n")
+                .arg(o.name, lcname, p.type.name, snakeCase(p.name));
+        }
+    }
+    if (!decl.isEmpty()) {
+        cpp << "extern \"C\" {\n" << decl << "};\n\n";
+    }
+}
+
 void initializeMembersEmpty(QTextStream& cpp, const Object& o, const Configuration& conf)
 {
     for (const Property& p: o.properties) {
@@ -821,32 +1282,90 @@ void initializeMembersEmpty(QTextStream& cpp, const Object& o, const Configurati
     }
 }
 
-void initializeMembersZero(QTextStream& cpp, const Object& o)
+QString newChild(const Property& p, const Configuration& conf)
+{
+    if (conf.isImported(p.type.name)) {
+        return QString("m_%1(%2::Binding::create(this))")
+            .arg(p.name, p.type.name);
+    }
+    return QString("m_%1(new %2(false, this))").arg(p.name, p.type.name);
+}
+
+void initializeMembersZero(QTextStream& cpp, const Object& o, const Configuration& conf)
 {
     for (const Property& p: o.properties) {
         if (p.type.type == BindingType::Object) {
-            cpp << QString("    m_%1(new %2(false, this)),\n")
-                   .arg(p.name, p.type.name);
+            cpp << "    " << newChild(p, conf) << ",\n";
         }
     }
 }
 
-void initializeMembers(QTextStream& cpp, const QString& prefix, const Object& o, const Configuration& conf)
+void initializeMembers(QTextStream& cpp, const QString& self, const Object& o, const Configuration& conf)
 {
     for (const Property& p: o.properties) {
         if (p.type.type == BindingType::Object) {
-            cpp << QString("    %1m_%2->m_d = %3_%4_get(%1m_d);\n")
-                   .arg(prefix, p.name, snakeCase(o.name), snakeCase(p.name));
-            initializeMembers(cpp, "m_" + p.name + "->",
-                    conf.findObject(p.type.name), conf);
+            const Object& c = conf.findObject(p.type.name);
+            const QString child = childObject(self, o, p, conf);
+            cpp << QString("    %1 = %2_%3_get(%4);\n")
+                   .arg(privateOf(child, c, conf), o.symbolPrefix + snakeCase(o.name),
+                        snakeCase(p.name), privateOf(self, o, conf));
+            initializeMembers(cpp, child, c, conf);
         }
     }
+    if (!o.hasConstants()) {
+        return;
+    }
+    if (self.isEmpty()) {
+        cpp << "    readConstants();\n";
+    } else if (conf.isImported(o.name)) {
+        cpp << QString("    %1::Binding::readConstants(%2);\n").arg(o.name, self);
+    } else {
+        cpp << QString("    %1->readConstants();\n").arg(self);
+    }
+}
+
+// Read the values of the const properties of 'o' from Rust into members.
+void writeCppConstants(QTextStream& cpp, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    cpp << QString("void %1::readConstants()\n{\n").arg(o.name);
+    for (const Property& p: o.properties) {
+        if (!p.constant || p.type.type == BindingType::Object) {
+            continue;
+        }
+        const QString base = QString("%1_%2").arg(lcname, snakeCase(p.name));
+        if (p.borrowed) {
+            const bool string = p.type.name == "QString";
+            cpp << QString(R"(    {
+        int len = 0;
+        const %1* v = %2_get(m_d, &len);
+        m_%3 = %4;
+    }
+)").arg(string ? "ushort" : "char", base, p.name,
+                string ? "QString(reinterpret_cast<const QChar*>(v), len)"
+                    : "QByteArray(v, len)");
+        } else if (p.type.isComplex()) {
+            cpp << QString("    %1_get(m_d, &m_%2, set_%3);\n")
+                .arg(base, p.name, p.type.name.toLower());
+        } else if (p.optional) {
+            cpp << QString(R"(    {
+        auto r = %1_get(m_d);
+        if (r.some) {
+            m_%2.setValue(r.value);
+        }
+    }
+)").arg(base, p.name);
+        } else {
+            cpp << QString("    m_%2 = %1_get(m_d);\n").arg(base, p.name);
+        }
+    }
+    cpp << "}\n";
 }
 
 void connect(QTextStream& cpp, const QString& d, const Object& o, const Configuration& conf) {
     for (auto p: o.properties) {
         if (p.type.type == BindingType::Object) {
-            connect(cpp, d + "->m_" + p.name, conf.findObject(p.type.name), conf);
+            connect(cpp, childObject(d, o, p, conf),
+                    conf.findObject(p.type.name), conf);
         }
     }
     if (o.type != ObjectType::Object) {
@@ -857,26 +1376,46 @@ void connect(QTextStream& cpp, const QString& d, const Object& o, const Configur
     }
 }
 
+// Rust may push a value from any thread, but a bindable property may only be
+// changed in the thread of its object.
+void writeBindableUpdate(QTextStream& cpp, const Object& o, const Property& p) {
+    QString args = QString("%1 v").arg(p.type.name);
+    QString convert;
+    if (p.type.name == "QString") {
+        args = "const char* s, int len";
+        convert = "        const QString v = QString::fromUtf8(s, len);\n";
+    } else if (p.type.name == "QByteArray") {
+        args = "const char* s, int len";
+        convert = "        const QByteArray v(s, len);\n";
+    }
+    cpp << QString(R"(    inline void %1(%2* o, %3)
+    {
+%4        if (o->thread() == QThread::currentThread()) {
+            o->bindable%5().setValue(v);
+        } else {
+            QMetaObject::invokeMethod(o, [o, v]() {
+                o->bindable%5().setValue(v);
+            }, Qt::QueuedConnection);
+        }
+    }
+)").arg(updateF(o, p), o.name, args, convert, upperInitial(p.name));
+}
+
 void writeCppObject(QTextStream& cpp, const Object& o, const Configuration& conf) {
-    const QString lcname(snakeCase(o.name));
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
     cpp << QString("%1::%1(bool /*owned*/, QObject *parent):\n    %2(parent),")
             .arg(o.name, baseType(o)) << endl;
-    for (const Property& p: o.properties) {
-        if (p.type.type == BindingType::Object) {
-            cpp << QString("    m_%1(new %2(false, this)),\n")
-                   .arg(p.name, p.type.name);
-        }
-    }
-    cpp << "    m_d(0),\n    m_ownsPrivate(false)\n{\n";
+    initializeMembersZero(cpp, o, conf);
+    cpp << "    m_d(0),\n    m_ownsPrivate(false)\n{\n    checkBindingHash();\n";
     if (o.type != ObjectType::Object) {
         cpp << "    initHeaderData();\n";
     }
     cpp << QString("}\n\n%1::%1(QObject *parent):\n    %2(parent),")
             .arg(o.name, baseType(o)) << endl;
-    initializeMembersZero(cpp, o);
-    cpp << QString("    m_d(%1_new(this").arg(lcname);
+    initializeMembersZero(cpp, o, conf);
+    cpp << QString("    m_d((checkBindingHash(), %1_new(this").arg(lcname);
     constructorArgs(cpp, "", o, conf);
-    cpp << ")),\n    m_ownsPrivate(true)\n{\n";
+    cpp << "))),\n    m_ownsPrivate(true)\n{\n";
     initializeMembers(cpp, "", o, conf);
     connect(cpp, "this", o, conf);
     if (o.type != ObjectType::Object) {
@@ -889,7 +1428,102 @@ void writeCppObject(QTextStream& cpp, const Object& o, const Configuration& conf
         %2_free(m_d);
     }
 }
+
+bool %1::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        %2_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
 )").arg(o.name, lcname);
+    if (o.hasCoalesced()) {
+        // the signals that are posted while these are emitted post a new event
+        cpp << "    if (e->type() == pendingSignalsEvent()) {\n"
+            << "        const int pending = m_pendingSignals.fetchAndStoreOrdered(0);\n";
+        if (o.coalesce) {
+            cpp << "        if (pending & 1) {\n"
+                << "            flushDataChanged(this);\n"
+                << "        }\n";
+        }
+        for (auto p: o.properties) {
+            if (p.coalesce) {
+                cpp << QString("        if (pending & (1 << %1)) {\n"
+                               "            emit %2Changed();\n"
+                               "        }\n").arg(QString::number(coalescedSignal(o, p)), p.name);
+            }
+        }
+        cpp << "        return true;\n    }\n";
+    }
+    cpp << QString(R"(    return %3::event(e);
+}
+
+void %1::postTask(%1* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, %2_drop_task));
+}
+)").arg(o.name, lcname, baseType(o));
+    if (o.hasCoalesced()) {
+        cpp << QString(R"(
+void %1::emitLater(%1* o, int signal)
+{
+    // only the first signal since the last delivery posts an event
+    if (o->m_pendingSignals.fetchAndOrOrdered(1 << signal) == 0) {
+        QCoreApplication::postEvent(o, new QEvent(pendingSignalsEvent()));
+    }
+}
+)").arg(o.name);
+    }
+    if (o.coalesce) {
+        cpp << QString(R"(
+void %1::dataChangedLater(%1* o, quintptr first, quintptr last)
+{
+    QMutexLocker lock(&o->m_dirtyMutex);
+    if (o->m_dirty) {
+        o->m_dirtyFirst = qMin(o->m_dirtyFirst, first);
+        o->m_dirtyLast = qMax(o->m_dirtyLast, last);
+    } else {
+        o->m_dirty = true;
+        o->m_dirtyFirst = first;
+        o->m_dirtyLast = last;
+        emitLater(o, 0);
+    }
+}
+
+// Called in the thread of the object, before its rows move and when the
+// posted event is delivered.
+void %1::flushDataChanged(%1* o)
+{
+    QMutexLocker lock(&o->m_dirtyMutex);
+    if (!o->m_dirty) {
+        return;
+    }
+    o->m_dirty = false;
+    const quintptr first = o->m_dirtyFirst;
+    const quintptr last = o->m_dirtyLast;
+    lock.unlock();
+    emit o->dataChanged(o->createIndex(first, 0, first),
+            o->createIndex(last, %2, last));
+}
+)").arg(o.name, QString::number(o.columnCount - 1));
+    }
+    if (o.singleton) {
+        cpp << QString(R"(
+%1* %1::instance()
+{
+    static %1* const instance = new %1();
+    return instance;
+}
+)").arg(o.name);
+        if (conf.qtVersion >= 6 && !conf.qmlUri.isEmpty()) {
+            cpp << QString(R"(
+%1* %1::create(QQmlEngine*, QJSEngine*)
+{
+    QQmlEngine::setObjectOwnership(instance(), QQmlEngine::CppOwnership);
+    return instance();
+}
+)").arg(o.name);
+        }
+    }
 
     if (o.type != ObjectType::Object) {
         cpp << QString("void %1::initHeaderData() {\n").arg(o.name);
@@ -905,6 +1539,9 @@ void writeCppObject(QTextStream& cpp, const Object& o, const Configuration& conf
         cpp << "}\n";
     }
 
+    if (o.hasConstants()) {
+        writeCppConstants(cpp, o);
+    }
     for (const Property& p: o.properties) {
         const QString base = QString("%1_%2").arg(lcname, snakeCase(p.name));
         if (p.type.type == BindingType::Object) {
@@ -917,6 +1554,59 @@ void writeCppObject(QTextStream& cpp, const Object& o, const Configuration& conf
     return m_%2;
 }
 )").arg(o.name, p.name, p.type.name);
+        } else if (p.constant) {
+            cpp << QString(R"(%3 %1::%2() const
+{
+    return m_%2;
+}
+)").arg(o.name, p.name, p.optional && !p.type.isComplex() ? "QVariant" : p.type.name);
+            if (p.borrowed) {
+                // the value does not change, so the view may wrap the copy
+                cpp << QString(R"(%3 %1::%2View() const
+{
+    return m_%2;
+}
+)").arg(o.name, p.name, p.type.name);
+            }
+        } else if (p.bindable) {
+            cpp << QString(R"(%3 %1::%2() const
+{
+    return m_%2;
+}
+QBindable<%3> %1::bindable%4()
+{
+    return &m_%2;
+}
+)").arg(o.name, p.name, p.type.name, upperInitial(p.name));
+        } else if (p.borrowed && p.type.name == "QString") {
+            // the getter copies, the view wraps the buffer of Rust
+            cpp << QString(R"(QString %1::%2() const
+{
+    int len = 0;
+    const ushort* v = %3_get(m_d, &len);
+    return QString(reinterpret_cast<const QChar*>(v), len);
+}
+QString %1::%2View() const
+{
+    int len = 0;
+    const ushort* v = %3_get(m_d, &len);
+    return QString::fromRawData(reinterpret_cast<const QChar*>(v), len);
+}
+)").arg(o.name, p.name, base);
+        } else if (p.borrowed) {
+            cpp << QString(R"(QByteArray %1::%2() const
+{
+    int len = 0;
+    const char* v = %3_get(m_d, &len);
+    return QByteArray(v, len);
+}
+QByteArray %1::%2View() const
+{
+    int len = 0;
+    const char* v = %3_get(m_d, &len);
+    return QByteArray::fromRawData(v, len);
+}
+)").arg(o.name, p.name, base);
         } else if (p.type.isComplex()) {
             cpp << QString("%3 %1::%2() const\n{\n").arg(o.name, p.name, p.type.name);
             cpp << "    " << p.type.name << " v;\n";
@@ -969,6 +1659,11 @@ void writeCppObject(QTextStream& cpp, const Object& o, const Configuration& conf
             }
             cpp << "}" << endl;
         }
+        if (p.reset) {
+            cpp << "void " << o.name << "::reset" << upperInitial(p.name) << "() {" << endl;
+            cpp << QString("    %1_reset(m_d);").arg(base) << endl;
+            cpp << "}" << endl;
+        }
     }
 
     for (const Function& f: o.functions) {
@@ -1007,6 +1702,25 @@ void writeCppObject(QTextStream& cpp, const Object& o, const Configuration& conf
     }
 }
 
+void openNamespace(QTextStream& out, const QString& ns) {
+    if (ns.isEmpty()) {
+        return;
+    }
+    for (auto part: ns.split("::")) {
+        out << "namespace " << part << " {\n";
+    }
+}
+
+void closeNamespace(QTextStream& out, const QString& ns) {
+    if (ns.isEmpty()) {
+        return;
+    }
+    const QStringList parts = ns.split("::");
+    for (int i = parts.size() - 1; i >= 0; --i) {
+        out << "} // namespace " << parts[i] << "\n";
+    }
+}
+
 void writeHeader(const Configuration& conf) {
     DifferentFileWriter w(conf.hFile.absoluteFilePath());
     QTextStream h(&w.buffer);
@@ -1017,15 +1731,52 @@ void writeHeader(const Configuration& conf) {
 
 #include <QObject>
 #include <QAbstractItemModel>
-
 )").arg(guard);
+    if (conf.qtVersion >= 6 && !conf.qmlUri.isEmpty()) {
+        h << "#include <QtQml/qqmlregistration.h>\n";
+    }
+    if (conf.hasBindable()) {
+        h << "#include <QProperty>\n";
+    }
+    if (conf.hasCoalescedList() || conf.hasBulkList()) {
+        h << "#include <QMutex>\n";
+    }
+    for (auto i: conf.imports) {
+        h << QString("#include \"%1\"\n")
+            .arg(conf.hFile.dir().relativeFilePath(i.hFile.absoluteFilePath()));
+    }
+    if (conf.qtVersion >= 6) {
+        h << R"(
+#if QT_VERSION < QT_VERSION_CHECK(6, 2, 0)
+#error "This file was generated for Qt 6.2 or newer."
+#endif
+)";
+        if (!conf.qmlUri.isEmpty()) {
+            h << "\nclass QQmlEngine;\nclass QJSEngine;\n";
+        }
+    }
+    h << "\n";
 
+    openNamespace(h, conf.cppNamespace);
+    for (auto object: conf.importedChildren()) {
+        const QString ns = conf.cppNamespaceOf(object.name);
+        if (ns != conf.cppNamespace) {
+            h << "using ::" << conf.qualifiedName(object.name) << ";\n";
+        }
+    }
     for (auto object: conf.objects) {
         h << "class " << object.name << ";\n";
     }
     for (auto object: conf.objects) {
         writeHeaderObject(h, object, conf);
     }
+    if (!conf.qmlUri.isEmpty()) {
+        h << QString(R"(
+// register all types with QML under the module %1 %2.%3
+void registerQmlTypes();
+)").arg(conf.qmlUri).arg(conf.qmlVersionMajor).arg(conf.qmlVersionMinor);
+    }
+    closeNamespace(h, conf.cppNamespace);
 
     h << QString("#endif // %1\n").arg(guard);
 }
@@ -1035,9 +1786,55 @@ void writeCpp(const Configuration& conf) {
     QTextStream cpp(&w.buffer);
     cpp << QString(R"(/* generated by rust_qt_binding_generator */
 #include "%1"
-
-namespace {
 )").arg(conf.hFile.fileName());
+    if (!conf.qmlUri.isEmpty()) {
+        cpp << "#include <QtQml/qqml.h>\n";
+        cpp << "#include <QQmlEngine>\n";
+    }
+    cpp << "#include <QCoreApplication>\n";
+    if (conf.hasBindable()) {
+        cpp << "#include <QThread>\n";
+    }
+    cpp << "\n";
+    openNamespace(cpp, conf.cppNamespace);
+    cpp << R"(namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+)";
+    if (conf.hasCoalesced()) {
+        cpp << R"(
+    // The event that delivers the coalesced signals of an object.
+    QEvent::Type pendingSignalsEvent() {
+        static const QEvent::Type t
+            = static_cast<QEvent::Type>(QEvent::registerEventType());
+        return t;
+    }
+)";
+    }
     for (auto option: conf.optionalTypes()) {
         if (option != "QString" && option != "QByteArray") {
             cpp << QString(R"(
@@ -1052,8 +1849,12 @@ namespace {
             return QVariant();
         }
     };
-    static_assert(std::is_pod<option_%1>::value, "option_%1 must be a POD type.");
-)").arg(option);
+    static_assert(%2, "option_%1 must be a POD type.");
+)").arg(option, conf.qtVersion >= 6
+                // std::is_pod is deprecated in C++20
+                ? QString("std::is_trivial<option_%1>::value\n"
+                    "        && std::is_standard_layout<option_%1>::value").arg(option)
+                : QString("std::is_pod<option_%1>::value").arg(option));
         }
     }
     if (conf.types().contains("QString")) {
@@ -1075,6 +1876,44 @@ namespace {
             v->append(bytes, nbytes);
         }
     }
+)";
+    }
+    QStringList bulkTypes;
+    for (auto o: conf.objects) {
+        for (auto ip: o.itemProperties) {
+            if (o.bulk && ip.type.isComplex() && !bulkTypes.contains(ip.type.name)) {
+                bulkTypes.append(ip.type.name);
+            }
+        }
+    }
+    if (bulkTypes.contains("QString")) {
+        cpp << R"(
+    // The strings of several rows, one after the other. An empty optional
+    // string has length -1.
+    typedef void (*qstring_rows_set)(QString* vals, const char* utf8, const int* nbytes, int count);
+    void set_qstring_rows(QString* vals, const char* utf8, const int* nbytes, int count) {
+        for (int i = 0; i < count; ++i) {
+            if (nbytes[i] >= 0) {
+                vals[i] = QString::fromUtf8(utf8, nbytes[i]);
+                utf8 += nbytes[i];
+            }
+        }
+    }
+)";
+    }
+    if (bulkTypes.contains("QByteArray")) {
+        cpp << R"(
+    // The bytes of several rows, one after the other. An empty optional
+    // value has length -1.
+    typedef void (*qbytearray_rows_set)(QByteArray* vals, const char* bytes, const int* nbytes, int count);
+    void set_qbytearray_rows(QByteArray* vals, const char* bytes, const int* nbytes, int count) {
+        for (int i = 0; i < count; ++i) {
+            if (nbytes[i] >= 0) {
+                vals[i] = QByteArray(bytes, nbytes[i]);
+                bytes += nbytes[i];
+            }
+        }
+    }
 )";
     }
     if (conf.hasListOrTree()) {
@@ -1089,12 +1928,21 @@ namespace {
 )";
     }
 
-    for (auto o: conf.objects) {
+    for (auto o: conf.objects + conf.importedChildren()) {
         for (auto p: o.properties) {
-            if (p.type.type == BindingType::Object) {
+            if (p.type.type == BindingType::Object || p.constant) {
+                continue;
+            }
+            if (p.bindable) {
+                writeBindableUpdate(cpp, o, p);
                 continue;
             }
             cpp << "    inline void " << changedF(o, p) << "(" << o.name << "* o)\n";
+            if (p.coalesce) {
+                cpp << QString("    {\n        %1::Binding::emitLater(o, %2);\n    }\n")
+                    .arg(o.name, coalescedSignal(o, p));
+                continue;
+            }
             cpp << "    {\n        emit o->" << p.name << "Changed();\n    }\n";
         }
     }
@@ -1102,7 +1950,7 @@ namespace {
 
     for (auto object: conf.objects) {
         if (object.type != ObjectType::Object) {
-            writeCppModel(cpp, object);
+            writeCppModel(cpp, object, conf);
         }
 
         cpp << "extern \"C\" {\n";
@@ -1110,7 +1958,61 @@ namespace {
         cpp << "};\n" << endl;
     }
 
+    for (auto object: conf.importedChildren()) {
+        writeImportedCDecl(cpp, object);
+    }
+
+    cpp << QString(R"(extern "C" {
+    quint64 %1();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = %1();
+        if (hash != Q_UINT64_C(%2)) {
+            qFatal("%3 and the Rust module %4 were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+)").arg(conf.bindingHashFunction(), conf.bindingHash(),
+        conf.cppFile.fileName(), conf.interfaceModule);
+
     for (auto object: conf.objects) {
         writeCppObject(cpp, object, conf);
     }
+
+    if (!conf.qmlUri.isEmpty()) {
+        cpp << "\nvoid registerQmlTypes()\n{\n";
+        cpp << QString("    const char* uri = \"%1\";\n").arg(conf.qmlUri);
+        if (conf.qtVersion >= 6) {
+            // the types carry their QML_ELEMENT and QML_SINGLETON macros
+            QStringList names;
+            for (auto object: conf.objects) {
+                names.append(object.name);
+            }
+            cpp << QString("    qmlRegisterTypesAndRevisions<%1>(uri, %2);\n")
+                .arg(names.join(", ")).arg(conf.qmlVersionMajor);
+        } else {
+            for (auto object: conf.objects) {
+                if (object.singleton) {
+                    cpp << QString(R"(    qmlRegisterSingletonType<%1>(uri, %2, %3, "%1",
+        [](QQmlEngine*, QJSEngine*) -> QObject* {
+            QQmlEngine::setObjectOwnership(%1::instance(), QQmlEngine::CppOwnership);
+            return %1::instance();
+        });
+)").arg(object.name).arg(conf.qmlVersionMajor).arg(conf.qmlVersionMinor);
+                } else {
+                    cpp << QString("    qmlRegisterType<%1>(uri, %2, %3, \"%1\");\n")
+                        .arg(object.name).arg(conf.qmlVersionMajor)
+                        .arg(conf.qmlVersionMinor);
+                }
+            }
+        }
+        cpp << "}\n";
+    }
+    closeNamespace(cpp, conf.cppNamespace);
 }
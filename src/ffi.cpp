@@ -0,0 +1,522 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+// The C header describes the functions that the Rust interface exports. It
+// follows the Rust side: the same names, argument names and types.
+
+#include "structs.h"
+#include "ffi.h"
+#include "helper.h"
+
+namespace {
+
+QString cType(const BindingTypeProperties& type) {
+    switch (type.type) {
+    case BindingType::Bool: return "bool";
+    case BindingType::Int8: return "int8_t";
+    case BindingType::UInt8: return "uint8_t";
+    case BindingType::Int16: return "int16_t";
+    case BindingType::UInt16: return "uint16_t";
+    case BindingType::Int32: return "int32_t";
+    case BindingType::UInt32: return "uint32_t";
+    case BindingType::Int64: return "int64_t";
+    case BindingType::UInt64: return "uint64_t";
+    case BindingType::Float: return "float";
+    case BindingType::Double: return "double";
+    case BindingType::Void: return "void";
+    default: return type.name;
+    }
+}
+
+template <typename T>
+QString cValueType(const T& p) {
+    if (p.optional) {
+        return "struct option_" + p.type.name;
+    }
+    return cType(p.type);
+}
+
+// arguments to pass a string or byte array from the caller to Rust
+QString cInput(const BindingTypeProperties& type, const QString& name,
+        const QString& len) {
+    if (type.type == BindingType::QString) {
+        return QString("const uint16_t* %1, int %2").arg(name, len);
+    }
+    return QString("const char* %1, int %2").arg(name, len);
+}
+
+// arguments to receive a string or byte array from Rust
+QString cOutput(const BindingTypeProperties& type, const QString& name) {
+    return QString("void* %1, %2_set set").arg(name, type.name.toLower());
+}
+
+void cDoc(QTextStream& h, const QStringList& lines) {
+    h << "/**\n";
+    for (auto line: lines) {
+        // a */ in the text would end the comment
+        line.replace("*/", "*\\/");
+        h << " *" << (line.isEmpty() ? "" : " " + line) << "\n";
+    }
+    h << " */\n";
+}
+
+// the documentation of a function with the doc string from the configuration
+void cDoc(QTextStream& h, const QString& summary, const QString& doc) {
+    QStringList lines;
+    lines << summary;
+    if (!doc.isEmpty()) {
+        lines << "" << doc.split('\n');
+    }
+    cDoc(h, lines);
+}
+
+void constructorParams(QStringList& params, const QString& name,
+        const Object& o, const Configuration& conf) {
+    const QString qobject = QString("const struct %1QObject*").arg(o.name);
+    params << QString("struct %1QObject* %2").arg(o.name, snakeCase(name));
+    // the task is passed to <name>_run_task in the thread of the QObject
+    params << QString("void (*%1_run_on_gui_thread)(%2, void* task)")
+        .arg(snakeCase(name), qobject);
+    for (const Property& p: o.properties) {
+        if (p.type.type == BindingType::Object) {
+            constructorParams(params, p.name, conf.findObject(p.type.name), conf);
+        } else if (p.bindable && p.type.isComplex()) {
+            // the value is passed as UTF-8 or bytes
+            params << QString("void (*%1_set)(%2, const char* v, int len)")
+                .arg(snakeCase(p.name), qobject);
+        } else if (p.bindable) {
+            params << QString("void (*%1_set)(%2, %3 v)")
+                .arg(snakeCase(p.name), qobject, cType(p.type));
+        } else if (!p.constant) {
+            params << QString("void (*%1_changed)(%2)")
+                .arg(snakeCase(p.name), qobject);
+        }
+    }
+    const QString lc = snakeCase(o.name);
+    if (o.type == ObjectType::List) {
+        params << QString("void (*%1_new_data_ready)(%2)").arg(lc, qobject);
+    } else if (o.type == ObjectType::Tree) {
+        params << QString("void (*%1_new_data_ready)(%2, uintptr_t item, bool valid)")
+            .arg(lc, qobject);
+    }
+    if (o.type != ObjectType::Object) {
+        const QString item = o.type == ObjectType::Tree
+            ? " uintptr_t item, bool valid," : "";
+        params << QString("void (*%1_data_changed)(%2, size_t first, size_t last)")
+            .arg(lc, qobject)
+            << QString("void (*%1_begin_reset_model)(%2)").arg(lc, qobject)
+            << QString("void (*%1_end_reset_model)(%2)").arg(lc, qobject)
+            << QString("void (*%1_begin_insert_rows)(%2,%3 size_t first, size_t last)")
+                .arg(lc, qobject, item)
+            << QString("void (*%1_end_insert_rows)(%2)").arg(lc, qobject)
+            << QString("void (*%1_begin_remove_rows)(%2,%3 size_t first, size_t last)")
+                .arg(lc, qobject, item)
+            << QString("void (*%1_end_remove_rows)(%2)").arg(lc, qobject);
+    }
+}
+
+void writeConstructor(QTextStream& h, const Object& o, const Configuration& conf) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    QStringList params;
+    constructorParams(params, o.name, o, conf);
+    QStringList doc;
+    doc << QString("Create the Rust object for the %1 %2.")
+        .arg(o.name, o.singleton ? "singleton" : "object")
+        << ""
+        << "The QObject pointers are passed back to the callbacks when the"
+        << "Rust object emits a signal. Objects that are properties come with"
+        << "their own QObject pointer and callbacks.";
+    if (o.singleton) {
+        doc << "" << "This aborts when it is called a second time.";
+    }
+    cDoc(h, doc);
+    h << QString("struct %1Private* %2_new(\n    %3);\n")
+        .arg(o.name, lcname, params.join(",\n    "));
+    cDoc(h, QStringList() << QString("Free the Rust object for %1.").arg(o.name));
+    h << QString("void %2_free(struct %1Private* ptr);\n").arg(o.name, lcname);
+    cDoc(h, QStringList()
+        << QString("Run a task that %1 passed to its run_on_gui_thread callback.")
+            .arg(o.name)
+        << "This is called in the thread of the QObject and consumes the task.");
+    h << QString("void %2_run_task(struct %1Private* ptr, void* task);\n")
+        .arg(o.name, lcname);
+    cDoc(h, QStringList()
+        << QString("Free a task of %1 without running it.").arg(o.name));
+    h << QString("void %1_drop_task(void* task);\n").arg(lcname);
+}
+
+void writeProperties(QTextStream& h, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    for (const Property& p: o.properties) {
+        const QString base = QString("%1_%2").arg(lcname, snakeCase(p.name));
+        if (p.type.type == BindingType::Object) {
+            cDoc(h, QString("The Rust object of the property %1. It is owned by %2.")
+                .arg(p.name, o.name), p.doc);
+            h << QString("struct %2Private* %3_get(struct %1Private* ptr);\n")
+                .arg(o.name, p.type.name, base);
+            continue;
+        }
+        if (p.bindable) {
+            // the value is pushed to a callback that is passed to _new
+        } else if (p.borrowed) {
+            QStringList doc;
+            doc << QString("Get the property %1 without a copy. The buffer belongs to")
+                    .arg(p.name)
+                << "Rust and is valid until the property changes. Its length is"
+                << "written to `len`.";
+            if (!p.doc.isEmpty()) {
+                doc << "" << p.doc.split('\n');
+            }
+            cDoc(h, doc);
+            h << QString("const %3* %2_get(const struct %1Private* ptr, int* len);\n")
+                .arg(o.name, base, p.type.name == "QString" ? "uint16_t" : "char");
+        } else if (p.type.isComplex()) {
+            cDoc(h, QString("Get the property %1 by passing its value to `set`.%2")
+                .arg(p.name, p.optional ? " `set` is not called when it is empty." : ""),
+                p.doc);
+            h << QString("void %2_get(const struct %1Private* ptr, %3);\n")
+                .arg(o.name, base, cOutput(p.type, "p"));
+        } else {
+            cDoc(h, QString("Get the property %1.").arg(p.name), p.doc);
+            h << QString("%3 %2_get(const struct %1Private* ptr);\n")
+                .arg(o.name, base, cValueType(p));
+        }
+        if (p.write) {
+            cDoc(h, QStringList() << QString("Set the property %1.").arg(p.name));
+            if (p.type.isComplex()) {
+                h << QString("void %2_set(struct %1Private* ptr, %3);\n")
+                    .arg(o.name, base, cInput(p.type, "v", "len"));
+            } else {
+                h << QString("void %2_set(struct %1Private* ptr, %3 v);\n")
+                    .arg(o.name, base, cType(p.type));
+            }
+        }
+        if (p.write && p.optional) {
+            cDoc(h, QStringList() << QString("Clear the property %1.").arg(p.name));
+            h << QString("void %2_set_none(struct %1Private* ptr);\n")
+                .arg(o.name, base);
+        }
+        if (p.reset) {
+            cDoc(h, QStringList() << QString("Reset the property %1 to its default.")
+                .arg(p.name));
+            h << QString("void %2_reset(struct %1Private* ptr);\n")
+                .arg(o.name, base);
+        }
+    }
+}
+
+void writeFunctions(QTextStream& h, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    for (const Function& f: o.functions) {
+        QStringList params;
+        params << QString("%1struct %2Private* ptr").arg(f.mut ? "" : "const ", o.name);
+        QStringList doc;
+        doc << QString("Call %1.%2().").arg(o.name, f.name);
+        if (!f.doc.isEmpty()) {
+            doc << "" << f.doc.split('\n');
+        }
+        for (auto a: f.args) {
+            if (a.type.isComplex()) {
+                params << cInput(a.type, a.name + "_str", a.name + "_len");
+            } else {
+                params << cType(a.type) + " " + a.name;
+            }
+            if (!a.doc.isEmpty()) {
+                doc << QString("@param %1 %2").arg(a.name, a.doc);
+            }
+        }
+        QString ret = cType(f.type);
+        if (f.type.isComplex()) {
+            ret = "void";
+            params << cOutput(f.type, "d");
+            doc << "" << "The result is passed to `set`.";
+        }
+        cDoc(h, doc);
+        h << QString("%1 %2_%3(%4);\n").arg(ret, lcname, snakeCase(f.name),
+            params.join(", "));
+    }
+}
+
+void writeModel(QTextStream& h, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    const QString d = o.name + "Private";
+    if (o.type == ObjectType::List) {
+        h << QString(R"(/** The number of rows. */
+int %2_row_count(const struct %1* ptr);
+/** Insert `count` rows at `row`. */
+bool %2_insert_rows(struct %1* ptr, int row, int count);
+/** Remove `count` rows at `row`. */
+bool %2_remove_rows(struct %1* ptr, int row, int count);
+/** Check if more rows can be fetched. */
+bool %2_can_fetch_more(const struct %1* ptr);
+/** Fetch more rows. */
+void %2_fetch_more(struct %1* ptr);
+/** Sort the rows by a column. */
+void %2_sort(struct %1* ptr, uint8_t column, enum SortOrder order);
+)").arg(d, lcname);
+    } else {
+        h << QString(R"(/**
+ * The number of rows under an item. The root item is passed with
+ * `valid` set to false.
+ */
+int %2_row_count(const struct %1* ptr, uintptr_t item, bool valid);
+/** Check if more rows can be fetched for an item. */
+bool %2_can_fetch_more(const struct %1* ptr, uintptr_t item, bool valid);
+/** Fetch more rows for an item. */
+void %2_fetch_more(struct %1* ptr, uintptr_t item, bool valid);
+/** Sort the rows by a column. */
+void %2_sort(struct %1* ptr, uint8_t column, enum SortOrder order);
+/** The item at `row` under an item. */
+uintptr_t %2_index(const struct %1* ptr, uintptr_t item, bool valid, int row);
+/** The parent of an item. The row is -1 for the root item. */
+struct qmodelindex_t %2_parent(const struct %1* ptr, uintptr_t index);
+/** The row of an item under its parent. */
+int %2_row(const struct %1* ptr, uintptr_t item);
+)").arg(d, lcname);
+    }
+    const QString index = o.type == ObjectType::List
+        ? "int row" : "uintptr_t item";
+    for (auto ip: o.itemProperties) {
+        const QString base = QString("%1_data_%2").arg(lcname, snakeCase(ip.name));
+        if (ip.type.isComplex()) {
+            cDoc(h, QString("Get %1 by passing it to `set`.%2").arg(ip.name,
+                ip.optional ? " `set` is not called when it is empty." : ""),
+                ip.doc);
+            h << QString("void %2(const struct %1* ptr, %3, %4);\n")
+                .arg(d, base, index, cOutput(ip.type, "d"));
+        } else {
+            cDoc(h, QString("Get %1.").arg(ip.name), ip.doc);
+            h << QString("%4 %2(const struct %1* ptr, %3);\n")
+                .arg(d, base, index, cValueType(ip));
+        }
+        const QString set = QString("%1_set_data_%2").arg(lcname, snakeCase(ip.name));
+        if (ip.write) {
+            cDoc(h, QStringList() << QString("Set %1. Returns true when it changed.")
+                .arg(ip.name));
+            if (ip.type.isComplex()) {
+                h << QString("bool %2(struct %1* ptr, %3, %4);\n")
+                    .arg(d, set, index, cInput(ip.type, "s", "len"));
+            } else {
+                h << QString("bool %2(struct %1* ptr, %3, %4 v);\n")
+                    .arg(d, set, index, cType(ip.type));
+            }
+        }
+        if (ip.write && ip.optional) {
+            cDoc(h, QStringList() << QString("Clear %1.").arg(ip.name));
+            h << QString("bool %2_none(struct %1* ptr, %3);\n")
+                .arg(d, set, index);
+        }
+    }
+    if (o.bulk) {
+        cDoc(h, QStringList()
+            << "Get the item properties of `count` rows from `first` on. Each"
+            << "value is written to an array of `count` elements. Strings and"
+            << "bytes are passed to their setter once, one row after the other,"
+            << "with an array of their lengths. The length of a missing optional"
+            << "value is -1.");
+        QStringList params;
+        params << QString("const struct %1* ptr").arg(d) << "int first" << "int count";
+        for (auto ip: o.itemProperties) {
+            const QString lc(snakeCase(ip.name));
+            if (ip.type.isComplex()) {
+                params << QString("void* %1_out").arg(lc)
+                    << QString("%1_rows_set %2_set").arg(ip.type.name.toLower(), lc);
+            } else {
+                params << QString("%1* %2_out").arg(cValueType(ip), lc);
+            }
+        }
+        h << QString("void %1_data_rows(%2);\n").arg(lcname, params.join(", "));
+    }
+}
+
+void writeFfiObject(QTextStream& h, const Object& o, const Configuration& conf) {
+    h << "\n/* " << o.name << " */\n\n";
+    writeConstructor(h, o, conf);
+    writeProperties(h, o);
+    writeFunctions(h, o);
+    if (o.type != ObjectType::Object) {
+        writeModel(h, o);
+    }
+}
+
+// The types that are shared by all headers are guarded so that the headers
+// of several configurations can be used together.
+void writeFfiTypes(QTextStream& h, const Configuration& conf) {
+    QList<QString> types = conf.types();
+    for (auto o: conf.objects) {
+        for (auto f: o.functions) {
+            types.append(f.type.name);
+        }
+    }
+    if (types.contains("QString")) {
+        h << R"(
+#ifndef RUST_QT_QSTRING_SET
+#define RUST_QT_QSTRING_SET
+/** Receives a UTF-8 string from Rust and copies it into `val`. */
+typedef void (*qstring_set)(void* val, const char* utf8, int nbytes);
+#endif
+)";
+    }
+    if (types.contains("QByteArray")) {
+        h << R"(
+#ifndef RUST_QT_QBYTEARRAY_SET
+#define RUST_QT_QBYTEARRAY_SET
+/** Receives bytes from Rust and copies them into `val`. */
+typedef void (*qbytearray_set)(void* val, const char* bytes, int nbytes);
+#endif
+)";
+    }
+    QList<QString> bulkTypes;
+    for (auto o: conf.objects) {
+        for (auto ip: o.itemProperties) {
+            if (o.bulk) {
+                bulkTypes.append(ip.type.name);
+            }
+        }
+    }
+    if (bulkTypes.contains("QString")) {
+        h << R"(
+#ifndef RUST_QT_QSTRING_ROWS_SET
+#define RUST_QT_QSTRING_ROWS_SET
+/** Receives the UTF-8 strings of `count` rows and copies them into `vals`. */
+typedef void (*qstring_rows_set)(void* vals, const char* utf8, const int* nbytes, int count);
+#endif
+)";
+    }
+    if (bulkTypes.contains("QByteArray")) {
+        h << R"(
+#ifndef RUST_QT_QBYTEARRAY_ROWS_SET
+#define RUST_QT_QBYTEARRAY_ROWS_SET
+/** Receives the bytes of `count` rows and copies them into `vals`. */
+typedef void (*qbytearray_rows_set)(void* vals, const char* bytes, const int* nbytes, int count);
+#endif
+)";
+    }
+    for (auto option: conf.optionalTypes()) {
+        if (option == "QString" || option == "QByteArray"
+                || option == "quintptr") {
+            continue;
+        }
+        const QString guard = "RUST_QT_OPTION_" + option.toUpper();
+        BindingTypeProperties type;
+        for (auto o: conf.objects) {
+            for (auto p: o.properties) {
+                if (p.type.name == option) {
+                    type = p.type;
+                }
+            }
+            for (auto ip: o.itemProperties) {
+                if (ip.type.name == option) {
+                    type = ip.type;
+                }
+            }
+        }
+        h << QString(R"(
+#ifndef %2
+#define %2
+/** A value that may be empty. `value` is only valid when `some` is true. */
+struct option_%1 {
+    %3 value;
+    bool some;
+};
+#endif
+)").arg(option, guard, cType(type));
+    }
+    if (conf.hasListOrTree()) {
+        h << R"(
+#ifndef RUST_QT_MODEL_TYPES
+#define RUST_QT_MODEL_TYPES
+enum SortOrder {
+    AscendingOrder = 0,
+    DescendingOrder = 1
+};
+/** A row and the identifier of an item in a tree. */
+struct qmodelindex_t {
+    int row;
+    uintptr_t id;
+};
+#endif
+)";
+    }
+}
+
+}
+
+void writeFfiHeader(const Configuration& conf) {
+    if (!conf.ffiHeader) {
+        return;
+    }
+    const QDir dir = conf.hFile.dir();
+    const QString name = conf.hFile.completeBaseName() + "_ffi.h";
+    DifferentFileWriter w(dir.absoluteFilePath(name));
+    QTextStream h(&w.buffer);
+    const QString guard = QString(name).replace('.', '_').toUpper();
+    h << QString(R"(/* generated by rust_qt_binding_generator */
+#ifndef %1
+#define %1
+
+/*
+ * The C functions that the Rust module %2 exports for %3.
+ *
+ * Each object is created with <name>_new and freed with <name>_free. The
+ * callbacks that are passed to <name>_new are called by Rust to emit signals.
+ * Strings are passed to Rust as UTF-16 and bytes as a pointer and a length.
+ * Strings and bytes from Rust are passed as UTF-8 to a setter function
+ * together with the pointer that was given by the caller. The data is only
+ * valid during that call.
+ */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+)").arg(guard, conf.interfaceModule, conf.cppFile.fileName());
+    writeFfiTypes(h, conf);
+    h << "\n";
+    QList<Object> objects = conf.objects + conf.importedChildren();
+    for (auto o: objects) {
+        h << "struct " << o.name << "Private;\n";
+        h << "struct " << o.name << "QObject;\n";
+    }
+    const QString hash = conf.bindingHashFunction();
+    h << QString(R"(
+/** The value that %1() returns for this header. */
+#define %2 UINT64_C(%3)
+/**
+ * A hash of the configuration that the Rust code was generated from. When it
+ * differs from %2,
+ * the functions in this header do not match the Rust code.
+ */
+uint64_t %1(void);
+)").arg(hash, hash.toUpper(), conf.bindingHash());
+    for (auto o: conf.objects) {
+        writeFfiObject(h, o, conf);
+    }
+    h << QString(R"(
+#ifdef __cplusplus
+}
+#endif
+
+#endif // %1
+)").arg(guard);
+}
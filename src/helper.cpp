@@ -18,6 +18,155 @@
  *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
  */
 
-#include <QTextStream>
+#include "helper.h"
+#include <QVector>
 
 QTextStream err(stderr);
+
+bool DifferentFileWriter::checkMode = false;
+QStringList DifferentFileWriter::staleFiles;
+
+namespace {
+
+struct DiffLine {
+    char op;
+    QString text;
+    int oldLine;
+    int newLine;
+};
+
+QStringList splitLines(const QByteArray& data) {
+    QStringList lines = QString::fromUtf8(data).split('\n');
+    if (!lines.isEmpty() && lines.last().isEmpty()) {
+        lines.removeLast();
+    }
+    return lines;
+}
+
+// Compare the lines with the algorithm of Myers, which takes time in the size
+// of the files times the number of differences. The common head and tail are
+// skipped first. When more than maxEdits lines differ, the changed region is
+// shown as removed and added as a whole.
+QList<DiffLine> diffLines(const QStringList& a, const QStringList& b) {
+    const int maxEdits = 2000;
+    int head = 0;
+    while (head < a.size() && head < b.size() && a[head] == b[head]) {
+        ++head;
+    }
+    int tail = 0;
+    while (tail < a.size() - head && tail < b.size() - head
+            && a[a.size() - 1 - tail] == b[b.size() - 1 - tail]) {
+        ++tail;
+    }
+    const int n = a.size() - head - tail;
+    const int m = b.size() - head - tail;
+    // v[k + offset] is the furthest line in 'a' on the diagonal k = x - y,
+    // trace[d] keeps v for -d <= k <= d after d edits
+    const int offset = maxEdits + 1;
+    QVector<int> v(2 * offset + 1, 0);
+    QVector<QVector<int>> trace;
+    int edits = -1;
+    for (int d = 0; d <= qMin(maxEdits, n + m) && edits < 0; ++d) {
+        for (int k = -d; k <= d; k += 2) {
+            int x = (k == -d || (k != d && v[offset + k - 1] < v[offset + k + 1]))
+                ? v[offset + k + 1] : v[offset + k - 1] + 1;
+            int y = x - k;
+            while (x < n && y < m && a[head + x] == b[head + y]) {
+                ++x;
+                ++y;
+            }
+            v[offset + k] = x;
+            if (x >= n && y >= m) {
+                edits = d;
+            }
+        }
+        trace.append(v.mid(offset - d, 2 * d + 1));
+    }
+    QList<DiffLine> changed;
+    if (edits < 0) {
+        for (int i = 0; i < n; ++i) {
+            changed.append({'-', a[head + i], head + i, head});
+        }
+        for (int j = 0; j < m; ++j) {
+            changed.append({'+', b[head + j], head + n, head + j});
+        }
+    } else {
+        // walk back from the end, the lines come out in reverse
+        int x = n, y = m;
+        for (int d = edits; d > 0; --d) {
+            const QVector<int>& prev = trace[d - 1];
+            const int k = x - y;
+            const int prevK = (k == -d || (k != d
+                    && prev[k - 1 + d - 1] < prev[k + 1 + d - 1])) ? k + 1 : k - 1;
+            const int prevX = prev[prevK + d - 1];
+            const int prevY = prevX - prevK;
+            while (x > prevX && y > prevY) {
+                --x;
+                --y;
+                changed.prepend({' ', a[head + x], head + x, head + y});
+            }
+            if (prevK == k + 1) {
+                --y;
+                changed.prepend({'+', b[head + y], head + x, head + y});
+            } else {
+                --x;
+                changed.prepend({'-', a[head + x], head + x, head + y});
+            }
+        }
+        while (x > 0 && y > 0) {
+            --x;
+            --y;
+            changed.prepend({' ', a[head + x], head + x, head + y});
+        }
+    }
+    QList<DiffLine> lines;
+    for (int i = 0; i < head; ++i) {
+        lines.append({' ', a[i], i, i});
+    }
+    lines.append(changed);
+    for (int k = 0; k < tail; ++k) {
+        lines.append({' ', a[head + n + k], head + n + k, head + m + k});
+    }
+    return lines;
+}
+
+}
+
+QString unifiedDiff(const QString& path, const QByteArray& old,
+        const QByteArray& updated) {
+    const int context = 3;
+    const QList<DiffLine> lines = diffLines(splitLines(old), splitLines(updated));
+    QString diff;
+    QTextStream out(&diff);
+    out << "--- " << path << "\n+++ " << path << " (generated)\n";
+    int i = 0;
+    while (i < lines.size()) {
+        if (lines[i].op == ' ') {
+            ++i;
+            continue;
+        }
+        // collect a hunk: changes that are less than two contexts apart
+        const int start = qMax(0, i - context);
+        int end = i;
+        int unchanged = 0;
+        while (end < lines.size() && unchanged <= 2 * context) {
+            unchanged = lines[end].op == ' ' ? unchanged + 1 : 0;
+            ++end;
+        }
+        end = qMin(lines.size(), end - unchanged + context);
+        int oldCount = 0, newCount = 0;
+        for (int k = start; k < end; ++k) {
+            oldCount += lines[k].op != '+';
+            newCount += lines[k].op != '-';
+        }
+        out << QString("@@ -%1,%2 +%3,%4 @@\n")
+            .arg(lines[start].oldLine + (oldCount ? 1 : 0)).arg(oldCount)
+            .arg(lines[start].newLine + (newCount ? 1 : 0)).arg(newCount);
+        for (int k = start; k < end; ++k) {
+            out << lines[k].op << lines[k].text << "\n";
+        }
+        i = end;
+    }
+    out.flush();
+    return diff;
+}
@@ -28,9 +28,17 @@ inline QString snakeCase(const QString& name) {
         .replace(QRegExp("([A-Z])"), "_\\1").toLower();
 }
 
+// Return a unified diff that turns 'old' into 'updated'.
+QString unifiedDiff(const QString& path, const QByteArray& old,
+        const QByteArray& updated);
+
 // Only write a file if it is different
 class DifferentFileWriter {
 public:
+    // In check mode, files are never written. Instead, a diff is printed for
+    // each file that would change and its path is added to 'staleFiles'.
+    static bool checkMode;
+    static QStringList staleFiles;
     const QString path;
     QByteArray buffer;
     bool overwrite;
@@ -40,7 +48,13 @@ public:
     ~DifferentFileWriter() {
         const QByteArray old = read();
         if (old != buffer && (old.isNull() || overwrite)) {
-            write();
+            if (checkMode) {
+                QTextStream out(stdout);
+                out << unifiedDiff(path, old, buffer);
+                staleFiles.append(path);
+            } else {
+                write();
+            }
         }
     }
     QByteArray read() const {
@@ -21,6 +21,8 @@
 #include "parseJson.h"
 #include "cpp.h"
 #include "rust.h"
+#include "qml.h"
+#include "ffi.h"
 #include "helper.h"
 #include <QCommandLineParser>
 
@@ -43,6 +45,22 @@ int main(int argc, char *argv[]) {
                 "Overwrite existing implementation."));
     parser.addOption(overwriteOption);
 
+    // A boolean option (--merge-implementation)
+    QCommandLineOption mergeOption(QStringList()
+            << "merge-implementation",
+            QCoreApplication::translate("main",
+                "Add stubs for missing trait methods to an existing "
+                "implementation."));
+    parser.addOption(mergeOption);
+
+    // A boolean option (--check)
+    QCommandLineOption checkOption(QStringList() << "check",
+            QCoreApplication::translate("main",
+                "Do not write any files. Print a diff of each generated file "
+                "that is not up to date and exit with an error if there are "
+                "any."));
+    parser.addOption(checkOption);
+
     parser.process(app);
 
     const QStringList args = parser.positionalArguments();
@@ -53,16 +71,28 @@ int main(int argc, char *argv[]) {
         return 1;
     }
 
+    DifferentFileWriter::checkMode = parser.isSet(checkOption);
     for (auto path: args) {
         const QString configurationFile(path);
         Configuration configuration = parseConfiguration(configurationFile);
         configuration.overwriteImplementation = parser.isSet(overwriteOption);
+        configuration.mergeImplementation = parser.isSet(mergeOption);
     
         writeHeader(configuration);
         writeCpp(configuration);
+        writeQmlTypes(configuration);
+        writeFfiHeader(configuration);
         writeRustInterface(configuration);
         writeRustImplementation(configuration);
     }
 
+    if (!DifferentFileWriter::staleFiles.isEmpty()) {
+        QTextStream err(stderr);
+        err << QCoreApplication::translate("main",
+            "%n generated file(s) are not up to date.\n", "",
+            DifferentFileWriter::staleFiles.size());
+        return 1;
+    }
+
     return 0;
 }
@@ -20,11 +20,131 @@
 
 #include "parseJson.h"
 #include "helper.h"
+#include <QJsonDocument>
 #include <QJsonParseError>
 #include <QJsonObject>
 #include <QJsonArray>
 #include <QMetaEnum>
 
+// Return 'line:column' of a byte offset in a JSON text.
+QString jsonLocation(const QByteArray& data, int offset) {
+    const QByteArray before = data.left(offset);
+    const int line = before.count('\n') + 1;
+    const int column = offset - before.lastIndexOf('\n');
+    return QString("%1:%2").arg(line).arg(column);
+}
+
+// Append a key to a JSON Pointer. As in RFC 6901, "~" and "/" in the key are
+// escaped, so that a key with a slash cannot be mistaken for a nested one.
+QString jsonPath(const QString& path, const QString& key) {
+    QString escaped(key);
+    escaped.replace("~", "~0").replace("/", "~1");
+    return path + "/" + escaped;
+}
+
+// QJsonObject keeps its keys sorted and forgets where they were. To keep the
+// generated code in the same order as the configuration file and to report
+// errors with a line and column, the raw text is scanned once more.
+class JsonSource {
+public:
+    JsonSource(const QByteArray& d) :data(d), pos(0) {
+        skipValue(QString());
+    }
+    // Return the keys of the object at 'path' in the order of the source file.
+    QStringList keys(const QJsonObject& object, const QString& path) const {
+        QStringList ordered;
+        for (auto key: order.value(path)) {
+            if (object.contains(key) && !ordered.contains(key)) {
+                ordered.append(key);
+            }
+        }
+        for (auto key: object.keys()) {
+            if (!ordered.contains(key)) {
+                ordered.append(key);
+            }
+        }
+        return ordered;
+    }
+    // Return 'line:column' of the key or array entry at 'path'.
+    QString location(const QString& path) const {
+        return jsonLocation(data, offset(path));
+    }
+    // Return the position in the source of the key or array entry at 'path'.
+    int offset(const QString& path) const {
+        return offsets.value(path);
+    }
+private:
+    const QByteArray data;
+    int pos;
+    QHash<QString, QStringList> order;
+    QHash<QString, int> offsets;
+
+    void skipWhitespace() {
+        while (pos < data.size() && QChar(data[pos]).isSpace()) {
+            ++pos;
+        }
+    }
+    QString readString() {
+        const int start = pos++;
+        while (pos < data.size() && data[pos] != '"') {
+            if (data[pos] == '\\') {
+                ++pos;
+            }
+            ++pos;
+        }
+        ++pos;
+        const QByteArray quoted = "[" + data.mid(start, pos - start) + "]";
+        return QJsonDocument::fromJson(quoted).array().at(0).toString();
+    }
+    void skipValue(const QString& path) {
+        skipWhitespace();
+        if (pos >= data.size()) {
+            return;
+        }
+        const char c = data[pos];
+        if (c == '{') {
+            ++pos;
+            skipWhitespace();
+            while (pos < data.size() && data[pos] != '}') {
+                const int start = pos;
+                const QString key = readString();
+                order[path].append(key);
+                offsets.insert(jsonPath(path, key), start);
+                skipWhitespace();
+                ++pos; // ':'
+                skipValue(jsonPath(path, key));
+                skipWhitespace();
+                if (pos < data.size() && data[pos] == ',') {
+                    ++pos;
+                    skipWhitespace();
+                }
+            }
+            ++pos;
+        } else if (c == '[') {
+            ++pos;
+            skipWhitespace();
+            int i = 0;
+            while (pos < data.size() && data[pos] != ']') {
+                offsets.insert(path + "/" + QString::number(i), pos);
+                skipValue(path + "/" + QString::number(i++));
+                skipWhitespace();
+                if (pos < data.size() && data[pos] == ',') {
+                    ++pos;
+                    skipWhitespace();
+                }
+            }
+            ++pos;
+        } else if (c == '"') {
+            readString();
+        } else {
+            while (pos < data.size() && !QChar(data[pos]).isSpace()
+                    && data[pos] != ',' && data[pos] != '}' && data[pos] != ']') {
+                ++pos;
+            }
+        }
+    }
+};
+
 BindingTypeProperties simpleType(BindingType type, const char* name, const char* init) {
     return {
         .type = type,
@@ -150,7 +270,20 @@ QList<BindingTypeProperties>& bindingTypeProperties() {
     return p;
 }
 
-BindingTypeProperties parseBindingType(const QString& value) {
+// The object types are passed in, so that the objects of one configuration
+// do not become known types while parsing the next one.
+BindingTypeProperties parseBindingType(const QString& value,
+        const QStringList& objectTypes = QStringList()) {
+    if (objectTypes.contains(value)) {
+        return {
+            .type = BindingType::Object,
+            .name = value,
+            .cppSetType = value,
+            .cSetType = value,
+            .rustType = value,
+            .rustTypeInit = "",
+        };
+    }
     for (auto type: bindingTypeProperties()) {
         if (value == type.name) {
             return type;
@@ -171,13 +304,21 @@ BindingTypeProperties parseBindingType(const QString& value) {
 }
 
 Property
-parseProperty(const QString& name, const QJsonObject& json) {
+parseProperty(const QString& name, const QJsonObject& json,
+        const QStringList& objectTypes) {
     Property p;
     p.name = name;
-    p.type = parseBindingType(json.value("type").toString());
+    p.type = parseBindingType(json.value("type").toString(), objectTypes);
     p.write = json.value("write").toBool();
     p.optional = json.value("optional").toBool();
     p.rustByValue = json.value("rustByValue").toBool();
+    p.constant = json.value("const").toBool();
+    p.reset = json.value("reset").toBool();
+    p.bindable = json.value("bindable").toBool();
+    p.coalesce = json.value("coalesce").toBool();
+    p.strict = json.value("strict").toBool();
+    p.borrowed = json.value("borrowed").toBool();
+    p.doc = json.value("doc").toString();
     return p;
 }
 
@@ -186,6 +327,8 @@ parseArgument(const QJsonObject& json) {
     Argument arg;
     arg.name = json.value("name").toString();
     arg.type = parseBindingType(json.value("type").toString());
+    arg.strict = json.value("strict").toBool();
+    arg.doc = json.value("doc").toString();
     QTextStream out(stdout);
     out.flush();
     if (arg.type.type == BindingType::Object) {
@@ -212,6 +355,7 @@ parseFunction(const QString& name, const QJsonObject& json) {
     Function f;
     f.name = name;
     f.mut = json.value("mut").toBool();
+    f.doc = json.value("doc").toString();
     f.type = parseBindingType(json.value("return").toString());
     if (f.type.type == BindingType::Object) {
         QTextStream err(stderr);
@@ -246,6 +390,8 @@ parseItemProperty(const QString& name, const QJsonObject& json) {
     ip.write = json.value("write").toBool();
     ip.optional = json.value("optional").toBool();
     ip.rustByValue = json.value("rustByValue").toBool();
+    ip.strict = json.value("strict").toBool();
+    ip.doc = json.value("doc").toString();
     QJsonArray roles = json.value("roles").toArray();
     for (auto r: roles) {
         QList<Qt::ItemDataRole> l;
@@ -258,7 +404,9 @@ parseItemProperty(const QString& name, const QJsonObject& json) {
 }
 
 Object
-parseObject(const QString& name, const QJsonObject& json) {
+parseObject(const QString& name, const QJsonObject& json,
+        const JsonSource& order, const QString& path,
+        const QStringList& objectTypes) {
     Object o;
     o.name = name;
     QString type = json.value("type").toString();
@@ -269,12 +417,17 @@ parseObject(const QString& name, const QJsonObject& json) {
     } else {
         o.type = ObjectType::Object;
     }
+    o.singleton = json.value("singleton").toBool();
+    o.coalesce = json.value("coalesce").toBool();
+    o.bulk = json.value("bulk").toBool();
+    o.doc = json.value("doc").toString();
     const QJsonObject& properties = json.value("properties").toObject();
-    for (const QString& key: properties.keys()) {
-        o.properties.append(parseProperty(key, properties[key].toObject()));
+    for (const QString& key: order.keys(properties, path + "/properties")) {
+        o.properties.append(parseProperty(key, properties[key].toObject(),
+                objectTypes));
     }
     const QJsonObject& functions = json.value("functions").toObject();
-    for (const QString& key: functions.keys()) {
+    for (const QString& key: order.keys(functions, path + "/functions")) {
         o.functions.append(parseFunction(key, functions[key].toObject()));
     }
     QTextStream err(stderr);
@@ -291,7 +444,8 @@ parseObject(const QString& name, const QJsonObject& json) {
         exit(1);
     }
     o.columnCount = 0;
-    for (const QString& key: itemProperties.keys()) {
+    for (const QString& key: order.keys(itemProperties,
+                path + "/itemProperties")) {
         ItemProperty p = parseItemProperty(key, itemProperties[key].toObject());
         o.columnCount = qMax(o.columnCount, p.roles.size());
         o.itemProperties.append(p);
@@ -300,8 +454,586 @@ parseObject(const QString& name, const QJsonObject& json) {
     return o;
 }
 
+const QStringList& rustKeywords() {
+    static const QStringList keywords = QStringList()
+        << "abstract" << "as" << "async" << "await" << "become" << "box"
+        << "break" << "const" << "continue" << "crate" << "do" << "dyn"
+        << "else" << "enum" << "extern" << "false" << "final" << "fn" << "for"
+        << "if" << "impl" << "in" << "let" << "loop" << "macro" << "match"
+        << "mod" << "move" << "mut" << "override" << "priv" << "pub" << "ref"
+        << "return" << "self" << "static" << "struct" << "super" << "trait"
+        << "true" << "try" << "type" << "typeof" << "unsafe" << "unsized"
+        << "use" << "virtual" << "where" << "while" << "yield";
+    return keywords;
+}
+
+// Checks a configuration before it is parsed. All problems are collected so
+// that they can be reported at once, each with its JSON path and location.
+class ConfigurationValidator {
+    Q_DECLARE_TR_FUNCTIONS(ConfigurationValidator)
+public:
+    QStringList errors;
+    ConfigurationValidator(const QString& f, const JsonSource& s,
+            const QList<Import>& i)
+        :file(f), source(s), imports(i) {
+    }
+    void validate(const QJsonObject& root) {
+        checkKeys(root, "", QStringList() << "$schema" << "imports"
+            << "cppFile" << "symbolPrefix" << "rust" << "cpp" << "objects");
+        validateImports(root);
+        if (checkValue(root, "", "symbolPrefix", QJsonValue::String, false)
+                && !QRegExp("[A-Za-z_][A-Za-z0-9_]*")
+                    .exactMatch(root.value("symbolPrefix").toString())) {
+            error("/symbolPrefix", tr("the symbol prefix should be a valid "
+                "start of a C identifier"));
+        }
+        if (checkValue(root, "", "cppFile", QJsonValue::String, true)
+                && root.value("cppFile").toString().isEmpty()) {
+            error("/cppFile", tr("the path of the C++ file is empty"));
+        }
+        if (checkValue(root, "", "rust", QJsonValue::Object, true)) {
+            const QJsonObject rust = root.value("rust").toObject();
+            const QStringList keys = QStringList() << "dir"
+                << "interfaceModule" << "implementationModule";
+            const QStringList bools = QStringList() << "serde" << "workers"
+                << "async";
+            checkKeys(rust, "/rust", QStringList(keys) << bools);
+            for (auto key: keys) {
+                checkValue(rust, "/rust", key, QJsonValue::String, true);
+            }
+            checkBools(rust, "/rust", bools);
+            for (auto key: QStringList() << "interfaceModule"
+                    << "implementationModule") {
+                const QString module = rust.value(key).toString();
+                if (rust.value(key).isString() && !QRegExp(
+                        "(crate::)?([A-Za-z_][A-Za-z0-9_]*::)*[A-Za-z_][A-Za-z0-9_]*")
+                        .exactMatch(module)) {
+                    error(jsonPath("/rust", key), tr("\"%1\" should be a module name "
+                        "or a path like crate::ui::bindings").arg(module));
+                }
+            }
+        }
+        if (checkValue(root, "", "cpp", QJsonValue::Object, false)) {
+            validateCpp(root.value("cpp").toObject(), "/cpp");
+        }
+        qtVersion = root.value("cpp").toObject().value("qtVersion").toInt(5);
+        if (!checkValue(root, "", "objects", QJsonValue::Object, true)) {
+            return;
+        }
+        const QJsonObject objects = root.value("objects").toObject();
+        if (objects.isEmpty()) {
+            error("/objects", tr("no objects are defined"));
+        }
+        const QStringList objectKeys = source.keys(objects, "/objects");
+        objectNames = objectKeys;
+        QHash<QString, QString> names;
+        for (auto i: imports) {
+            for (auto o: i.objects) {
+                objectNames.append(o.name);
+                names.insert(snakeCase(o.name), o.name);
+            }
+        }
+        for (auto name: objectKeys) {
+            const QString path = jsonPath("/objects", name);
+            if (names.values().contains(name)) {
+                error(path, tr("%1 is already defined in an imported file")
+                    .arg(name));
+                continue;
+            }
+            checkName(name, path, names);
+            if (checkValue(objects, "/objects", name, QJsonValue::Object, true)) {
+                validateObject(objects.value(name).toObject(), path);
+            }
+        }
+        for (auto name: objectKeys) {
+            const QString path = jsonPath("/objects", name) + "/properties";
+            const QJsonObject properties = objects.value(name).toObject()
+                .value("properties").toObject();
+            for (auto p: source.keys(properties, path)) {
+                const QString type = properties.value(p).toObject()
+                    .value("type").toString();
+                if (objects.value(type).toObject().value("singleton").toBool()
+                        || importedSingleton(type)) {
+                    error(jsonPath(path, p),
+                        tr("%1 is a singleton and cannot be used as a property")
+                        .arg(type));
+                }
+                // the snapshot of the child is generated with its own file
+                if (root.value("rust").toObject().value("serde").toBool()
+                        && importedWithoutSerde(type)) {
+                    error(jsonPath(path, p),
+                        tr("%1 is imported from a file without \"serde\"")
+                        .arg(type));
+                }
+            }
+        }
+    }
+private:
+    const QString file;
+    const JsonSource& source;
+    const QList<Import>& imports;
+    QStringList objectNames;
+    int qtVersion;
+    // the positions of 'errors' in the source
+    QList<int> errorOffsets;
+
+    bool importedSingleton(const QString& name) const {
+        for (auto i: imports) {
+            for (auto o: i.objects) {
+                if (o.name == name) {
+                    return o.singleton;
+                }
+            }
+        }
+        return false;
+    }
+    bool importedWithoutSerde(const QString& name) const {
+        for (auto i: imports) {
+            for (auto o: i.objects) {
+                if (o.name == name) {
+                    return !i.serde;
+                }
+            }
+        }
+        return false;
+    }
+    void validateImports(const QJsonObject& root) {
+        if (!checkValue(root, "", "imports", QJsonValue::Array, false)) {
+            return;
+        }
+        const QJsonArray paths = root.value("imports").toArray();
+        for (int i = 0; i < paths.size(); ++i) {
+            if (!paths[i].isString()) {
+                error("/imports/" + QString::number(i),
+                    tr("an import should be the path of a configuration file"));
+            }
+        }
+        if (!root.value("rust").isObject()) {
+            return;
+        }
+        const QJsonObject rust = root.value("rust").toObject();
+        const QDir rustdir(QFileInfo(file).dir()
+            .filePath(rust.value("dir").toString()));
+        for (auto i: imports) {
+            if (QFileInfo(i.rustdir.path()) != QFileInfo(rustdir.path())) {
+                continue;
+            }
+            for (auto key: QStringList() << "interfaceModule"
+                    << "implementationModule") {
+                const QString module = rust.value(key).toString();
+                if (module == i.interfaceModule
+                        || module == i.implementationModule) {
+                    error(jsonPath("/rust", key), tr("the module %1 is also used by %2")
+                        .arg(module, i.file));
+                }
+            }
+        }
+    }
+
+    // The checks run in several passes, so each error is inserted at the
+    // position of its location in the file.
+    void error(const QString& path, const QString& message) {
+        const int offset = source.offset(path);
+        int i = errorOffsets.size();
+        while (i > 0 && errorOffsets.at(i - 1) > offset) {
+            --i;
+        }
+        errorOffsets.insert(i, offset);
+        errors.insert(i, QString("%1:%2: %3: %4").arg(file,
+            source.location(path), path.isEmpty() ? "/" : path, message));
+    }
+    void checkKeys(const QJsonObject& json, const QString& path,
+            const QStringList& allowed) {
+        for (auto key: source.keys(json, path)) {
+            if (!allowed.contains(key)) {
+                error(jsonPath(path, key), tr("unknown key \"%1\", expected one of %2")
+                    .arg(key, allowed.join(", ")));
+            }
+        }
+    }
+    // Return true if the key is present and has the right type.
+    bool checkValue(const QJsonObject& json, const QString& path,
+            const QString& key, QJsonValue::Type type, bool required) {
+        if (!json.contains(key)) {
+            if (required) {
+                error(path, tr("the key \"%1\" is missing").arg(key));
+            }
+            return false;
+        }
+        if (json.value(key).type() == type) {
+            return true;
+        }
+        QString name;
+        switch (type) {
+        case QJsonValue::Bool: name = tr("a boolean"); break;
+        case QJsonValue::String: name = tr("a string"); break;
+        case QJsonValue::Array: name = tr("an array"); break;
+        default: name = tr("an object"); break;
+        }
+        error(jsonPath(path, key), tr("\"%1\" should be %2").arg(key, name));
+        return false;
+    }
+    void checkBools(const QJsonObject& json, const QString& path,
+            const QStringList& keys) {
+        for (auto key: keys) {
+            checkValue(json, path, key, QJsonValue::Bool, false);
+        }
+    }
+    // Names become identifiers in C++ and Rust. In Rust they are snake_case,
+    // which may make two different names the same.
+    void checkName(const QString& name, const QString& path,
+            QHash<QString, QString>& seen,
+            const QStringList& reserved = QStringList()) {
+        if (!QRegExp("[A-Za-z_][A-Za-z0-9_]*").exactMatch(name)) {
+            error(path, tr("\"%1\" is not a valid identifier").arg(name));
+            return;
+        }
+        const QString lc = snakeCase(name);
+        if (rustKeywords().contains(lc)) {
+            error(path, tr("\"%1\" is a keyword in Rust").arg(lc));
+        } else if (reserved.contains(lc)) {
+            error(path, tr("\"%1\" clashes with a generated method").arg(lc));
+        } else if (seen.contains(lc)) {
+            error(path, tr("\"%1\" and \"%2\" are both called \"%3\" in Rust")
+                .arg(seen.value(lc), name, lc));
+        } else {
+            seen.insert(lc, name);
+        }
+    }
+    // Check a type name. Return true if it is a known type.
+    bool checkType(const QJsonObject& json, const QString& path,
+            const QString& key, bool allowObject, bool allowVoid) {
+        if (!checkValue(json, path, key, QJsonValue::String, true)) {
+            return false;
+        }
+        const QString type = json.value(key).toString();
+        if (objectNames.contains(type)) {
+            if (!allowObject) {
+                error(jsonPath(path, key),
+                    tr("the object type %1 is not allowed here").arg(type));
+            }
+            return allowObject;
+        }
+        QStringList types;
+        for (auto t: bindingTypeProperties()) {
+            if (t.type != BindingType::Object
+                    && (allowVoid || t.type != BindingType::Void)) {
+                types.append(t.name);
+            }
+        }
+        if (!types.contains(type)) {
+            error(jsonPath(path, key), tr("\"%1\" is not a supported type, use one of %2")
+                .arg(type, types.join(", ")));
+            return false;
+        }
+        return true;
+    }
+    void validateCpp(const QJsonObject& cpp, const QString& path) {
+        checkKeys(cpp, path, QStringList() << "namespace" << "ffiHeader"
+            << "qtVersion" << "qml");
+        checkValue(cpp, path, "ffiHeader", QJsonValue::Bool, false);
+        if (checkValue(cpp, path, "qtVersion", QJsonValue::Double, false)
+                && cpp.value("qtVersion").toDouble() != 5
+                && cpp.value("qtVersion").toDouble() != 6) {
+            error(path + "/qtVersion", tr("qtVersion must be 5 or 6"));
+        }
+        if (checkValue(cpp, path, "namespace", QJsonValue::String, false)
+                && !QRegExp("[A-Za-z_][A-Za-z0-9_]*(::[A-Za-z_][A-Za-z0-9_]*)*")
+                    .exactMatch(cpp.value("namespace").toString())) {
+            error(path + "/namespace", tr("\"%1\" is not a valid C++ namespace")
+                .arg(cpp.value("namespace").toString()));
+        }
+        if (!checkValue(cpp, path, "qml", QJsonValue::Object, false)) {
+            return;
+        }
+        const QJsonObject qml = cpp.value("qml").toObject();
+        checkKeys(qml, path + "/qml", QStringList() << "uri" << "version");
+        checkValue(qml, path + "/qml", "uri", QJsonValue::String, true);
+        if (checkValue(qml, path + "/qml", "version", QJsonValue::String, false)
+                && !QRegExp("\\d+\\.\\d+").exactMatch(qml.value("version").toString())) {
+            error(path + "/qml/version", tr("the version should look like \"1.0\""));
+        }
+    }
+    void validateObject(const QJsonObject& json, const QString& path) {
+        checkKeys(json, path, QStringList() << "type" << "properties"
+            << "itemProperties" << "functions" << "singleton" << "coalesce"
+            << "bulk" << "doc");
+        checkBools(json, path, QStringList() << "singleton" << "coalesce" << "bulk");
+        checkValue(json, path, "doc", QJsonValue::String, false);
+        QString type = "Object";
+        if (checkValue(json, path, "type", QJsonValue::String, false)) {
+            type = json.value("type").toString();
+            if (type != "Object" && type != "List" && type != "Tree") {
+                error(path + "/type",
+                    tr("\"%1\" is not an object type, use Object, List or Tree")
+                    .arg(type));
+            }
+        }
+        if (json.value("coalesce").toBool() && type != "List") {
+            error(path + "/coalesce", tr("only the data changes of a List can be coalesced"));
+        }
+        if (json.value("bulk").toBool() && type != "List") {
+            error(path + "/bulk", tr("only the rows of a List can be fetched in bulk"));
+        }
+        QStringList reserved = QStringList() << "new" << "emit";
+        if (type == "List") {
+            reserved << "row_count" << "insert_rows" << "remove_rows"
+                << "can_fetch_more" << "fetch_more" << "sort";
+            if (json.value("bulk").toBool()) {
+                // the C function that fetches the rows is <name>_data_rows
+                reserved << "rows";
+            }
+        } else if (type == "Tree") {
+            reserved << "row_count" << "can_fetch_more" << "fetch_more"
+                << "sort" << "index" << "parent" << "row";
+        }
+        QHash<QString, QString> names;
+        if (checkValue(json, path, "properties", QJsonValue::Object, false)) {
+            const QJsonObject properties = json.value("properties").toObject();
+            int coalesced = 0;
+            for (auto name: source.keys(properties, path + "/properties")) {
+                const QString p = jsonPath(path + "/properties", name);
+                checkName(name, p, names, reserved);
+                if (checkValue(properties, path + "/properties", name,
+                        QJsonValue::Object, true)) {
+                    validateProperty(properties.value(name).toObject(), p);
+                    coalesced += properties.value(name).toObject()
+                        .value("coalesce").toBool();
+                }
+            }
+            // the pending signals are bits in an int
+            if (coalesced > 30) {
+                error(path + "/properties",
+                    tr("at most 30 properties of an object can be coalesced"));
+            }
+        }
+        if (checkValue(json, path, "functions", QJsonValue::Object, false)) {
+            const QJsonObject functions = json.value("functions").toObject();
+            for (auto name: source.keys(functions, path + "/functions")) {
+                const QString p = jsonPath(path + "/functions", name);
+                checkName(name, p, names, reserved);
+                if (checkValue(functions, path + "/functions", name,
+                        QJsonValue::Object, true)) {
+                    validateFunction(functions.value(name).toObject(), p);
+                }
+            }
+        }
+        QJsonObject itemProperties;
+        if (checkValue(json, path, "itemProperties", QJsonValue::Object, false)) {
+            itemProperties = json.value("itemProperties").toObject();
+            for (auto name: source.keys(itemProperties,
+                    path + "/itemProperties")) {
+                const QString p = jsonPath(path + "/itemProperties", name);
+                checkName(name, p, names, reserved);
+                if (checkValue(itemProperties, path + "/itemProperties", name,
+                        QJsonValue::Object, true)) {
+                    validateItemProperty(itemProperties.value(name).toObject(), p);
+                }
+            }
+        }
+        if (type == "Object" && !itemProperties.isEmpty()) {
+            error(path + "/itemProperties",
+                tr("an Object should not have item properties"));
+        } else if (type != "Object" && itemProperties.isEmpty()) {
+            error(path, tr("a %1 needs item properties").arg(type));
+        }
+    }
+    void validateProperty(const QJsonObject& json, const QString& path) {
+        checkKeys(json, path, QStringList() << "type" << "write" << "optional"
+            << "rustByValue" << "const" << "reset" << "bindable" << "coalesce"
+            << "strict" << "borrowed" << "doc");
+        checkBools(json, path, QStringList() << "write" << "optional"
+            << "rustByValue" << "const" << "reset" << "bindable" << "coalesce"
+            << "strict" << "borrowed");
+        checkValue(json, path, "doc", QJsonValue::String, false);
+        if (!checkType(json, path, "type", true, false)) {
+            return;
+        }
+        const QString type = json.value("type").toString();
+        const bool write = json.value("write").toBool();
+        const bool reset = json.value("reset").toBool();
+        if (objectNames.contains(type)) {
+            for (auto key: QStringList() << "write" << "optional"
+                    << "rustByValue" << "reset" << "bindable" << "coalesce") {
+                if (json.value(key).toBool()) {
+                    error(jsonPath(path, key), tr("\"%1\" cannot be used on a property of object type %2")
+                        .arg(key, type));
+                }
+            }
+        } else if (json.value("rustByValue").toBool() && !type.startsWith("Q")) {
+            error(path + "/rustByValue",
+                tr("\"rustByValue\" only applies to QString and QByteArray"));
+        }
+        if (json.value("const").toBool() && (write || reset)) {
+            error(path + "/const",
+                tr("a const property cannot be writable or resettable"));
+        }
+        if (json.value("bindable").toBool()) {
+            if (qtVersion < 6) {
+                error(path + "/bindable",
+                    tr("bindable properties need \"qtVersion\": 6 in the cpp section"));
+            }
+            if (json.value("optional").toBool() || json.value("const").toBool()) {
+                error(path + "/bindable",
+                    tr("a bindable property cannot be optional or const"));
+            }
+        }
+        if (json.value("coalesce").toBool()
+                && (json.value("const").toBool() || json.value("bindable").toBool())) {
+            error(path + "/coalesce",
+                tr("a const or bindable property has no change signal to coalesce"));
+        }
+        checkStrict(json, path, write);
+        if (json.value("borrowed").toBool()) {
+            if (type != "QString" && type != "QByteArray") {
+                error(path + "/borrowed",
+                    tr("\"borrowed\" only applies to QString and QByteArray"));
+            }
+            for (auto key: QStringList() << "optional" << "rustByValue"
+                    << "bindable" << "strict") {
+                if (json.value(key).toBool()) {
+                    error(path + "/borrowed", tr("a borrowed property cannot "
+                        "be %1").arg(key == "rustByValue" ? "returned by value"
+                        : key));
+                }
+            }
+        }
+    }
+    void validateItemProperty(const QJsonObject& json, const QString& path) {
+        checkKeys(json, path, QStringList() << "type" << "write" << "optional"
+            << "rustByValue" << "strict" << "roles" << "doc");
+        checkBools(json, path, QStringList() << "write" << "optional"
+            << "rustByValue" << "strict");
+        checkStrict(json, path, json.value("write").toBool());
+        checkValue(json, path, "doc", QJsonValue::String, false);
+        if (checkType(json, path, "type", false, false)
+                && json.value("rustByValue").toBool()
+                && !json.value("type").toString().startsWith("Q")) {
+            error(path + "/rustByValue",
+                tr("\"rustByValue\" only applies to QString and QByteArray"));
+        }
+        if (!checkValue(json, path, "roles", QJsonValue::Array, false)) {
+            return;
+        }
+        const QJsonArray columns = json.value("roles").toArray();
+        for (int i = 0; i < columns.size(); ++i) {
+            const QString column = path + "/roles/" + QString::number(i);
+            if (!columns[i].isArray()) {
+                error(column, tr("the roles of a column should be an array"));
+                continue;
+            }
+            const QJsonArray roles = columns[i].toArray();
+            for (int j = 0; j < roles.size(); ++j) {
+                const QString s = roles[j].toString();
+                const QString name = s.left(1).toUpper() + s.mid(1) + "Role";
+                if (s.isEmpty() || QMetaEnum::fromType<Qt::ItemDataRole>()
+                        .keyToValue(name.toUtf8()) < 0) {
+                    error(column + "/" + QString::number(j),
+                        tr("\"%1\" is not a valid role name").arg(s));
+                }
+            }
+        }
+    }
+    void validateFunction(const QJsonObject& json, const QString& path) {
+        checkKeys(json, path, QStringList() << "return" << "mut"
+            << "arguments" << "doc");
+        checkBools(json, path, QStringList() << "mut");
+        checkValue(json, path, "doc", QJsonValue::String, false);
+        checkType(json, path, "return", false, true);
+        if (!checkValue(json, path, "arguments", QJsonValue::Array, false)) {
+            return;
+        }
+        const QJsonArray args = json.value("arguments").toArray();
+        QHash<QString, QString> names;
+        for (int i = 0; i < args.size(); ++i) {
+            const QString p = path + "/arguments/" + QString::number(i);
+            if (!args[i].isObject()) {
+                error(p, tr("an argument should be an object"));
+                continue;
+            }
+            const QJsonObject arg = args[i].toObject();
+            checkKeys(arg, p, QStringList() << "name" << "type" << "strict"
+                << "doc");
+            checkBools(arg, p, QStringList() << "strict");
+            checkValue(arg, p, "doc", QJsonValue::String, false);
+            if (checkValue(arg, p, "name", QJsonValue::String, true)) {
+                checkName(arg.value("name").toString(), p, names,
+                    QStringList() << "ptr" << "o" << "r" << "d" << "set");
+            }
+            checkType(arg, p, "type", false, false);
+            checkStrict(arg, p, true);
+        }
+    }
+    // A strict QString is passed to Rust as a Result, which only makes sense
+    // for a value that comes from C++.
+    void checkStrict(const QJsonObject& json, const QString& path,
+            bool write) {
+        if (!json.value("strict").toBool()) {
+            return;
+        }
+        if (json.value("type").toString() != "QString") {
+            error(path + "/strict", tr("\"strict\" only applies to QString"));
+        } else if (!write) {
+            error(path + "/strict",
+                tr("\"strict\" only applies to a writable value"));
+        }
+    }
+};
+
+Configuration
+parseConfiguration(const QString& path, QStringList& stack);
+
+// Parse the configurations that are imported by 'o'. The imports of the
+// imports are added too, so that nested object properties can be resolved.
+QList<Import>
+parseImports(const QJsonObject& o, const QDir& base, QStringList& stack) {
+    QList<Import> imports;
+    for (auto value: o.value("imports").toArray()) {
+        if (!value.isString()) {
+            continue;
+        }
+        const QString path = base.filePath(value.toString());
+        const QString canonical = QFileInfo(path).canonicalFilePath();
+        if (stack.contains(canonical)) {
+            QTextStream err(stderr);
+            err << QCoreApplication::translate("main",
+                "The imports form a cycle: %1.\n")
+                .arg((stack.mid(stack.indexOf(canonical)) << canonical)
+                    .join(" -> "));
+            err.flush();
+            exit(1);
+        }
+        const Configuration c = parseConfiguration(path, stack);
+        Import i;
+        i.file = path;
+        i.hFile = c.hFile;
+        i.rustdir = c.rustdir;
+        i.interfaceModule = c.interfaceModule;
+        i.implementationModule = c.implementationModule;
+        i.cppNamespace = c.cppNamespace;
+        i.serde = c.serde;
+        i.objects = c.objects;
+        for (auto nested: QList<Import>() << i << c.imports) {
+            bool known = false;
+            for (auto existing: imports) {
+                known |= existing.hFile == nested.hFile;
+            }
+            if (!known) {
+                imports.append(nested);
+            }
+        }
+    }
+    return imports;
+}
+
 Configuration
 parseConfiguration(const QString& path) {
+    QStringList stack;
+    return parseConfiguration(path, stack);
+}
+
+Configuration
+parseConfiguration(const QString& path, QStringList& stack) {
     QFile configurationFile(path);
     const QDir base = QFileInfo(configurationFile).dir();
     QTextStream err(stderr);
@@ -315,33 +1047,65 @@ parseConfiguration(const QString& path) {
     QJsonParseError error;
     const QJsonDocument doc(QJsonDocument::fromJson(data, &error));
     if (error.error != QJsonParseError::NoError) {
-        err << error.errorString();
+        err << QString("%1:%2: %3\n").arg(path,
+            jsonLocation(data, error.offset), error.errorString());
         err.flush();
         exit(1);
     }
     const QJsonObject o = doc.object();
+    const JsonSource order(data);
+    stack.append(QFileInfo(path).canonicalFilePath());
+    const QList<Import> imports = parseImports(o, base, stack);
+    stack.removeLast();
+    ConfigurationValidator validator(path, order, imports);
+    validator.validate(o);
+    if (!validator.errors.isEmpty()) {
+        for (auto e: validator.errors) {
+            err << e << "\n";
+        }
+        err.flush();
+        exit(1);
+    }
     Configuration c;
+    c.imports = imports;
     c.cppFile = QFileInfo(base, o.value("cppFile").toString());
-    QDir(c.cppFile.dir()).mkpath(".");
+    if (!DifferentFileWriter::checkMode) {
+        QDir(c.cppFile.dir()).mkpath(".");
+    }
     c.hFile = QFileInfo(c.cppFile.dir(), c.cppFile.completeBaseName() + ".h");
+    c.cppNamespace = o.value("cpp").toObject().value("namespace").toString();
+    c.ffiHeader = o.value("cpp").toObject().value("ffiHeader").toBool();
+    c.qtVersion = o.value("cpp").toObject().value("qtVersion").toInt(5);
+    const QJsonObject qml = o.value("cpp").toObject().value("qml").toObject();
+    c.qmlUri = qml.value("uri").toString();
+    const QStringList version = qml.value("version").toString("1.0").split('.');
+    c.qmlVersionMajor = version.value(0).toInt();
+    c.qmlVersionMinor = version.value(1).toInt();
     const QJsonObject& object = o.value("objects").toObject();
-    for (const QString& key: object.keys()) {
-        bindingTypeProperties().append({
-            .type = BindingType::Object,
-            .name = key,
-            .cppSetType = key,
-            .cSetType = key,
-            .rustType = key,
-            .rustTypeInit = "",
-        });
+    const QStringList objectNames = order.keys(object, "/objects");
+    QStringList objectTypes = objectNames;
+    for (auto i: imports) {
+        for (auto o: i.objects) {
+            objectTypes.append(o.name);
+        }
     }
-    for (const QString& key: object.keys()) {
-        Object o = parseObject(key, object[key].toObject());
+    const QString symbolPrefix = o.value("symbolPrefix").toString();
+    c.symbolPrefix = symbolPrefix;
+    const QJsonObject rust = o.value("rust").toObject();
+    c.workers = rust.value("workers").toBool();
+    c.async = rust.value("async").toBool();
+    for (const QString& key: objectNames) {
+        Object o = parseObject(key, object[key].toObject(), order,
+                jsonPath("/objects", key), objectTypes);
+        o.symbolPrefix = symbolPrefix;
+        // an importing file builds the emitters of these objects too
+        o.workers = c.workers;
+        o.async = c.async;
         c.objects.append(o);
     }
-    const QJsonObject rust = o.value("rust").toObject();
     c.rustdir = QDir(base.filePath(rust.value("dir").toString()));
     c.interfaceModule = rust.value("interfaceModule").toString();
     c.implementationModule = rust.value("implementationModule").toString();
+    c.serde = rust.value("serde").toBool();
     return c;
 }
@@ -0,0 +1,157 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include "structs.h"
+#include "qml.h"
+#include "helper.h"
+
+// the name under which QML tooling knows a C++ type
+QString qmlType(const QString& type) {
+    if (type == "QString") {
+        return "string";
+    } else if (type == "float" || type == "double") {
+        return "double";
+    } else if (type == "qint64") {
+        return "qlonglong";
+    } else if (type == "quint64") {
+        return "qulonglong";
+    } else if (type == "quint8" || type == "quint16" || type == "quint32") {
+        return "uint";
+    } else if (type.startsWith("qint")) {
+        return "int";
+    }
+    return type;
+}
+
+template <typename T>
+QString qmlPropertyType(const T& p)
+{
+    if (p.optional && !p.type.isComplex()) {
+        return "QVariant";
+    }
+    return qmlType(p.type.name);
+}
+
+void writeQmlMethod(QTextStream& q, const QString& name, const QString& type,
+        const QList<QPair<QString,QString>>& args) {
+    q << "        Method {\n";
+    q << QString("            name: \"%1\"\n").arg(name);
+    if (type != "void") {
+        q << QString("            type: \"%1\"\n").arg(type);
+    }
+    for (auto a: args) {
+        q << QString("            Parameter { name: \"%1\"; type: \"%2\" }\n")
+            .arg(a.first, a.second);
+    }
+    q << "        }\n";
+}
+
+void writeQmlComponent(QTextStream& q, const Object& o, const Configuration& conf) {
+    const QString prototype = o.type == ObjectType::Object
+        ? "QObject" : "QAbstractItemModel";
+    q << QString(R"(    Component {
+        name: "%1"
+        prototype: "%2"
+        exports: ["%3/%6 %4.%5"]
+        exportMetaObjectRevisions: [0]
+)").arg(conf.qualifiedName(o.name), prototype, conf.qmlUri)
+        .arg(conf.qmlVersionMajor)
+        .arg(conf.qmlVersionMinor).arg(o.name);
+    if (o.singleton) {
+        q << "        isCreatable: false\n        isSingleton: true\n";
+    }
+    for (auto p: o.properties) {
+        if (p.type.type == BindingType::Object) {
+            q << QString("        Property { name: \"%1\"; type: \"%2\"; isReadonly: true; isPointer: true }\n")
+                .arg(p.name, conf.qualifiedName(p.type.name));
+        } else {
+            QString attributes = p.write ? "" : "; isReadonly: true";
+            if (p.bindable) {
+                attributes += QString("; bindable: \"bindable%1\"")
+                    .arg(p.name.left(1).toUpper() + p.name.mid(1));
+            }
+            q << QString("        Property { name: \"%1\"; type: \"%2\"%3 }\n")
+                .arg(p.name, qmlPropertyType(p), attributes);
+        }
+    }
+    for (auto p: o.properties) {
+        if (!p.constant) {
+            q << QString("        Signal { name: \"%1Changed\" }\n").arg(p.name);
+        }
+    }
+    if (o.type != ObjectType::Object) {
+        q << R"(        Signal {
+            name: "newDataReady"
+            Parameter { name: "parent"; type: "QModelIndex" }
+        }
+)";
+    }
+    for (auto f: o.functions) {
+        QList<QPair<QString,QString>> args;
+        for (auto a: f.args) {
+            args.append(qMakePair(a.name, qmlType(a.type.name)));
+        }
+        writeQmlMethod(q, f.name, qmlType(f.type.name), args);
+    }
+    for (auto ip: o.itemProperties) {
+        QList<QPair<QString,QString>> index;
+        if (o.type == ObjectType::List) {
+            index.append(qMakePair(QString("row"), QString("int")));
+        } else {
+            index.append(qMakePair(QString("index"), QString("QModelIndex")));
+        }
+        writeQmlMethod(q, ip.name, qmlPropertyType(ip), index);
+        if (ip.write) {
+            auto args = index;
+            args.append(qMakePair(QString("value"), qmlPropertyType(ip)));
+            writeQmlMethod(q, "set" + ip.name.left(1).toUpper() + ip.name.mid(1),
+                "bool", args);
+        }
+    }
+    q << "    }\n";
+}
+
+void writeQmlTypes(const Configuration& conf) {
+    if (conf.qmlUri.isEmpty()) {
+        return;
+    }
+    const QDir dir = conf.hFile.dir();
+    const QString typeinfo = conf.hFile.completeBaseName() + ".qmltypes";
+    {
+        DifferentFileWriter w(dir.absoluteFilePath("qmldir"));
+        QTextStream q(&w.buffer);
+        q << "module " << conf.qmlUri << "\n";
+        q << "typeinfo " << typeinfo << "\n";
+    }
+    DifferentFileWriter w(dir.absoluteFilePath(typeinfo));
+    QTextStream q(&w.buffer);
+    q << R"(import QtQuick.tooling 1.2
+
+// This file describes the types generated by rust_qt_binding_generator.
+// It is used for QML tooling purposes only.
+
+Module {
+    dependencies: []
+)";
+    for (auto object: conf.objects) {
+        writeQmlComponent(q, object, conf);
+    }
+    q << "}\n";
+}
@@ -20,6 +20,7 @@
 
 #include "structs.h"
 #include "helper.h"
+#include <QMap>
 
 template <typename T>
 QString rustType(const T& p)
@@ -67,13 +68,98 @@ QString rustTypeInit(const T& p)
     return p.type.rustTypeInit;
 }
 
+template <typename T>
+QString rustSetType(const T& p)
+{
+    if (p.type.name == "QByteArray") {
+        return p.optional ? "Option<&[u8]>" : "&[u8]";
+    }
+    if (p.strict) {
+        return "Result<" + rustType(p) + ", Utf16Error>";
+    }
+    return rustType(p);
+}
+
+// The type of a function argument in the trait.
+QString rustArgType(const Argument& a)
+{
+    if (a.type.name == "QByteArray") {
+        return "&[u8]";
+    }
+    if (a.strict) {
+        return "Result<String, Utf16Error>";
+    }
+    return a.type.rustType;
+}
+
+// A borrowed QString property is passed as UTF-16 in both directions.
+QString borrowedOr(const Property& p, const QString& type)
+{
+    return p.borrowed && p.type.name == "QString" ? "&[u16]" : type;
+}
+
+// Decode the UTF-16 that C++ passes as a pointer and a length into the
+// variable 'name'. A strict value becomes a Result, otherwise unpaired
+// surrogates are replaced with U+FFFD.
+QString rustDecodeString(bool strict, const QString& name, const QString& str,
+        const QString& len)
+{
+    if (strict) {
+        return QString("    let %1 = string_from_utf16_strict(%2, %3);\n")
+            .arg(name, str, len);
+    }
+    return QString("    let mut %1 = String::new();\n"
+                   "    set_string_from_utf16(&mut %1, %2, %3);\n")
+        .arg(name, str, len);
+}
+
+// The arguments with which the value of a bindable property is passed to C++.
+QString rustBindableCArgs(const Property& p)
+{
+    if (p.type.isComplex()) {
+        return "*const c_char, c_int";
+    }
+    return p.type.rustType;
+}
+
+void rustDoc(QTextStream& r, const QString& indent, const QString& doc) {
+    if (doc.isEmpty()) {
+        return;
+    }
+    for (auto line: doc.split('\n')) {
+        r << indent << "///" << (line.isEmpty() ? "" : " " + line) << "\n";
+    }
+}
+
+void rustFunctionDoc(QTextStream& r, const Function& f) {
+    QString doc = f.doc;
+    bool first = true;
+    for (auto a: f.args) {
+        if (!a.doc.isEmpty()) {
+            if (first) {
+                doc += doc.isEmpty() ? "" : "\n\n";
+                first = false;
+            } else {
+                doc += "\n";
+            }
+            doc += QString("* `%1`: %2").arg(a.name, a.doc);
+        }
+    }
+    rustDoc(r, "    ", doc);
+}
+
 void rConstructorArgsDecl(QTextStream& r, const QString& name, const Object& o, const Configuration& conf) {
     r << QString("    %2: *mut %1QObject").arg(o.name, snakeCase(name));
+    r << QString(",\n    %2_run_on_gui_thread: fn(*const %1QObject, *mut c_void)")
+        .arg(o.name, snakeCase(name));
     for (const Property& p: o.properties) {
         if (p.type.type == BindingType::Object) {
             r << QString(",\n");
             rConstructorArgsDecl(r, p.name, conf.findObject(p.type.name), conf);
-        } else {
+        } else if (p.bindable) {
+            r << QString(",\n    %2_set: fn(*const %1QObject, %3)")
+                .arg(o.name, snakeCase(p.name), rustBindableCArgs(p));
+        } else if (!p.constant) {
             r << QString(",\n    %2_changed: fn(*const %1QObject)")
                 .arg(o.name, snakeCase(p.name));
         }
@@ -102,6 +188,18 @@ void rConstructorArgsDecl(QTextStream& r, const QString& name, const Object& o,
     }
 }
 
+// The paths, like o.person().address(), of the objects that are properties
+// of 'o', directly or via other objects.
+void childEmitters(QStringList& paths, const QString& path, const Object& o, const Configuration& conf) {
+    for (const Property& p: o.properties) {
+        if (p.type.type == BindingType::Object) {
+            const QString child = path + "." + snakeCase(p.name) + "()";
+            paths.append(child);
+            childEmitters(paths, child, conf.findObject(p.type.name), conf);
+        }
+    }
+}
+
 void rConstructorArgs(QTextStream& r, const QString& name, const Object& o, const Configuration& conf) {
     const QString lcname(snakeCase(o.name));
     for (const Property& p: o.properties) {
@@ -110,10 +208,17 @@ void rConstructorArgs(QTextStream& r, const QString& name, const Object& o, cons
         }
     }
     r << QString(R"(    let %2_emit = %1Emitter {
-        qobject: Arc::new(Mutex::new(%2)),
-)").arg(o.name, snakeCase(name));
+        qobject: Arc::new(AtomicPtr::new(%2)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+%3        run_on_gui_thread: %2_run_on_gui_thread,
+)").arg(o.name, snakeCase(name),
+        o.hasCancelToken() ? "        cancel: Default::default(),\n" : "");
     for (const Property& p: o.properties) {
-        if (p.type.type == BindingType::Object) continue;
+        if (p.type.type == BindingType::Object || p.constant) continue;
+        if (p.bindable) {
+            r << QString("        %1_set: %1_set,\n").arg(snakeCase(p.name));
+            continue;
+        }
         r << QString("        %1_changed: %1_changed,\n").arg(snakeCase(p.name));
     }
     if (o.type != ObjectType::Object) {
@@ -146,6 +251,109 @@ void rConstructorArgs(QTextStream& r, const QString& name, const Object& o, cons
     r << ");\n";
 }
 
+// The arguments of %1_new in a fixture. The QObjects are the TestQObject,
+// which records each signal by its name in C++.
+void rFixtureArgs(QTextStream& r, const Object& o, const Configuration& conf) {
+    r << QString("            q as *mut %1QObject,\n").arg(o.name);
+    r << QString("            |_, task| unsafe { %1_drop_task(task) },\n")
+        .arg(o.symbolPrefix + snakeCase(o.name));
+    const QString emit("            |%1| TestQObject::emit(q, \"%2\"),\n");
+    for (const Property& p: o.properties) {
+        if (p.type.type == BindingType::Object) {
+            rFixtureArgs(r, conf.findObject(p.type.name), conf);
+        } else if (p.bindable) {
+            r << emit.arg(p.type.isComplex() ? "q, _, _" : "q, _", p.name + "Changed");
+        } else if (!p.constant) {
+            r << emit.arg("q", p.name + "Changed");
+        }
+    }
+    if (o.type == ObjectType::Object) {
+        return;
+    }
+    const bool tree = o.type == ObjectType::Tree;
+    const QString rows = tree ? "q, _, _, _, _" : "q, _, _";
+    r << emit.arg(tree ? "q, _, _" : "q", "newDataReady")
+      << emit.arg("q, _, _", "dataChanged")
+      << emit.arg("q", "beginResetModel")
+      << emit.arg("q", "endResetModel")
+      << emit.arg(rows, "beginInsertRows")
+      << emit.arg("q", "endInsertRows")
+      << emit.arg(rows, "beginRemoveRows")
+      << emit.arg("q", "endRemoveRows");
+}
+
+// The QObject of the fixtures in the tests of the implementation.
+void writeRustTestQObject(QTextStream& r) {
+    r << R"(
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+)";
+}
+
+// An object that the tests of the implementation can create without C++.
+void writeRustFixture(QTextStream& r, const Object& o, const Configuration& conf) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    r << QString(R"(
+/// Owns a %1 for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct %1Fixture {
+    pub ptr: *mut %1,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl %1Fixture {
+    pub fn new() -> %1Fixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = %2_new(
+)").arg(o.name, lcname);
+    rFixtureArgs(r, o, conf);
+    r << QString(R"(        );
+        %1Fixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &%1 {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut %1 {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { %2_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for %1Fixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+)").arg(o.name, lcname);
+}
+
 void writeFunction(QTextStream& r, const Function& f, const QString& lcname, const Object& o) {
     const QString lc(snakeCase(f.name));
     r << QString(R"(
@@ -173,8 +381,8 @@ pub extern "C" fn %1_%2(ptr: *%3 %4)").arg(lcname, lc, f.mut ? "mut" : "const",
     }
     for (auto a = f.args.begin(); a < f.args.end(); a++) {
         if (a->type.name == "QString") {
-            r << "    let mut " << a->name << " = String::new();\n";
-            r << QString("    set_string_from_utf16(&mut %1, %1_str, %1_len);\n").arg(a->name);
+            r << rustDecodeString(a->strict, a->name, a->name + "_str",
+                a->name + "_len");
         } else if (a->type.name == "QByteArray") {
             r << QString("    let %1 = unsafe { slice::from_raw_parts(%1_str as *const u8, to_usize(%1_len)) };\n").arg(a->name);
         }
@@ -201,64 +409,227 @@ pub extern "C" fn %1_%2(ptr: *%3 %4)").arg(lcname, lc, f.mut ? "mut" : "const",
     r << "}\n";
 }
 
+// The function that writes the item properties of a block of rows to arrays.
+// Strings and bytes are concatenated and passed to a setter with the length
+// of each row, -1 for None.
+void writeRustDataRows(QTextStream& r, const Object& o) {
+    const QString lcname(o.symbolPrefix + snakeCase(o.name));
+    r << QString(R"(
+#[no_mangle]
+pub unsafe extern "C" fn %2_data_rows(
+    ptr: *const %1,
+    first: c_int,
+    count: c_int,
+)").arg(o.name, lcname);
+    for (auto ip: o.itemProperties) {
+        const QString lc(snakeCase(ip.name));
+        if (ip.type.isComplex()) {
+            r << QString("    %1_out: *mut %2,\n"
+                         "    %1_set: fn(*mut %2, *const c_char, *const c_int, c_int),\n")
+                .arg(lc, ip.type.name);
+        } else {
+            r << QString("    %1_out: *mut %2,\n").arg(lc, rustCType(ip));
+        }
+    }
+    r << ") {\n    let o = &*ptr;\n    let first = to_usize(first);\n"
+        "    let count = to_usize(count);\n";
+    for (auto ip: o.itemProperties) {
+        if (ip.type.isComplex()) {
+            r << QString("    let mut %1_bytes: Vec<u8> = Vec::new();\n"
+                         "    let mut %1_lens = Vec::with_capacity(count);\n")
+                .arg(snakeCase(ip.name));
+        }
+    }
+    r << "    for i in 0..count {\n        let row = first + i;\n";
+    for (auto ip: o.itemProperties) {
+        const QString lc(snakeCase(ip.name));
+        if (ip.type.isComplex() && ip.optional) {
+            r << QString(R"(        if let Some(data) = o.%1(row) {
+            %1_bytes.extend_from_slice(data.as_ref());
+            %1_lens.push(to_c_int(data.len()));
+        } else {
+            %1_lens.push(-1);
+        }
+)").arg(lc);
+        } else if (ip.type.isComplex()) {
+            r << QString(R"(        let data = o.%1(row);
+        %1_bytes.extend_from_slice(data.as_ref());
+        %1_lens.push(to_c_int(data.len()));
+)").arg(lc);
+        } else {
+            r << QString("        *%1_out.add(i) = o.%1(row).into();\n").arg(lc);
+        }
+    }
+    r << "    }\n";
+    for (auto ip: o.itemProperties) {
+        if (ip.type.isComplex()) {
+            r << QString(R"(    %1_set(
+        %1_out,
+        %1_bytes.as_ptr() as *const c_char,
+        %1_lens.as_ptr(),
+        to_c_int(count),
+    );
+)").arg(snakeCase(ip.name));
+        }
+    }
+    r << "}\n";
+}
+
 void writeRustInterfaceObject(QTextStream& r, const Object& o, const Configuration& conf) {
-    const QString lcname(snakeCase(o.name));
+    const QString name(snakeCase(o.name));
+    const QString lcname(o.symbolPrefix + name);
     r << QString(R"(
 pub struct %1QObject {}
 
-#[derive(Clone)]
+)").arg(o.name);
+    r << QString(R"(#[derive(Clone)]
 pub struct %1Emitter {
-    qobject: Arc<Mutex<*const %1QObject>>,
+    pub(crate) qobject: Arc<AtomicPtr<%1QObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+)").arg(o.name);
+    if (o.hasCancelToken()) {
+        r << "    pub(crate) cancel: CancelToken,
+";
+    }
+    r << QString(R"(    pub(crate) run_on_gui_thread: fn(*const %1QObject, *mut c_void),
 )").arg(o.name);
     for (const Property& p: o.properties) {
-        if (p.type.type == BindingType::Object) {
+        if (p.type.type == BindingType::Object || p.constant) {
             continue;
         }
-        r << QString("    %2_changed: fn(*const %1QObject),\n")
+        if (p.bindable) {
+            r << QString("    pub(crate) %2_set: fn(*const %1QObject, %3),\n")
+                .arg(o.name, snakeCase(p.name), rustBindableCArgs(p));
+            continue;
+        }
+        r << QString("    pub(crate) %2_changed: fn(*const %1QObject),\n")
             .arg(o.name, snakeCase(p.name));
     }
     if (o.type == ObjectType::List) {
-        r << QString("    new_data_ready: fn(*const %1QObject),\n")
+        r << QString("    pub(crate) new_data_ready: fn(*const %1QObject),\n")
             .arg(o.name);
     } else if (o.type == ObjectType::Tree) {
-        r << QString("    new_data_ready: fn(*const %1QObject, item: usize, valid: bool),\n")
+        r << QString("    pub(crate) new_data_ready: fn(*const %1QObject, item: usize, valid: bool),\n")
             .arg(o.name);
     }
     r << QString(R"(}
 
-unsafe impl Send for %1Emitter {}
-
 impl %1Emitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const %1QObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by %2_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+%3        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut %1) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+)").arg(o.name, lcname, o.hasCancelToken() ? "        self.cancel.cancel();\n" : "");
+    if (o.hasCancelToken()) {
+        r << R"(    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+)";
+    }
+    if (o.async) {
+        r << QString(R"(    /// Run `future` on the executor that was set with `set_executor`. When
+    /// it completes, `then` gets its output and `&mut` access to the object
+    /// in the thread of the QObject. Neither happens when the QObject is
+    /// deleted first. Fails when no executor was set.
+    #[cfg(feature = "async")]
+    pub fn spawn<F, C>(&self, future: F, then: C) -> Result<(), NoExecutor>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+        C: FnOnce(&mut %1, F::Output) + Send + 'static,
+    {
+        let executor = EXECUTOR.lock().unwrap().clone().ok_or(NoExecutor)?;
+        let emit = self.clone();
+        executor.spawn(Box::pin(Completion {
+            future: Box::pin(future),
+            done: Some(Box::new(move |v| {
+                emit.run_on_gui_thread(Box::new(move |o| then(o, v)));
+            })),
+            token: self.cancel.clone(),
+        }));
+        Ok(())
+    }
+    /// Poll `future` in the thread of the QObject, driven by its event loop.
+    /// When it completes, `then` gets its output and `&mut` access to the
+    /// object. The future is dropped when the QObject is deleted first.
+    #[cfg(feature = "async")]
+    pub fn spawn_local<F, C>(&self, future: F, then: C)
+    where
+        F: Future + Send + 'static,
+        F::Output: 'static,
+        C: FnOnce(&mut %1, F::Output) + Send + 'static,
+    {
+        let emit = self.clone();
+        let task = Arc::new(GuiTask::<%1, F::Output> {
+            state: Mutex::new(Some((Box::pin(future), Box::new(then)))),
+            post: Box::new(move |f| emit.run_on_gui_thread(f)),
+        });
+        task.wake();
     }
 )").arg(o.name);
+    }
     for (const Property& p: o.properties) {
-        if (p.type.type == BindingType::Object) {
+        if (p.type.type == BindingType::Object || p.constant) {
             continue;
         }
-        r << QString(R"(    pub fn %1_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.%1_changed)(ptr);
+        rustDoc(r, "    ", p.doc);
+        if (p.bindable) {
+            QString type = p.type.rustType;
+            QString args = "value";
+            if (p.type.isComplex()) {
+                type = p.type.name == "QString" ? "&str" : "&[u8]";
+                args = "value.as_ptr() as *const c_char, to_c_int(value.len())";
+            }
+            r << QString(R"(    pub fn set_%1(&self, value: %2) {
+        self.with_qobject(|ptr| (self.%1_set)(ptr, %3));
+    }
+)").arg(snakeCase(p.name), type, args);
+            continue;
         }
+        r << QString(R"(    pub fn %1_changed(&self) {
+        self.with_qobject(|ptr| (self.%1_changed)(ptr));
     }
 )").arg(snakeCase(p.name));
     }
     if (o.type == ObjectType::List) {
         r << R"(    pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
-        }
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
     }
 )";
     } else if (o.type == ObjectType::Tree) {
         r << R"(    pub fn new_data_ready(&self, item: Option<usize>) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
+        self.with_qobject(|ptr| {
             (self.new_data_ready)(ptr, item.unwrap_or(13), item.is_some());
-        }
+        });
     }
 )";
     }
@@ -275,17 +646,16 @@ impl %1Emitter {
             indexCDecl = " item: usize, valid: bool,";
             index = " item.unwrap_or(13), item.is_some(),";
         }
-        r << QString(R"(}
-
-pub struct %1%2 {
-    qobject: *const %1QObject,
-    data_changed: fn(*const %1QObject, usize, usize),
-    begin_reset_model: fn(*const %1QObject),
-    end_reset_model: fn(*const %1QObject),
-    begin_insert_rows: fn(*const %1QObject,%5 usize, usize),
-    end_insert_rows: fn(*const %1QObject),
-    begin_remove_rows: fn(*const %1QObject,%5 usize, usize),
-    end_remove_rows: fn(*const %1QObject),
+        r << "}\n\n";
+        r << QString(R"(pub struct %1%2 {
+    pub(crate) qobject: *const %1QObject,
+    pub(crate) data_changed: fn(*const %1QObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const %1QObject),
+    pub(crate) end_reset_model: fn(*const %1QObject),
+    pub(crate) begin_insert_rows: fn(*const %1QObject,%5 usize, usize),
+    pub(crate) end_insert_rows: fn(*const %1QObject),
+    pub(crate) begin_remove_rows: fn(*const %1QObject,%5 usize, usize),
+    pub(crate) end_remove_rows: fn(*const %1QObject),
 }
 
 impl %1%2 {
@@ -313,9 +683,9 @@ impl %1%2 {
 )").arg(o.name, type, indexDecl, index, indexCDecl);
     }
 
-    r << QString(R"(}
-
-pub trait %1Trait {
+    r << "}\n\n";
+    rustDoc(r, "", o.doc);
+    r << QString(R"(pub trait %1Trait {
     fn new(emit: %1Emitter%2)").arg(o.name, modelStruct);
     for (const Property& p: o.properties) {
         if (p.type.type == BindingType::Object) {
@@ -327,12 +697,20 @@ pub trait %1Trait {
 )").arg(o.name);
     for (const Property& p: o.properties) {
         const QString lc(snakeCase(p.name));
+        if (!p.bindable) {
+            rustDoc(r, "    ", p.doc);
+        }
         if (p.type.type == BindingType::Object) {
             r << QString("    fn %1(&self) -> &%2;\n").arg(lc, rustType(p));
+            rustDoc(r, "    ", p.doc);
             r << QString("    fn %1_mut(&mut self) -> &mut %2;\n").arg(lc, rustType(p));
         } else {
-            r << QString("    fn %1(&self) -> %2;\n").arg(lc, rustReturnType(p));
+            if (!p.bindable) {
+                r << QString("    fn %1(&self) -> %2;\n")
+                    .arg(lc, borrowedOr(p, rustReturnType(p)));
+            }
             if (p.write) {
+                rustDoc(r, "    ", p.doc);
                 if (p.type.name == "QByteArray") {
                     if (p.optional) {
                         r << QString("    fn set_%1(&mut self, value: Option<&[u8]>);\n").arg(lc);
@@ -340,9 +718,14 @@ pub trait %1Trait {
                         r << QString("    fn set_%1(&mut self, value: &[u8]);\n").arg(lc);
                     }
                 } else {
-                    r << QString("    fn set_%1(&mut self, value: %2);\n").arg(lc, rustType(p));
+                    r << QString("    fn set_%1(&mut self, value: %2);\n")
+                        .arg(lc, borrowedOr(p, rustSetType(p)));
                 }
             }
+            if (p.reset) {
+                rustDoc(r, "    ", p.doc);
+                r << QString("    fn reset_%1(&mut self);\n").arg(lc);
+            }
         }
     }
     for (const Function& f: o.functions) {
@@ -350,10 +733,10 @@ pub trait %1Trait {
         QString argList;
         if (f.args.size() > 0) {
             for (auto a = f.args.begin(); a < f.args.end(); a++) {
-                auto t = a->type.name == "QByteArray" ?"&[u8]" :a->type.rustType;
-                argList.append(QString(", %1: %2").arg(a->name, t));
+                argList.append(QString(", %1: %2").arg(a->name, rustArgType(*a)));
             }
         }
+        rustFunctionDoc(r, f);
         r << QString("    fn %1(&%2self%4) -> %3;\n")
             .arg(lc, f.mut ? "mut " : "", f.type.rustType, argList);
     }
@@ -381,9 +764,11 @@ pub trait %1Trait {
     }
     if (o.type != ObjectType::Object) {
         for (auto ip: o.itemProperties) {
+            rustDoc(r, "    ", ip.doc);
             r << QString("    fn %1(&self, item: usize) -> %2;\n")
                     .arg(snakeCase(ip.name), rustReturnType(ip));
             if (ip.write) {
+                rustDoc(r, "    ", ip.doc);
                 if (ip.type.name == "QByteArray") {
                     if (ip.optional) {
                         r << QString("    fn set_%1(&mut self, item: usize, Option<&[u8]>) -> bool;\n")
@@ -394,28 +779,62 @@ pub trait %1Trait {
                     }
                 } else {
                     r << QString("    fn set_%1(&mut self, item: usize, %2) -> bool;\n")
-                        .arg(snakeCase(ip.name), rustType(ip));
+                        .arg(snakeCase(ip.name), rustSetType(ip));
                 }
             }
         }
     }
 
-    r << QString(R"(}
-
+    r << "}\n";
+    if (o.singleton) {
+        r << QString("\nstatic %1_CREATED: AtomicBool = AtomicBool::new(false);\n")
+            .arg(name.toUpper());
+    }
+    r << QString(R"(
 #[no_mangle]
 pub extern "C" fn %1_new(
 )").arg(lcname);
-    rConstructorArgsDecl(r, lcname, o, conf);
+    rConstructorArgsDecl(r, name, o, conf);
     r << QString(",\n) -> *mut %1 {\n").arg(o.name);
-    rConstructorArgs(r, lcname, o, conf);
-    r << QString(R"(    Box::into_raw(Box::new(d_%2))
+    if (o.singleton) {
+        r << QString(R"(    if %1_CREATED.swap(true, Ordering::SeqCst) {
+        // a panic must not unwind into the C++ caller
+        eprintln!("%2 is a singleton and can only be created once.");
+        ::std::process::abort();
+    }
+)").arg(name.toUpper(), o.name);
+    }
+    rConstructorArgs(r, name, o, conf);
+    r << QString(R"(    Box::into_raw(Box::new(d_%3))
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn %2_free(ptr: *mut %1) {
-    Box::from_raw(ptr).emit().clear();
+)").arg(o.name, lcname, name);
+    QStringList children;
+    childEmitters(children, "o", o, conf);
+    if (children.isEmpty()) {
+        r << "    Box::from_raw(ptr).emit().clear();\n";
+    } else {
+        // the child objects are deleted with this one
+        r << "    let o = Box::from_raw(ptr);\n    o.emit().clear();\n";
+        for (auto child: children) {
+            r << "    " << child << ".emit().clear();\n";
+        }
+    }
+    r << QString(R"(}
+
+#[no_mangle]
+pub unsafe extern "C" fn %2_run_task(ptr: *mut %1, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut %1) + Send>);
+    f(&mut *ptr);
 }
-)").arg(o.name, lcname);
+
+#[no_mangle]
+pub unsafe extern "C" fn %2_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut %1) + Send>));
+}
+)").arg(o.name, lcname, name);
     for (const Property& p: o.properties) {
         const QString base = QString("%1_%2").arg(lcname, snakeCase(p.name));
         QString ret = ") -> " + rustType(p);
@@ -427,6 +846,55 @@ pub unsafe extern "C" fn %2_get(ptr: *mut %1) -> *mut %4 {
 }
 )").arg(o.name, base, snakeCase(p.name), rustType(p));
 
+        } else if (p.bindable) {
+            // C++ keeps the value, so there is only a setter
+            if (p.write && p.type.name == "QString") {
+                r << QString(R"(
+#[no_mangle]
+pub extern "C" fn %2_set(ptr: *mut %1, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+%4    o.set_%3(s);
+}
+)").arg(o.name, base, snakeCase(p.name),
+                    rustDecodeString(p.strict, "s", "v", "len"));
+            } else if (p.write && p.type.name == "QByteArray") {
+                r << QString(R"(
+#[no_mangle]
+pub extern "C" fn %2_set(ptr: *mut %1, v: *const c_char, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let v = unsafe { slice::from_raw_parts(v as *const u8, to_usize(len)) };
+    o.set_%3(v);
+}
+)").arg(o.name, base, snakeCase(p.name));
+            } else if (p.write) {
+                r << QString(R"(
+#[no_mangle]
+pub unsafe extern "C" fn %2_set(ptr: *mut %1, v: %4) {
+    (&mut *ptr).set_%3(v);
+}
+)").arg(o.name, base, snakeCase(p.name), rustType(p));
+            }
+        } else if (p.borrowed) {
+            // C++ wraps the buffer of Rust without copying it
+            const QString c = p.type.name == "QString" ? "c_ushort" : "c_char";
+            r << QString(R"(
+#[no_mangle]
+pub unsafe extern "C" fn %2_get(ptr: *const %1, len: *mut c_int) -> *const %4 {
+    let v = (&*ptr).%3();
+    *len = to_c_int(v.len());
+    v.as_ptr() as *const %4
+}
+)").arg(o.name, base, snakeCase(p.name), c);
+            if (p.write) {
+                r << QString(R"(
+#[no_mangle]
+pub unsafe extern "C" fn %2_set(ptr: *mut %1, v: *const %4, len: c_int) {
+    let v = slice::from_raw_parts(v as *const %5, to_usize(len));
+    (&mut *ptr).set_%3(v);
+}
+)").arg(o.name, base, snakeCase(p.name), c,
+                    p.type.name == "QString" ? "u16" : "u8");
+            }
         } else if (p.type.isComplex() && !p.optional) {
             r << QString(R"(
 #[no_mangle]
@@ -446,11 +914,10 @@ pub extern "C" fn %2_get(
 #[no_mangle]
 pub extern "C" fn %2_set(ptr: *mut %1, v: *const c_ushort, len: c_int) {
     let o = unsafe { &mut *ptr };
-    let mut s = String::new();
-    set_string_from_utf16(&mut s, v, len);
-    o.set_%3(s);
+%4    o.set_%3(s);
 }
-)").arg(o.name, base, snakeCase(p.name));
+)").arg(o.name, base, snakeCase(p.name),
+                    rustDecodeString(p.strict, "s", "v", "len"));
             } else if (p.write) {
                 r << QString(R"(
 #[no_mangle]
@@ -482,11 +949,11 @@ pub extern "C" fn %2_get(
 #[no_mangle]
 pub extern "C" fn %2_set(ptr: *mut %1, v: *const c_ushort, len: c_int) {
     let o = unsafe { &mut *ptr };
-    let mut s = String::new();
-    set_string_from_utf16(&mut s, v, len);
-    o.set_%3(Some(s));
+%4    o.set_%3(%5);
 }
-)").arg(o.name, base, snakeCase(p.name));
+)").arg(o.name, base, snakeCase(p.name),
+                    rustDecodeString(p.strict, "s", "v", "len"),
+                    p.strict ? "s.map(Some)" : "Some(s)");
             } else if (p.write) {
                 r << QString(R"(
 #[no_mangle]
@@ -536,7 +1003,15 @@ pub unsafe extern "C" fn %2_set(ptr: *mut %1, v: %4) {
 #[no_mangle]
 pub extern "C" fn %2_set_none(ptr: *mut %1) {
     let o = unsafe { &mut *ptr };
-    o.set_%3(None);
+    o.set_%3(%4);
+}
+)").arg(o.name, base, snakeCase(p.name), p.strict ? "Ok(None)" : "None");
+        }
+        if (p.reset) {
+            r << QString(R"(
+#[no_mangle]
+pub unsafe extern "C" fn %2_reset(ptr: *mut %1) {
+    (&mut *ptr).reset_%3();
 }
 )").arg(o.name, base, snakeCase(p.name));
         }
@@ -552,11 +1027,17 @@ pub unsafe extern "C" fn %2_row_count(ptr: *const %1) -> c_int {
 }
 #[no_mangle]
 pub unsafe extern "C" fn %2_insert_rows(ptr: *mut %1, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn %2_remove_rows(ptr: *mut %1, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn %2_can_fetch_more(ptr: *const %1) -> bool {
@@ -700,7 +1181,7 @@ pub extern "C" fn %2_data_%3(ptr: *const %1%5) -> %4 {
             if (ip.write) {
                 QString val = "v";
                 if (ip.optional) {
-                    val = "Some(" + val + ")";
+                    val = ip.strict ? "v.map(Some)" : "Some(v)";
                 }
                 if (ip.type.name == "QString") {
                     r << QString(R"(
@@ -710,11 +1191,10 @@ pub extern "C" fn %2_set_data_%3(
     s: *const c_ushort, len: c_int,
 ) -> bool {
     let o = unsafe { &mut *ptr };
-    let mut v = String::new();
-    set_string_from_utf16(&mut v, s, len);
-    o.set_%3(%5, %6)
+%7    o.set_%3(%5, %6)
 }
-)").arg(o.name, lcname, snakeCase(ip.name), indexDecl, index, val);
+)").arg(o.name, lcname, snakeCase(ip.name), indexDecl, index, val,
+                        rustDecodeString(ip.strict, "v", "s", "len"));
                 } else if (ip.type.name == "QByteArray") {
                     r << QString(R"(
 #[no_mangle]
@@ -744,16 +1224,30 @@ pub unsafe extern "C" fn %2_set_data_%3(
                 r << QString(R"(
 #[no_mangle]
 pub unsafe extern "C" fn %2_set_data_%3_none(ptr: *mut %1%4) -> bool {
-    (&mut *ptr).set_%3(%5, None)
+    (&mut *ptr).set_%3(%5, %6)
 }
-)").arg(o.name, lcname, snakeCase(ip.name), indexDecl, index);
+)").arg(o.name, lcname, snakeCase(ip.name), indexDecl, index,
+                    ip.strict ? "Ok(None)" : "None");
             }
         }
     }
+    if (o.bulk) {
+        writeRustDataRows(r, o);
+    }
 }
 
-QString rustFile(const QDir rustdir, const QString& module) {
+// The file of a module. A path like crate::ui::bindings is a file in a
+// subdirectory, src/ui/bindings.rs.
+QString rustFile(const QDir rustdir, QString module) {
+    if (module.startsWith("crate::")) {
+        module = module.mid(7);
+    }
+    module.replace("::", "/");
     QDir src(rustdir.absoluteFilePath("src"));
+    // in check mode, nothing is written, not even a directory
+    if (!DifferentFileWriter::checkMode) {
+        src.mkpath(QFileInfo(module).path());
+    }
     QString modulePath = src.absoluteFilePath(module + "/mod.rs");
     if (QFile::exists(modulePath)) {
         return modulePath;
@@ -761,30 +1255,81 @@ QString rustFile(const QDir rustdir, const QString& module) {
     return src.absoluteFilePath(module + ".rs");
 }
 
-void writeRustTypes(const Configuration& conf, QTextStream& r) {
-    bool hasOption = false;
-    bool hasString = false;
-    bool hasStringWrite = false;
-    bool hasByteArray = false;
-    bool hasListOrTree = false;
+// The types that the interface passes between Rust and C++.
+struct RustTypeUse {
+    bool option = false;
+    // a QString that Rust gets as UTF-16
+    bool string = false;
+    bool byteArray = false;
+    bool listOrTree = false;
+    bool strict = false;
+    bool borrowed = false;
+    // a function that returns a QString or QByteArray
+    bool returnsBytes = false;
+    // a function that takes a QByteArray
+    bool takesBytes = false;
+};
 
+RustTypeUse rustTypeUse(const Configuration& conf) {
+    RustTypeUse use;
     for (auto o: conf.objects) {
-        hasListOrTree |= o.type != ObjectType::Object;
+        use.listOrTree |= o.type != ObjectType::Object;
         for (auto p: o.properties) {
-            hasOption |= p.optional;
-            hasString |= p.type.type == BindingType::QString;
-            hasStringWrite |= p.type.type == BindingType::QString
-                    && p.write;
-            hasByteArray |= p.type.type == BindingType::QByteArray;
+            use.option |= p.optional;
+            use.string |= p.type.type == BindingType::QString && !p.borrowed;
+            use.borrowed |= p.type.type == BindingType::QString && p.borrowed;
+            use.byteArray |= p.type.type == BindingType::QByteArray;
+            use.strict |= p.strict;
         }
         for (auto p: o.itemProperties) {
-            hasOption |= p.optional;
-            hasString |= p.type.type == BindingType::QString;
-            hasByteArray |= p.type.type == BindingType::QByteArray;
+            use.option |= p.optional;
+            use.string |= p.type.type == BindingType::QString;
+            use.byteArray |= p.type.type == BindingType::QByteArray;
+            use.strict |= p.strict;
+        }
+        for (auto f: o.functions) {
+            use.returnsBytes |= f.type.isComplex();
+            for (auto a: f.args) {
+                use.string |= a.type.type == BindingType::QString;
+                use.takesBytes |= a.type.type == BindingType::QByteArray;
+                use.strict |= a.strict;
+            }
         }
     }
+    return use;
+}
 
-    if (hasOption || hasListOrTree) {
+// The imports of the interface module. Those for strings and bytes are only
+// written when they are used.
+void writeRustUses(const Configuration& conf, QTextStream& r) {
+    const RustTypeUse use = rustTypeUse(conf);
+    const bool utf16 = use.string || use.borrowed;
+    const bool slices = utf16 || use.byteArray || use.takesBytes;
+    QStringList types;
+    if (slices || use.returnsBytes) {
+        types << "c_char";
+    }
+    if (utf16) {
+        types << "c_ushort";
+    }
+    // lengths, counts and rows are passed as c_int
+    if (slices || use.returnsBytes || use.listOrTree) {
+        types << "c_int";
+    }
+    types << "c_void";
+    r << (types.size() == 1 ? "use libc::c_void;\n"
+            : "use libc::{" + types.join(", ") + "};\n");
+    if (slices) {
+        r << "use std::slice;\n";
+    }
+    if (utf16) {
+        r << "use std::char::{decode_utf16, REPLACEMENT_CHARACTER};\n";
+    }
+}
+
+void writeRustTypes(const Configuration& conf, QTextStream& r) {
+    const RustTypeUse use = rustTypeUse(conf);
+    if (use.option || use.listOrTree) {
         r << R"(
 
 #[repr(C)]
@@ -813,7 +1358,7 @@ where
 }
 )";
     }
-    if (hasString) {
+    if (use.string) {
         r << R"(
 
 pub enum QString {}
@@ -821,21 +1366,59 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
 
 )";
-                 }
-    if (hasByteArray) {
+    }
+    if (use.strict) {
+        r << R"(
+/// A QString that is not valid UTF-16 because it contains an unpaired
+/// surrogate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utf16Error {
+    /// The string with U+FFFD in place of each unpaired surrogate.
+    pub lossy: String,
+}
+
+impl ::std::fmt::Display for Utf16Error {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid UTF-16: unpaired surrogate in {:?}", self.lossy)
+    }
+}
+
+impl ::std::error::Error for Utf16Error {}
+
+fn string_from_utf16_strict(str: *const c_ushort, len: c_int) -> Result<String, Utf16Error> {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    String::from_utf16(utf16).map_err(|_| Utf16Error {
+        lossy: String::from_utf16_lossy(utf16),
+    })
+}
+
+)";
+    }
+    if (use.borrowed) {
+        r << R"(
+/// Decode the UTF-16 of a borrowed QString. Each unpaired surrogate becomes
+/// U+FFFD.
+pub fn utf16_to_string(utf16: &[u16]) -> String {
+    decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+)";
+    }
+    if (use.byteArray) {
         r << R"(
 
 pub enum QByteArray {}
 )";
     }
-    if (hasListOrTree) {
+    if (use.listOrTree) {
         r << R"(
 
 #[repr(C)]
@@ -852,24 +1435,61 @@ pub struct QModelIndex {
 )";
     }
 
-    if (hasString || hasByteArray || hasListOrTree) {
+    if (use.string || use.borrowed || use.byteArray || use.listOrTree) {
+        // counts, rows and lengths are clamped instead of aborting
         r << R"(
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-)";
-    }
-
-    if (hasString || hasByteArray || hasListOrTree) {
-        r << R"(
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
@@ -878,41 +1498,458 @@ fn to_c_int(n: usize) -> c_int {
     }
 }
 
+// The token that tells worker threads that their object is gone and, with
+// "workers", the registry that joins them.
+void writeRustCancel(QTextStream& r, const Configuration& conf) {
+    r << R"(
+/// Cancelled when the QObject of an emitter is deleted. Worker threads can
+/// check it, wait on it or await it to stop in time.
+#[derive(Clone)]
+pub struct CancelToken {
+    state: Arc<CancelState>,
+}
+
+struct CancelState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+    condvar: Condvar,
+}
+
+impl Default for CancelToken {
+    fn default() -> CancelToken {
+        CancelToken {
+            state: Arc::new(CancelState {
+                cancelled: AtomicBool::new(false),
+                wakers: Mutex::new(Vec::new()),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+}
+
+impl CancelToken {
+    fn cancel(&self) {
+        let wakers: Vec<Waker> = {
+            let mut wakers = self.state.wakers.lock().unwrap();
+            self.state.cancelled.store(true, Ordering::SeqCst);
+            self.state.condvar.notify_all();
+            wakers.drain(..).collect()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+    /// Sleep until the token is cancelled or `timeout` has passed. Returns
+    /// `true` when the token is cancelled.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let wakers = self.state.wakers.lock().unwrap();
+        let _ = self.state.condvar
+            .wait_timeout_while(wakers, timeout, |_| !self.is_cancelled())
+            .unwrap();
+        self.is_cancelled()
+    }
+    /// A future that is ready when the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled { token: self.clone() }
+    }
+    // Keep `waker` to wake it when the token is cancelled. Returns `true`
+    // when the token already is.
+    fn register(&self, waker: &Waker) -> bool {
+        let mut wakers = self.state.wakers.lock().unwrap();
+        if self.is_cancelled() {
+            return true;
+        }
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+        false
+    }
+)";
+    if (conf.async) {
+        r << R"(    #[cfg(feature = "async")]
+    fn unregister(&self, waker: &Waker) {
+        self.state.wakers.lock().unwrap().retain(|w| !w.will_wake(waker));
+    }
+)";
+    }
+    r << R"(}
+
+/// The future of `CancelToken::cancelled`.
+pub struct Cancelled {
+    token: CancelToken,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.token.register(cx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+)";
+    if (!conf.workers) {
+        return;
+    }
+    r << R"(
+/// Worker threads that are cancelled and joined when this is dropped. Keep
+/// it in a field of the Rust object and its threads end with the object.
+pub struct Workers {
+    token: CancelToken,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Workers {
+    pub fn new(token: CancelToken) -> Workers {
+        Workers {
+            token: token,
+            threads: Vec::new(),
+        }
+    }
+    /// Run `f` in a new thread. `f` should return soon after the token that
+    /// it gets is cancelled.
+    pub fn spawn<F: FnOnce(CancelToken) + Send + 'static>(&mut self, f: F) {
+        self.threads.retain(|t| !t.is_finished());
+        let token = self.token.clone();
+        self.threads.push(thread::spawn(move || f(token)));
+    }
+}
+
+impl Drop for Workers {
+    fn drop(&mut self) {
+        self.token.cancel();
+        for t in self.threads.drain(..) {
+            let _ = t.join();
+        }
+    }
+}
+)";
+}
+
+// The executors for the futures that the emitters spawn. They are only
+// compiled with the cargo feature "async".
+void writeRustAsync(QTextStream& r) {
+    r << R"(
+/// Runs the futures of `spawn` on the emitters, for example on the thread
+/// pool of an async runtime. Set one with `set_executor`.
+#[cfg(feature = "async")]
+pub trait Executor: Send + Sync {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>);
+}
+
+#[cfg(feature = "async")]
+static EXECUTOR: Mutex<Option<Arc<dyn Executor>>> = Mutex::new(None);
+
+/// Set the executor that runs the futures of `spawn`.
+#[cfg(feature = "async")]
+pub fn set_executor<E: Executor + 'static>(executor: E) {
+    *EXECUTOR.lock().unwrap() = Some(Arc::new(executor));
+}
+
+/// The error of `spawn` when `set_executor` was not called.
+#[cfg(feature = "async")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoExecutor;
+
+#[cfg(feature = "async")]
+impl ::std::fmt::Display for NoExecutor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "set_executor() was not called")
+    }
+}
+
+#[cfg(feature = "async")]
+impl ::std::error::Error for NoExecutor {}
+
+// A future on the executor that hands its output to `done`. Its waker is
+// woken when the object is deleted, so that it is dropped without finishing.
+#[cfg(feature = "async")]
+struct Completion<F: Future> {
+    future: Pin<Box<F>>,
+    done: Option<Box<dyn FnOnce(F::Output) + Send>>,
+    token: CancelToken,
+}
+
+#[cfg(feature = "async")]
+impl<F: Future> Future for Completion<F> {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.token.register(cx.waker()) {
+            return Poll::Ready(());
+        }
+        match self.future.as_mut().poll(cx) {
+            Poll::Ready(v) => {
+                self.token.unregister(cx.waker());
+                if let Some(done) = self.done.take() {
+                    done(v);
+                }
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+// A future that is polled in the thread of the QObject of type `O`. Waking
+// it posts the next poll to that thread with `post`.
+#[cfg(feature = "async")]
+struct GuiTask<O, T> {
+    state: Mutex<Option<(
+        Pin<Box<dyn Future<Output = T> + Send>>,
+        Box<dyn FnOnce(&mut O, T) + Send>,
+    )>>,
+    post: Box<dyn Fn(Box<dyn FnOnce(&mut O) + Send>) + Send + Sync>,
+}
+
+#[cfg(feature = "async")]
+impl<O: 'static, T: 'static> GuiTask<O, T> {
+    fn poll(self: Arc<Self>, o: &mut O) {
+        let waker = Waker::from(self.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut state = self.state.lock().unwrap();
+        let v = match *state {
+            Some((ref mut future, _)) => match future.as_mut().poll(&mut cx) {
+                Poll::Ready(v) => v,
+                Poll::Pending => return,
+            },
+            None => return,
+        };
+        let (_, then) = state.take().unwrap();
+        drop(state);
+        then(o, v);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<O: 'static, T: 'static> Wake for GuiTask<O, T> {
+    fn wake(self: Arc<Self>) {
+        let task = self.clone();
+        (self.post)(Box::new(move |o| task.poll(o)));
+    }
+}
+)";
+}
+
+// Use the modules of the imported configurations whose objects are
+// properties of the objects in this configuration.
+void writeRustImports(QTextStream& r, const Configuration& conf, bool interface) {
+    const QList<Object> children = conf.importedChildren();
+    for (auto i: conf.imports) {
+        bool used = false;
+        for (auto o: i.objects) {
+            for (auto c: children) {
+                used |= c.name == o.name;
+            }
+        }
+        if (used && interface) {
+            r << QString("use %1::*;\n").arg(i.interfaceModule);
+        }
+        if (used) {
+            r << QString("use %1::*;\n").arg(i.implementationModule);
+        }
+    }
+}
+
+// The properties that a snapshot holds: the writable values that the trait
+// can read back and the object properties.
+bool inSnapshot(const Property& p) {
+    return p.type.type == BindingType::Object || (p.write && !p.bindable);
+}
+
+// A copy of the property values of 'o' that can be serialized. It is read
+// and restored through the trait, so that the setters emit the signals.
+void writeRustSnapshot(QTextStream& r, const Object& o) {
+    r << QString(R"(
+/// The values of the writable properties of %1 and of its object
+/// properties. `restore` passes them to the setters, which emit the change
+/// signals.
+%2#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct %1Snapshot {
+)").arg(o.name, o.type == ObjectType::Object ? "" :
+        "/// The rows are read with `rows()` and restored with `set_rows()`, which\n"
+        "/// resets the model.\n");
+    QString take;
+    QString restore;
+    for (const Property& p: o.properties) {
+        if (!inSnapshot(p)) {
+            continue;
+        }
+        const QString lc(snakeCase(p.name));
+        QString type = rustType(p);
+        QString get = QString("o.%1()").arg(lc);
+        QString set = QString("self.%1.clone()").arg(lc);
+        if (p.type.type == BindingType::Object) {
+            type = p.type.name + "Snapshot";
+            get = QString("%1Snapshot::take(o.%2())").arg(p.type.name, lc);
+        } else if (p.borrowed && p.type.name == "QString") {
+            type = "Vec<u16>";
+        }
+        if (p.type.type == BindingType::Object || p.rustByValue
+                || !p.type.isComplex()) {
+            // already owned
+        } else if (p.optional) {
+            get += ".map(|v| v.to_owned())";
+        } else {
+            get += ".to_owned()";
+        }
+        if (p.type.name == "QByteArray" || p.borrowed) {
+            set = p.optional ? QString("self.%1.as_ref().map(|v| &v[..])").arg(lc)
+                : QString("&self.%1").arg(lc);
+        } else if (!p.type.isComplex()) {
+            set = QString("self.%1").arg(lc);
+        }
+        if (p.strict) {
+            set = "Ok(" + set + ")";
+        }
+        r << QString("    pub %1: %2,\n").arg(lc, type);
+        take += QString("            %1: %2,\n").arg(lc, get);
+        if (p.type.type == BindingType::Object) {
+            restore += QString("        self.%1.restore(o.%1_mut());\n").arg(lc);
+        } else {
+            restore += QString("        o.set_%1(%2);\n").arg(lc, set);
+        }
+    }
+    if (o.type != ObjectType::Object) {
+        // rows() and set_rows() are written with a new implementation
+        r << QString("    pub rows: Vec<%1Item>,\n").arg(o.name);
+        take += "            rows: o.rows().to_vec(),\n";
+        restore += "        o.set_rows(self.rows.clone());\n";
+    }
+    r << QString(R"(}
+
+#[cfg(feature = "serde")]
+impl %1Snapshot {
+    /// Read the values with the getters.
+    pub fn take(%4: &%1) -> %1Snapshot {
+        %1Snapshot {
+%2        }
+    }
+    /// Pass the values to the setters.
+    pub fn restore(&self, %4: &mut %1) {
+%3    }
+}
+)").arg(o.name, take, restore, take.isEmpty() ? "_o" : "o");
+}
+
 void writeRustInterface(const Configuration& conf) {
     DifferentFileWriter w(rustFile(conf.rustdir, conf.interfaceModule));
     QTextStream r(&w.buffer);
-    r << QString(R"(/* generated by rust_qt_binding_generator */
+    r << R"(/* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
-use std::slice;
-use std::char::decode_utf16;
-
-use std::sync::{Arc, Mutex};
-use std::ptr::null;
-
-use %1::*;
-)").arg(conf.implementationModule);
+)";
+    writeRustUses(conf, r);
+    const RustTypeUse use = rustTypeUse(conf);
+    // the CancelToken of the emitters
+    const bool cancel = conf.workers || conf.async;
+    bool singleton = false;
+    for (auto o: conf.objects) {
+        singleton |= o.singleton;
+    }
+    QStringList sync("Arc");
+    if (cancel) {
+        sync << "Condvar";
+    }
+    if (cancel || use.string || use.borrowed || use.byteArray || use.listOrTree) {
+        // the overflow hook
+        sync << "Mutex";
+    }
+    r << "\nuse std::sync::" << (sync.size() == 1 ? sync[0]
+        : "{" + sync.join(", ") + "}") << ";\n";
+    r << QString("use std::sync::atomic::{%1AtomicPtr, AtomicUsize, Ordering};\n")
+        .arg(cancel || singleton ? "AtomicBool, " : "");
+    r << "use std::ptr::null_mut;\nuse std::thread;\n";
+    if (conf.workers) {
+        r << "use std::thread::JoinHandle;\n";
+    }
+    if (cancel) {
+        r << R"(use std::time::Duration;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+)";
+    }
+    if (conf.async) {
+        r << "#[cfg(feature = \"async\")]\nuse std::task::Wake;\n";
+    }
+    r << QString("\nuse %1::*;\n").arg(conf.implementationModule);
+    writeRustImports(r, conf, true);
+    if (conf.serde) {
+        r << "#[cfg(feature = \"serde\")]\nuse serde::{Deserialize, Serialize};\n";
+    }
 
     writeRustTypes(conf, r);
+    if (cancel) {
+        writeRustCancel(r, conf);
+    }
+    if (conf.async) {
+        writeRustAsync(r);
+    }
+
+    r << QString(R"(
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn %1() -> u64 {
+    %2
+}
+)").arg(conf.bindingHashFunction(), conf.bindingHash());
 
+    bool fixtures = false;
+    for (auto object: conf.objects) {
+        // a singleton can only be created once in a process
+        fixtures |= !object.singleton;
+    }
+    if (fixtures) {
+        writeRustTestQObject(r);
+    }
     for (auto object: conf.objects) {
         writeRustInterfaceObject(r, object, conf);
+        if (conf.serde) {
+            writeRustSnapshot(r, object);
+        }
+        if (!object.singleton) {
+            writeRustFixture(r, object, conf);
+        }
     }
 }
 
-void writeRustImplementationObject(QTextStream& r, const Object& o) {
+// A new implementation ignores a value that is not valid UTF-16.
+QString rustRejectInvalid(bool strict, const QString& name, const QString& ret) {
+    if (!strict) {
+        return QString();
+    }
+    return QString(R"(        let %1 = match %1 {
+            Ok(%1) => %1,
+            Err(_) => return%2,
+        };
+)").arg(name, ret);
+}
+
+void writeRustImplementationObject(QTextStream& r, const Object& o, bool serde) {
     const QString lcname(snakeCase(o.name));
     if (o.type != ObjectType::Object) {
         r << "#[derive(Default, Clone)]\n";
-        r << QString("struct %1Item {\n").arg(o.name);
+        if (serde) {
+            // the application saves the rows, so it has to name them
+            r << "#[cfg_attr(feature = \"serde\", derive(Debug, PartialEq, Serialize, Deserialize))]\n";
+        }
+        r << QString("%1struct %2Item {\n").arg(serde ? "pub " : "", o.name);
         for (auto ip: o.itemProperties) {
             const QString lc(snakeCase(ip.name));
+            rustDoc(r, "    ", ip.doc);
             r << QString("    %1: %2,\n").arg(lc, ip.type.rustType);
         }
         r << "}\n\n";
     }
     QString modelStruct = "";
+    rustDoc(r, "", o.doc);
     r << QString("pub struct %1 {\n    emit: %1Emitter,\n").arg((o.name));
     if (o.type == ObjectType::List) {
         modelStruct = ", model: " + o.name + "List";
@@ -923,7 +1960,10 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
     }
     for (const Property& p: o.properties) {
         const QString lc(snakeCase(p.name));
-        r << QString("    %1: %2,\n").arg(lc, rustType(p));
+        rustDoc(r, "    ", p.doc);
+        const QString type = p.borrowed && p.type.name == "QString"
+            ? "Vec<u16>" : rustType(p);
+        r << QString("    %1: %2,\n").arg(lc, type);
     }
     if (o.type != ObjectType::Object) {
         r << QString("    list: Vec<%1Item>,\n").arg(o.name);
@@ -934,11 +1974,20 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
             modelStruct += ", " + p.name + ": " + p.type.name;
         }
     }
+    // the values of the bindable properties are pushed to C++ at the end of new()
+    QStringList pushes;
+    for (const Property& p: o.properties) {
+        if (p.bindable) {
+            const QString lc(snakeCase(p.name));
+            pushes << QString("        o.emit.set_%1(%2o.%1);\n")
+                .arg(lc, p.type.isComplex() ? "&" : "");
+        }
+    }
     r << QString(R"(impl %1Trait for %1 {
     fn new(emit: %1Emitter%2) -> %1 {
-        %1 {
+        %3%1 {
             emit: emit,
-)").arg(o.name, modelStruct);
+)").arg(o.name, modelStruct, pushes.isEmpty() ? "" : "let o = ");
     if (o.type != ObjectType::Object) {
         r << QString("            model: model,\n");
     }
@@ -946,12 +1995,20 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
         const QString lc(snakeCase(p.name));
         if (p.type.type == BindingType::Object) {
             r << QString("            %1: %1,\n").arg(lc);
+        } else if (p.borrowed) {
+            r << QString("            %1: Vec::new(),\n").arg(lc);
         } else {
             r << QString("            %1: %2,\n").arg(lc, rustTypeInit(p));
         }
     }
-    r << QString(R"(        }
+    if (pushes.isEmpty()) {
+        r << "        }\n";
+    } else {
+        r << "        };\n";
+        r << "        // C++ starts with the default values, the initial values are pushed\n";
+        r << pushes.join("") << "        o\n";
     }
+    r << QString(R"(    }
     fn emit(&self) -> &%1Emitter {
         &self.emit
     }
@@ -966,6 +2023,43 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
         &mut self.%1
     }
 )").arg(lc, rustReturnType(p));
+        } else if (p.bindable) {
+            // the value is pushed to C++ whenever it changes
+            const QString value = p.type.isComplex() ? "&self." + lc : "self." + lc;
+            if (p.write) {
+                r << QString(R"(    fn set_%1(&mut self, value: %2) {
+%4        self.%1 = value;
+        self.emit.set_%1(%3);
+    }
+)").arg(lc, rustSetType(p), value, rustRejectInvalid(p.strict, "value", ""));
+            }
+            if (p.reset) {
+                r << QString(R"(    fn reset_%1(&mut self) {
+        self.%1 = %2;
+        self.emit.set_%1(%3);
+    }
+)").arg(lc, rustTypeInit(p), value);
+            }
+        } else if (p.borrowed) {
+            // the getter hands out the buffer that C++ wraps
+            r << QString(R"(    fn %1(&self) -> %2 {
+        &self.%1
+    }
+)").arg(lc, borrowedOr(p, rustReturnType(p)));
+            if (p.write) {
+                r << QString(R"(    fn set_%1(&mut self, value: %2) {
+        self.%1 = value.to_vec();
+        self.emit.%1_changed();
+    }
+)").arg(lc, borrowedOr(p, rustSetType(p)));
+            }
+            if (p.reset) {
+                r << QString(R"(    fn reset_%1(&mut self) {
+        self.%1.clear();
+        self.emit.%1_changed();
+    }
+)").arg(lc);
+            }
         } else {
             r << QString("    fn %1(&self) -> %2 {\n").arg(lc, rustReturnType(p));
             if (p.type.isComplex()) {
@@ -986,10 +2080,17 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
             r << "    }\n";
             if (p.write) {
                 r << QString(R"(    fn set_%1(&mut self, value: %2) {
-        self.%1 = value;
+%3        self.%1 = value;
         self.emit.%1_changed();
     }
-)").arg(lc, rustType(p));
+)").arg(lc, rustSetType(p), rustRejectInvalid(p.strict, "value", ""));
+            }
+            if (p.reset) {
+                r << QString(R"(    fn reset_%1(&mut self) {
+        self.%1 = %2;
+        self.emit.%1_changed();
+    }
+)").arg(lc, rustTypeInit(p));
             }
         }
     }
@@ -1027,7 +2128,8 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
             r << "    }\n";
             if (ip.write) {
                 r << QString("    fn set_%1(&mut self, item: usize, v: %2) -> bool {\n")
-                        .arg(snakeCase(ip.name), rustType(ip));
+                        .arg(snakeCase(ip.name), ip.strict ? rustSetType(ip) : rustType(ip));
+                r << rustRejectInvalid(ip.strict, "v", " false");
                 r << "        self.list[item]." << lc << " = v;\n";
                 r << "        true\n";
                 r << "    }\n";
@@ -1035,20 +2137,347 @@ void writeRustImplementationObject(QTextStream& r, const Object& o) {
         }
     }
     r << "}\n\n";
+    if (o.type != ObjectType::Object && serde) {
+        r << QString(R"(#[cfg(feature = "serde")]
+impl %1 {
+    /// The rows, for example to serialize them.
+    pub fn rows(&self) -> &[%1Item] {
+        &self.list
+    }
+    /// Replace all rows, for example with deserialized ones. The model is
+    /// reset.
+    pub fn set_rows(&mut self, rows: Vec<%1Item>) {
+        self.model.begin_reset_model();
+        self.list = rows;
+        self.model.end_reset_model();
+    }
+}
+
+)").arg(o.name);
+    }
+}
+
+// A method of a generated trait with a default body for new implementations.
+// The code is empty when no body can be generated.
+struct RustMethod {
+    QString name;
+    QString code;
+};
+
+template <typename T>
+QString rustStubValue(const T& p)
+{
+    if (p.optional) {
+        return "None";
+    }
+    if (p.type.isComplex() && !p.rustByValue) {
+        return p.type.name == "QString" ? "\"\"" : "&[]";
+    }
+    return rustTypeInit(p);
+}
+
+RustMethod rustMethod(const QString& name, const QString& signature,
+        const QString& body) {
+    QString code = QString("    fn %1 {\n").arg(signature);
+    if (!body.isEmpty()) {
+        code += "        " + body + "\n";
+    }
+    code += "    }\n";
+    return { name, code };
+}
+
+// The methods that an implementation of the trait of 'o' has to provide.
+QList<RustMethod> rustTraitStubs(const Object& o) {
+    QList<RustMethod> methods;
+    for (const Property& p: o.properties) {
+        const QString lc(snakeCase(p.name));
+        if (p.type.type == BindingType::Object) {
+            // the child is a field that is set in new()
+            methods.append({ lc, QString() });
+            methods.append({ lc + "_mut", QString() });
+            continue;
+        }
+        if (!p.bindable) {
+            methods.append(rustMethod(lc, QString("%1(&self) -> %2")
+                .arg(lc, borrowedOr(p, rustReturnType(p))),
+                p.borrowed ? "&[]" : rustStubValue(p)));
+        }
+        if (p.write) {
+            methods.append(rustMethod("set_" + lc, QString("set_%1(&mut self, value: %2)")
+                .arg(lc, borrowedOr(p, rustSetType(p))), ""));
+        }
+        if (p.reset) {
+            methods.append(rustMethod("reset_" + lc, QString("reset_%1(&mut self)")
+                .arg(lc), ""));
+        }
+    }
+    for (const Function& f: o.functions) {
+        const QString lc(snakeCase(f.name));
+        QString argList;
+        for (auto a: f.args) {
+            argList.append(QString(", %1: %2").arg(a.name, rustArgType(a)));
+        }
+        const QString value = f.type.type == BindingType::Void
+            ? "" : f.type.rustTypeInit;
+        methods.append(rustMethod(lc, QString("%1(&%2self%3) -> %4")
+            .arg(lc, f.mut ? "mut " : "", argList, f.type.rustType), value));
+    }
+    if (o.type == ObjectType::List) {
+        methods.append(rustMethod("row_count", "row_count(&self) -> usize", "0"));
+    } else if (o.type == ObjectType::Tree) {
+        methods.append(rustMethod("row_count",
+            "row_count(&self, item: Option<usize>) -> usize", "0"));
+        methods.append(rustMethod("index",
+            "index(&self, item: Option<usize>, row: usize) -> usize", "0"));
+        methods.append(rustMethod("parent",
+            "parent(&self, item: usize) -> Option<usize>", "None"));
+        methods.append(rustMethod("row", "row(&self, item: usize) -> usize", "0"));
+    }
+    for (auto ip: o.itemProperties) {
+        const QString lc(snakeCase(ip.name));
+        methods.append(rustMethod(lc, QString("%1(&self, item: usize) -> %2")
+            .arg(lc, rustReturnType(ip)), rustStubValue(ip)));
+        if (ip.write) {
+            methods.append(rustMethod("set_" + lc,
+                QString("set_%1(&mut self, item: usize, v: %2) -> bool")
+                .arg(lc, rustSetType(ip)), "false"));
+        }
+    }
+    return methods;
+}
+
+// The methods for which the trait has a default implementation.
+QStringList rustTraitDefaults(const Object& o) {
+    if (o.type == ObjectType::List) {
+        return QStringList() << "insert_rows" << "remove_rows"
+            << "can_fetch_more" << "fetch_more" << "sort";
+    } else if (o.type == ObjectType::Tree) {
+        return QStringList() << "can_fetch_more" << "fetch_more" << "sort";
+    }
+    return QStringList();
+}
+
+// The position of an 'impl FooTrait for ...' block in a Rust source file and
+// the names of the methods in it.
+struct RustImplBlock {
+    QString path;
+    int open;
+    int close;
+    QStringList methods;
+};
+
+// Skip a string, character literal or comment that starts at 'i'.
+int skipRustLiteral(const QString& s, int i) {
+    if (s.midRef(i, 2) == "//") {
+        const int end = s.indexOf('\n', i);
+        return end < 0 ? s.size() : end;
+    }
+    if (s.midRef(i, 2) == "/*") {
+        const int end = s.indexOf("*/", i + 2);
+        return end < 0 ? s.size() : end + 1;
+    }
+    if (s[i] == '"') {
+        ++i;
+        while (i < s.size() && s[i] != '"') {
+            i += s[i] == '\\' ? 2 : 1;
+        }
+        return i;
+    }
+    if (s[i] == '\'' && i + 2 < s.size() && (s[i + 1] == '\\' || s[i + 2] == '\'')) {
+        const int end = s.indexOf('\'', i + 3);
+        return s[i + 1] == '\\' && end >= 0 ? end : i + 2;
+    }
+    return i;
+}
+
+bool findRustImplBlock(const QString& path, const QString& source,
+        const QString& trait, RustImplBlock& block) {
+    QRegExp impl(QString("\\bimpl\\b[^{;]*\\b%1\\s+for\\b[^{;]*\\{").arg(trait));
+    const int start = impl.indexIn(source);
+    if (start < 0) {
+        return false;
+    }
+    QRegExp fn("^fn\\s+([A-Za-z_][A-Za-z0-9_]*)");
+    block.path = path;
+    block.methods.clear();
+    block.open = start + impl.matchedLength();
+    int depth = 1;
+    int i = block.open;
+    for (; i < source.size() && depth > 0; ++i) {
+        const int end = skipRustLiteral(source, i);
+        if (end != i) {
+            i = end;
+        } else if (source[i] == '{') {
+            ++depth;
+        } else if (source[i] == '}') {
+            --depth;
+        } else if (depth == 1 && fn.indexIn(source.mid(i, 200)) == 0
+                && (i == 0 || !(source[i - 1].isLetterOrNumber()
+                        || source[i - 1] == '_'))) {
+            block.methods.append(fn.cap(1));
+        }
+    }
+    block.close = i - 1;
+    return depth == 0;
+}
+
+// The types of the arguments of new() in the trait of 'o'.
+QStringList rustConstructorTypes(const Object& o) {
+    QStringList types;
+    types << o.name + "Emitter";
+    if (o.type == ObjectType::List) {
+        types << o.name + "List";
+    } else if (o.type == ObjectType::Tree) {
+        types << o.name + "Tree";
+    }
+    for (const Property& p: o.properties) {
+        if (p.type.type == BindingType::Object) {
+            types << p.type.name;
+        }
+    }
+    return types;
+}
+
+// The types of the arguments of new() in an implementation, without their
+// module paths. Returns false if there is no new() in the block.
+bool rustImplConstructorTypes(const QString& source, const RustImplBlock& block,
+        QStringList& types) {
+    QRegExp fn("\\bfn\\s+new\\s*\\(([^)]*)\\)");
+    const int pos = fn.indexIn(source, block.open);
+    if (pos < 0 || pos > block.close) {
+        return false;
+    }
+    types.clear();
+    for (auto arg: fn.cap(1).split(',')) {
+        const QString type = arg.section(':', 1).simplified();
+        if (!type.isEmpty()) {
+            types << type.section("::", -1);
+        }
+    }
+    return true;
+}
+
+// Add stubs for the trait methods that are missing from an existing
+// implementation and report the methods that are no longer in the trait.
+// Nothing is written when a method cannot be generated.
+void mergeRustImplementation(const Configuration& conf, const QString& path) {
+    QStringList files;
+    files.append(path);
+    if (QFileInfo(path).fileName() == "mod.rs") {
+        for (auto f: QFileInfo(path).dir().entryInfoList(QStringList() << "*.rs")) {
+            if (f.fileName() != "mod.rs") {
+                files.append(f.absoluteFilePath());
+            }
+        }
+    }
+    QMap<QString, QString> sources;
+    for (auto f: files) {
+        QFile file(f);
+        if (file.open(QIODevice::ReadOnly)) {
+            sources.insert(f, QString::fromUtf8(file.readAll()));
+        }
+    }
+    QTextStream err(stderr);
+    QString appended;
+    QTextStream a(&appended);
+    bool refused = false;
+    for (auto o: conf.objects) {
+        const QString trait = o.name + "Trait";
+        RustImplBlock block;
+        bool found = false;
+        for (auto f: files) {
+            if (findRustImplBlock(f, sources.value(f), trait, block)) {
+                found = true;
+                break;
+            }
+        }
+        if (!found) {
+            err << QCoreApplication::translate("main",
+                "%1: adding an implementation of %2.\n").arg(path, trait);
+            writeRustImplementationObject(a, o, conf.serde);
+            continue;
+        }
+        QStringList types;
+        const QStringList expected = rustConstructorTypes(o);
+        if (rustImplConstructorTypes(sources.value(block.path), block, types)
+                && types != expected) {
+            err << QCoreApplication::translate("main",
+                "%1: %2::new takes (%3), but the trait now expects (%4).\n")
+                .arg(block.path, trait, types.join(", "), expected.join(", "));
+        }
+        QStringList names;
+        QString stubs;
+        for (auto m: rustTraitStubs(o)) {
+            names.append(m.name);
+            if (block.methods.contains(m.name)) {
+                continue;
+            }
+            if (m.code.isEmpty()) {
+                err << QCoreApplication::translate("main",
+                    "%1: %2::%3 is missing. It returns an object property, which "
+                    "has to be added to new() by hand.\n")
+                    .arg(block.path, trait, m.name);
+                refused = true;
+                continue;
+            }
+            err << QCoreApplication::translate("main",
+                "%1: adding %2::%3.\n").arg(block.path, trait, m.name);
+            stubs += m.code;
+        }
+        for (auto m: block.methods) {
+            if (!names.contains(m) && m != "new" && m != "emit"
+                    && !rustTraitDefaults(o).contains(m)) {
+                err << QCoreApplication::translate("main",
+                    "%1: %2::%3 is not part of the trait anymore.\n")
+                    .arg(block.path, trait, m);
+            }
+        }
+        if (conf.serde && o.type != ObjectType::Object
+                && !sources.value(block.path).contains(QRegExp("\\bfn\\s+set_rows\\b"))) {
+            err << QCoreApplication::translate("main",
+                "%1: %2Snapshot needs %2::rows() and %2::set_rows(), which are "
+                "only written in a new implementation.\n")
+                .arg(block.path, o.name);
+        }
+        if (!stubs.isEmpty()) {
+            QString& source = sources[block.path];
+            source.insert(block.close, stubs);
+        }
+    }
+    if (refused) {
+        err << QCoreApplication::translate("main",
+            "%1 was not changed.\n").arg(path);
+        err.flush();
+        exit(1);
+    }
+    err.flush();
+    sources[path] += appended;
+    for (auto f: sources.keys()) {
+        DifferentFileWriter w(f);
+        w.buffer = sources.value(f).toUtf8();
+    }
 }
 
 void writeRustImplementation(const Configuration& conf) {
-    DifferentFileWriter w(rustFile(conf.rustdir, conf.implementationModule),
-        conf.overwriteImplementation);
+    const QString path = rustFile(conf.rustdir, conf.implementationModule);
+    if (conf.mergeImplementation && QFile::exists(path)) {
+        mergeRustImplementation(conf, path);
+        return;
+    }
+    DifferentFileWriter w(path, conf.overwriteImplementation);
     QTextStream r(&w.buffer);
     r << QString(R"(#![allow(unused_imports)]
 #![allow(unused_variables)]
 #![allow(dead_code)]
 use %1::*;
-
 )").arg(conf.interfaceModule);
+    writeRustImports(r, conf, false);
+    if (conf.serde) {
+        r << "#[cfg(feature = \"serde\")]\nuse serde::{Deserialize, Serialize};\n";
+    }
+    r << "\n";
 
     for (auto object: conf.objects) {
-        writeRustImplementationObject(r, object);
+        writeRustImplementationObject(r, object, conf.serde);
     }
 }
@@ -25,6 +25,7 @@
 #include <QDir>
 #include <QTextStream>
 #include <QCoreApplication>
+#include <QCryptographicHash>
 
 enum class ObjectType {
     Object,
@@ -76,11 +77,25 @@ struct Property {
     bool write;
     bool optional;
     bool rustByValue;
+    bool constant;
+    bool reset;
+    // the C++ object caches the value, Rust pushes changes to it
+    bool bindable;
+    // the change signals are merged and delivered once per event loop
+    // iteration
+    bool coalesce;
+    // the setter gets a Result instead of a lossily decoded QString
+    bool strict;
+    // the value is passed between C++ and Rust without a copy
+    bool borrowed;
+    QString doc;
 };
 
 struct Argument {
     QString name;
     BindingTypeProperties type;
+    bool strict;
+    QString doc;
 };
 
 struct Function {
@@ -88,6 +103,7 @@ struct Function {
     BindingTypeProperties type;
     QList<Argument> args;
     bool mut;
+    QString doc;
 };
 
 struct ItemProperty {
@@ -96,7 +112,9 @@ struct ItemProperty {
     bool write;
     bool optional;
     bool rustByValue;
+    bool strict;
     QList<QList<Qt::ItemDataRole>> roles;
+    QString doc;
 };
 
 struct Object {
@@ -106,6 +124,19 @@ struct Object {
     QList<ItemProperty> itemProperties;
     QList<Function> functions;
     int columnCount;
+    bool singleton;
+    // the data changes of a list are merged into one range and delivered
+    // once per event loop iteration
+    bool coalesce;
+    // the item properties of a block of rows are fetched with one call and
+    // cached in C++
+    bool bulk;
+    QString doc;
+    // prepended to the names of the C functions of this object
+    QString symbolPrefix;
+    // "workers" and "async" of the configuration of this object
+    bool workers;
+    bool async;
     bool containsObject() {
         for (auto p: properties) {
             if (p.type.type == BindingType::Object) {
@@ -114,9 +145,47 @@ struct Object {
         }
         return false;
     }
+    // the properties whose values are read from Rust once, when the object
+    // is constructed
+    bool hasConstants() const {
+        for (auto p: properties) {
+            if (p.constant && p.type.type != BindingType::Object) {
+                return true;
+            }
+        }
+        return false;
+    }
+    // the emitter has a CancelToken
+    bool hasCancelToken() const {
+        return workers || async;
+    }
+    bool hasCoalesced() const {
+        if (coalesce) {
+            return true;
+        }
+        for (auto p: properties) {
+            if (p.coalesce) {
+                return true;
+            }
+        }
+        return false;
+    }
+};
+
+// The objects of a configuration that is imported by another one.
+struct Import {
+    QString file;
+    QFileInfo hFile;
+    QDir rustdir;
+    QString interfaceModule;
+    QString implementationModule;
+    QString cppNamespace;
+    bool serde;
+    QList<Object> objects;
 };
 
 struct Configuration {
+    QList<Import> imports;
     QFileInfo hFile;
     QFileInfo cppFile;
     QDir rustdir;
@@ -124,18 +193,127 @@ struct Configuration {
     QString implementationModule;
     QList<Object> objects;
     bool overwriteImplementation;
+    bool mergeImplementation;
+    // snapshots and derives of Serialize and Deserialize behind the cargo
+    // feature serde
+    bool serde;
+    // CancelToken and Workers for the worker threads of the implementation
+    bool workers;
+    // the executor and the futures of the emitters, behind the cargo feature
+    // async
+    bool async;
+    QString cppNamespace;
+    QString symbolPrefix;
+    bool ffiHeader;
+    // the major version of Qt that the C++ code is generated for
+    int qtVersion;
+    QString qmlUri;
+    int qmlVersionMajor;
+    int qmlVersionMinor;
     const Object& findObject(const QString& name) const {
         for (auto& o: objects) {
             if (o.name == name) {
                 return o;
             }
         }
+        for (auto& i: imports) {
+            for (auto& o: i.objects) {
+                if (o.name == name) {
+                    return o;
+                }
+            }
+        }
         QTextStream err(stderr);
         err << QCoreApplication::translate("main",
             "Cannot find type %1.\n").arg(name);
         err.flush();
         exit(1);
     }
+    bool isImported(const QString& name) const {
+        for (auto& o: objects) {
+            if (o.name == name) {
+                return false;
+            }
+        }
+        return true;
+    }
+    // The C++ namespace in which the class of an object is declared.
+    QString cppNamespaceOf(const QString& name) const {
+        for (auto& i: imports) {
+            for (auto& o: i.objects) {
+                if (o.name == name) {
+                    return i.cppNamespace;
+                }
+            }
+        }
+        return cppNamespace;
+    }
+    // The C function that returns the binding hash of the Rust code.
+    QString bindingHashFunction() const {
+        return symbolPrefix + interfaceModule.split("::").last()
+            + "_binding_hash";
+    }
+    // A hash of everything that the C functions between the C++ and the Rust
+    // code depend on, written as a hexadecimal number. Both halves contain it
+    // so that code from different configurations is not linked together.
+    QString bindingHash() const {
+        const char* objectTypes[] = { "Object", "List", "Tree" };
+        QString description;
+        QTextStream d(&description);
+        for (auto o: objects + importedChildren()) {
+            d << "object " << o.name << " " << objectTypes[int(o.type)]
+                << " " << o.symbolPrefix << "\n";
+            for (auto p: o.properties) {
+                d << "property " << p.name << " " << p.type.name << " "
+                    << int(p.write) << int(p.optional) << int(p.constant)
+                    << int(p.reset) << (p.bindable ? " bindable" : "")
+                    << (p.borrowed ? " borrowed" : "") << "\n";
+            }
+            for (auto ip: o.itemProperties) {
+                d << "item " << ip.name << " " << ip.type.name << " "
+                    << int(ip.write) << int(ip.optional) << "\n";
+            }
+            for (auto f: o.functions) {
+                d << "function " << f.name << " " << f.type.name << " "
+                    << int(f.mut);
+                for (auto a: f.args) {
+                    d << " " << a.type.name;
+                }
+                d << "\n";
+            }
+        }
+        d.flush();
+        const QByteArray hash = QCryptographicHash::hash(description.toUtf8(),
+            QCryptographicHash::Sha256);
+        return "0x" + QString::fromLatin1(hash.left(8).toHex());
+    }
+    QString qualifiedName(const QString& name) const {
+        const QString ns = cppNamespaceOf(name);
+        return ns.isEmpty() ? name : ns + "::" + name;
+    }
+    // The imported objects that are used as properties, directly or via
+    // other objects. Their constructors are called from this configuration.
+    QList<Object> importedChildren() const {
+        QList<Object> children;
+        QList<Object> todo = objects;
+        while (!todo.isEmpty()) {
+            const Object o = todo.takeFirst();
+            for (auto p: o.properties) {
+                if (p.type.type != BindingType::Object) {
+                    continue;
+                }
+                bool known = false;
+                for (auto c: children) {
+                    known |= c.name == p.type.name;
+                }
+                if (!known && isImported(p.type.name)) {
+                    children.append(findObject(p.type.name));
+                    todo.append(findObject(p.type.name));
+                }
+            }
+        }
+        return children;
+    }
     QList<QString> types() const {
         QList<QString> ops;
         for (auto o: objects) {
@@ -169,8 +347,51 @@ struct Configuration {
                 ops.append("quintptr");
             }
         }
+        for (auto o: importedChildren()) {
+            if (o.type == ObjectType::Tree && !ops.contains("quintptr")) {
+                ops.append("quintptr");
+            }
+        }
         return ops;
     }
+    bool hasBindable() const {
+        for (auto o: objects + importedChildren()) {
+            for (auto p: o.properties) {
+                if (p.bindable) {
+                    return true;
+                }
+            }
+        }
+        return false;
+    }
+    // the emitter has a CancelToken
+    bool hasCancelToken() const {
+        return workers || async;
+    }
+    bool hasCoalesced() const {
+        for (auto o: objects) {
+            if (o.hasCoalesced()) {
+                return true;
+            }
+        }
+        return false;
+    }
+    bool hasCoalescedList() const {
+        for (auto o: objects) {
+            if (o.coalesce) {
+                return true;
+            }
+        }
+        return false;
+    }
+    bool hasBulkList() const {
+        for (auto o: objects) {
+            if (o.bulk) {
+                return true;
+            }
+        }
+        return false;
+    }
     bool hasListOrTree() const {
         for (auto o: objects) {
             if (o.type == ObjectType::List || o.type == ObjectType::Tree) {
@@ -1,13 +1,12 @@
 #include "Bindings.h"
 
-#include <QtQml/qqml.h>
 #include <QGuiApplication>
 #include <QQmlApplicationEngine>
 
 int main(int argc, char *argv[])
 {
     QGuiApplication app(argc, argv);
-    qmlRegisterType<Simple>("RustCode", 1, 0, "Simple");
+    registerQmlTypes();
 
     QQmlApplicationEngine engine;
     engine.load(QUrl(QStringLiteral("qrc:/main.qml")));
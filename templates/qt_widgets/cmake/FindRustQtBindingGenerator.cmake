@@ -3,3 +3,16 @@ find_program(RustQtBindingGenerator_EXECUTABLE rust_qt_binding_generator)
 find_package_handle_standard_args(RustQtBindingGenerator
     REQUIRED_VARS RustQtBindingGenerator_EXECUTABLE)
 mark_as_advanced(RustQtBindingGenerator_EXECUTABLE)
+
+# Sets VAR to the major version of Qt that the code generated from the
+# bindings file JSON is written for: 6 when its cpp section has
+# "qtVersion": 6, 5 otherwise. CMake runs again when the file changes.
+function(rust_qt_binding_qt_version JSON VAR)
+    set_property(DIRECTORY APPEND PROPERTY CMAKE_CONFIGURE_DEPENDS "${JSON}")
+    file(READ "${JSON}" BINDINGS)
+    if (BINDINGS MATCHES "\"qtVersion\"[ \t\r\n]*:[ \t\r\n]*6")
+        set(${VAR} 6 PARENT_SCOPE)
+    else()
+        set(${VAR} 5 PARENT_SCOPE)
+    endif()
+endfunction()
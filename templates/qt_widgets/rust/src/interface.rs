@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -16,50 +18,142 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
 
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x12028c116fd2dc7b
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct SimpleQObject {}
 
 #[derive(Clone)]
 pub struct SimpleEmitter {
-    qobject: Arc<Mutex<*const SimpleQObject>>,
-    message_changed: fn(*const SimpleQObject),
+    pub(crate) qobject: Arc<AtomicPtr<SimpleQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const SimpleQObject, *mut c_void),
+    pub(crate) message_changed: fn(*const SimpleQObject),
 }
 
-unsafe impl Send for SimpleEmitter {}
-
 impl SimpleEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn message_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const SimpleQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.message_changed)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by simple_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Simple) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn message_changed(&self) {
+        self.with_qobject(|ptr| (self.message_changed)(ptr));
+    }
 }
 
 pub trait SimpleTrait {
@@ -72,10 +166,13 @@ pub trait SimpleTrait {
 #[no_mangle]
 pub extern "C" fn simple_new(
     simple: *mut SimpleQObject,
+    simple_run_on_gui_thread: fn(*const SimpleQObject, *mut c_void),
     message_changed: fn(*const SimpleQObject),
 ) -> *mut Simple {
     let simple_emit = SimpleEmitter {
-        qobject: Arc::new(Mutex::new(simple)),
+        qobject: Arc::new(AtomicPtr::new(simple)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: simple_run_on_gui_thread,
         message_changed: message_changed,
     };
     let d_simple = Simple::new(simple_emit);
@@ -87,6 +184,17 @@ pub unsafe extern "C" fn simple_free(ptr: *mut Simple) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn simple_run_task(ptr: *mut Simple, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Simple) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simple_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Simple) + Send>));
+}
+
 #[no_mangle]
 pub extern "C" fn simple_message_get(
     ptr: *const Simple,
@@ -106,3 +214,45 @@ pub extern "C" fn simple_message_set(ptr: *mut Simple, v: *const c_ushort, len:
     set_string_from_utf16(&mut s, v, len);
     o.set_message(s);
 }
+
+/// Owns a Simple for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct SimpleFixture {
+    pub ptr: *mut Simple,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl SimpleFixture {
+    pub fn new() -> SimpleFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = simple_new(
+            q as *mut SimpleQObject,
+            |_, task| unsafe { simple_drop_task(task) },
+            |q| TestQObject::emit(q, "messageChanged"),
+        );
+        SimpleFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Simple {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Simple {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { simple_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for SimpleFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
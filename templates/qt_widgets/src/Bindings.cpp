@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "Bindings.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
     void set_qstring(QString* val, const char* utf8, int nbytes) {
@@ -13,23 +40,43 @@ namespace {
     }
 }
 extern "C" {
-    Simple::Private* simple_new(Simple*, void (*)(Simple*));
+    Simple::Private* simple_new(Simple*, void (*)(Simple*, void*), void (*)(Simple*));
     void simple_free(Simple::Private*);
+    void simple_run_task(Simple::Private*, void*);
+    void simple_drop_task(void*);
     void simple_message_get(const Simple::Private*, QString*, qstring_set);
     void simple_message_set(Simple::Private*, const ushort *str, int len);
 };
 
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x12028c116fd2dc7b)) {
+            qFatal("Bindings.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Simple::Simple(bool /*owned*/, QObject *parent):
     QObject(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 Simple::Simple(QObject *parent):
     QObject(parent),
-    m_d(simple_new(this,
-        simpleMessageChanged)),
+    m_d((checkBindingHash(), simple_new(this,
+        Simple::postTask,
+        simpleMessageChanged))),
     m_ownsPrivate(true)
 {
 }
@@ -39,6 +86,20 @@ Simple::~Simple() {
         simple_free(m_d);
     }
 }
+
+bool Simple::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        simple_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Simple::postTask(Simple* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, simple_drop_task));
+}
 QString Simple::message() const
 {
     QString v;
@@ -12,6 +12,14 @@ class Simple : public QObject
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Simple for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Simple* create(QObject* parent) { return new Simple(false, parent); }
+        static Private*& d(Simple* o) { return o->m_d; }
+        static Private* d(const Simple* o) { return o->m_d; }
+        static void postTask(Simple* o, void* task) { Simple::postTask(o, task); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -22,6 +30,11 @@ public:
     ~Simple();
     QString message() const;
     void setMessage(const QString& v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Simple* o, void* task);
 signals:
     void messageChanged();
 };
@@ -0,0 +1,16 @@
+# Checks that the strings in ORDER, separated by '|', appear in FILE in that
+# order.
+#
+#   cmake -DFILE=<generated file> -DORDER=<string|string|...> -P check_source_order.cmake
+
+file(READ "${FILE}" REST)
+string(REPLACE "|" ";" ORDER "${ORDER}")
+foreach(NEEDLE ${ORDER})
+    string(FIND "${REST}" "${NEEDLE}" POS)
+    if (POS EQUAL -1)
+        message(FATAL_ERROR "'${NEEDLE}' is missing or out of order in ${FILE}")
+    endif()
+    string(LENGTH "${NEEDLE}" LEN)
+    math(EXPR POS "${POS} + ${LEN}")
+    string(SUBSTRING "${REST}" ${POS} -1 REST)
+endforeach()
@@ -0,0 +1,22 @@
+# Runs the generator on an invalid configuration and checks that it fails
+# with the errors in ERRORS, separated by '|', in that order.
+#
+#   cmake -DGENERATOR=<generator> -DFILE=<configuration> -DERRORS=<error|error|...> -P check_errors.cmake
+
+execute_process(COMMAND "${GENERATOR}" "${FILE}"
+    RESULT_VARIABLE RESULT
+    OUTPUT_QUIET
+    ERROR_VARIABLE REST)
+if (RESULT EQUAL 0)
+    message(FATAL_ERROR "${FILE} was accepted")
+endif()
+string(REPLACE "|" ";" ERRORS "${ERRORS}")
+foreach(NEEDLE ${ERRORS})
+    string(FIND "${REST}" "${NEEDLE}" POS)
+    if (POS EQUAL -1)
+        message(FATAL_ERROR "'${NEEDLE}' is missing or out of order in:\n${REST}")
+    endif()
+    string(LENGTH "${NEEDLE}" LEN)
+    math(EXPR POS "${POS} + ${LEN}")
+    string(SUBSTRING "${REST}" ${POS} -1 REST)
+endforeach()
@@ -0,0 +1,41 @@
+# Runs the generator with --merge-implementation on a copy of CONFIG whose
+# Rust implementation, rust/src/implementation.rs, is a copy of OLD. The
+# result should equal EXPECTED and the messages in MESSAGES, separated by '|',
+# should be printed in that order. With FAIL, the generator should fail.
+#
+#   cmake -DGENERATOR=<generator> -DWORK=<directory> -DCONFIG=<configuration>
+#       -DOLD=<file> -DEXPECTED=<file> -DMESSAGES=<message|...> [-DFAIL=ON]
+#       -P check_merge.cmake
+
+file(REMOVE_RECURSE "${WORK}")
+get_filename_component(NAME "${CONFIG}" NAME)
+configure_file("${CONFIG}" "${WORK}/${NAME}" COPYONLY)
+set(IMPLEMENTATION "${WORK}/rust/src/implementation.rs")
+configure_file("${OLD}" "${IMPLEMENTATION}" COPYONLY)
+
+execute_process(COMMAND "${GENERATOR}" --merge-implementation "${WORK}/${NAME}"
+    RESULT_VARIABLE RESULT
+    OUTPUT_QUIET
+    ERROR_VARIABLE REST)
+if (FAIL AND RESULT EQUAL 0)
+    message(FATAL_ERROR "${NAME} was merged:\n${REST}")
+elseif (NOT FAIL AND NOT RESULT EQUAL 0)
+    message(FATAL_ERROR "${NAME} was not merged:\n${REST}")
+endif()
+
+string(REPLACE "|" ";" MESSAGES "${MESSAGES}")
+foreach(NEEDLE ${MESSAGES})
+    string(FIND "${REST}" "${NEEDLE}" POS)
+    if (POS EQUAL -1)
+        message(FATAL_ERROR "'${NEEDLE}' is missing or out of order in:\n${REST}")
+    endif()
+    string(LENGTH "${NEEDLE}" LEN)
+    math(EXPR POS "${POS} + ${LEN}")
+    string(SUBSTRING "${REST}" ${POS} -1 REST)
+endforeach()
+
+file(READ "${IMPLEMENTATION}" MERGED)
+file(READ "${EXPECTED}" WANTED)
+if (NOT MERGED STREQUAL WANTED)
+    message(FATAL_ERROR "${IMPLEMENTATION} differs from ${EXPECTED}:\n${MERGED}")
+endif()
@@ -0,0 +1,56 @@
+use interface::*;
+
+pub struct Person {
+    emit: PersonEmitter,
+    name: String,
+}
+
+impl PersonTrait for Person {
+    fn new(emit: PersonEmitter) -> Person {
+        Person {
+            emit: emit,
+            name: String::new(),
+        }
+    }
+    fn emit(&self) -> &PersonEmitter {
+        &self.emit
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn set_name(&mut self, value: String) {
+        self.name = value;
+        self.emit.name_changed();
+    }
+    fn nickname(&self) -> &str {
+        ""
+    }
+    fn age(&self) -> u8 {
+        0
+    }
+    fn set_age(&mut self, value: u8) {
+    }
+}
+
+pub struct Items {
+    emit: ItemsEmitter,
+    labels: Vec<String>,
+}
+
+impl ItemsTrait for Items {
+    fn new(emit: ItemsEmitter) -> Items {
+        Items {
+            emit: emit,
+            labels: Vec::new(),
+        }
+    }
+    fn emit(&self) -> &ItemsEmitter {
+        &self.emit
+    }
+    fn row_count(&self) -> usize {
+        self.labels.len()
+    }
+    fn label(&self, item: usize) -> &str {
+        &self.labels[item]
+    }
+}
@@ -0,0 +1,34 @@
+use interface::*;
+
+pub struct Person {
+    emit: PersonEmitter,
+}
+
+impl PersonTrait for Person {
+    fn new(emit: PersonEmitter) -> Person {
+        Person { emit: emit }
+    }
+    fn emit(&self) -> &PersonEmitter {
+        &self.emit
+    }
+    fn name(&self) -> &str {
+        "Konqi"
+    }
+}
+
+pub struct Group {
+    emit: GroupEmitter,
+    leader: Person,
+}
+
+impl GroupTrait for Group {
+    fn new(emit: GroupEmitter, leader: Person) -> Group {
+        Group {
+            emit: emit,
+            leader: leader,
+        }
+    }
+    fn emit(&self) -> &GroupEmitter {
+        &self.emit
+    }
+}
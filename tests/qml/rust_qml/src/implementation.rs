@@ -0,0 +1,52 @@
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+use interface::*;
+
+pub struct Person {
+    emit: PersonEmitter,
+    user_name: String,
+}
+
+impl PersonTrait for Person {
+    fn new(emit: PersonEmitter) -> Person {
+        Person {
+            emit: emit,
+            user_name: String::new(),
+        }
+    }
+    fn emit(&self) -> &PersonEmitter {
+        &self.emit
+    }
+    fn user_name(&self) -> &str {
+        &self.user_name
+    }
+    fn set_user_name(&mut self, value: String) {
+        self.user_name = value;
+        self.emit.user_name_changed();
+    }
+}
+
+pub struct Config {
+    emit: ConfigEmitter,
+    theme: String,
+}
+
+impl ConfigTrait for Config {
+    fn new(emit: ConfigEmitter) -> Config {
+        Config {
+            emit: emit,
+            theme: String::new(),
+        }
+    }
+    fn emit(&self) -> &ConfigEmitter {
+        &self.emit
+    }
+    fn theme(&self) -> &str {
+        &self.theme
+    }
+    fn set_theme(&mut self, value: String) {
+        self.theme = value;
+        self.emit.theme_changed();
+    }
+}
@@ -0,0 +1,4 @@
+extern crate libc;
+
+pub mod interface;
+mod implementation;
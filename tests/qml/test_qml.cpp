@@ -0,0 +1,99 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include "test_qml_rust.h"
+#include <QTest>
+#include <QSignalSpy>
+#include <QQmlEngine>
+#include <QQmlComponent>
+
+class TestRustQml : public QObject
+{
+    Q_OBJECT
+private slots:
+    void initTestCase();
+    void testCreateFromQml();
+    void testSingleton();
+    void testSingletonFromQml();
+};
+
+void TestRustQml::initTestCase()
+{
+    registerQmlTypes();
+}
+
+void TestRustQml::testCreateFromQml()
+{
+    // GIVEN
+    QQmlEngine engine;
+    QQmlComponent component(&engine);
+
+    // WHEN
+    component.setData("import RustQml 1.0\n"
+        "Person { userName: \"Konqi\" }", QUrl());
+    QScopedPointer<QObject> object(component.create());
+
+    // THEN
+    QVERIFY2(object, qPrintable(component.errorString()));
+    Person* person = qobject_cast<Person*>(object.data());
+    QVERIFY(person);
+    QCOMPARE(person->userName(), QString("Konqi"));
+}
+
+void TestRustQml::testSingleton()
+{
+    // GIVEN
+    Config* config = Config::instance();
+    QSignalSpy spy(config, &Config::themeChanged);
+
+    // WHEN
+    config->setTheme("dark");
+
+    // THEN
+    QVERIFY(spy.isValid());
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(Config::instance(), config);
+    QCOMPARE(Config::instance()->theme(), QString("dark"));
+}
+
+void TestRustQml::testSingletonFromQml()
+{
+    // GIVEN
+    Config::instance()->setTheme("light");
+    QString theme;
+
+    // WHEN
+    {
+        QQmlEngine engine;
+        QQmlComponent component(&engine);
+        component.setData("import QtQml 2.2\nimport RustQml 1.0\n"
+            "QtObject { property string theme: Config.theme }", QUrl());
+        QScopedPointer<QObject> object(component.create());
+        QVERIFY2(object, qPrintable(component.errorString()));
+        theme = object->property("theme").toString();
+    }
+
+    // THEN the engine did not delete the instance
+    QCOMPARE(theme, QString("light"));
+    QCOMPARE(Config::instance()->theme(), QString("light"));
+}
+
+QTEST_MAIN(TestRustQml)
+#include "test_qml.moc"
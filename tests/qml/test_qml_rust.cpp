@@ -0,0 +1,188 @@
+/* generated by rust_qt_binding_generator */
+#include "test_qml_rust.h"
+#include <QtQml/qqml.h>
+#include <QQmlEngine>
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
+    void set_qstring(QString* val, const char* utf8, int nbytes) {
+        *val = QString::fromUtf8(utf8, nbytes);
+    }
+    inline void personUserNameChanged(Person* o)
+    {
+        emit o->userNameChanged();
+    }
+    inline void configThemeChanged(Config* o)
+    {
+        emit o->themeChanged();
+    }
+}
+extern "C" {
+    Person::Private* person_new(Person*, void (*)(Person*, void*), void (*)(Person*));
+    void person_free(Person::Private*);
+    void person_run_task(Person::Private*, void*);
+    void person_drop_task(void*);
+    void person_user_name_get(const Person::Private*, QString*, qstring_set);
+    void person_user_name_set(Person::Private*, const ushort *str, int len);
+};
+
+extern "C" {
+    Config::Private* config_new(Config*, void (*)(Config*, void*), void (*)(Config*));
+    void config_free(Config::Private*);
+    void config_run_task(Config::Private*, void*);
+    void config_drop_task(void*);
+    void config_theme_get(const Config::Private*, QString*, qstring_set);
+    void config_theme_set(Config::Private*, const ushort *str, int len);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xc2883d263ed99e1d)) {
+            qFatal("test_qml_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Person::Person(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Person::Person(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), person_new(this,
+        Person::postTask,
+        personUserNameChanged))),
+    m_ownsPrivate(true)
+{
+}
+
+Person::~Person() {
+    if (m_ownsPrivate) {
+        person_free(m_d);
+    }
+}
+
+bool Person::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        person_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Person::postTask(Person* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, person_drop_task));
+}
+QString Person::userName() const
+{
+    QString v;
+    person_user_name_get(m_d, &v, set_qstring);
+    return v;
+}
+void Person::setUserName(const QString& v) {
+    person_user_name_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+Config::Config(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Config::Config(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), config_new(this,
+        Config::postTask,
+        configThemeChanged))),
+    m_ownsPrivate(true)
+{
+}
+
+Config::~Config() {
+    if (m_ownsPrivate) {
+        config_free(m_d);
+    }
+}
+
+bool Config::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        config_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Config::postTask(Config* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, config_drop_task));
+}
+
+Config* Config::instance()
+{
+    static Config* const instance = new Config();
+    return instance;
+}
+QString Config::theme() const
+{
+    QString v;
+    config_theme_get(m_d, &v, set_qstring);
+    return v;
+}
+void Config::setTheme(const QString& v) {
+    config_theme_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+
+void registerQmlTypes()
+{
+    const char* uri = "RustQml";
+    qmlRegisterType<Person>(uri, 1, 0, "Person");
+    qmlRegisterSingletonType<Config>(uri, 1, 0, "Config",
+        [](QQmlEngine*, QJSEngine*) -> QObject* {
+            QQmlEngine::setObjectOwnership(Config::instance(), QQmlEngine::CppOwnership);
+            return Config::instance();
+        });
+}
@@ -0,0 +1,80 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_QML_RUST_H
+#define TEST_QML_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+
+class Person;
+class Config;
+
+class Person : public QObject
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Person for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Person* create(QObject* parent) { return new Person(false, parent); }
+        static Private*& d(Person* o) { return o->m_d; }
+        static Private* d(const Person* o) { return o->m_d; }
+        static void postTask(Person* o, void* task) { Person::postTask(o, task); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(QString userName READ userName WRITE setUserName NOTIFY userNameChanged FINAL)
+    explicit Person(bool owned, QObject *parent);
+public:
+    explicit Person(QObject *parent = nullptr);
+    ~Person();
+    QString userName() const;
+    void setUserName(const QString& v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Person* o, void* task);
+signals:
+    void userNameChanged();
+};
+
+class Config : public QObject
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Config for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Config* create(QObject* parent) { return new Config(false, parent); }
+        static Private*& d(Config* o) { return o->m_d; }
+        static Private* d(const Config* o) { return o->m_d; }
+        static void postTask(Config* o, void* task) { Config::postTask(o, task); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(QString theme READ theme WRITE setTheme NOTIFY themeChanged FINAL)
+    explicit Config(bool owned, QObject *parent);
+    explicit Config(QObject *parent = nullptr);
+    // the instance is never deleted
+    ~Config();
+public:
+    // the one instance of Config, it lives as long as the program
+    static Config* instance();
+    QString theme() const;
+    void setTheme(const QString& v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Config* o, void* task);
+signals:
+    void themeChanged();
+};
+
+// register all types with QML under the module RustQml 1.0
+void registerQmlTypes();
+#endif // TEST_QML_RUST_H
@@ -0,0 +1,99 @@
+use interface::*;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+struct Entry {
+    line: String,
+    // changed by change_levels while C++ reads the rows
+    level: AtomicU8,
+    note: Option<String>,
+    tag: Option<Vec<u8>>,
+    duration: Option<u64>,
+}
+
+fn entry(n: usize) -> Entry {
+    Entry {
+        line: format!("line {}", n),
+        level: AtomicU8::new((n % 5) as u8),
+        note: if n % 2 == 0 { Some(format!("note {}", n)) } else { None },
+        tag: match n % 3 {
+            0 => Some(b"tag".to_vec()),
+            1 => Some(Vec::new()),
+            _ => None,
+        },
+        duration: if n % 2 == 1 { Some(n as u64 * 1000) } else { None },
+    }
+}
+
+pub struct Log {
+    emit: LogEmitter,
+    model: LogList,
+    list: Vec<Entry>,
+    // how often a line was read, to see that the rows are fetched in blocks
+    reads: Cell<u32>,
+}
+
+impl LogTrait for Log {
+    fn new(emit: LogEmitter, model: LogList) -> Log {
+        Log {
+            emit: emit,
+            model: model,
+            list: (0..1000).map(entry).collect(),
+            reads: Cell::new(0),
+        }
+    }
+    fn emit(&self) -> &LogEmitter {
+        &self.emit
+    }
+    fn reads(&self) -> u32 {
+        self.reads.get()
+    }
+    // called from a thread of the test, each row is reported after it changed
+    fn change_levels(&self, level: u8) {
+        for (row, e) in self.list.iter().enumerate() {
+            e.level.store(level, Ordering::SeqCst);
+            self.model.data_changed(row, row);
+        }
+    }
+    fn row_count(&self) -> usize {
+        self.list.len()
+    }
+    fn insert_rows(&mut self, row: usize, count: usize) -> bool {
+        if count == 0 || row > self.list.len() {
+            return false;
+        }
+        self.model.begin_insert_rows(row, row + count - 1);
+        for i in 0..count {
+            let mut e = entry(row + i);
+            e.line = "new".into();
+            self.list.insert(row + i, e);
+        }
+        self.model.end_insert_rows();
+        true
+    }
+    // reverses the rows and reports them as changed
+    fn sort(&mut self, _: u8, _: SortOrder) {
+        self.list.reverse();
+        self.model.data_changed(0, self.list.len() - 1);
+    }
+    fn line(&self, item: usize) -> &str {
+        self.reads.set(self.reads.get() + 1);
+        &self.list[item].line
+    }
+    fn set_line(&mut self, item: usize, v: String) -> bool {
+        self.list[item].line = v;
+        true
+    }
+    fn level(&self, item: usize) -> u8 {
+        self.list[item].level.load(Ordering::SeqCst)
+    }
+    fn note(&self, item: usize) -> Option<&str> {
+        self.list[item].note.as_ref().map(|s| &s[..])
+    }
+    fn tag(&self, item: usize) -> Option<&[u8]> {
+        self.list[item].tag.as_ref().map(|t| &t[..])
+    }
+    fn duration(&self, item: usize) -> Option<u64> {
+        self.list[item].duration
+    }
+}
@@ -0,0 +1,528 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::{c_char, c_ushort, c_int, c_void};
+use std::slice;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use implementation::*;
+
+
+#[repr(C)]
+pub struct COption<T> {
+    data: T,
+    some: bool,
+}
+
+impl<T> From<Option<T>> for COption<T>
+where
+    T: Default,
+{
+    fn from(t: Option<T>) -> COption<T> {
+        if let Some(v) = t {
+            COption {
+                data: v,
+                some: true,
+            }
+        } else {
+            COption {
+                data: T::default(),
+                some: false,
+            }
+        }
+    }
+}
+
+
+pub enum QString {}
+
+fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    let characters = decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
+    s.clear();
+    s.extend(characters);
+}
+
+
+
+pub enum QByteArray {}
+
+
+#[repr(C)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(C)]
+pub struct QModelIndex {
+    row: c_int,
+    internal_id: usize,
+}
+
+
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
+    }
+    n as usize
+}
+
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
+}
+
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x6d1d769c5372a568
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct LogQObject {}
+
+#[derive(Clone)]
+pub struct LogEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<LogQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const LogQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const LogQObject),
+}
+
+impl LogEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const LogQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by log_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Log) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
+}
+
+pub struct LogList {
+    pub(crate) qobject: *const LogQObject,
+    pub(crate) data_changed: fn(*const LogQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const LogQObject),
+    pub(crate) end_reset_model: fn(*const LogQObject),
+    pub(crate) begin_insert_rows: fn(*const LogQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const LogQObject),
+    pub(crate) begin_remove_rows: fn(*const LogQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const LogQObject),
+}
+
+impl LogList {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait LogTrait {
+    fn new(emit: LogEmitter, model: LogList) -> Self;
+    fn emit(&self) -> &LogEmitter;
+    fn reads(&self) -> u32;
+    fn change_levels(&self, level: u8) -> ();
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    fn fetch_more(&mut self) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn line(&self, item: usize) -> &str;
+    fn set_line(&mut self, item: usize, String) -> bool;
+    fn level(&self, item: usize) -> u8;
+    fn note(&self, item: usize) -> Option<&str>;
+    fn tag(&self, item: usize) -> Option<&[u8]>;
+    fn duration(&self, item: usize) -> Option<u64>;
+}
+
+#[no_mangle]
+pub extern "C" fn log_new(
+    log: *mut LogQObject,
+    log_run_on_gui_thread: fn(*const LogQObject, *mut c_void),
+    log_new_data_ready: fn(*const LogQObject),
+    log_data_changed: fn(*const LogQObject, usize, usize),
+    log_begin_reset_model: fn(*const LogQObject),
+    log_end_reset_model: fn(*const LogQObject),
+    log_begin_insert_rows: fn(*const LogQObject, usize, usize),
+    log_end_insert_rows: fn(*const LogQObject),
+    log_begin_remove_rows: fn(*const LogQObject, usize, usize),
+    log_end_remove_rows: fn(*const LogQObject),
+) -> *mut Log {
+    let log_emit = LogEmitter {
+        qobject: Arc::new(AtomicPtr::new(log)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: log_run_on_gui_thread,
+        new_data_ready: log_new_data_ready,
+    };
+    let model = LogList {
+        qobject: log,
+        data_changed: log_data_changed,
+        begin_reset_model: log_begin_reset_model,
+        end_reset_model: log_end_reset_model,
+        begin_insert_rows: log_begin_insert_rows,
+        end_insert_rows: log_end_insert_rows,
+        begin_remove_rows: log_begin_remove_rows,
+        end_remove_rows: log_end_remove_rows,
+    };
+    let d_log = Log::new(log_emit, model);
+    Box::into_raw(Box::new(d_log))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn log_free(ptr: *mut Log) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn log_run_task(ptr: *mut Log, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Log) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn log_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Log) + Send>));
+}
+
+#[no_mangle]
+pub extern "C" fn log_reads(ptr: *const Log) -> u32 {
+    let o = unsafe { &*ptr };
+    let r = o.reads();
+    r
+}
+
+#[no_mangle]
+pub extern "C" fn log_change_levels(ptr: *const Log, level: u8) -> () {
+    let o = unsafe { &*ptr };
+    let r = o.change_levels(level);
+    r
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn log_row_count(ptr: *const Log) -> c_int {
+    to_c_int((&*ptr).row_count())
+}
+#[no_mangle]
+pub unsafe extern "C" fn log_insert_rows(ptr: *mut Log, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn log_remove_rows(ptr: *mut Log, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn log_can_fetch_more(ptr: *const Log) -> bool {
+    (&*ptr).can_fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn log_fetch_more(ptr: *mut Log) {
+    (&mut *ptr).fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn log_sort(
+    ptr: *mut Log,
+    column: u8,
+    order: SortOrder,
+) {
+    (&mut *ptr).sort(column, order)
+}
+
+#[no_mangle]
+pub extern "C" fn log_data_line(
+    ptr: *const Log, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.line(to_usize(row));
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn log_set_data_line(
+    ptr: *mut Log, row: c_int,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let mut v = String::new();
+    set_string_from_utf16(&mut v, s, len);
+    o.set_line(to_usize(row), v)
+}
+
+#[no_mangle]
+pub extern "C" fn log_data_level(ptr: *const Log, row: c_int) -> u8 {
+    let o = unsafe { &*ptr };
+    o.level(to_usize(row)).into()
+}
+
+#[no_mangle]
+pub extern "C" fn log_data_note(
+    ptr: *const Log, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.note(to_usize(row));
+    if let Some(data) = data {
+        let s: *const c_char = data.as_ptr() as (*const c_char);
+        set(d, s, to_c_int(data.len()));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn log_data_tag(
+    ptr: *const Log, row: c_int,
+    d: *mut QByteArray,
+    set: fn(*mut QByteArray, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.tag(to_usize(row));
+    if let Some(data) = data {
+        let s: *const c_char = data.as_ptr() as (*const c_char);
+        set(d, s, to_c_int(data.len()));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn log_data_duration(ptr: *const Log, row: c_int) -> COption<u64> {
+    let o = unsafe { &*ptr };
+    o.duration(to_usize(row)).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn log_data_rows(
+    ptr: *const Log,
+    first: c_int,
+    count: c_int,
+    line_out: *mut QString,
+    line_set: fn(*mut QString, *const c_char, *const c_int, c_int),
+    level_out: *mut u8,
+    note_out: *mut QString,
+    note_set: fn(*mut QString, *const c_char, *const c_int, c_int),
+    tag_out: *mut QByteArray,
+    tag_set: fn(*mut QByteArray, *const c_char, *const c_int, c_int),
+    duration_out: *mut COption<u64>,
+) {
+    let o = &*ptr;
+    let first = to_usize(first);
+    let count = to_usize(count);
+    let mut line_bytes: Vec<u8> = Vec::new();
+    let mut line_lens = Vec::with_capacity(count);
+    let mut note_bytes: Vec<u8> = Vec::new();
+    let mut note_lens = Vec::with_capacity(count);
+    let mut tag_bytes: Vec<u8> = Vec::new();
+    let mut tag_lens = Vec::with_capacity(count);
+    for i in 0..count {
+        let row = first + i;
+        let data = o.line(row);
+        line_bytes.extend_from_slice(data.as_ref());
+        line_lens.push(to_c_int(data.len()));
+        *level_out.add(i) = o.level(row).into();
+        if let Some(data) = o.note(row) {
+            note_bytes.extend_from_slice(data.as_ref());
+            note_lens.push(to_c_int(data.len()));
+        } else {
+            note_lens.push(-1);
+        }
+        if let Some(data) = o.tag(row) {
+            tag_bytes.extend_from_slice(data.as_ref());
+            tag_lens.push(to_c_int(data.len()));
+        } else {
+            tag_lens.push(-1);
+        }
+        *duration_out.add(i) = o.duration(row).into();
+    }
+    line_set(
+        line_out,
+        line_bytes.as_ptr() as *const c_char,
+        line_lens.as_ptr(),
+        to_c_int(count),
+    );
+    note_set(
+        note_out,
+        note_bytes.as_ptr() as *const c_char,
+        note_lens.as_ptr(),
+        to_c_int(count),
+    );
+    tag_set(
+        tag_out,
+        tag_bytes.as_ptr() as *const c_char,
+        tag_lens.as_ptr(),
+        to_c_int(count),
+    );
+}
+
+/// Owns a Log for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct LogFixture {
+    pub ptr: *mut Log,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl LogFixture {
+    pub fn new() -> LogFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = log_new(
+            q as *mut LogQObject,
+            |_, task| unsafe { log_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        LogFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Log {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Log {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { log_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for LogFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
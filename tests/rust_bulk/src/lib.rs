@@ -0,0 +1,7 @@
+extern crate libc;
+
+pub mod interface;
+mod implementation;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+// Reads blocks of rows with log_data_rows, the way the C++ model does. The
+// strings and bytes of a column arrive in one buffer with a length per row,
+// and a missing optional value has length -1.
+
+use interface::*;
+use libc::{c_char, c_int};
+use std::slice;
+
+// Stands in for the QString and QByteArray arrays of C++.
+#[derive(Default)]
+struct Column {
+    bytes: Vec<u8>,
+    lens: Vec<c_int>,
+}
+
+impl Column {
+    fn values(&self) -> Vec<Option<&[u8]>> {
+        let mut pos = 0;
+        let mut values = Vec::new();
+        for &len in &self.lens {
+            if len < 0 {
+                values.push(None);
+            } else {
+                values.push(Some(&self.bytes[pos..pos + len as usize]));
+                pos += len as usize;
+            }
+        }
+        values
+    }
+}
+
+fn set_column<T>(out: *mut T, data: *const c_char, lens: *const c_int, count: c_int) {
+    let column = unsafe { &mut *(out as *mut Column) };
+    column.lens = unsafe { slice::from_raw_parts(lens, count as usize) }.to_vec();
+    let total: c_int = column.lens.iter().filter(|&&l| l > 0).sum();
+    column.bytes = unsafe { slice::from_raw_parts(data as *const u8, total as usize) }.to_vec();
+}
+
+// The layout of COption<u64>.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct Duration {
+    data: u64,
+    some: bool,
+}
+
+struct Rows {
+    line: Column,
+    level: Vec<u8>,
+    note: Column,
+    tag: Column,
+    duration: Vec<Duration>,
+}
+
+fn data_rows(first: c_int, count: c_int) -> Rows {
+    let log = LogFixture::new();
+    let mut rows = Rows {
+        line: Column::default(),
+        level: vec![0; count as usize],
+        note: Column::default(),
+        tag: Column::default(),
+        duration: vec![Duration::default(); count as usize],
+    };
+    unsafe {
+        log_data_rows(
+            log.ptr,
+            first,
+            count,
+            &mut rows.line as *mut Column as *mut QString,
+            set_column,
+            rows.level.as_mut_ptr(),
+            &mut rows.note as *mut Column as *mut QString,
+            set_column,
+            &mut rows.tag as *mut Column as *mut QByteArray,
+            set_column,
+            rows.duration.as_mut_ptr() as *mut COption<u64>,
+        );
+    }
+    rows
+}
+
+#[test]
+fn strings_of_all_rows_in_one_buffer() {
+    let rows = data_rows(8, 3);
+    assert_eq!(rows.line.lens, vec![6, 6, 7]);
+    assert_eq!(&rows.line.bytes[..], &b"line 8line 9line 10"[..]);
+    assert_eq!(rows.level, vec![3, 4, 0]);
+}
+
+#[test]
+fn missing_optional_strings_have_length_minus_one() {
+    let rows = data_rows(0, 4);
+    assert_eq!(rows.note.lens, vec![6, -1, 6, -1]);
+    assert_eq!(
+        rows.note.values(),
+        vec![Some(&b"note 0"[..]), None, Some(&b"note 2"[..]), None]
+    );
+}
+
+#[test]
+fn empty_bytes_are_not_missing() {
+    let rows = data_rows(0, 4);
+    assert_eq!(rows.tag.lens, vec![3, 0, -1, 3]);
+    assert_eq!(
+        rows.tag.values(),
+        vec![Some(&b"tag"[..]), Some(&b""[..]), None, Some(&b"tag"[..])]
+    );
+}
+
+#[test]
+fn optional_numbers() {
+    let rows = data_rows(0, 3);
+    let durations: Vec<Option<u64>> = rows
+        .duration
+        .iter()
+        .map(|d| if d.some { Some(d.data) } else { None })
+        .collect();
+    assert_eq!(durations, vec![None, Some(1000), None]);
+}
@@ -0,0 +1,90 @@
+use interface::*;
+
+pub struct Cells {
+    emit: CellsEmitter,
+    model: CellsList,
+    list: Vec<u32>,
+}
+
+impl CellsTrait for Cells {
+    fn new(emit: CellsEmitter, model: CellsList) -> Cells {
+        Cells {
+            emit: emit,
+            model: model,
+            list: (0..10).collect(),
+        }
+    }
+    fn emit(&self) -> &CellsEmitter {
+        &self.emit
+    }
+    // several threads report rows at the same time, as if the model had been
+    // handed to workers
+    fn report_from_threads(&self) -> u32 {
+        struct Shared<'a>(&'a CellsList);
+        unsafe impl<'a> Sync for Shared<'a> {}
+        let model = Shared(&self.model);
+        let rows = self.list.len();
+        ::std::thread::scope(|s| {
+            for _ in 0..4 {
+                let model = &model;
+                s.spawn(move || {
+                    for _ in 0..100 {
+                        for row in 0..rows {
+                            model.0.data_changed(row, row);
+                        }
+                    }
+                });
+            }
+        });
+        (4 * 100 * rows) as u32
+    }
+    fn row_count(&self) -> usize {
+        self.list.len()
+    }
+    fn value(&self, item: usize) -> u32 {
+        self.list[item]
+    }
+    fn remove_rows(&mut self, row: usize, count: usize) -> bool {
+        if count == 0 || row + count > self.list.len() {
+            return false;
+        }
+        self.model.begin_remove_rows(row, row + count - 1);
+        self.list.drain(row..row + count);
+        self.model.end_remove_rows();
+        true
+    }
+    // every row is reported on its own, the C++ side merges them
+    fn sort(&mut self, _: u8, order: SortOrder) {
+        match order {
+            SortOrder::Ascending => self.list.sort(),
+            SortOrder::Descending => self.list.sort_by(|a, b| b.cmp(a)),
+        }
+        for row in 0..self.list.len() {
+            self.model.data_changed(row, row);
+        }
+    }
+}
+
+pub struct Meter {
+    emit: MeterEmitter,
+    level: u32,
+}
+
+impl MeterTrait for Meter {
+    fn new(emit: MeterEmitter) -> Meter {
+        Meter {
+            emit: emit,
+            level: 0,
+        }
+    }
+    fn emit(&self) -> &MeterEmitter {
+        &self.emit
+    }
+    fn level(&self) -> u32 {
+        self.level
+    }
+    fn set_level(&mut self, value: u32) {
+        self.level = value;
+        self.emit.level_changed();
+    }
+}
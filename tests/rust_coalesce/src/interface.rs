@@ -0,0 +1,517 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::{c_int, c_void};
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use implementation::*;
+
+
+#[repr(C)]
+pub struct COption<T> {
+    data: T,
+    some: bool,
+}
+
+impl<T> From<Option<T>> for COption<T>
+where
+    T: Default,
+{
+    fn from(t: Option<T>) -> COption<T> {
+        if let Some(v) = t {
+            COption {
+                data: v,
+                some: true,
+            }
+        } else {
+            COption {
+                data: T::default(),
+                some: false,
+            }
+        }
+    }
+}
+
+
+#[repr(C)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(C)]
+pub struct QModelIndex {
+    row: c_int,
+    internal_id: usize,
+}
+
+
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
+    }
+    n as usize
+}
+
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
+}
+
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xe3d66af312919603
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct CellsQObject {}
+
+#[derive(Clone)]
+pub struct CellsEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<CellsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const CellsQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const CellsQObject),
+}
+
+impl CellsEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const CellsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by cells_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Cells) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
+}
+
+pub struct CellsList {
+    pub(crate) qobject: *const CellsQObject,
+    pub(crate) data_changed: fn(*const CellsQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const CellsQObject),
+    pub(crate) end_reset_model: fn(*const CellsQObject),
+    pub(crate) begin_insert_rows: fn(*const CellsQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const CellsQObject),
+    pub(crate) begin_remove_rows: fn(*const CellsQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const CellsQObject),
+}
+
+impl CellsList {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait CellsTrait {
+    fn new(emit: CellsEmitter, model: CellsList) -> Self;
+    fn emit(&self) -> &CellsEmitter;
+    fn report_from_threads(&self) -> u32;
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    fn fetch_more(&mut self) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn value(&self, item: usize) -> u32;
+}
+
+#[no_mangle]
+pub extern "C" fn cells_new(
+    cells: *mut CellsQObject,
+    cells_run_on_gui_thread: fn(*const CellsQObject, *mut c_void),
+    cells_new_data_ready: fn(*const CellsQObject),
+    cells_data_changed: fn(*const CellsQObject, usize, usize),
+    cells_begin_reset_model: fn(*const CellsQObject),
+    cells_end_reset_model: fn(*const CellsQObject),
+    cells_begin_insert_rows: fn(*const CellsQObject, usize, usize),
+    cells_end_insert_rows: fn(*const CellsQObject),
+    cells_begin_remove_rows: fn(*const CellsQObject, usize, usize),
+    cells_end_remove_rows: fn(*const CellsQObject),
+) -> *mut Cells {
+    let cells_emit = CellsEmitter {
+        qobject: Arc::new(AtomicPtr::new(cells)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: cells_run_on_gui_thread,
+        new_data_ready: cells_new_data_ready,
+    };
+    let model = CellsList {
+        qobject: cells,
+        data_changed: cells_data_changed,
+        begin_reset_model: cells_begin_reset_model,
+        end_reset_model: cells_end_reset_model,
+        begin_insert_rows: cells_begin_insert_rows,
+        end_insert_rows: cells_end_insert_rows,
+        begin_remove_rows: cells_begin_remove_rows,
+        end_remove_rows: cells_end_remove_rows,
+    };
+    let d_cells = Cells::new(cells_emit, model);
+    Box::into_raw(Box::new(d_cells))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cells_free(ptr: *mut Cells) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cells_run_task(ptr: *mut Cells, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Cells) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cells_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Cells) + Send>));
+}
+
+#[no_mangle]
+pub extern "C" fn cells_report_from_threads(ptr: *const Cells) -> u32 {
+    let o = unsafe { &*ptr };
+    let r = o.report_from_threads();
+    r
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cells_row_count(ptr: *const Cells) -> c_int {
+    to_c_int((&*ptr).row_count())
+}
+#[no_mangle]
+pub unsafe extern "C" fn cells_insert_rows(ptr: *mut Cells, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn cells_remove_rows(ptr: *mut Cells, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn cells_can_fetch_more(ptr: *const Cells) -> bool {
+    (&*ptr).can_fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn cells_fetch_more(ptr: *mut Cells) {
+    (&mut *ptr).fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn cells_sort(
+    ptr: *mut Cells,
+    column: u8,
+    order: SortOrder,
+) {
+    (&mut *ptr).sort(column, order)
+}
+
+#[no_mangle]
+pub extern "C" fn cells_data_value(ptr: *const Cells, row: c_int) -> u32 {
+    let o = unsafe { &*ptr };
+    o.value(to_usize(row)).into()
+}
+
+/// Owns a Cells for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct CellsFixture {
+    pub ptr: *mut Cells,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl CellsFixture {
+    pub fn new() -> CellsFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = cells_new(
+            q as *mut CellsQObject,
+            |_, task| unsafe { cells_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        CellsFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Cells {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Cells {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { cells_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for CellsFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct MeterQObject {}
+
+#[derive(Clone)]
+pub struct MeterEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<MeterQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const MeterQObject, *mut c_void),
+    pub(crate) level_changed: fn(*const MeterQObject),
+}
+
+impl MeterEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const MeterQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by meter_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Meter) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn level_changed(&self) {
+        self.with_qobject(|ptr| (self.level_changed)(ptr));
+    }
+}
+
+pub trait MeterTrait {
+    fn new(emit: MeterEmitter) -> Self;
+    fn emit(&self) -> &MeterEmitter;
+    fn level(&self) -> u32;
+    fn set_level(&mut self, value: u32);
+}
+
+#[no_mangle]
+pub extern "C" fn meter_new(
+    meter: *mut MeterQObject,
+    meter_run_on_gui_thread: fn(*const MeterQObject, *mut c_void),
+    level_changed: fn(*const MeterQObject),
+) -> *mut Meter {
+    let meter_emit = MeterEmitter {
+        qobject: Arc::new(AtomicPtr::new(meter)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: meter_run_on_gui_thread,
+        level_changed: level_changed,
+    };
+    let d_meter = Meter::new(meter_emit);
+    Box::into_raw(Box::new(d_meter))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn meter_free(ptr: *mut Meter) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn meter_run_task(ptr: *mut Meter, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Meter) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn meter_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Meter) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn meter_level_get(ptr: *const Meter) -> u32 {
+    (&*ptr).level()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn meter_level_set(ptr: *mut Meter, v: u32) {
+    (&mut *ptr).set_level(v);
+}
+
+/// Owns a Meter for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct MeterFixture {
+    pub ptr: *mut Meter,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl MeterFixture {
+    pub fn new() -> MeterFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = meter_new(
+            q as *mut MeterQObject,
+            |_, task| unsafe { meter_drop_task(task) },
+            |q| TestQObject::emit(q, "levelChanged"),
+        );
+        MeterFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Meter {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Meter {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { meter_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for MeterFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -0,0 +1,36 @@
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+use interface::*;
+use people_implementation::*;
+
+pub struct Library {
+    emit: LibraryEmitter,
+    author: Person,
+    readers: Readers,
+}
+
+impl LibraryTrait for Library {
+    fn new(emit: LibraryEmitter, author: Person, readers: Readers) -> Library {
+        Library {
+            emit: emit,
+            author: author,
+            readers: readers,
+        }
+    }
+    fn emit(&self) -> &LibraryEmitter {
+        &self.emit
+    }
+    fn author(&self) -> &Person {
+        &self.author
+    }
+    fn author_mut(&mut self) -> &mut Person {
+        &mut self.author
+    }
+    fn readers(&self) -> &Readers {
+        &self.readers
+    }
+    fn readers_mut(&mut self) -> &mut Readers {
+        &mut self.readers
+    }
+}
@@ -0,0 +1,231 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::c_void;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use implementation::*;
+use people_interface::*;
+use people_implementation::*;
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x575e5cc1208b1175
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct LibraryQObject {}
+
+#[derive(Clone)]
+pub struct LibraryEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<LibraryQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const LibraryQObject, *mut c_void),
+}
+
+impl LibraryEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const LibraryQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by library_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Library) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+}
+
+pub trait LibraryTrait {
+    fn new(emit: LibraryEmitter,
+        author: Person,
+        readers: Readers) -> Self;
+    fn emit(&self) -> &LibraryEmitter;
+    fn author(&self) -> &Person;
+    fn author_mut(&mut self) -> &mut Person;
+    fn readers(&self) -> &Readers;
+    fn readers_mut(&mut self) -> &mut Readers;
+}
+
+#[no_mangle]
+pub extern "C" fn library_new(
+    library: *mut LibraryQObject,
+    library_run_on_gui_thread: fn(*const LibraryQObject, *mut c_void),
+    author: *mut PersonQObject,
+    author_run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
+    user_name_changed: fn(*const PersonQObject),
+    readers: *mut ReadersQObject,
+    readers_run_on_gui_thread: fn(*const ReadersQObject, *mut c_void),
+    readers_new_data_ready: fn(*const ReadersQObject),
+    readers_data_changed: fn(*const ReadersQObject, usize, usize),
+    readers_begin_reset_model: fn(*const ReadersQObject),
+    readers_end_reset_model: fn(*const ReadersQObject),
+    readers_begin_insert_rows: fn(*const ReadersQObject, usize, usize),
+    readers_end_insert_rows: fn(*const ReadersQObject),
+    readers_begin_remove_rows: fn(*const ReadersQObject, usize, usize),
+    readers_end_remove_rows: fn(*const ReadersQObject),
+) -> *mut Library {
+    let author_emit = PersonEmitter {
+        qobject: Arc::new(AtomicPtr::new(author)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: author_run_on_gui_thread,
+        user_name_changed: user_name_changed,
+    };
+    let d_author = Person::new(author_emit);
+    let readers_emit = ReadersEmitter {
+        qobject: Arc::new(AtomicPtr::new(readers)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: readers_run_on_gui_thread,
+        new_data_ready: readers_new_data_ready,
+    };
+    let model = ReadersList {
+        qobject: readers,
+        data_changed: readers_data_changed,
+        begin_reset_model: readers_begin_reset_model,
+        end_reset_model: readers_end_reset_model,
+        begin_insert_rows: readers_begin_insert_rows,
+        end_insert_rows: readers_end_insert_rows,
+        begin_remove_rows: readers_begin_remove_rows,
+        end_remove_rows: readers_end_remove_rows,
+    };
+    let d_readers = Readers::new(readers_emit, model);
+    let library_emit = LibraryEmitter {
+        qobject: Arc::new(AtomicPtr::new(library)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: library_run_on_gui_thread,
+    };
+    let d_library = Library::new(library_emit,
+        d_author,
+        d_readers);
+    Box::into_raw(Box::new(d_library))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn library_free(ptr: *mut Library) {
+    let o = Box::from_raw(ptr);
+    o.emit().clear();
+    o.author().emit().clear();
+    o.readers().emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn library_run_task(ptr: *mut Library, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Library) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn library_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Library) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn library_author_get(ptr: *mut Library) -> *mut Person {
+    (&mut *ptr).author_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn library_readers_get(ptr: *mut Library) -> *mut Readers {
+    (&mut *ptr).readers_mut()
+}
+
+/// Owns a Library for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct LibraryFixture {
+    pub ptr: *mut Library,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl LibraryFixture {
+    pub fn new() -> LibraryFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = library_new(
+            q as *mut LibraryQObject,
+            |_, task| unsafe { library_drop_task(task) },
+            q as *mut PersonQObject,
+            |_, task| unsafe { person_drop_task(task) },
+            |q| TestQObject::emit(q, "userNameChanged"),
+            q as *mut ReadersQObject,
+            |_, task| unsafe { readers_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        LibraryFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Library {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Library {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { library_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for LibraryFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -0,0 +1,6 @@
+extern crate libc;
+
+pub mod interface;
+pub mod people_interface;
+mod implementation;
+mod people_implementation;
@@ -0,0 +1,62 @@
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+use people_interface::*;
+
+pub struct Person {
+    emit: PersonEmitter,
+    user_name: String,
+}
+
+impl PersonTrait for Person {
+    fn new(emit: PersonEmitter) -> Person {
+        Person {
+            emit: emit,
+            user_name: String::new(),
+        }
+    }
+    fn emit(&self) -> &PersonEmitter {
+        &self.emit
+    }
+    fn user_name(&self) -> &str {
+        &self.user_name
+    }
+    fn set_user_name(&mut self, value: String) {
+        self.user_name = value;
+        self.emit.user_name_changed();
+    }
+}
+
+#[derive(Default, Clone)]
+struct ReadersItem {
+    user_name: String,
+}
+
+pub struct Readers {
+    emit: ReadersEmitter,
+    model: ReadersList,
+    list: Vec<ReadersItem>,
+}
+
+impl ReadersTrait for Readers {
+    fn new(emit: ReadersEmitter, model: ReadersList) -> Readers {
+        Readers {
+            emit: emit,
+            model: model,
+            list: vec![ReadersItem::default(); 3],
+        }
+    }
+    fn emit(&self) -> &ReadersEmitter {
+        &self.emit
+    }
+    fn row_count(&self) -> usize {
+        self.list.len()
+    }
+    fn user_name(&self, item: usize) -> &str {
+        &self.list[item].user_name
+    }
+    fn set_user_name(&mut self, item: usize, v: String) -> bool {
+        self.list[item].user_name = v;
+        true
+    }
+}
@@ -0,0 +1,551 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::{c_char, c_ushort, c_int, c_void};
+use std::slice;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use people_implementation::*;
+
+
+#[repr(C)]
+pub struct COption<T> {
+    data: T,
+    some: bool,
+}
+
+impl<T> From<Option<T>> for COption<T>
+where
+    T: Default,
+{
+    fn from(t: Option<T>) -> COption<T> {
+        if let Some(v) = t {
+            COption {
+                data: v,
+                some: true,
+            }
+        } else {
+            COption {
+                data: T::default(),
+                some: false,
+            }
+        }
+    }
+}
+
+
+pub enum QString {}
+
+fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    let characters = decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
+    s.clear();
+    s.extend(characters);
+}
+
+
+
+#[repr(C)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(C)]
+pub struct QModelIndex {
+    row: c_int,
+    internal_id: usize,
+}
+
+
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
+    }
+    n as usize
+}
+
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
+}
+
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn people_interface_binding_hash() -> u64 {
+    0x99bde2f1ef11a87a
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct PersonQObject {}
+
+#[derive(Clone)]
+pub struct PersonEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<PersonQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
+    pub(crate) user_name_changed: fn(*const PersonQObject),
+}
+
+impl PersonEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const PersonQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by person_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Person) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn user_name_changed(&self) {
+        self.with_qobject(|ptr| (self.user_name_changed)(ptr));
+    }
+}
+
+pub trait PersonTrait {
+    fn new(emit: PersonEmitter) -> Self;
+    fn emit(&self) -> &PersonEmitter;
+    fn user_name(&self) -> &str;
+    fn set_user_name(&mut self, value: String);
+}
+
+#[no_mangle]
+pub extern "C" fn person_new(
+    person: *mut PersonQObject,
+    person_run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
+    user_name_changed: fn(*const PersonQObject),
+) -> *mut Person {
+    let person_emit = PersonEmitter {
+        qobject: Arc::new(AtomicPtr::new(person)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: person_run_on_gui_thread,
+        user_name_changed: user_name_changed,
+    };
+    let d_person = Person::new(person_emit);
+    Box::into_raw(Box::new(d_person))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_free(ptr: *mut Person) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_run_task(ptr: *mut Person, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Person) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Person) + Send>));
+}
+
+#[no_mangle]
+pub extern "C" fn person_user_name_get(
+    ptr: *const Person,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.user_name();
+    let s: *const c_char = v.as_ptr() as (*const c_char);
+    set(p, s, to_c_int(v.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn person_user_name_set(ptr: *mut Person, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let mut s = String::new();
+    set_string_from_utf16(&mut s, v, len);
+    o.set_user_name(s);
+}
+
+/// Owns a Person for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct PersonFixture {
+    pub ptr: *mut Person,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl PersonFixture {
+    pub fn new() -> PersonFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = person_new(
+            q as *mut PersonQObject,
+            |_, task| unsafe { person_drop_task(task) },
+            |q| TestQObject::emit(q, "userNameChanged"),
+        );
+        PersonFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Person {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Person {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { person_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for PersonFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct ReadersQObject {}
+
+#[derive(Clone)]
+pub struct ReadersEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<ReadersQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const ReadersQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const ReadersQObject),
+}
+
+impl ReadersEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const ReadersQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by readers_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Readers) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
+}
+
+pub struct ReadersList {
+    pub(crate) qobject: *const ReadersQObject,
+    pub(crate) data_changed: fn(*const ReadersQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const ReadersQObject),
+    pub(crate) end_reset_model: fn(*const ReadersQObject),
+    pub(crate) begin_insert_rows: fn(*const ReadersQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const ReadersQObject),
+    pub(crate) begin_remove_rows: fn(*const ReadersQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const ReadersQObject),
+}
+
+impl ReadersList {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait ReadersTrait {
+    fn new(emit: ReadersEmitter, model: ReadersList) -> Self;
+    fn emit(&self) -> &ReadersEmitter;
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    fn fetch_more(&mut self) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn user_name(&self, item: usize) -> &str;
+    fn set_user_name(&mut self, item: usize, String) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn readers_new(
+    readers: *mut ReadersQObject,
+    readers_run_on_gui_thread: fn(*const ReadersQObject, *mut c_void),
+    readers_new_data_ready: fn(*const ReadersQObject),
+    readers_data_changed: fn(*const ReadersQObject, usize, usize),
+    readers_begin_reset_model: fn(*const ReadersQObject),
+    readers_end_reset_model: fn(*const ReadersQObject),
+    readers_begin_insert_rows: fn(*const ReadersQObject, usize, usize),
+    readers_end_insert_rows: fn(*const ReadersQObject),
+    readers_begin_remove_rows: fn(*const ReadersQObject, usize, usize),
+    readers_end_remove_rows: fn(*const ReadersQObject),
+) -> *mut Readers {
+    let readers_emit = ReadersEmitter {
+        qobject: Arc::new(AtomicPtr::new(readers)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: readers_run_on_gui_thread,
+        new_data_ready: readers_new_data_ready,
+    };
+    let model = ReadersList {
+        qobject: readers,
+        data_changed: readers_data_changed,
+        begin_reset_model: readers_begin_reset_model,
+        end_reset_model: readers_end_reset_model,
+        begin_insert_rows: readers_begin_insert_rows,
+        end_insert_rows: readers_end_insert_rows,
+        begin_remove_rows: readers_begin_remove_rows,
+        end_remove_rows: readers_end_remove_rows,
+    };
+    let d_readers = Readers::new(readers_emit, model);
+    Box::into_raw(Box::new(d_readers))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn readers_free(ptr: *mut Readers) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn readers_run_task(ptr: *mut Readers, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Readers) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn readers_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Readers) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn readers_row_count(ptr: *const Readers) -> c_int {
+    to_c_int((&*ptr).row_count())
+}
+#[no_mangle]
+pub unsafe extern "C" fn readers_insert_rows(ptr: *mut Readers, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn readers_remove_rows(ptr: *mut Readers, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn readers_can_fetch_more(ptr: *const Readers) -> bool {
+    (&*ptr).can_fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn readers_fetch_more(ptr: *mut Readers) {
+    (&mut *ptr).fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn readers_sort(
+    ptr: *mut Readers,
+    column: u8,
+    order: SortOrder,
+) {
+    (&mut *ptr).sort(column, order)
+}
+
+#[no_mangle]
+pub extern "C" fn readers_data_user_name(
+    ptr: *const Readers, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.user_name(to_usize(row));
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn readers_set_data_user_name(
+    ptr: *mut Readers, row: c_int,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let mut v = String::new();
+    set_string_from_utf16(&mut v, s, len);
+    o.set_user_name(to_usize(row), v)
+}
+
+/// Owns a Readers for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct ReadersFixture {
+    pub ptr: *mut Readers,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl ReadersFixture {
+    pub fn new() -> ReadersFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = readers_new(
+            q as *mut ReadersQObject,
+            |_, task| unsafe { readers_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        ReadersFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Readers {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Readers {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { readers_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for ReadersFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
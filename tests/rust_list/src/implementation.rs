@@ -2,6 +2,7 @@
 #![allow(unused_variables)]
 #![allow(dead_code)]
 use interface::*;
+use std::thread;
 
 #[derive(Default, Clone)]
 struct PersonsItem {
@@ -36,6 +37,20 @@ impl PersonsTrait for Persons {
         self.list[item].user_name = v;
         true
     }
+    // the rows are added later by a worker thread
+    fn insert_rows(&mut self, row: usize, count: usize) -> bool {
+        let emit = self.emit.clone();
+        thread::spawn(move || {
+            emit.run_on_gui_thread(Box::new(move |persons: &mut Persons| {
+                persons.model.begin_insert_rows(row, row + count - 1);
+                for _ in 0..count {
+                    persons.list.insert(row, PersonsItem::default());
+                }
+                persons.model.end_insert_rows();
+            }));
+        });
+        true
+    }
 }
 
 pub struct NoRole {
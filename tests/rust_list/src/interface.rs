@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -42,8 +44,7 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
@@ -63,56 +64,149 @@ pub struct QModelIndex {
 }
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
-pub struct NoRoleQObject {}
-
-#[derive(Clone)]
-pub struct NoRoleEmitter {
-    qobject: Arc<Mutex<*const NoRoleQObject>>,
-    new_data_ready: fn(*const NoRoleQObject),
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x5b4e9b65a8ba4139
 }
 
-unsafe impl Send for NoRoleEmitter {}
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
 
-impl NoRoleEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
     }
-    pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct PersonsQObject {}
+
+#[derive(Clone)]
+pub struct PersonsEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<PersonsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const PersonsQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const PersonsQObject),
+}
+
+impl PersonsEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const PersonsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by persons_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Persons) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
 }
 
-pub struct NoRoleList {
-    qobject: *const NoRoleQObject,
-    data_changed: fn(*const NoRoleQObject, usize, usize),
-    begin_reset_model: fn(*const NoRoleQObject),
-    end_reset_model: fn(*const NoRoleQObject),
-    begin_insert_rows: fn(*const NoRoleQObject, usize, usize),
-    end_insert_rows: fn(*const NoRoleQObject),
-    begin_remove_rows: fn(*const NoRoleQObject, usize, usize),
-    end_remove_rows: fn(*const NoRoleQObject),
+pub struct PersonsList {
+    pub(crate) qobject: *const PersonsQObject,
+    pub(crate) data_changed: fn(*const PersonsQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const PersonsQObject),
+    pub(crate) end_reset_model: fn(*const PersonsQObject),
+    pub(crate) begin_insert_rows: fn(*const PersonsQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const PersonsQObject),
+    pub(crate) begin_remove_rows: fn(*const PersonsQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const PersonsQObject),
 }
 
-impl NoRoleList {
+impl PersonsList {
     pub fn data_changed(&self, first: usize, last: usize) {
         (self.data_changed)(self.qobject, first, last);
     }
@@ -136,9 +230,9 @@ impl NoRoleList {
     }
 }
 
-pub trait NoRoleTrait {
-    fn new(emit: NoRoleEmitter, model: NoRoleList) -> Self;
-    fn emit(&self) -> &NoRoleEmitter;
+pub trait PersonsTrait {
+    fn new(emit: PersonsEmitter, model: PersonsList) -> Self;
+    fn emit(&self) -> &PersonsEmitter;
     fn row_count(&self) -> usize;
     fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
     fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
@@ -147,70 +241,88 @@ pub trait NoRoleTrait {
     }
     fn fetch_more(&mut self) {}
     fn sort(&mut self, u8, SortOrder) {}
-    fn user_age(&self, item: usize) -> u8;
-    fn set_user_age(&mut self, item: usize, u8) -> bool;
     fn user_name(&self, item: usize) -> &str;
     fn set_user_name(&mut self, item: usize, String) -> bool;
 }
 
 #[no_mangle]
-pub extern "C" fn no_role_new(
-    no_role: *mut NoRoleQObject,
-    no_role_new_data_ready: fn(*const NoRoleQObject),
-    no_role_data_changed: fn(*const NoRoleQObject, usize, usize),
-    no_role_begin_reset_model: fn(*const NoRoleQObject),
-    no_role_end_reset_model: fn(*const NoRoleQObject),
-    no_role_begin_insert_rows: fn(*const NoRoleQObject, usize, usize),
-    no_role_end_insert_rows: fn(*const NoRoleQObject),
-    no_role_begin_remove_rows: fn(*const NoRoleQObject, usize, usize),
-    no_role_end_remove_rows: fn(*const NoRoleQObject),
-) -> *mut NoRole {
-    let no_role_emit = NoRoleEmitter {
-        qobject: Arc::new(Mutex::new(no_role)),
-        new_data_ready: no_role_new_data_ready,
+pub extern "C" fn persons_new(
+    persons: *mut PersonsQObject,
+    persons_run_on_gui_thread: fn(*const PersonsQObject, *mut c_void),
+    persons_new_data_ready: fn(*const PersonsQObject),
+    persons_data_changed: fn(*const PersonsQObject, usize, usize),
+    persons_begin_reset_model: fn(*const PersonsQObject),
+    persons_end_reset_model: fn(*const PersonsQObject),
+    persons_begin_insert_rows: fn(*const PersonsQObject, usize, usize),
+    persons_end_insert_rows: fn(*const PersonsQObject),
+    persons_begin_remove_rows: fn(*const PersonsQObject, usize, usize),
+    persons_end_remove_rows: fn(*const PersonsQObject),
+) -> *mut Persons {
+    let persons_emit = PersonsEmitter {
+        qobject: Arc::new(AtomicPtr::new(persons)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: persons_run_on_gui_thread,
+        new_data_ready: persons_new_data_ready,
     };
-    let model = NoRoleList {
-        qobject: no_role,
-        data_changed: no_role_data_changed,
-        begin_reset_model: no_role_begin_reset_model,
-        end_reset_model: no_role_end_reset_model,
-        begin_insert_rows: no_role_begin_insert_rows,
-        end_insert_rows: no_role_end_insert_rows,
-        begin_remove_rows: no_role_begin_remove_rows,
-        end_remove_rows: no_role_end_remove_rows,
+    let model = PersonsList {
+        qobject: persons,
+        data_changed: persons_data_changed,
+        begin_reset_model: persons_begin_reset_model,
+        end_reset_model: persons_end_reset_model,
+        begin_insert_rows: persons_begin_insert_rows,
+        end_insert_rows: persons_end_insert_rows,
+        begin_remove_rows: persons_begin_remove_rows,
+        end_remove_rows: persons_end_remove_rows,
     };
-    let d_no_role = NoRole::new(no_role_emit, model);
-    Box::into_raw(Box::new(d_no_role))
+    let d_persons = Persons::new(persons_emit, model);
+    Box::into_raw(Box::new(d_persons))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn no_role_free(ptr: *mut NoRole) {
+pub unsafe extern "C" fn persons_free(ptr: *mut Persons) {
     Box::from_raw(ptr).emit().clear();
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn no_role_row_count(ptr: *const NoRole) -> c_int {
+pub unsafe extern "C" fn persons_run_task(ptr: *mut Persons, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Persons) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn persons_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Persons) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn persons_row_count(ptr: *const Persons) -> c_int {
     to_c_int((&*ptr).row_count())
 }
 #[no_mangle]
-pub unsafe extern "C" fn no_role_insert_rows(ptr: *mut NoRole, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+pub unsafe extern "C" fn persons_insert_rows(ptr: *mut Persons, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
-pub unsafe extern "C" fn no_role_remove_rows(ptr: *mut NoRole, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+pub unsafe extern "C" fn persons_remove_rows(ptr: *mut Persons, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
-pub unsafe extern "C" fn no_role_can_fetch_more(ptr: *const NoRole) -> bool {
+pub unsafe extern "C" fn persons_can_fetch_more(ptr: *const Persons) -> bool {
     (&*ptr).can_fetch_more()
 }
 #[no_mangle]
-pub unsafe extern "C" fn no_role_fetch_more(ptr: *mut NoRole) {
+pub unsafe extern "C" fn persons_fetch_more(ptr: *mut Persons) {
     (&mut *ptr).fetch_more()
 }
 #[no_mangle]
-pub unsafe extern "C" fn no_role_sort(
-    ptr: *mut NoRole,
+pub unsafe extern "C" fn persons_sort(
+    ptr: *mut Persons,
     column: u8,
     order: SortOrder,
 ) {
@@ -218,22 +330,8 @@ pub unsafe extern "C" fn no_role_sort(
 }
 
 #[no_mangle]
-pub extern "C" fn no_role_data_user_age(ptr: *const NoRole, row: c_int) -> u8 {
-    let o = unsafe { &*ptr };
-    o.user_age(to_usize(row)).into()
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn no_role_set_data_user_age(
-    ptr: *mut NoRole, row: c_int,
-    v: u8,
-) -> bool {
-    (&mut *ptr).set_user_age(to_usize(row), v)
-}
-
-#[no_mangle]
-pub extern "C" fn no_role_data_user_name(
-    ptr: *const NoRole, row: c_int,
+pub extern "C" fn persons_data_user_name(
+    ptr: *const Persons, row: c_int,
     d: *mut QString,
     set: fn(*mut QString, *const c_char, len: c_int),
 ) {
@@ -244,8 +342,8 @@ pub extern "C" fn no_role_data_user_name(
 }
 
 #[no_mangle]
-pub extern "C" fn no_role_set_data_user_name(
-    ptr: *mut NoRole, row: c_int,
+pub extern "C" fn persons_set_data_user_name(
+    ptr: *mut Persons, row: c_int,
     s: *const c_ushort, len: c_int,
 ) -> bool {
     let o = unsafe { &mut *ptr };
@@ -254,40 +352,115 @@ pub extern "C" fn no_role_set_data_user_name(
     o.set_user_name(to_usize(row), v)
 }
 
-pub struct PersonsQObject {}
+/// Owns a Persons for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct PersonsFixture {
+    pub ptr: *mut Persons,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl PersonsFixture {
+    pub fn new() -> PersonsFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = persons_new(
+            q as *mut PersonsQObject,
+            |_, task| unsafe { persons_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        PersonsFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Persons {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Persons {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { persons_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
 
-#[derive(Clone)]
-pub struct PersonsEmitter {
-    qobject: Arc<Mutex<*const PersonsQObject>>,
-    new_data_ready: fn(*const PersonsQObject),
+#[cfg(test)]
+impl Drop for PersonsFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
 
-unsafe impl Send for PersonsEmitter {}
+pub struct NoRoleQObject {}
 
-impl PersonsEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+#[derive(Clone)]
+pub struct NoRoleEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<NoRoleQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const NoRoleQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const NoRoleQObject),
+}
+
+impl NoRoleEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const NoRoleQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by no_role_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut NoRole) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
 }
 
-pub struct PersonsList {
-    qobject: *const PersonsQObject,
-    data_changed: fn(*const PersonsQObject, usize, usize),
-    begin_reset_model: fn(*const PersonsQObject),
-    end_reset_model: fn(*const PersonsQObject),
-    begin_insert_rows: fn(*const PersonsQObject, usize, usize),
-    end_insert_rows: fn(*const PersonsQObject),
-    begin_remove_rows: fn(*const PersonsQObject, usize, usize),
-    end_remove_rows: fn(*const PersonsQObject),
+pub struct NoRoleList {
+    pub(crate) qobject: *const NoRoleQObject,
+    pub(crate) data_changed: fn(*const NoRoleQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const NoRoleQObject),
+    pub(crate) end_reset_model: fn(*const NoRoleQObject),
+    pub(crate) begin_insert_rows: fn(*const NoRoleQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const NoRoleQObject),
+    pub(crate) begin_remove_rows: fn(*const NoRoleQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const NoRoleQObject),
 }
 
-impl PersonsList {
+impl NoRoleList {
     pub fn data_changed(&self, first: usize, last: usize) {
         (self.data_changed)(self.qobject, first, last);
     }
@@ -311,9 +484,9 @@ impl PersonsList {
     }
 }
 
-pub trait PersonsTrait {
-    fn new(emit: PersonsEmitter, model: PersonsList) -> Self;
-    fn emit(&self) -> &PersonsEmitter;
+pub trait NoRoleTrait {
+    fn new(emit: NoRoleEmitter, model: NoRoleList) -> Self;
+    fn emit(&self) -> &NoRoleEmitter;
     fn row_count(&self) -> usize;
     fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
     fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
@@ -324,66 +497,88 @@ pub trait PersonsTrait {
     fn sort(&mut self, u8, SortOrder) {}
     fn user_name(&self, item: usize) -> &str;
     fn set_user_name(&mut self, item: usize, String) -> bool;
+    fn user_age(&self, item: usize) -> u8;
+    fn set_user_age(&mut self, item: usize, u8) -> bool;
 }
 
 #[no_mangle]
-pub extern "C" fn persons_new(
-    persons: *mut PersonsQObject,
-    persons_new_data_ready: fn(*const PersonsQObject),
-    persons_data_changed: fn(*const PersonsQObject, usize, usize),
-    persons_begin_reset_model: fn(*const PersonsQObject),
-    persons_end_reset_model: fn(*const PersonsQObject),
-    persons_begin_insert_rows: fn(*const PersonsQObject, usize, usize),
-    persons_end_insert_rows: fn(*const PersonsQObject),
-    persons_begin_remove_rows: fn(*const PersonsQObject, usize, usize),
-    persons_end_remove_rows: fn(*const PersonsQObject),
-) -> *mut Persons {
-    let persons_emit = PersonsEmitter {
-        qobject: Arc::new(Mutex::new(persons)),
-        new_data_ready: persons_new_data_ready,
+pub extern "C" fn no_role_new(
+    no_role: *mut NoRoleQObject,
+    no_role_run_on_gui_thread: fn(*const NoRoleQObject, *mut c_void),
+    no_role_new_data_ready: fn(*const NoRoleQObject),
+    no_role_data_changed: fn(*const NoRoleQObject, usize, usize),
+    no_role_begin_reset_model: fn(*const NoRoleQObject),
+    no_role_end_reset_model: fn(*const NoRoleQObject),
+    no_role_begin_insert_rows: fn(*const NoRoleQObject, usize, usize),
+    no_role_end_insert_rows: fn(*const NoRoleQObject),
+    no_role_begin_remove_rows: fn(*const NoRoleQObject, usize, usize),
+    no_role_end_remove_rows: fn(*const NoRoleQObject),
+) -> *mut NoRole {
+    let no_role_emit = NoRoleEmitter {
+        qobject: Arc::new(AtomicPtr::new(no_role)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: no_role_run_on_gui_thread,
+        new_data_ready: no_role_new_data_ready,
     };
-    let model = PersonsList {
-        qobject: persons,
-        data_changed: persons_data_changed,
-        begin_reset_model: persons_begin_reset_model,
-        end_reset_model: persons_end_reset_model,
-        begin_insert_rows: persons_begin_insert_rows,
-        end_insert_rows: persons_end_insert_rows,
-        begin_remove_rows: persons_begin_remove_rows,
-        end_remove_rows: persons_end_remove_rows,
+    let model = NoRoleList {
+        qobject: no_role,
+        data_changed: no_role_data_changed,
+        begin_reset_model: no_role_begin_reset_model,
+        end_reset_model: no_role_end_reset_model,
+        begin_insert_rows: no_role_begin_insert_rows,
+        end_insert_rows: no_role_end_insert_rows,
+        begin_remove_rows: no_role_begin_remove_rows,
+        end_remove_rows: no_role_end_remove_rows,
     };
-    let d_persons = Persons::new(persons_emit, model);
-    Box::into_raw(Box::new(d_persons))
+    let d_no_role = NoRole::new(no_role_emit, model);
+    Box::into_raw(Box::new(d_no_role))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn persons_free(ptr: *mut Persons) {
+pub unsafe extern "C" fn no_role_free(ptr: *mut NoRole) {
     Box::from_raw(ptr).emit().clear();
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn persons_row_count(ptr: *const Persons) -> c_int {
+pub unsafe extern "C" fn no_role_run_task(ptr: *mut NoRole, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut NoRole) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn no_role_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut NoRole) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn no_role_row_count(ptr: *const NoRole) -> c_int {
     to_c_int((&*ptr).row_count())
 }
 #[no_mangle]
-pub unsafe extern "C" fn persons_insert_rows(ptr: *mut Persons, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+pub unsafe extern "C" fn no_role_insert_rows(ptr: *mut NoRole, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
-pub unsafe extern "C" fn persons_remove_rows(ptr: *mut Persons, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+pub unsafe extern "C" fn no_role_remove_rows(ptr: *mut NoRole, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
-pub unsafe extern "C" fn persons_can_fetch_more(ptr: *const Persons) -> bool {
+pub unsafe extern "C" fn no_role_can_fetch_more(ptr: *const NoRole) -> bool {
     (&*ptr).can_fetch_more()
 }
 #[no_mangle]
-pub unsafe extern "C" fn persons_fetch_more(ptr: *mut Persons) {
+pub unsafe extern "C" fn no_role_fetch_more(ptr: *mut NoRole) {
     (&mut *ptr).fetch_more()
 }
 #[no_mangle]
-pub unsafe extern "C" fn persons_sort(
-    ptr: *mut Persons,
+pub unsafe extern "C" fn no_role_sort(
+    ptr: *mut NoRole,
     column: u8,
     order: SortOrder,
 ) {
@@ -391,8 +586,8 @@ pub unsafe extern "C" fn persons_sort(
 }
 
 #[no_mangle]
-pub extern "C" fn persons_data_user_name(
-    ptr: *const Persons, row: c_int,
+pub extern "C" fn no_role_data_user_name(
+    ptr: *const NoRole, row: c_int,
     d: *mut QString,
     set: fn(*mut QString, *const c_char, len: c_int),
 ) {
@@ -403,8 +598,8 @@ pub extern "C" fn persons_data_user_name(
 }
 
 #[no_mangle]
-pub extern "C" fn persons_set_data_user_name(
-    ptr: *mut Persons, row: c_int,
+pub extern "C" fn no_role_set_data_user_name(
+    ptr: *mut NoRole, row: c_int,
     s: *const c_ushort, len: c_int,
 ) -> bool {
     let o = unsafe { &mut *ptr };
@@ -412,3 +607,66 @@ pub extern "C" fn persons_set_data_user_name(
     set_string_from_utf16(&mut v, s, len);
     o.set_user_name(to_usize(row), v)
 }
+
+#[no_mangle]
+pub extern "C" fn no_role_data_user_age(ptr: *const NoRole, row: c_int) -> u8 {
+    let o = unsafe { &*ptr };
+    o.user_age(to_usize(row)).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn no_role_set_data_user_age(
+    ptr: *mut NoRole, row: c_int,
+    v: u8,
+) -> bool {
+    (&mut *ptr).set_user_age(to_usize(row), v)
+}
+
+/// Owns a NoRole for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct NoRoleFixture {
+    pub ptr: *mut NoRole,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl NoRoleFixture {
+    pub fn new() -> NoRoleFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = no_role_new(
+            q as *mut NoRoleQObject,
+            |_, task| unsafe { no_role_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        NoRoleFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &NoRole {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut NoRole {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { no_role_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for NoRoleFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -42,8 +44,7 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
@@ -66,53 +67,146 @@ pub struct QModelIndex {
 }
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xe00d27b7b2b00a91
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct ListQObject {}
 
 #[derive(Clone)]
 pub struct ListEmitter {
-    qobject: Arc<Mutex<*const ListQObject>>,
-    new_data_ready: fn(*const ListQObject),
+    pub(crate) qobject: Arc<AtomicPtr<ListQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const ListQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const ListQObject),
 }
 
-unsafe impl Send for ListEmitter {}
-
 impl ListEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn new_data_ready(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const ListQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.new_data_ready)(ptr);
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by list_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut List) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
 }
 
 pub struct ListList {
-    qobject: *const ListQObject,
-    data_changed: fn(*const ListQObject, usize, usize),
-    begin_reset_model: fn(*const ListQObject),
-    end_reset_model: fn(*const ListQObject),
-    begin_insert_rows: fn(*const ListQObject, usize, usize),
-    end_insert_rows: fn(*const ListQObject),
-    begin_remove_rows: fn(*const ListQObject, usize, usize),
-    end_remove_rows: fn(*const ListQObject),
+    pub(crate) qobject: *const ListQObject,
+    pub(crate) data_changed: fn(*const ListQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const ListQObject),
+    pub(crate) end_reset_model: fn(*const ListQObject),
+    pub(crate) begin_insert_rows: fn(*const ListQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const ListQObject),
+    pub(crate) begin_remove_rows: fn(*const ListQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const ListQObject),
 }
 
 impl ListList {
@@ -152,41 +246,42 @@ pub trait ListTrait {
     fn sort(&mut self, u8, SortOrder) {}
     fn boolean(&self, item: usize) -> bool;
     fn set_boolean(&mut self, item: usize, bool) -> bool;
-    fn bytearray(&self, item: usize) -> &[u8];
-    fn set_bytearray(&mut self, item: usize, &[u8]) -> bool;
-    fn f32(&self, item: usize) -> f32;
-    fn set_f32(&mut self, item: usize, f32) -> bool;
-    fn f64(&self, item: usize) -> f64;
-    fn set_f64(&mut self, item: usize, f64) -> bool;
-    fn i16(&self, item: usize) -> i16;
-    fn set_i16(&mut self, item: usize, i16) -> bool;
-    fn i32(&self, item: usize) -> i32;
-    fn set_i32(&mut self, item: usize, i32) -> bool;
-    fn i64(&self, item: usize) -> i64;
-    fn set_i64(&mut self, item: usize, i64) -> bool;
-    fn i8(&self, item: usize) -> i8;
-    fn set_i8(&mut self, item: usize, i8) -> bool;
     fn optional_boolean(&self, item: usize) -> Option<bool>;
     fn set_optional_boolean(&mut self, item: usize, Option<bool>) -> bool;
-    fn optional_bytearray(&self, item: usize) -> Option<&[u8]>;
-    fn set_optional_bytearray(&mut self, item: usize, Option<&[u8]>) -> bool;
-    fn optional_string(&self, item: usize) -> Option<&str>;
-    fn set_optional_string(&mut self, item: usize, Option<String>) -> bool;
-    fn string(&self, item: usize) -> &str;
-    fn set_string(&mut self, item: usize, String) -> bool;
+    fn i8(&self, item: usize) -> i8;
+    fn set_i8(&mut self, item: usize, i8) -> bool;
+    fn u8(&self, item: usize) -> u8;
+    fn set_u8(&mut self, item: usize, u8) -> bool;
+    fn i16(&self, item: usize) -> i16;
+    fn set_i16(&mut self, item: usize, i16) -> bool;
     fn u16(&self, item: usize) -> u16;
     fn set_u16(&mut self, item: usize, u16) -> bool;
+    fn i32(&self, item: usize) -> i32;
+    fn set_i32(&mut self, item: usize, i32) -> bool;
     fn u32(&self, item: usize) -> u32;
     fn set_u32(&mut self, item: usize, u32) -> bool;
+    fn i64(&self, item: usize) -> i64;
+    fn set_i64(&mut self, item: usize, i64) -> bool;
     fn u64(&self, item: usize) -> u64;
     fn set_u64(&mut self, item: usize, u64) -> bool;
-    fn u8(&self, item: usize) -> u8;
-    fn set_u8(&mut self, item: usize, u8) -> bool;
+    fn f32(&self, item: usize) -> f32;
+    fn set_f32(&mut self, item: usize, f32) -> bool;
+    fn f64(&self, item: usize) -> f64;
+    fn set_f64(&mut self, item: usize, f64) -> bool;
+    fn string(&self, item: usize) -> &str;
+    fn set_string(&mut self, item: usize, String) -> bool;
+    fn optional_string(&self, item: usize) -> Option<&str>;
+    fn set_optional_string(&mut self, item: usize, Option<String>) -> bool;
+    fn bytearray(&self, item: usize) -> &[u8];
+    fn set_bytearray(&mut self, item: usize, &[u8]) -> bool;
+    fn optional_bytearray(&self, item: usize) -> Option<&[u8]>;
+    fn set_optional_bytearray(&mut self, item: usize, Option<&[u8]>) -> bool;
 }
 
 #[no_mangle]
 pub extern "C" fn list_new(
     list: *mut ListQObject,
+    list_run_on_gui_thread: fn(*const ListQObject, *mut c_void),
     list_new_data_ready: fn(*const ListQObject),
     list_data_changed: fn(*const ListQObject, usize, usize),
     list_begin_reset_model: fn(*const ListQObject),
@@ -197,7 +292,9 @@ pub extern "C" fn list_new(
     list_end_remove_rows: fn(*const ListQObject),
 ) -> *mut List {
     let list_emit = ListEmitter {
-        qobject: Arc::new(Mutex::new(list)),
+        qobject: Arc::new(AtomicPtr::new(list)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: list_run_on_gui_thread,
         new_data_ready: list_new_data_ready,
     };
     let model = ListList {
@@ -219,17 +316,34 @@ pub unsafe extern "C" fn list_free(ptr: *mut List) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn list_run_task(ptr: *mut List, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut List) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn list_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut List) + Send>));
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn list_row_count(ptr: *const List) -> c_int {
     to_c_int((&*ptr).row_count())
 }
 #[no_mangle]
 pub unsafe extern "C" fn list_insert_rows(ptr: *mut List, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).insert_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn list_remove_rows(ptr: *mut List, row: c_int, count: c_int) -> bool {
-    (&mut *ptr).remove_rows(to_usize(row), to_usize(count))
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
 }
 #[no_mangle]
 pub unsafe extern "C" fn list_can_fetch_more(ptr: *const List) -> bool {
@@ -263,53 +377,50 @@ pub unsafe extern "C" fn list_set_data_boolean(
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_bytearray(
-    ptr: *const List, row: c_int,
-    d: *mut QByteArray,
-    set: fn(*mut QByteArray, *const c_char, len: c_int),
-) {
+pub extern "C" fn list_data_optional_boolean(ptr: *const List, row: c_int) -> COption<bool> {
     let o = unsafe { &*ptr };
-    let data = o.bytearray(to_usize(row));
-    let s: *const c_char = data.as_ptr() as (*const c_char);
-    set(d, s, to_c_int(data.len()));
+    o.optional_boolean(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub extern "C" fn list_set_data_bytearray(
+pub unsafe extern "C" fn list_set_data_optional_boolean(
     ptr: *mut List, row: c_int,
-    s: *const c_char, len: c_int,
+    v: bool,
 ) -> bool {
-    let o = unsafe { &mut *ptr };
-    let slice = unsafe { ::std::slice::from_raw_parts(s as *const u8, to_usize(len)) };
-    o.set_bytearray(to_usize(row), slice)
+    (&mut *ptr).set_optional_boolean(to_usize(row), Some(v))
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_f32(ptr: *const List, row: c_int) -> f32 {
+pub unsafe extern "C" fn list_set_data_optional_boolean_none(ptr: *mut List, row: c_int) -> bool {
+    (&mut *ptr).set_optional_boolean(to_usize(row), None)
+}
+
+#[no_mangle]
+pub extern "C" fn list_data_i8(ptr: *const List, row: c_int) -> i8 {
     let o = unsafe { &*ptr };
-    o.f32(to_usize(row)).into()
+    o.i8(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_f32(
+pub unsafe extern "C" fn list_set_data_i8(
     ptr: *mut List, row: c_int,
-    v: f32,
+    v: i8,
 ) -> bool {
-    (&mut *ptr).set_f32(to_usize(row), v)
+    (&mut *ptr).set_i8(to_usize(row), v)
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_f64(ptr: *const List, row: c_int) -> f64 {
+pub extern "C" fn list_data_u8(ptr: *const List, row: c_int) -> u8 {
     let o = unsafe { &*ptr };
-    o.f64(to_usize(row)).into()
+    o.u8(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_f64(
+pub unsafe extern "C" fn list_set_data_u8(
     ptr: *mut List, row: c_int,
-    v: f64,
+    v: u8,
 ) -> bool {
-    (&mut *ptr).set_f64(to_usize(row), v)
+    (&mut *ptr).set_u8(to_usize(row), v)
 }
 
 #[no_mangle]
@@ -326,6 +437,20 @@ pub unsafe extern "C" fn list_set_data_i16(
     (&mut *ptr).set_i16(to_usize(row), v)
 }
 
+#[no_mangle]
+pub extern "C" fn list_data_u16(ptr: *const List, row: c_int) -> u16 {
+    let o = unsafe { &*ptr };
+    o.u16(to_usize(row)).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn list_set_data_u16(
+    ptr: *mut List, row: c_int,
+    v: u16,
+) -> bool {
+    (&mut *ptr).set_u16(to_usize(row), v)
+}
+
 #[no_mangle]
 pub extern "C" fn list_data_i32(ptr: *const List, row: c_int) -> i32 {
     let o = unsafe { &*ptr };
@@ -340,6 +465,20 @@ pub unsafe extern "C" fn list_set_data_i32(
     (&mut *ptr).set_i32(to_usize(row), v)
 }
 
+#[no_mangle]
+pub extern "C" fn list_data_u32(ptr: *const List, row: c_int) -> u32 {
+    let o = unsafe { &*ptr };
+    o.u32(to_usize(row)).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn list_set_data_u32(
+    ptr: *mut List, row: c_int,
+    v: u32,
+) -> bool {
+    (&mut *ptr).set_u32(to_usize(row), v)
+}
+
 #[no_mangle]
 pub extern "C" fn list_data_i64(ptr: *const List, row: c_int) -> i64 {
     let o = unsafe { &*ptr };
@@ -355,65 +494,68 @@ pub unsafe extern "C" fn list_set_data_i64(
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_i8(ptr: *const List, row: c_int) -> i8 {
+pub extern "C" fn list_data_u64(ptr: *const List, row: c_int) -> u64 {
     let o = unsafe { &*ptr };
-    o.i8(to_usize(row)).into()
+    o.u64(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_i8(
+pub unsafe extern "C" fn list_set_data_u64(
     ptr: *mut List, row: c_int,
-    v: i8,
+    v: u64,
 ) -> bool {
-    (&mut *ptr).set_i8(to_usize(row), v)
+    (&mut *ptr).set_u64(to_usize(row), v)
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_optional_boolean(ptr: *const List, row: c_int) -> COption<bool> {
+pub extern "C" fn list_data_f32(ptr: *const List, row: c_int) -> f32 {
     let o = unsafe { &*ptr };
-    o.optional_boolean(to_usize(row)).into()
+    o.f32(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_optional_boolean(
+pub unsafe extern "C" fn list_set_data_f32(
     ptr: *mut List, row: c_int,
-    v: bool,
+    v: f32,
 ) -> bool {
-    (&mut *ptr).set_optional_boolean(to_usize(row), Some(v))
+    (&mut *ptr).set_f32(to_usize(row), v)
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_optional_boolean_none(ptr: *mut List, row: c_int) -> bool {
-    (&mut *ptr).set_optional_boolean(to_usize(row), None)
+pub extern "C" fn list_data_f64(ptr: *const List, row: c_int) -> f64 {
+    let o = unsafe { &*ptr };
+    o.f64(to_usize(row)).into()
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_optional_bytearray(
+pub unsafe extern "C" fn list_set_data_f64(
+    ptr: *mut List, row: c_int,
+    v: f64,
+) -> bool {
+    (&mut *ptr).set_f64(to_usize(row), v)
+}
+
+#[no_mangle]
+pub extern "C" fn list_data_string(
     ptr: *const List, row: c_int,
-    d: *mut QByteArray,
-    set: fn(*mut QByteArray, *const c_char, len: c_int),
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
 ) {
     let o = unsafe { &*ptr };
-    let data = o.optional_bytearray(to_usize(row));
-    if let Some(data) = data {
-        let s: *const c_char = data.as_ptr() as (*const c_char);
-        set(d, s, to_c_int(data.len()));
-    }
+    let data = o.string(to_usize(row));
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
 }
 
 #[no_mangle]
-pub extern "C" fn list_set_data_optional_bytearray(
+pub extern "C" fn list_set_data_string(
     ptr: *mut List, row: c_int,
-    s: *const c_char, len: c_int,
+    s: *const c_ushort, len: c_int,
 ) -> bool {
     let o = unsafe { &mut *ptr };
-    let slice = unsafe { ::std::slice::from_raw_parts(s as *const u8, to_usize(len)) };
-    o.set_optional_bytearray(to_usize(row), Some(slice))
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn list_set_data_optional_bytearray_none(ptr: *mut List, row: c_int) -> bool {
-    (&mut *ptr).set_optional_bytearray(to_usize(row), None)
+    let mut v = String::new();
+    set_string_from_utf16(&mut v, s, len);
+    o.set_string(to_usize(row), v)
 }
 
 #[no_mangle]
@@ -447,80 +589,101 @@ pub unsafe extern "C" fn list_set_data_optional_string_none(ptr: *mut List, row:
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_string(
+pub extern "C" fn list_data_bytearray(
     ptr: *const List, row: c_int,
-    d: *mut QString,
-    set: fn(*mut QString, *const c_char, len: c_int),
+    d: *mut QByteArray,
+    set: fn(*mut QByteArray, *const c_char, len: c_int),
 ) {
     let o = unsafe { &*ptr };
-    let data = o.string(to_usize(row));
+    let data = o.bytearray(to_usize(row));
     let s: *const c_char = data.as_ptr() as (*const c_char);
     set(d, s, to_c_int(data.len()));
 }
 
 #[no_mangle]
-pub extern "C" fn list_set_data_string(
+pub extern "C" fn list_set_data_bytearray(
     ptr: *mut List, row: c_int,
-    s: *const c_ushort, len: c_int,
+    s: *const c_char, len: c_int,
 ) -> bool {
     let o = unsafe { &mut *ptr };
-    let mut v = String::new();
-    set_string_from_utf16(&mut v, s, len);
-    o.set_string(to_usize(row), v)
-}
-
-#[no_mangle]
-pub extern "C" fn list_data_u16(ptr: *const List, row: c_int) -> u16 {
-    let o = unsafe { &*ptr };
-    o.u16(to_usize(row)).into()
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn list_set_data_u16(
-    ptr: *mut List, row: c_int,
-    v: u16,
-) -> bool {
-    (&mut *ptr).set_u16(to_usize(row), v)
+    let slice = unsafe { ::std::slice::from_raw_parts(s as *const u8, to_usize(len)) };
+    o.set_bytearray(to_usize(row), slice)
 }
 
 #[no_mangle]
-pub extern "C" fn list_data_u32(ptr: *const List, row: c_int) -> u32 {
+pub extern "C" fn list_data_optional_bytearray(
+    ptr: *const List, row: c_int,
+    d: *mut QByteArray,
+    set: fn(*mut QByteArray, *const c_char, len: c_int),
+) {
     let o = unsafe { &*ptr };
-    o.u32(to_usize(row)).into()
+    let data = o.optional_bytearray(to_usize(row));
+    if let Some(data) = data {
+        let s: *const c_char = data.as_ptr() as (*const c_char);
+        set(d, s, to_c_int(data.len()));
+    }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_u32(
+pub extern "C" fn list_set_data_optional_bytearray(
     ptr: *mut List, row: c_int,
-    v: u32,
+    s: *const c_char, len: c_int,
 ) -> bool {
-    (&mut *ptr).set_u32(to_usize(row), v)
-}
-
-#[no_mangle]
-pub extern "C" fn list_data_u64(ptr: *const List, row: c_int) -> u64 {
-    let o = unsafe { &*ptr };
-    o.u64(to_usize(row)).into()
+    let o = unsafe { &mut *ptr };
+    let slice = unsafe { ::std::slice::from_raw_parts(s as *const u8, to_usize(len)) };
+    o.set_optional_bytearray(to_usize(row), Some(slice))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn list_set_data_u64(
-    ptr: *mut List, row: c_int,
-    v: u64,
-) -> bool {
-    (&mut *ptr).set_u64(to_usize(row), v)
+pub unsafe extern "C" fn list_set_data_optional_bytearray_none(ptr: *mut List, row: c_int) -> bool {
+    (&mut *ptr).set_optional_bytearray(to_usize(row), None)
 }
 
-#[no_mangle]
-pub extern "C" fn list_data_u8(ptr: *const List, row: c_int) -> u8 {
-    let o = unsafe { &*ptr };
-    o.u8(to_usize(row)).into()
+/// Owns a List for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct ListFixture {
+    pub ptr: *mut List,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl ListFixture {
+    pub fn new() -> ListFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = list_new(
+            q as *mut ListQObject,
+            |_, task| unsafe { list_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        ListFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &List {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut List {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { list_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn list_set_data_u8(
-    ptr: *mut List, row: c_int,
-    v: u8,
-) -> bool {
-    (&mut *ptr).set_u8(to_usize(row), v)
+#[cfg(test)]
+impl Drop for ListFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
@@ -6,6 +6,7 @@ use interface::*;
 pub struct Person {
     emit: PersonEmitter,
     user_name: String,
+    age: u8,
 }
 
 impl PersonTrait for Person {
@@ -13,6 +14,7 @@ impl PersonTrait for Person {
         Person {
             emit: emit,
             user_name: String::new(),
+            age: 0,
         }
     }
     fn emit(&self) -> &PersonEmitter {
@@ -25,5 +27,18 @@ impl PersonTrait for Person {
         self.user_name = value;
         self.emit.user_name_changed();
     }
+    fn age(&self) -> u8 {
+        self.age
+    }
+    fn set_age(&mut self, value: u8) {
+        self.age = value;
+        self.emit.age_changed();
+    }
+    fn reset_age(&mut self) {
+        self.set_age(0);
+    }
+    fn species(&self) -> &str {
+        "Konqi"
+    }
 }
 
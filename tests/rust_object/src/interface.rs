@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -16,49 +18,145 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
 
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x6535b948b4df56d9
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct PersonQObject {}
 
 #[derive(Clone)]
 pub struct PersonEmitter {
-    qobject: Arc<Mutex<*const PersonQObject>>,
-    user_name_changed: fn(*const PersonQObject),
+    pub(crate) qobject: Arc<AtomicPtr<PersonQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
+    pub(crate) user_name_changed: fn(*const PersonQObject),
+    pub(crate) age_changed: fn(*const PersonQObject),
 }
 
-unsafe impl Send for PersonEmitter {}
-
 impl PersonEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn user_name_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const PersonQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.user_name_changed)(ptr);
+            f(ptr);
         }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by person_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Person) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn user_name_changed(&self) {
+        self.with_qobject(|ptr| (self.user_name_changed)(ptr));
+    }
+    pub fn age_changed(&self) {
+        self.with_qobject(|ptr| (self.age_changed)(ptr));
     }
 }
 
@@ -67,16 +165,25 @@ pub trait PersonTrait {
     fn emit(&self) -> &PersonEmitter;
     fn user_name(&self) -> &str;
     fn set_user_name(&mut self, value: String);
+    fn age(&self) -> u8;
+    fn set_age(&mut self, value: u8);
+    fn reset_age(&mut self);
+    fn species(&self) -> &str;
 }
 
 #[no_mangle]
 pub extern "C" fn person_new(
     person: *mut PersonQObject,
+    person_run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
     user_name_changed: fn(*const PersonQObject),
+    age_changed: fn(*const PersonQObject),
 ) -> *mut Person {
     let person_emit = PersonEmitter {
-        qobject: Arc::new(Mutex::new(person)),
+        qobject: Arc::new(AtomicPtr::new(person)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: person_run_on_gui_thread,
         user_name_changed: user_name_changed,
+        age_changed: age_changed,
     };
     let d_person = Person::new(person_emit);
     Box::into_raw(Box::new(d_person))
@@ -87,6 +194,17 @@ pub unsafe extern "C" fn person_free(ptr: *mut Person) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn person_run_task(ptr: *mut Person, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Person) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Person) + Send>));
+}
+
 #[no_mangle]
 pub extern "C" fn person_user_name_get(
     ptr: *const Person,
@@ -106,3 +224,73 @@ pub extern "C" fn person_user_name_set(ptr: *mut Person, v: *const c_ushort, len
     set_string_from_utf16(&mut s, v, len);
     o.set_user_name(s);
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn person_age_get(ptr: *const Person) -> u8 {
+    (&*ptr).age()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_age_set(ptr: *mut Person, v: u8) {
+    (&mut *ptr).set_age(v);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_age_reset(ptr: *mut Person) {
+    (&mut *ptr).reset_age();
+}
+
+#[no_mangle]
+pub extern "C" fn person_species_get(
+    ptr: *const Person,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.species();
+    let s: *const c_char = v.as_ptr() as (*const c_char);
+    set(p, s, to_c_int(v.len()));
+}
+
+/// Owns a Person for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct PersonFixture {
+    pub ptr: *mut Person,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl PersonFixture {
+    pub fn new() -> PersonFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = person_new(
+            q as *mut PersonQObject,
+            |_, task| unsafe { person_drop_task(task) },
+            |q| TestQObject::emit(q, "userNameChanged"),
+            |q| TestQObject::emit(q, "ageChanged"),
+        );
+        PersonFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Person {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Person {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { person_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for PersonFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -42,8 +44,7 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
@@ -53,153 +54,198 @@ fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
 pub enum QByteArray {}
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x6608da53a1939b42
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct ObjectQObject {}
 
 #[derive(Clone)]
 pub struct ObjectEmitter {
-    qobject: Arc<Mutex<*const ObjectQObject>>,
-    boolean_changed: fn(*const ObjectQObject),
-    bytearray_changed: fn(*const ObjectQObject),
-    f32_changed: fn(*const ObjectQObject),
-    f64_changed: fn(*const ObjectQObject),
-    i16_changed: fn(*const ObjectQObject),
-    i32_changed: fn(*const ObjectQObject),
-    i64_changed: fn(*const ObjectQObject),
-    i8_changed: fn(*const ObjectQObject),
-    optional_boolean_changed: fn(*const ObjectQObject),
-    optional_bytearray_changed: fn(*const ObjectQObject),
-    optional_string_changed: fn(*const ObjectQObject),
-    optional_u64_changed: fn(*const ObjectQObject),
-    string_changed: fn(*const ObjectQObject),
-    u16_changed: fn(*const ObjectQObject),
-    u32_changed: fn(*const ObjectQObject),
-    u64_changed: fn(*const ObjectQObject),
-    u8_changed: fn(*const ObjectQObject),
+    pub(crate) qobject: Arc<AtomicPtr<ObjectQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const ObjectQObject, *mut c_void),
+    pub(crate) boolean_changed: fn(*const ObjectQObject),
+    pub(crate) optional_boolean_changed: fn(*const ObjectQObject),
+    pub(crate) i8_changed: fn(*const ObjectQObject),
+    pub(crate) u8_changed: fn(*const ObjectQObject),
+    pub(crate) i16_changed: fn(*const ObjectQObject),
+    pub(crate) u16_changed: fn(*const ObjectQObject),
+    pub(crate) i32_changed: fn(*const ObjectQObject),
+    pub(crate) u32_changed: fn(*const ObjectQObject),
+    pub(crate) i64_changed: fn(*const ObjectQObject),
+    pub(crate) u64_changed: fn(*const ObjectQObject),
+    pub(crate) optional_u64_changed: fn(*const ObjectQObject),
+    pub(crate) f32_changed: fn(*const ObjectQObject),
+    pub(crate) f64_changed: fn(*const ObjectQObject),
+    pub(crate) string_changed: fn(*const ObjectQObject),
+    pub(crate) optional_string_changed: fn(*const ObjectQObject),
+    pub(crate) bytearray_changed: fn(*const ObjectQObject),
+    pub(crate) optional_bytearray_changed: fn(*const ObjectQObject),
 }
 
-unsafe impl Send for ObjectEmitter {}
-
 impl ObjectEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn boolean_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.boolean_changed)(ptr);
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const ObjectQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
         }
-    }
-    pub fn bytearray_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.bytearray_changed)(ptr);
+            f(ptr);
         }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
     }
-    pub fn f32_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.f32_changed)(ptr);
+    // Called by object_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
         }
     }
-    pub fn f64_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.f64_changed)(ptr);
-        }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Object) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn boolean_changed(&self) {
+        self.with_qobject(|ptr| (self.boolean_changed)(ptr));
+    }
+    pub fn optional_boolean_changed(&self) {
+        self.with_qobject(|ptr| (self.optional_boolean_changed)(ptr));
+    }
+    pub fn i8_changed(&self) {
+        self.with_qobject(|ptr| (self.i8_changed)(ptr));
+    }
+    pub fn u8_changed(&self) {
+        self.with_qobject(|ptr| (self.u8_changed)(ptr));
     }
     pub fn i16_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.i16_changed)(ptr);
-        }
+        self.with_qobject(|ptr| (self.i16_changed)(ptr));
+    }
+    pub fn u16_changed(&self) {
+        self.with_qobject(|ptr| (self.u16_changed)(ptr));
     }
     pub fn i32_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.i32_changed)(ptr);
-        }
+        self.with_qobject(|ptr| (self.i32_changed)(ptr));
     }
-    pub fn i64_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.i64_changed)(ptr);
-        }
+    pub fn u32_changed(&self) {
+        self.with_qobject(|ptr| (self.u32_changed)(ptr));
     }
-    pub fn i8_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.i8_changed)(ptr);
-        }
+    pub fn i64_changed(&self) {
+        self.with_qobject(|ptr| (self.i64_changed)(ptr));
     }
-    pub fn optional_boolean_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.optional_boolean_changed)(ptr);
-        }
+    pub fn u64_changed(&self) {
+        self.with_qobject(|ptr| (self.u64_changed)(ptr));
     }
-    pub fn optional_bytearray_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.optional_bytearray_changed)(ptr);
-        }
+    pub fn optional_u64_changed(&self) {
+        self.with_qobject(|ptr| (self.optional_u64_changed)(ptr));
     }
-    pub fn optional_string_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.optional_string_changed)(ptr);
-        }
+    pub fn f32_changed(&self) {
+        self.with_qobject(|ptr| (self.f32_changed)(ptr));
     }
-    pub fn optional_u64_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.optional_u64_changed)(ptr);
-        }
+    pub fn f64_changed(&self) {
+        self.with_qobject(|ptr| (self.f64_changed)(ptr));
     }
     pub fn string_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.string_changed)(ptr);
-        }
+        self.with_qobject(|ptr| (self.string_changed)(ptr));
     }
-    pub fn u16_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.u16_changed)(ptr);
-        }
-    }
-    pub fn u32_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.u32_changed)(ptr);
-        }
+    pub fn optional_string_changed(&self) {
+        self.with_qobject(|ptr| (self.optional_string_changed)(ptr));
     }
-    pub fn u64_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.u64_changed)(ptr);
-        }
+    pub fn bytearray_changed(&self) {
+        self.with_qobject(|ptr| (self.bytearray_changed)(ptr));
     }
-    pub fn u8_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
-            (self.u8_changed)(ptr);
-        }
+    pub fn optional_bytearray_changed(&self) {
+        self.with_qobject(|ptr| (self.optional_bytearray_changed)(ptr));
     }
 }
 
@@ -208,80 +254,83 @@ pub trait ObjectTrait {
     fn emit(&self) -> &ObjectEmitter;
     fn boolean(&self) -> bool;
     fn set_boolean(&mut self, value: bool);
-    fn bytearray(&self) -> &[u8];
-    fn set_bytearray(&mut self, value: &[u8]);
-    fn f32(&self) -> f32;
-    fn set_f32(&mut self, value: f32);
-    fn f64(&self) -> f64;
-    fn set_f64(&mut self, value: f64);
+    fn optional_boolean(&self) -> Option<bool>;
+    fn set_optional_boolean(&mut self, value: Option<bool>);
+    fn i8(&self) -> i8;
+    fn set_i8(&mut self, value: i8);
+    fn u8(&self) -> u8;
+    fn set_u8(&mut self, value: u8);
     fn i16(&self) -> i16;
     fn set_i16(&mut self, value: i16);
+    fn u16(&self) -> u16;
+    fn set_u16(&mut self, value: u16);
     fn i32(&self) -> i32;
     fn set_i32(&mut self, value: i32);
+    fn u32(&self) -> u32;
+    fn set_u32(&mut self, value: u32);
     fn i64(&self) -> i64;
     fn set_i64(&mut self, value: i64);
-    fn i8(&self) -> i8;
-    fn set_i8(&mut self, value: i8);
-    fn optional_boolean(&self) -> Option<bool>;
-    fn set_optional_boolean(&mut self, value: Option<bool>);
-    fn optional_bytearray(&self) -> Option<&[u8]>;
-    fn set_optional_bytearray(&mut self, value: Option<&[u8]>);
-    fn optional_string(&self) -> Option<&str>;
-    fn set_optional_string(&mut self, value: Option<String>);
+    fn u64(&self) -> u64;
+    fn set_u64(&mut self, value: u64);
     fn optional_u64(&self) -> Option<u64>;
     fn set_optional_u64(&mut self, value: Option<u64>);
+    fn f32(&self) -> f32;
+    fn set_f32(&mut self, value: f32);
+    fn f64(&self) -> f64;
+    fn set_f64(&mut self, value: f64);
     fn string(&self) -> &str;
     fn set_string(&mut self, value: String);
-    fn u16(&self) -> u16;
-    fn set_u16(&mut self, value: u16);
-    fn u32(&self) -> u32;
-    fn set_u32(&mut self, value: u32);
-    fn u64(&self) -> u64;
-    fn set_u64(&mut self, value: u64);
-    fn u8(&self) -> u8;
-    fn set_u8(&mut self, value: u8);
+    fn optional_string(&self) -> Option<&str>;
+    fn set_optional_string(&mut self, value: Option<String>);
+    fn bytearray(&self) -> &[u8];
+    fn set_bytearray(&mut self, value: &[u8]);
+    fn optional_bytearray(&self) -> Option<&[u8]>;
+    fn set_optional_bytearray(&mut self, value: Option<&[u8]>);
 }
 
 #[no_mangle]
 pub extern "C" fn object_new(
     object: *mut ObjectQObject,
+    object_run_on_gui_thread: fn(*const ObjectQObject, *mut c_void),
     boolean_changed: fn(*const ObjectQObject),
-    bytearray_changed: fn(*const ObjectQObject),
-    f32_changed: fn(*const ObjectQObject),
-    f64_changed: fn(*const ObjectQObject),
+    optional_boolean_changed: fn(*const ObjectQObject),
+    i8_changed: fn(*const ObjectQObject),
+    u8_changed: fn(*const ObjectQObject),
     i16_changed: fn(*const ObjectQObject),
+    u16_changed: fn(*const ObjectQObject),
     i32_changed: fn(*const ObjectQObject),
+    u32_changed: fn(*const ObjectQObject),
     i64_changed: fn(*const ObjectQObject),
-    i8_changed: fn(*const ObjectQObject),
-    optional_boolean_changed: fn(*const ObjectQObject),
-    optional_bytearray_changed: fn(*const ObjectQObject),
-    optional_string_changed: fn(*const ObjectQObject),
+    u64_changed: fn(*const ObjectQObject),
     optional_u64_changed: fn(*const ObjectQObject),
+    f32_changed: fn(*const ObjectQObject),
+    f64_changed: fn(*const ObjectQObject),
     string_changed: fn(*const ObjectQObject),
-    u16_changed: fn(*const ObjectQObject),
-    u32_changed: fn(*const ObjectQObject),
-    u64_changed: fn(*const ObjectQObject),
-    u8_changed: fn(*const ObjectQObject),
+    optional_string_changed: fn(*const ObjectQObject),
+    bytearray_changed: fn(*const ObjectQObject),
+    optional_bytearray_changed: fn(*const ObjectQObject),
 ) -> *mut Object {
     let object_emit = ObjectEmitter {
-        qobject: Arc::new(Mutex::new(object)),
+        qobject: Arc::new(AtomicPtr::new(object)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: object_run_on_gui_thread,
         boolean_changed: boolean_changed,
-        bytearray_changed: bytearray_changed,
-        f32_changed: f32_changed,
-        f64_changed: f64_changed,
+        optional_boolean_changed: optional_boolean_changed,
+        i8_changed: i8_changed,
+        u8_changed: u8_changed,
         i16_changed: i16_changed,
+        u16_changed: u16_changed,
         i32_changed: i32_changed,
+        u32_changed: u32_changed,
         i64_changed: i64_changed,
-        i8_changed: i8_changed,
-        optional_boolean_changed: optional_boolean_changed,
-        optional_bytearray_changed: optional_bytearray_changed,
-        optional_string_changed: optional_string_changed,
+        u64_changed: u64_changed,
         optional_u64_changed: optional_u64_changed,
+        f32_changed: f32_changed,
+        f64_changed: f64_changed,
         string_changed: string_changed,
-        u16_changed: u16_changed,
-        u32_changed: u32_changed,
-        u64_changed: u64_changed,
-        u8_changed: u8_changed,
+        optional_string_changed: optional_string_changed,
+        bytearray_changed: bytearray_changed,
+        optional_bytearray_changed: optional_bytearray_changed,
     };
     let d_object = Object::new(object_emit);
     Box::into_raw(Box::new(d_object))
@@ -292,6 +341,17 @@ pub unsafe extern "C" fn object_free(ptr: *mut Object) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn object_run_task(ptr: *mut Object, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Object) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn object_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Object) + Send>));
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn object_boolean_get(ptr: *const Object) -> bool {
     (&*ptr).boolean()
@@ -303,42 +363,42 @@ pub unsafe extern "C" fn object_boolean_set(ptr: *mut Object, v: bool) {
 }
 
 #[no_mangle]
-pub extern "C" fn object_bytearray_get(
-    ptr: *const Object,
-    p: *mut QByteArray,
-    set: fn(*mut QByteArray, *const c_char, c_int),
-) {
-    let o = unsafe { &*ptr };
-    let v = o.bytearray();
-    let s: *const c_char = v.as_ptr() as (*const c_char);
-    set(p, s, to_c_int(v.len()));
+pub unsafe extern "C" fn object_optional_boolean_get(ptr: *const Object) -> COption<bool> {
+    match (&*ptr).optional_boolean() {
+        Some(value) => COption { data: value, some: true },
+        None => COption { data: bool::default(), some: false}
+    }
 }
 
 #[no_mangle]
-pub extern "C" fn object_bytearray_set(ptr: *mut Object, v: *const c_char, len: c_int) {
+pub unsafe extern "C" fn object_optional_boolean_set(ptr: *mut Object, v: bool) {
+    (&mut *ptr).set_optional_boolean(Some(v));
+}
+
+#[no_mangle]
+pub extern "C" fn object_optional_boolean_set_none(ptr: *mut Object) {
     let o = unsafe { &mut *ptr };
-    let v = unsafe { slice::from_raw_parts(v as *const u8, to_usize(len)) };
-    o.set_bytearray(v);
+    o.set_optional_boolean(None);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_f32_get(ptr: *const Object) -> f32 {
-    (&*ptr).f32()
+pub unsafe extern "C" fn object_i8_get(ptr: *const Object) -> i8 {
+    (&*ptr).i8()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_f32_set(ptr: *mut Object, v: f32) {
-    (&mut *ptr).set_f32(v);
+pub unsafe extern "C" fn object_i8_set(ptr: *mut Object, v: i8) {
+    (&mut *ptr).set_i8(v);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_f64_get(ptr: *const Object) -> f64 {
-    (&*ptr).f64()
+pub unsafe extern "C" fn object_u8_get(ptr: *const Object) -> u8 {
+    (&*ptr).u8()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_f64_set(ptr: *mut Object, v: f64) {
-    (&mut *ptr).set_f64(v);
+pub unsafe extern "C" fn object_u8_set(ptr: *mut Object, v: u8) {
+    (&mut *ptr).set_u8(v);
 }
 
 #[no_mangle]
@@ -351,6 +411,16 @@ pub unsafe extern "C" fn object_i16_set(ptr: *mut Object, v: i16) {
     (&mut *ptr).set_i16(v);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn object_u16_get(ptr: *const Object) -> u16 {
+    (&*ptr).u16()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn object_u16_set(ptr: *mut Object, v: u16) {
+    (&mut *ptr).set_u16(v);
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn object_i32_get(ptr: *const Object) -> i32 {
     (&*ptr).i32()
@@ -361,6 +431,16 @@ pub unsafe extern "C" fn object_i32_set(ptr: *mut Object, v: i32) {
     (&mut *ptr).set_i32(v);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn object_u32_get(ptr: *const Object) -> u32 {
+    (&*ptr).u32()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn object_u32_set(ptr: *mut Object, v: u32) {
+    (&mut *ptr).set_u32(v);
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn object_i64_get(ptr: *const Object) -> i64 {
     (&*ptr).i64()
@@ -372,59 +452,72 @@ pub unsafe extern "C" fn object_i64_set(ptr: *mut Object, v: i64) {
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_i8_get(ptr: *const Object) -> i8 {
-    (&*ptr).i8()
+pub unsafe extern "C" fn object_u64_get(ptr: *const Object) -> u64 {
+    (&*ptr).u64()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_i8_set(ptr: *mut Object, v: i8) {
-    (&mut *ptr).set_i8(v);
+pub unsafe extern "C" fn object_u64_set(ptr: *mut Object, v: u64) {
+    (&mut *ptr).set_u64(v);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_optional_boolean_get(ptr: *const Object) -> COption<bool> {
-    match (&*ptr).optional_boolean() {
+pub unsafe extern "C" fn object_optional_u64_get(ptr: *const Object) -> COption<u64> {
+    match (&*ptr).optional_u64() {
         Some(value) => COption { data: value, some: true },
-        None => COption { data: bool::default(), some: false}
+        None => COption { data: u64::default(), some: false}
     }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_optional_boolean_set(ptr: *mut Object, v: bool) {
-    (&mut *ptr).set_optional_boolean(Some(v));
+pub unsafe extern "C" fn object_optional_u64_set(ptr: *mut Object, v: u64) {
+    (&mut *ptr).set_optional_u64(Some(v));
 }
 
 #[no_mangle]
-pub extern "C" fn object_optional_boolean_set_none(ptr: *mut Object) {
+pub extern "C" fn object_optional_u64_set_none(ptr: *mut Object) {
     let o = unsafe { &mut *ptr };
-    o.set_optional_boolean(None);
+    o.set_optional_u64(None);
 }
 
 #[no_mangle]
-pub extern "C" fn object_optional_bytearray_get(
-    ptr: *const Object,
-    p: *mut QByteArray,
-    set: fn(*mut QByteArray, *const c_char, c_int),
-) {
-    let o = unsafe { &*ptr };
-    let v = o.optional_bytearray();
-    if let Some(v) = v {
-        let s: *const c_char = v.as_ptr() as (*const c_char);
-        set(p, s, to_c_int(v.len()));
-    }
+pub unsafe extern "C" fn object_f32_get(ptr: *const Object) -> f32 {
+    (&*ptr).f32()
 }
 
 #[no_mangle]
-pub extern "C" fn object_optional_bytearray_set(ptr: *mut Object, v: *const c_char, len: c_int) {
-    let o = unsafe { &mut *ptr };
-    let v = unsafe { slice::from_raw_parts(v as *const u8, to_usize(len)) };
-    o.set_optional_bytearray(Some(v.into()));
+pub unsafe extern "C" fn object_f32_set(ptr: *mut Object, v: f32) {
+    (&mut *ptr).set_f32(v);
 }
 
 #[no_mangle]
-pub extern "C" fn object_optional_bytearray_set_none(ptr: *mut Object) {
+pub unsafe extern "C" fn object_f64_get(ptr: *const Object) -> f64 {
+    (&*ptr).f64()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn object_f64_set(ptr: *mut Object, v: f64) {
+    (&mut *ptr).set_f64(v);
+}
+
+#[no_mangle]
+pub extern "C" fn object_string_get(
+    ptr: *const Object,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.string();
+    let s: *const c_char = v.as_ptr() as (*const c_char);
+    set(p, s, to_c_int(v.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn object_string_set(ptr: *mut Object, v: *const c_ushort, len: c_int) {
     let o = unsafe { &mut *ptr };
-    o.set_optional_bytearray(None);
+    let mut s = String::new();
+    set_string_from_utf16(&mut s, v, len);
+    o.set_string(s);
 }
 
 #[no_mangle]
@@ -456,80 +549,105 @@ pub extern "C" fn object_optional_string_set_none(ptr: *mut Object) {
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_optional_u64_get(ptr: *const Object) -> COption<u64> {
-    match (&*ptr).optional_u64() {
-        Some(value) => COption { data: value, some: true },
-        None => COption { data: u64::default(), some: false}
-    }
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn object_optional_u64_set(ptr: *mut Object, v: u64) {
-    (&mut *ptr).set_optional_u64(Some(v));
-}
-
-#[no_mangle]
-pub extern "C" fn object_optional_u64_set_none(ptr: *mut Object) {
-    let o = unsafe { &mut *ptr };
-    o.set_optional_u64(None);
-}
-
-#[no_mangle]
-pub extern "C" fn object_string_get(
+pub extern "C" fn object_bytearray_get(
     ptr: *const Object,
-    p: *mut QString,
-    set: fn(*mut QString, *const c_char, c_int),
+    p: *mut QByteArray,
+    set: fn(*mut QByteArray, *const c_char, c_int),
 ) {
     let o = unsafe { &*ptr };
-    let v = o.string();
+    let v = o.bytearray();
     let s: *const c_char = v.as_ptr() as (*const c_char);
     set(p, s, to_c_int(v.len()));
 }
 
 #[no_mangle]
-pub extern "C" fn object_string_set(ptr: *mut Object, v: *const c_ushort, len: c_int) {
+pub extern "C" fn object_bytearray_set(ptr: *mut Object, v: *const c_char, len: c_int) {
     let o = unsafe { &mut *ptr };
-    let mut s = String::new();
-    set_string_from_utf16(&mut s, v, len);
-    o.set_string(s);
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn object_u16_get(ptr: *const Object) -> u16 {
-    (&*ptr).u16()
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn object_u16_set(ptr: *mut Object, v: u16) {
-    (&mut *ptr).set_u16(v);
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn object_u32_get(ptr: *const Object) -> u32 {
-    (&*ptr).u32()
+    let v = unsafe { slice::from_raw_parts(v as *const u8, to_usize(len)) };
+    o.set_bytearray(v);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_u32_set(ptr: *mut Object, v: u32) {
-    (&mut *ptr).set_u32(v);
+pub extern "C" fn object_optional_bytearray_get(
+    ptr: *const Object,
+    p: *mut QByteArray,
+    set: fn(*mut QByteArray, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.optional_bytearray();
+    if let Some(v) = v {
+        let s: *const c_char = v.as_ptr() as (*const c_char);
+        set(p, s, to_c_int(v.len()));
+    }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_u64_get(ptr: *const Object) -> u64 {
-    (&*ptr).u64()
+pub extern "C" fn object_optional_bytearray_set(ptr: *mut Object, v: *const c_char, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let v = unsafe { slice::from_raw_parts(v as *const u8, to_usize(len)) };
+    o.set_optional_bytearray(Some(v.into()));
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn object_u64_set(ptr: *mut Object, v: u64) {
-    (&mut *ptr).set_u64(v);
+pub extern "C" fn object_optional_bytearray_set_none(ptr: *mut Object) {
+    let o = unsafe { &mut *ptr };
+    o.set_optional_bytearray(None);
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn object_u8_get(ptr: *const Object) -> u8 {
-    (&*ptr).u8()
+/// Owns a Object for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct ObjectFixture {
+    pub ptr: *mut Object,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl ObjectFixture {
+    pub fn new() -> ObjectFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = object_new(
+            q as *mut ObjectQObject,
+            |_, task| unsafe { object_drop_task(task) },
+            |q| TestQObject::emit(q, "booleanChanged"),
+            |q| TestQObject::emit(q, "optionalBooleanChanged"),
+            |q| TestQObject::emit(q, "i8Changed"),
+            |q| TestQObject::emit(q, "u8Changed"),
+            |q| TestQObject::emit(q, "i16Changed"),
+            |q| TestQObject::emit(q, "u16Changed"),
+            |q| TestQObject::emit(q, "i32Changed"),
+            |q| TestQObject::emit(q, "u32Changed"),
+            |q| TestQObject::emit(q, "i64Changed"),
+            |q| TestQObject::emit(q, "u64Changed"),
+            |q| TestQObject::emit(q, "optionalU64Changed"),
+            |q| TestQObject::emit(q, "f32Changed"),
+            |q| TestQObject::emit(q, "f64Changed"),
+            |q| TestQObject::emit(q, "stringChanged"),
+            |q| TestQObject::emit(q, "optionalStringChanged"),
+            |q| TestQObject::emit(q, "bytearrayChanged"),
+            |q| TestQObject::emit(q, "optionalBytearrayChanged"),
+        );
+        ObjectFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Object {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Object {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { object_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn object_u8_set(ptr: *mut Object, v: u8) {
-    (&mut *ptr).set_u8(v);
+#[cfg(test)]
+impl Drop for ObjectFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
 }
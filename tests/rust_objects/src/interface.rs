@@ -1,14 +1,18 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 
 pub enum QString {}
@@ -16,107 +20,141 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
 
 
 
-fn to_usize(n: c_int) -> usize {
-    if n < 0 {
-        panic!("Cannot cast {} to usize", n);
-    }
-    n as usize
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
 }
 
-
-fn to_c_int(n: usize) -> c_int {
-    if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
     }
-    n as c_int
 }
 
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
 
-pub struct GroupQObject {}
-
-#[derive(Clone)]
-pub struct GroupEmitter {
-    qobject: Arc<Mutex<*const GroupQObject>>,
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
 }
 
-unsafe impl Send for GroupEmitter {}
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
 
-impl GroupEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
     }
+    n as usize
 }
 
-pub trait GroupTrait {
-    fn new(emit: GroupEmitter,
-        person: Person) -> Self;
-    fn emit(&self) -> &GroupEmitter;
-    fn person(&self) -> &Person;
-    fn person_mut(&mut self) -> &mut Person;
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
 }
 
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
 #[no_mangle]
-pub extern "C" fn group_new(
-    group: *mut GroupQObject,
-    person: *mut PersonQObject,
-    object: *mut InnerObjectQObject,
-    description_changed: fn(*const InnerObjectQObject),
-) -> *mut Group {
-    let object_emit = InnerObjectEmitter {
-        qobject: Arc::new(Mutex::new(object)),
-        description_changed: description_changed,
-    };
-    let d_object = InnerObject::new(object_emit);
-    let person_emit = PersonEmitter {
-        qobject: Arc::new(Mutex::new(person)),
-    };
-    let d_person = Person::new(person_emit,
-        d_object);
-    let group_emit = GroupEmitter {
-        qobject: Arc::new(Mutex::new(group)),
-    };
-    let d_group = Group::new(group_emit,
-        d_person);
-    Box::into_raw(Box::new(d_group))
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x4a224064e945469d
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn group_free(ptr: *mut Group) {
-    Box::from_raw(ptr).emit().clear();
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn group_person_get(ptr: *mut Group) -> *mut Person {
-    (&mut *ptr).person_mut()
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
 }
 
 pub struct InnerObjectQObject {}
 
 #[derive(Clone)]
 pub struct InnerObjectEmitter {
-    qobject: Arc<Mutex<*const InnerObjectQObject>>,
-    description_changed: fn(*const InnerObjectQObject),
+    pub(crate) qobject: Arc<AtomicPtr<InnerObjectQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const InnerObjectQObject, *mut c_void),
+    pub(crate) description_changed: fn(*const InnerObjectQObject),
 }
 
-unsafe impl Send for InnerObjectEmitter {}
-
 impl InnerObjectEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
-    }
-    pub fn description_changed(&self) {
-        let ptr = *self.qobject.lock().unwrap();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const InnerObjectQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
         if !ptr.is_null() {
-            (self.description_changed)(ptr);
+            f(ptr);
         }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by inner_object_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut InnerObject) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn description_changed(&self) {
+        self.with_qobject(|ptr| (self.description_changed)(ptr));
     }
 }
 
@@ -130,10 +168,13 @@ pub trait InnerObjectTrait {
 #[no_mangle]
 pub extern "C" fn inner_object_new(
     inner_object: *mut InnerObjectQObject,
+    inner_object_run_on_gui_thread: fn(*const InnerObjectQObject, *mut c_void),
     description_changed: fn(*const InnerObjectQObject),
 ) -> *mut InnerObject {
     let inner_object_emit = InnerObjectEmitter {
-        qobject: Arc::new(Mutex::new(inner_object)),
+        qobject: Arc::new(AtomicPtr::new(inner_object)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: inner_object_run_on_gui_thread,
         description_changed: description_changed,
     };
     let d_inner_object = InnerObject::new(inner_object_emit);
@@ -145,6 +186,17 @@ pub unsafe extern "C" fn inner_object_free(ptr: *mut InnerObject) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn inner_object_run_task(ptr: *mut InnerObject, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut InnerObject) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn inner_object_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut InnerObject) + Send>));
+}
+
 #[no_mangle]
 pub extern "C" fn inner_object_description_get(
     ptr: *const InnerObject,
@@ -165,18 +217,112 @@ pub extern "C" fn inner_object_description_set(ptr: *mut InnerObject, v: *const
     o.set_description(s);
 }
 
+/// The values of the writable properties of InnerObject and of its object
+/// properties. `restore` passes them to the setters, which emit the change
+/// signals.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InnerObjectSnapshot {
+    pub description: String,
+}
+
+#[cfg(feature = "serde")]
+impl InnerObjectSnapshot {
+    /// Read the values with the getters.
+    pub fn take(o: &InnerObject) -> InnerObjectSnapshot {
+        InnerObjectSnapshot {
+            description: o.description().to_owned(),
+        }
+    }
+    /// Pass the values to the setters.
+    pub fn restore(&self, o: &mut InnerObject) {
+        o.set_description(self.description.clone());
+    }
+}
+
+/// Owns a InnerObject for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct InnerObjectFixture {
+    pub ptr: *mut InnerObject,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl InnerObjectFixture {
+    pub fn new() -> InnerObjectFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = inner_object_new(
+            q as *mut InnerObjectQObject,
+            |_, task| unsafe { inner_object_drop_task(task) },
+            |q| TestQObject::emit(q, "descriptionChanged"),
+        );
+        InnerObjectFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &InnerObject {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut InnerObject {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { inner_object_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for InnerObjectFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
 pub struct PersonQObject {}
 
 #[derive(Clone)]
 pub struct PersonEmitter {
-    qobject: Arc<Mutex<*const PersonQObject>>,
+    pub(crate) qobject: Arc<AtomicPtr<PersonQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
 }
 
-unsafe impl Send for PersonEmitter {}
-
 impl PersonEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const PersonQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by person_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Person) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
     }
 }
 
@@ -191,16 +337,22 @@ pub trait PersonTrait {
 #[no_mangle]
 pub extern "C" fn person_new(
     person: *mut PersonQObject,
+    person_run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
     object: *mut InnerObjectQObject,
+    object_run_on_gui_thread: fn(*const InnerObjectQObject, *mut c_void),
     description_changed: fn(*const InnerObjectQObject),
 ) -> *mut Person {
     let object_emit = InnerObjectEmitter {
-        qobject: Arc::new(Mutex::new(object)),
+        qobject: Arc::new(AtomicPtr::new(object)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: object_run_on_gui_thread,
         description_changed: description_changed,
     };
     let d_object = InnerObject::new(object_emit);
     let person_emit = PersonEmitter {
-        qobject: Arc::new(Mutex::new(person)),
+        qobject: Arc::new(AtomicPtr::new(person)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: person_run_on_gui_thread,
     };
     let d_person = Person::new(person_emit,
         d_object);
@@ -209,10 +361,269 @@ pub extern "C" fn person_new(
 
 #[no_mangle]
 pub unsafe extern "C" fn person_free(ptr: *mut Person) {
-    Box::from_raw(ptr).emit().clear();
+    let o = Box::from_raw(ptr);
+    o.emit().clear();
+    o.object().emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_run_task(ptr: *mut Person, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Person) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn person_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Person) + Send>));
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn person_object_get(ptr: *mut Person) -> *mut InnerObject {
     (&mut *ptr).object_mut()
 }
+
+/// The values of the writable properties of Person and of its object
+/// properties. `restore` passes them to the setters, which emit the change
+/// signals.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersonSnapshot {
+    pub object: InnerObjectSnapshot,
+}
+
+#[cfg(feature = "serde")]
+impl PersonSnapshot {
+    /// Read the values with the getters.
+    pub fn take(o: &Person) -> PersonSnapshot {
+        PersonSnapshot {
+            object: InnerObjectSnapshot::take(o.object()),
+        }
+    }
+    /// Pass the values to the setters.
+    pub fn restore(&self, o: &mut Person) {
+        self.object.restore(o.object_mut());
+    }
+}
+
+/// Owns a Person for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct PersonFixture {
+    pub ptr: *mut Person,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl PersonFixture {
+    pub fn new() -> PersonFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = person_new(
+            q as *mut PersonQObject,
+            |_, task| unsafe { person_drop_task(task) },
+            q as *mut InnerObjectQObject,
+            |_, task| unsafe { inner_object_drop_task(task) },
+            |q| TestQObject::emit(q, "descriptionChanged"),
+        );
+        PersonFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Person {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Person {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { person_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for PersonFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct GroupQObject {}
+
+#[derive(Clone)]
+pub struct GroupEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<GroupQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const GroupQObject, *mut c_void),
+}
+
+impl GroupEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const GroupQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by group_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Group) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+}
+
+pub trait GroupTrait {
+    fn new(emit: GroupEmitter,
+        person: Person) -> Self;
+    fn emit(&self) -> &GroupEmitter;
+    fn person(&self) -> &Person;
+    fn person_mut(&mut self) -> &mut Person;
+}
+
+#[no_mangle]
+pub extern "C" fn group_new(
+    group: *mut GroupQObject,
+    group_run_on_gui_thread: fn(*const GroupQObject, *mut c_void),
+    person: *mut PersonQObject,
+    person_run_on_gui_thread: fn(*const PersonQObject, *mut c_void),
+    object: *mut InnerObjectQObject,
+    object_run_on_gui_thread: fn(*const InnerObjectQObject, *mut c_void),
+    description_changed: fn(*const InnerObjectQObject),
+) -> *mut Group {
+    let object_emit = InnerObjectEmitter {
+        qobject: Arc::new(AtomicPtr::new(object)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: object_run_on_gui_thread,
+        description_changed: description_changed,
+    };
+    let d_object = InnerObject::new(object_emit);
+    let person_emit = PersonEmitter {
+        qobject: Arc::new(AtomicPtr::new(person)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: person_run_on_gui_thread,
+    };
+    let d_person = Person::new(person_emit,
+        d_object);
+    let group_emit = GroupEmitter {
+        qobject: Arc::new(AtomicPtr::new(group)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: group_run_on_gui_thread,
+    };
+    let d_group = Group::new(group_emit,
+        d_person);
+    Box::into_raw(Box::new(d_group))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn group_free(ptr: *mut Group) {
+    let o = Box::from_raw(ptr);
+    o.emit().clear();
+    o.person().emit().clear();
+    o.person().object().emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn group_run_task(ptr: *mut Group, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Group) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn group_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Group) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn group_person_get(ptr: *mut Group) -> *mut Person {
+    (&mut *ptr).person_mut()
+}
+
+/// The values of the writable properties of Group and of its object
+/// properties. `restore` passes them to the setters, which emit the change
+/// signals.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupSnapshot {
+    pub person: PersonSnapshot,
+}
+
+#[cfg(feature = "serde")]
+impl GroupSnapshot {
+    /// Read the values with the getters.
+    pub fn take(o: &Group) -> GroupSnapshot {
+        GroupSnapshot {
+            person: PersonSnapshot::take(o.person()),
+        }
+    }
+    /// Pass the values to the setters.
+    pub fn restore(&self, o: &mut Group) {
+        self.person.restore(o.person_mut());
+    }
+}
+
+/// Owns a Group for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct GroupFixture {
+    pub ptr: *mut Group,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl GroupFixture {
+    pub fn new() -> GroupFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = group_new(
+            q as *mut GroupQObject,
+            |_, task| unsafe { group_drop_task(task) },
+            q as *mut PersonQObject,
+            |_, task| unsafe { person_drop_task(task) },
+            q as *mut InnerObjectQObject,
+            |_, task| unsafe { inner_object_drop_task(task) },
+            |q| TestQObject::emit(q, "descriptionChanged"),
+        );
+        GroupFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Group {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Group {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { group_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for GroupFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -0,0 +1,25 @@
+// Saves the nested objects of a group as JSON and restores them into another
+// group. The snapshot of an object contains the snapshots of its object
+// properties, so restoring reaches the innermost setter.
+
+use interface::*;
+use serde_json;
+
+#[test]
+fn nested_snapshot_round_trip() {
+    let mut a = GroupFixture::new();
+    a.get_mut()
+        .person_mut()
+        .object_mut()
+        .set_description("inner".into());
+
+    let json = serde_json::to_string(&GroupSnapshot::take(a.get())).unwrap();
+    assert_eq!(json, r#"{"person":{"object":{"description":"inner"}}}"#);
+    let snapshot: GroupSnapshot = serde_json::from_str(&json).unwrap();
+
+    let mut b = GroupFixture::new();
+    snapshot.restore(b.get_mut());
+    assert_eq!(b.get().person().object().description(), "inner");
+    assert_eq!(GroupSnapshot::take(b.get()), snapshot);
+    assert_eq!(b.qobject.signals(), vec!["descriptionChanged"]);
+}
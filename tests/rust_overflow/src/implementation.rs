@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+use interface::*;
+
+// A list with any number of empty rows. Nothing is stored per row.
+pub struct Rows {
+    emit: RowsEmitter,
+    model: RowsList,
+    pub(crate) count: usize,
+    // the last row and count that insert_rows got
+    pub(crate) inserted: Option<(usize, usize)>,
+}
+
+impl RowsTrait for Rows {
+    fn new(emit: RowsEmitter, model: RowsList) -> Rows {
+        Rows {
+            emit: emit,
+            model: model,
+            count: 0,
+            inserted: None,
+        }
+    }
+    fn emit(&self) -> &RowsEmitter {
+        &self.emit
+    }
+    fn row_count(&self) -> usize {
+        self.count
+    }
+    fn insert_rows(&mut self, row: usize, count: usize) -> bool {
+        self.inserted = Some((row, count));
+        true
+    }
+    fn user_name(&self, _: usize) -> &str {
+        ""
+    }
+    fn set_user_name(&mut self, _: usize, _: String) -> bool {
+        false
+    }
+}
+
+// A tree with `count` top level items. The item of row `r` is `r + 1`.
+pub struct Nodes {
+    emit: NodesEmitter,
+    model: NodesTree,
+    pub(crate) count: usize,
+}
+
+impl NodesTrait for Nodes {
+    fn new(emit: NodesEmitter, model: NodesTree) -> Nodes {
+        Nodes {
+            emit: emit,
+            model: model,
+            count: 0,
+        }
+    }
+    fn emit(&self) -> &NodesEmitter {
+        &self.emit
+    }
+    fn row_count(&self, item: Option<usize>) -> usize {
+        if item.is_none() {
+            self.count
+        } else {
+            0
+        }
+    }
+    fn index(&self, _: Option<usize>, row: usize) -> usize {
+        row + 1
+    }
+    fn parent(&self, _: usize) -> Option<usize> {
+        None
+    }
+    fn row(&self, item: usize) -> usize {
+        item - 1
+    }
+    fn user_name(&self, _: usize) -> &str {
+        ""
+    }
+    fn set_user_name(&mut self, _: usize, _: String) -> bool {
+        false
+    }
+}
@@ -0,0 +1,696 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::{c_char, c_ushort, c_int, c_void};
+use std::slice;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use implementation::*;
+
+
+#[repr(C)]
+pub struct COption<T> {
+    data: T,
+    some: bool,
+}
+
+impl<T> From<Option<T>> for COption<T>
+where
+    T: Default,
+{
+    fn from(t: Option<T>) -> COption<T> {
+        if let Some(v) = t {
+            COption {
+                data: v,
+                some: true,
+            }
+        } else {
+            COption {
+                data: T::default(),
+                some: false,
+            }
+        }
+    }
+}
+
+
+pub enum QString {}
+
+fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    let characters = decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
+    s.clear();
+    s.extend(characters);
+}
+
+
+
+#[repr(C)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(C)]
+pub struct QModelIndex {
+    row: c_int,
+    internal_id: usize,
+}
+
+
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
+    }
+    n as usize
+}
+
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
+}
+
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x04517bc2e729aca3
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct RowsQObject {}
+
+#[derive(Clone)]
+pub struct RowsEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<RowsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const RowsQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const RowsQObject),
+}
+
+impl RowsEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const RowsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by rows_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Rows) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
+}
+
+pub struct RowsList {
+    pub(crate) qobject: *const RowsQObject,
+    pub(crate) data_changed: fn(*const RowsQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const RowsQObject),
+    pub(crate) end_reset_model: fn(*const RowsQObject),
+    pub(crate) begin_insert_rows: fn(*const RowsQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const RowsQObject),
+    pub(crate) begin_remove_rows: fn(*const RowsQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const RowsQObject),
+}
+
+impl RowsList {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait RowsTrait {
+    fn new(emit: RowsEmitter, model: RowsList) -> Self;
+    fn emit(&self) -> &RowsEmitter;
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    fn fetch_more(&mut self) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn user_name(&self, item: usize) -> &str;
+    fn set_user_name(&mut self, item: usize, String) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn rows_new(
+    rows: *mut RowsQObject,
+    rows_run_on_gui_thread: fn(*const RowsQObject, *mut c_void),
+    rows_new_data_ready: fn(*const RowsQObject),
+    rows_data_changed: fn(*const RowsQObject, usize, usize),
+    rows_begin_reset_model: fn(*const RowsQObject),
+    rows_end_reset_model: fn(*const RowsQObject),
+    rows_begin_insert_rows: fn(*const RowsQObject, usize, usize),
+    rows_end_insert_rows: fn(*const RowsQObject),
+    rows_begin_remove_rows: fn(*const RowsQObject, usize, usize),
+    rows_end_remove_rows: fn(*const RowsQObject),
+) -> *mut Rows {
+    let rows_emit = RowsEmitter {
+        qobject: Arc::new(AtomicPtr::new(rows)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: rows_run_on_gui_thread,
+        new_data_ready: rows_new_data_ready,
+    };
+    let model = RowsList {
+        qobject: rows,
+        data_changed: rows_data_changed,
+        begin_reset_model: rows_begin_reset_model,
+        end_reset_model: rows_end_reset_model,
+        begin_insert_rows: rows_begin_insert_rows,
+        end_insert_rows: rows_end_insert_rows,
+        begin_remove_rows: rows_begin_remove_rows,
+        end_remove_rows: rows_end_remove_rows,
+    };
+    let d_rows = Rows::new(rows_emit, model);
+    Box::into_raw(Box::new(d_rows))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rows_free(ptr: *mut Rows) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rows_run_task(ptr: *mut Rows, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Rows) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rows_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Rows) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rows_row_count(ptr: *const Rows) -> c_int {
+    to_c_int((&*ptr).row_count())
+}
+#[no_mangle]
+pub unsafe extern "C" fn rows_insert_rows(ptr: *mut Rows, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn rows_remove_rows(ptr: *mut Rows, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn rows_can_fetch_more(ptr: *const Rows) -> bool {
+    (&*ptr).can_fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn rows_fetch_more(ptr: *mut Rows) {
+    (&mut *ptr).fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn rows_sort(
+    ptr: *mut Rows,
+    column: u8,
+    order: SortOrder,
+) {
+    (&mut *ptr).sort(column, order)
+}
+
+#[no_mangle]
+pub extern "C" fn rows_data_user_name(
+    ptr: *const Rows, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.user_name(to_usize(row));
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn rows_set_data_user_name(
+    ptr: *mut Rows, row: c_int,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let mut v = String::new();
+    set_string_from_utf16(&mut v, s, len);
+    o.set_user_name(to_usize(row), v)
+}
+
+/// Owns a Rows for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct RowsFixture {
+    pub ptr: *mut Rows,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl RowsFixture {
+    pub fn new() -> RowsFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = rows_new(
+            q as *mut RowsQObject,
+            |_, task| unsafe { rows_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        RowsFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Rows {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Rows {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { rows_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for RowsFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct NodesQObject {}
+
+#[derive(Clone)]
+pub struct NodesEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<NodesQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const NodesQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const NodesQObject, item: usize, valid: bool),
+}
+
+impl NodesEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const NodesQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by nodes_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Nodes) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self, item: Option<usize>) {
+        self.with_qobject(|ptr| {
+            (self.new_data_ready)(ptr, item.unwrap_or(13), item.is_some());
+        });
+    }
+}
+
+pub struct NodesTree {
+    pub(crate) qobject: *const NodesQObject,
+    pub(crate) data_changed: fn(*const NodesQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const NodesQObject),
+    pub(crate) end_reset_model: fn(*const NodesQObject),
+    pub(crate) begin_insert_rows: fn(*const NodesQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_insert_rows: fn(*const NodesQObject),
+    pub(crate) begin_remove_rows: fn(*const NodesQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_remove_rows: fn(*const NodesQObject),
+}
+
+impl NodesTree {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, item: Option<usize>, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, item.unwrap_or(13), item.is_some(), first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, item: Option<usize>, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, item.unwrap_or(13), item.is_some(), first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait NodesTrait {
+    fn new(emit: NodesEmitter, model: NodesTree) -> Self;
+    fn emit(&self) -> &NodesEmitter;
+    fn row_count(&self, Option<usize>) -> usize;
+    fn can_fetch_more(&self, Option<usize>) -> bool {
+        false
+    }
+    fn fetch_more(&mut self, Option<usize>) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn index(&self, item: Option<usize>, row: usize) -> usize;
+    fn parent(&self, item: usize) -> Option<usize>;
+    fn row(&self, item: usize) -> usize;
+    fn user_name(&self, item: usize) -> &str;
+    fn set_user_name(&mut self, item: usize, String) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn nodes_new(
+    nodes: *mut NodesQObject,
+    nodes_run_on_gui_thread: fn(*const NodesQObject, *mut c_void),
+    nodes_new_data_ready: fn(*const NodesQObject, item: usize, valid: bool),
+    nodes_data_changed: fn(*const NodesQObject, usize, usize),
+    nodes_begin_reset_model: fn(*const NodesQObject),
+    nodes_end_reset_model: fn(*const NodesQObject),
+    nodes_begin_insert_rows: fn(*const NodesQObject, item: usize, valid: bool, usize, usize),
+    nodes_end_insert_rows: fn(*const NodesQObject),
+    nodes_begin_remove_rows: fn(*const NodesQObject, item: usize, valid: bool, usize, usize),
+    nodes_end_remove_rows: fn(*const NodesQObject),
+) -> *mut Nodes {
+    let nodes_emit = NodesEmitter {
+        qobject: Arc::new(AtomicPtr::new(nodes)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: nodes_run_on_gui_thread,
+        new_data_ready: nodes_new_data_ready,
+    };
+    let model = NodesTree {
+        qobject: nodes,
+        data_changed: nodes_data_changed,
+        begin_reset_model: nodes_begin_reset_model,
+        end_reset_model: nodes_end_reset_model,
+        begin_insert_rows: nodes_begin_insert_rows,
+        end_insert_rows: nodes_end_insert_rows,
+        begin_remove_rows: nodes_begin_remove_rows,
+        end_remove_rows: nodes_end_remove_rows,
+    };
+    let d_nodes = Nodes::new(nodes_emit, model);
+    Box::into_raw(Box::new(d_nodes))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nodes_free(ptr: *mut Nodes) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nodes_run_task(ptr: *mut Nodes, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Nodes) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nodes_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Nodes) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nodes_row_count(
+    ptr: *const Nodes,
+    item: usize,
+    valid: bool,
+) -> c_int {
+    to_c_int(if valid {
+        (&*ptr).row_count(Some(item))
+    } else {
+        (&*ptr).row_count(None)
+    })
+}
+#[no_mangle]
+pub unsafe extern "C" fn nodes_can_fetch_more(
+    ptr: *const Nodes,
+    item: usize,
+    valid: bool,
+) -> bool {
+    if valid {
+        (&*ptr).can_fetch_more(Some(item))
+    } else {
+        (&*ptr).can_fetch_more(None)
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn nodes_fetch_more(ptr: *mut Nodes, item: usize, valid: bool) {
+    if valid {
+        (&mut *ptr).fetch_more(Some(item))
+    } else {
+        (&mut *ptr).fetch_more(None)
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn nodes_sort(
+    ptr: *mut Nodes,
+    column: u8,
+    order: SortOrder
+) {
+    (&mut *ptr).sort(column, order)
+}
+#[no_mangle]
+pub unsafe extern "C" fn nodes_index(
+    ptr: *const Nodes,
+    item: usize,
+    valid: bool,
+    row: c_int,
+) -> usize {
+    if !valid {
+        (&*ptr).index(None, to_usize(row))
+    } else {
+        (&*ptr).index(Some(item), to_usize(row))
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn nodes_parent(ptr: *const Nodes, index: usize) -> QModelIndex {
+    if let Some(parent) = (&*ptr).parent(index) {
+        QModelIndex {
+            row: to_c_int((&*ptr).row(parent)),
+            internal_id: parent,
+        }
+    } else {
+        QModelIndex {
+            row: -1,
+            internal_id: 0,
+        }
+    }
+}
+#[no_mangle]
+pub unsafe extern "C" fn nodes_row(ptr: *const Nodes, item: usize) -> c_int {
+    to_c_int((&*ptr).row(item))
+}
+
+#[no_mangle]
+pub extern "C" fn nodes_data_user_name(
+    ptr: *const Nodes, item: usize,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.user_name(item);
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn nodes_set_data_user_name(
+    ptr: *mut Nodes, item: usize,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let mut v = String::new();
+    set_string_from_utf16(&mut v, s, len);
+    o.set_user_name(item, v)
+}
+
+/// Owns a Nodes for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct NodesFixture {
+    pub ptr: *mut Nodes,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl NodesFixture {
+    pub fn new() -> NodesFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = nodes_new(
+            q as *mut NodesQObject,
+            |_, task| unsafe { nodes_drop_task(task) },
+            |q, _, _| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        NodesFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Nodes {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Nodes {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { nodes_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for NodesFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
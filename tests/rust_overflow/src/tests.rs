@@ -0,0 +1,103 @@
+// Passes counts and rows at the limits of c_int between the list or tree
+// and C++. Values that do not fit are clamped and reported to the overflow
+// hook instead of aborting the process.
+
+use interface::*;
+use libc::c_int;
+use std::cell::RefCell;
+
+const MAX: c_int = c_int::max_value();
+const TOO_LARGE: usize = MAX as usize + 1;
+
+thread_local! {
+    static OVERFLOWS: RefCell<Vec<Overflow>> = RefCell::new(Vec::new());
+}
+
+// The hook is global, so every test installs the same one. Each test runs
+// in its own thread and only sees its own overflows.
+fn record(o: Overflow) {
+    OVERFLOWS.with(|v| v.borrow_mut().push(o));
+}
+
+fn overflows() -> Vec<Overflow> {
+    OVERFLOWS.with(|v| v.borrow_mut().drain(..).collect())
+}
+
+// Creates the objects with a row count and installs the overflow hook.
+fn list(count: usize) -> RowsFixture {
+    set_overflow_hook(Some(record));
+    let mut l = RowsFixture::new();
+    l.get_mut().count = count;
+    l
+}
+
+fn tree(count: usize) -> NodesFixture {
+    set_overflow_hook(Some(record));
+    let mut t = NodesFixture::new();
+    t.get_mut().count = count;
+    t
+}
+
+#[test]
+fn list_row_count_at_the_limit() {
+    let l = list(MAX as usize);
+    assert_eq!(unsafe { rows_row_count(l.ptr) }, MAX);
+    assert_eq!(overflows(), vec![]);
+}
+
+#[test]
+fn list_row_count_is_clamped() {
+    for &count in &[TOO_LARGE, usize::max_value()] {
+        let l = list(count);
+        assert_eq!(unsafe { rows_row_count(l.ptr) }, MAX);
+        assert_eq!(overflows(), vec![Overflow::TooLarge(count)]);
+    }
+}
+
+#[test]
+fn list_negative_rows_are_rejected() {
+    let l = list(10);
+    assert!(unsafe { rows_insert_rows(l.ptr, 0, MAX) });
+    assert_eq!(l.get().inserted, Some((0, MAX as usize)));
+    assert_eq!(overflows(), vec![]);
+    // the clamped count is not passed on
+    assert!(!unsafe { rows_insert_rows(l.ptr, -1, c_int::min_value()) });
+    assert_eq!(l.get().inserted, Some((0, MAX as usize)));
+    assert_eq!(
+        overflows(),
+        vec![Overflow::Negative(-1), Overflow::Negative(c_int::min_value())]
+    );
+}
+
+#[test]
+fn tree_row_count_is_clamped() {
+    let t = tree(MAX as usize);
+    assert_eq!(unsafe { nodes_row_count(t.ptr, 0, false) }, MAX);
+    assert_eq!(unsafe { nodes_row_count(t.ptr, 1, true) }, 0);
+    assert_eq!(overflows(), vec![]);
+    let t = tree(usize::max_value());
+    assert_eq!(unsafe { nodes_row_count(t.ptr, 0, false) }, MAX);
+    assert_eq!(overflows(), vec![Overflow::TooLarge(usize::max_value())]);
+}
+
+#[test]
+fn tree_rows_and_items() {
+    let t = tree(usize::max_value());
+    // item ids are passed as they are, only rows are c_int
+    assert_eq!(unsafe { nodes_index(t.ptr, 0, false, MAX) }, TOO_LARGE);
+    assert_eq!(unsafe { nodes_row(t.ptr, TOO_LARGE) }, MAX);
+    assert_eq!(overflows(), vec![]);
+    assert_eq!(unsafe { nodes_row(t.ptr, usize::max_value()) }, MAX);
+    assert_eq!(overflows(), vec![Overflow::TooLarge(usize::max_value() - 1)]);
+    assert_eq!(unsafe { nodes_index(t.ptr, 0, false, -1) }, 1);
+    assert_eq!(overflows(), vec![Overflow::Negative(-1)]);
+}
+
+#[test]
+fn overflow_message() {
+    assert_eq!(
+        Overflow::TooLarge(TOO_LARGE).to_string(),
+        format!("{} does not fit in a c_int, using {}", TOO_LARGE, MAX)
+    );
+    assert_eq!(Overflow::Negative(-3).to_string(), "-3 is negative, using 0");
+}
@@ -0,0 +1,76 @@
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+use interface::*;
+
+pub struct Flags {
+    emit: FlagsEmitter,
+    model: FlagsList,
+    list: Vec<Option<bool>>,
+}
+
+impl FlagsTrait for Flags {
+    fn new(emit: FlagsEmitter, model: FlagsList) -> Flags {
+        Flags {
+            emit: emit,
+            model: model,
+            list: vec![None; 3],
+        }
+    }
+    fn emit(&self) -> &FlagsEmitter {
+        &self.emit
+    }
+    fn row_count(&self) -> usize {
+        self.list.len()
+    }
+    fn flag(&self, item: usize) -> Option<bool> {
+        self.list[item]
+    }
+    fn set_flag(&mut self, item: usize, v: Option<bool>) -> bool {
+        self.list[item] = v;
+        true
+    }
+}
+
+pub struct Settings {
+    emit: SettingsEmitter,
+    theme: String,
+    load: f32,
+    title: String,
+}
+
+impl SettingsTrait for Settings {
+    fn new(emit: SettingsEmitter) -> Settings {
+        let o = Settings {
+            emit: emit,
+            theme: String::from("light"),
+            load: 0.5,
+            title: String::from("Settings"),
+        };
+        // C++ starts with the default values, the initial values are pushed
+        o.emit.set_load(o.load);
+        o.emit.set_title(&o.title);
+        o
+    }
+    fn emit(&self) -> &SettingsEmitter {
+        &self.emit
+    }
+    fn theme(&self) -> &str {
+        &self.theme
+    }
+    fn set_theme(&mut self, value: String) {
+        self.theme = value;
+        self.emit.theme_changed();
+    }
+    fn set_load(&mut self, value: f32) {
+        self.load = value;
+        self.emit.set_load(self.load);
+    }
+    fn set_title(&mut self, value: String) {
+        // pushed from another thread, it arrives in the GUI thread later
+        let emit = self.emit.clone();
+        let title = value.clone();
+        ::std::thread::spawn(move || emit.set_title(&title)).join().unwrap();
+        self.title = value;
+    }
+}
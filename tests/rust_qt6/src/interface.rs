@@ -0,0 +1,539 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::{c_char, c_ushort, c_int, c_void};
+use std::slice;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use implementation::*;
+
+
+#[repr(C)]
+pub struct COption<T> {
+    data: T,
+    some: bool,
+}
+
+impl<T> From<Option<T>> for COption<T>
+where
+    T: Default,
+{
+    fn from(t: Option<T>) -> COption<T> {
+        if let Some(v) = t {
+            COption {
+                data: v,
+                some: true,
+            }
+        } else {
+            COption {
+                data: T::default(),
+                some: false,
+            }
+        }
+    }
+}
+
+
+pub enum QString {}
+
+fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    let characters = decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
+    s.clear();
+    s.extend(characters);
+}
+
+
+
+#[repr(C)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(C)]
+pub struct QModelIndex {
+    row: c_int,
+    internal_id: usize,
+}
+
+
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
+    }
+    n as usize
+}
+
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
+}
+
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xe46381206ea990dd
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct FlagsQObject {}
+
+#[derive(Clone)]
+pub struct FlagsEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<FlagsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const FlagsQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const FlagsQObject),
+}
+
+impl FlagsEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const FlagsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by flags_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Flags) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
+}
+
+pub struct FlagsList {
+    pub(crate) qobject: *const FlagsQObject,
+    pub(crate) data_changed: fn(*const FlagsQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const FlagsQObject),
+    pub(crate) end_reset_model: fn(*const FlagsQObject),
+    pub(crate) begin_insert_rows: fn(*const FlagsQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const FlagsQObject),
+    pub(crate) begin_remove_rows: fn(*const FlagsQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const FlagsQObject),
+}
+
+impl FlagsList {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait FlagsTrait {
+    fn new(emit: FlagsEmitter, model: FlagsList) -> Self;
+    fn emit(&self) -> &FlagsEmitter;
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    fn fetch_more(&mut self) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn flag(&self, item: usize) -> Option<bool>;
+    fn set_flag(&mut self, item: usize, Option<bool>) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn flags_new(
+    flags: *mut FlagsQObject,
+    flags_run_on_gui_thread: fn(*const FlagsQObject, *mut c_void),
+    flags_new_data_ready: fn(*const FlagsQObject),
+    flags_data_changed: fn(*const FlagsQObject, usize, usize),
+    flags_begin_reset_model: fn(*const FlagsQObject),
+    flags_end_reset_model: fn(*const FlagsQObject),
+    flags_begin_insert_rows: fn(*const FlagsQObject, usize, usize),
+    flags_end_insert_rows: fn(*const FlagsQObject),
+    flags_begin_remove_rows: fn(*const FlagsQObject, usize, usize),
+    flags_end_remove_rows: fn(*const FlagsQObject),
+) -> *mut Flags {
+    let flags_emit = FlagsEmitter {
+        qobject: Arc::new(AtomicPtr::new(flags)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: flags_run_on_gui_thread,
+        new_data_ready: flags_new_data_ready,
+    };
+    let model = FlagsList {
+        qobject: flags,
+        data_changed: flags_data_changed,
+        begin_reset_model: flags_begin_reset_model,
+        end_reset_model: flags_end_reset_model,
+        begin_insert_rows: flags_begin_insert_rows,
+        end_insert_rows: flags_end_insert_rows,
+        begin_remove_rows: flags_begin_remove_rows,
+        end_remove_rows: flags_end_remove_rows,
+    };
+    let d_flags = Flags::new(flags_emit, model);
+    Box::into_raw(Box::new(d_flags))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn flags_free(ptr: *mut Flags) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn flags_run_task(ptr: *mut Flags, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Flags) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn flags_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Flags) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn flags_row_count(ptr: *const Flags) -> c_int {
+    to_c_int((&*ptr).row_count())
+}
+#[no_mangle]
+pub unsafe extern "C" fn flags_insert_rows(ptr: *mut Flags, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn flags_remove_rows(ptr: *mut Flags, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn flags_can_fetch_more(ptr: *const Flags) -> bool {
+    (&*ptr).can_fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn flags_fetch_more(ptr: *mut Flags) {
+    (&mut *ptr).fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn flags_sort(
+    ptr: *mut Flags,
+    column: u8,
+    order: SortOrder,
+) {
+    (&mut *ptr).sort(column, order)
+}
+
+#[no_mangle]
+pub extern "C" fn flags_data_flag(ptr: *const Flags, row: c_int) -> COption<bool> {
+    let o = unsafe { &*ptr };
+    o.flag(to_usize(row)).into()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn flags_set_data_flag(
+    ptr: *mut Flags, row: c_int,
+    v: bool,
+) -> bool {
+    (&mut *ptr).set_flag(to_usize(row), Some(v))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn flags_set_data_flag_none(ptr: *mut Flags, row: c_int) -> bool {
+    (&mut *ptr).set_flag(to_usize(row), None)
+}
+
+/// Owns a Flags for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct FlagsFixture {
+    pub ptr: *mut Flags,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl FlagsFixture {
+    pub fn new() -> FlagsFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = flags_new(
+            q as *mut FlagsQObject,
+            |_, task| unsafe { flags_drop_task(task) },
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        FlagsFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Flags {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Flags {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { flags_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for FlagsFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+pub struct SettingsQObject {}
+
+#[derive(Clone)]
+pub struct SettingsEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<SettingsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const SettingsQObject, *mut c_void),
+    pub(crate) theme_changed: fn(*const SettingsQObject),
+    pub(crate) load_set: fn(*const SettingsQObject, f32),
+    pub(crate) title_set: fn(*const SettingsQObject, *const c_char, c_int),
+}
+
+impl SettingsEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const SettingsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by settings_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Settings) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn theme_changed(&self) {
+        self.with_qobject(|ptr| (self.theme_changed)(ptr));
+    }
+    pub fn set_load(&self, value: f32) {
+        self.with_qobject(|ptr| (self.load_set)(ptr, value));
+    }
+    pub fn set_title(&self, value: &str) {
+        self.with_qobject(|ptr| (self.title_set)(ptr, value.as_ptr() as *const c_char, to_c_int(value.len())));
+    }
+}
+
+pub trait SettingsTrait {
+    fn new(emit: SettingsEmitter) -> Self;
+    fn emit(&self) -> &SettingsEmitter;
+    fn theme(&self) -> &str;
+    fn set_theme(&mut self, value: String);
+    fn set_load(&mut self, value: f32);
+    fn set_title(&mut self, value: String);
+}
+
+static SETTINGS_CREATED: AtomicBool = AtomicBool::new(false);
+
+#[no_mangle]
+pub extern "C" fn settings_new(
+    settings: *mut SettingsQObject,
+    settings_run_on_gui_thread: fn(*const SettingsQObject, *mut c_void),
+    theme_changed: fn(*const SettingsQObject),
+    load_set: fn(*const SettingsQObject, f32),
+    title_set: fn(*const SettingsQObject, *const c_char, c_int),
+) -> *mut Settings {
+    if SETTINGS_CREATED.swap(true, Ordering::SeqCst) {
+        // a panic must not unwind into the C++ caller
+        eprintln!("Settings is a singleton and can only be created once.");
+        ::std::process::abort();
+    }
+    let settings_emit = SettingsEmitter {
+        qobject: Arc::new(AtomicPtr::new(settings)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: settings_run_on_gui_thread,
+        theme_changed: theme_changed,
+        load_set: load_set,
+        title_set: title_set,
+    };
+    let d_settings = Settings::new(settings_emit);
+    Box::into_raw(Box::new(d_settings))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn settings_free(ptr: *mut Settings) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn settings_run_task(ptr: *mut Settings, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Settings) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn settings_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Settings) + Send>));
+}
+
+#[no_mangle]
+pub extern "C" fn settings_theme_get(
+    ptr: *const Settings,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.theme();
+    let s: *const c_char = v.as_ptr() as (*const c_char);
+    set(p, s, to_c_int(v.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn settings_theme_set(ptr: *mut Settings, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let mut s = String::new();
+    set_string_from_utf16(&mut s, v, len);
+    o.set_theme(s);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn settings_load_set(ptr: *mut Settings, v: f32) {
+    (&mut *ptr).set_load(v);
+}
+
+#[no_mangle]
+pub extern "C" fn settings_title_set(ptr: *mut Settings, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let mut s = String::new();
+    set_string_from_utf16(&mut s, v, len);
+    o.set_title(s);
+}
@@ -0,0 +1,219 @@
+// Runs futures from the emitter with a stand-in executor. The test thread
+// plays the GUI thread: it runs the tasks that are posted to the QObject.
+
+use implementation::Ticker;
+use interface::*;
+use libc::c_void;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Stands in for the QObject and its event queue.
+struct Receiver {
+    tasks: Mutex<Vec<usize>>,
+}
+
+fn receiver<'a>(qobject: *const TickerQObject) -> &'a Receiver {
+    unsafe { &*(qobject as *const Receiver) }
+}
+
+fn ticks_changed(_: *const TickerQObject) {}
+
+fn run_on_gui_thread(qobject: *const TickerQObject, task: *mut c_void) {
+    receiver(qobject).tasks.lock().unwrap().push(task as usize);
+}
+
+struct Object {
+    receiver: Box<Receiver>,
+    ticker: *mut Ticker,
+}
+
+impl Object {
+    fn new() -> Object {
+        let receiver = Box::new(Receiver {
+            tasks: Mutex::new(Vec::new()),
+        });
+        let qobject = &*receiver as *const Receiver as *mut TickerQObject;
+        let ticker = ticker_new(qobject, run_on_gui_thread, ticks_changed);
+        Object {
+            receiver: receiver,
+            ticker: ticker,
+        }
+    }
+    fn emit(&self) -> &TickerEmitter {
+        unsafe { (&*self.ticker).emit() }
+    }
+    // run the posted tasks until `done` or the time is up
+    fn process_events(&self, done: &AtomicBool) {
+        let start = Instant::now();
+        while !done.load(Ordering::SeqCst) && start.elapsed() < Duration::from_secs(5) {
+            let tasks: Vec<usize> = self.receiver.tasks.lock().unwrap().drain(..).collect();
+            for task in tasks {
+                unsafe { ticker_run_task(self.ticker, task as *mut c_void) };
+            }
+            thread::yield_now();
+        }
+    }
+    fn free(self) {
+        unsafe { ticker_free(self.ticker) };
+        for task in self.receiver.tasks.lock().unwrap().drain(..) {
+            unsafe { ticker_drop_task(task as *mut c_void) };
+        }
+    }
+}
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// The stand-in executor: each future gets a thread that polls it.
+struct ThreadExecutor;
+
+impl Executor for ThreadExecutor {
+    fn spawn(&self, mut future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        thread::spawn(move || {
+            let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+            let mut cx = Context::from_waker(&waker);
+            while future.as_mut().poll(&mut cx).is_pending() {
+                thread::park();
+            }
+        });
+    }
+}
+
+// Ready after a delay. The waker is called from a timer thread.
+struct Delay {
+    started: bool,
+    done: Arc<AtomicBool>,
+    delay: Duration,
+}
+
+fn delay(ms: u64) -> Delay {
+    Delay {
+        started: false,
+        done: Arc::new(AtomicBool::new(false)),
+        delay: Duration::from_millis(ms),
+    }
+}
+
+impl Future for Delay {
+    type Output = thread::ThreadId;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<thread::ThreadId> {
+        if self.done.load(Ordering::SeqCst) {
+            return Poll::Ready(thread::current().id());
+        }
+        if !self.started {
+            self.started = true;
+            let done = self.done.clone();
+            let delay = self.delay;
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(delay);
+                done.store(true, Ordering::SeqCst);
+                waker.wake();
+            });
+        }
+        Poll::Pending
+    }
+}
+
+#[test]
+fn spawn_returns_to_the_gui_thread() {
+    set_executor(ThreadExecutor);
+    let object = Object::new();
+    let done = Arc::new(AtomicBool::new(false));
+    let d = done.clone();
+    let gui = thread::current().id();
+    object
+        .emit()
+        .spawn(delay(10), move |ticker: &mut Ticker, polled_in| {
+            assert!(polled_in != gui);
+            assert_eq!(thread::current().id(), gui);
+            ticker.emit().ticks_changed();
+            d.store(true, Ordering::SeqCst);
+        })
+        .unwrap();
+    object.process_events(&done);
+    assert!(done.load(Ordering::SeqCst));
+    object.free();
+}
+
+#[test]
+fn spawn_local_polls_in_the_gui_thread() {
+    let object = Object::new();
+    let done = Arc::new(AtomicBool::new(false));
+    let d = done.clone();
+    let gui = thread::current().id();
+    object.emit().spawn_local(delay(10), move |_: &mut Ticker, polled_in| {
+        assert_eq!(polled_in, gui);
+        d.store(true, Ordering::SeqCst);
+    });
+    object.process_events(&done);
+    assert!(done.load(Ordering::SeqCst));
+    object.free();
+}
+
+#[test]
+fn nothing_returns_after_free() {
+    set_executor(ThreadExecutor);
+    let object = Object::new();
+    let called = Arc::new(AtomicBool::new(false));
+    let c = called.clone();
+    object
+        .emit()
+        .spawn(delay(10), move |_: &mut Ticker, _| {
+            c.store(true, Ordering::SeqCst);
+        })
+        .unwrap();
+    let c = called.clone();
+    object.emit().spawn_local(delay(10), move |_: &mut Ticker, _| {
+        c.store(true, Ordering::SeqCst);
+    });
+    object.free();
+    thread::sleep(Duration::from_millis(50));
+    assert!(!called.load(Ordering::SeqCst));
+}
+
+// Never ready. Sets `dropped` when the executor drops it.
+struct Forever {
+    dropped: Arc<AtomicBool>,
+}
+
+impl Future for Forever {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, _: &mut Context) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+impl Drop for Forever {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn pending_future_is_dropped_after_free() {
+    set_executor(ThreadExecutor);
+    let object = Object::new();
+    let dropped = Arc::new(AtomicBool::new(false));
+    let future = Forever {
+        dropped: dropped.clone(),
+    };
+    object.emit().spawn(future, |_: &mut Ticker, _| {}).unwrap();
+    object.free();
+    // the executor is woken and drops the future
+    let start = Instant::now();
+    while !dropped.load(Ordering::SeqCst) && start.elapsed() < Duration::from_secs(5) {
+        thread::yield_now();
+    }
+    assert!(dropped.load(Ordering::SeqCst));
+}
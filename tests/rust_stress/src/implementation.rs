@@ -0,0 +1,42 @@
+use interface::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+/// The number of threads that emit ticksChanged for each Ticker.
+pub const WORKERS: usize = 4;
+
+pub struct Ticker {
+    emit: TickerEmitter,
+    ticks: Arc<AtomicU64>,
+}
+
+impl TickerTrait for Ticker {
+    fn new(emit: TickerEmitter) -> Ticker {
+        let ticks = Arc::new(AtomicU64::new(0));
+        // the workers are not joined, so they can still be emitting while
+        // the object is freed
+        for _ in 0..WORKERS {
+            let emit = emit.clone();
+            let ticks = ticks.clone();
+            let token = emit.cancel_token();
+            thread::spawn(move || {
+                while !token.is_cancelled() {
+                    ticks.fetch_add(1, Ordering::SeqCst);
+                    emit.ticks_changed();
+                    thread::yield_now();
+                }
+            });
+        }
+        Ticker {
+            emit: emit,
+            ticks: ticks,
+        }
+    }
+    fn emit(&self) -> &TickerEmitter {
+        &self.emit
+    }
+    fn ticks(&self) -> u64 {
+        self.ticks.load(Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,441 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::c_void;
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+#[cfg(feature = "async")]
+use std::task::Wake;
+
+use implementation::*;
+
+/// Cancelled when the QObject of an emitter is deleted. Worker threads can
+/// check it, wait on it or await it to stop in time.
+#[derive(Clone)]
+pub struct CancelToken {
+    state: Arc<CancelState>,
+}
+
+struct CancelState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+    condvar: Condvar,
+}
+
+impl Default for CancelToken {
+    fn default() -> CancelToken {
+        CancelToken {
+            state: Arc::new(CancelState {
+                cancelled: AtomicBool::new(false),
+                wakers: Mutex::new(Vec::new()),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+}
+
+impl CancelToken {
+    fn cancel(&self) {
+        let wakers: Vec<Waker> = {
+            let mut wakers = self.state.wakers.lock().unwrap();
+            self.state.cancelled.store(true, Ordering::SeqCst);
+            self.state.condvar.notify_all();
+            wakers.drain(..).collect()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+    /// Sleep until the token is cancelled or `timeout` has passed. Returns
+    /// `true` when the token is cancelled.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let wakers = self.state.wakers.lock().unwrap();
+        let _ = self.state.condvar
+            .wait_timeout_while(wakers, timeout, |_| !self.is_cancelled())
+            .unwrap();
+        self.is_cancelled()
+    }
+    /// A future that is ready when the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled { token: self.clone() }
+    }
+    // Keep `waker` to wake it when the token is cancelled. Returns `true`
+    // when the token already is.
+    fn register(&self, waker: &Waker) -> bool {
+        let mut wakers = self.state.wakers.lock().unwrap();
+        if self.is_cancelled() {
+            return true;
+        }
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+        false
+    }
+    #[cfg(feature = "async")]
+    fn unregister(&self, waker: &Waker) {
+        self.state.wakers.lock().unwrap().retain(|w| !w.will_wake(waker));
+    }
+}
+
+/// The future of `CancelToken::cancelled`.
+pub struct Cancelled {
+    token: CancelToken,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.token.register(cx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Worker threads that are cancelled and joined when this is dropped. Keep
+/// it in a field of the Rust object and its threads end with the object.
+pub struct Workers {
+    token: CancelToken,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Workers {
+    pub fn new(token: CancelToken) -> Workers {
+        Workers {
+            token: token,
+            threads: Vec::new(),
+        }
+    }
+    /// Run `f` in a new thread. `f` should return soon after the token that
+    /// it gets is cancelled.
+    pub fn spawn<F: FnOnce(CancelToken) + Send + 'static>(&mut self, f: F) {
+        self.threads.retain(|t| !t.is_finished());
+        let token = self.token.clone();
+        self.threads.push(thread::spawn(move || f(token)));
+    }
+}
+
+impl Drop for Workers {
+    fn drop(&mut self) {
+        self.token.cancel();
+        for t in self.threads.drain(..) {
+            let _ = t.join();
+        }
+    }
+}
+
+/// Runs the futures of `spawn` on the emitters, for example on the thread
+/// pool of an async runtime. Set one with `set_executor`.
+#[cfg(feature = "async")]
+pub trait Executor: Send + Sync {
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>);
+}
+
+#[cfg(feature = "async")]
+static EXECUTOR: Mutex<Option<Arc<dyn Executor>>> = Mutex::new(None);
+
+/// Set the executor that runs the futures of `spawn`.
+#[cfg(feature = "async")]
+pub fn set_executor<E: Executor + 'static>(executor: E) {
+    *EXECUTOR.lock().unwrap() = Some(Arc::new(executor));
+}
+
+/// The error of `spawn` when `set_executor` was not called.
+#[cfg(feature = "async")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoExecutor;
+
+#[cfg(feature = "async")]
+impl ::std::fmt::Display for NoExecutor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "set_executor() was not called")
+    }
+}
+
+#[cfg(feature = "async")]
+impl ::std::error::Error for NoExecutor {}
+
+// A future on the executor that hands its output to `done`. Its waker is
+// woken when the object is deleted, so that it is dropped without finishing.
+#[cfg(feature = "async")]
+struct Completion<F: Future> {
+    future: Pin<Box<F>>,
+    done: Option<Box<dyn FnOnce(F::Output) + Send>>,
+    token: CancelToken,
+}
+
+#[cfg(feature = "async")]
+impl<F: Future> Future for Completion<F> {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.token.register(cx.waker()) {
+            return Poll::Ready(());
+        }
+        match self.future.as_mut().poll(cx) {
+            Poll::Ready(v) => {
+                self.token.unregister(cx.waker());
+                if let Some(done) = self.done.take() {
+                    done(v);
+                }
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+// A future that is polled in the thread of the QObject of type `O`. Waking
+// it posts the next poll to that thread with `post`.
+#[cfg(feature = "async")]
+struct GuiTask<O, T> {
+    state: Mutex<Option<(
+        Pin<Box<dyn Future<Output = T> + Send>>,
+        Box<dyn FnOnce(&mut O, T) + Send>,
+    )>>,
+    post: Box<dyn Fn(Box<dyn FnOnce(&mut O) + Send>) + Send + Sync>,
+}
+
+#[cfg(feature = "async")]
+impl<O: 'static, T: 'static> GuiTask<O, T> {
+    fn poll(self: Arc<Self>, o: &mut O) {
+        let waker = Waker::from(self.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut state = self.state.lock().unwrap();
+        let v = match *state {
+            Some((ref mut future, _)) => match future.as_mut().poll(&mut cx) {
+                Poll::Ready(v) => v,
+                Poll::Pending => return,
+            },
+            None => return,
+        };
+        let (_, then) = state.take().unwrap();
+        drop(state);
+        then(o, v);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<O: 'static, T: 'static> Wake for GuiTask<O, T> {
+    fn wake(self: Arc<Self>) {
+        let task = self.clone();
+        (self.post)(Box::new(move |o| task.poll(o)));
+    }
+}
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xd47326da137cf13d
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct TickerQObject {}
+
+#[derive(Clone)]
+pub struct TickerEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<TickerQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) cancel: CancelToken,
+    pub(crate) run_on_gui_thread: fn(*const TickerQObject, *mut c_void),
+    pub(crate) ticks_changed: fn(*const TickerQObject),
+}
+
+impl TickerEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const TickerQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by ticker_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.cancel.cancel();
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Ticker) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    /// The token that is cancelled when the QObject is deleted.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+    /// Run `future` on the executor that was set with `set_executor`. When
+    /// it completes, `then` gets its output and `&mut` access to the object
+    /// in the thread of the QObject. Neither happens when the QObject is
+    /// deleted first. Fails when no executor was set.
+    #[cfg(feature = "async")]
+    pub fn spawn<F, C>(&self, future: F, then: C) -> Result<(), NoExecutor>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+        C: FnOnce(&mut Ticker, F::Output) + Send + 'static,
+    {
+        let executor = EXECUTOR.lock().unwrap().clone().ok_or(NoExecutor)?;
+        let emit = self.clone();
+        executor.spawn(Box::pin(Completion {
+            future: Box::pin(future),
+            done: Some(Box::new(move |v| {
+                emit.run_on_gui_thread(Box::new(move |o| then(o, v)));
+            })),
+            token: self.cancel.clone(),
+        }));
+        Ok(())
+    }
+    /// Poll `future` in the thread of the QObject, driven by its event loop.
+    /// When it completes, `then` gets its output and `&mut` access to the
+    /// object. The future is dropped when the QObject is deleted first.
+    #[cfg(feature = "async")]
+    pub fn spawn_local<F, C>(&self, future: F, then: C)
+    where
+        F: Future + Send + 'static,
+        F::Output: 'static,
+        C: FnOnce(&mut Ticker, F::Output) + Send + 'static,
+    {
+        let emit = self.clone();
+        let task = Arc::new(GuiTask::<Ticker, F::Output> {
+            state: Mutex::new(Some((Box::pin(future), Box::new(then)))),
+            post: Box::new(move |f| emit.run_on_gui_thread(f)),
+        });
+        task.wake();
+    }
+    pub fn ticks_changed(&self) {
+        self.with_qobject(|ptr| (self.ticks_changed)(ptr));
+    }
+}
+
+pub trait TickerTrait {
+    fn new(emit: TickerEmitter) -> Self;
+    fn emit(&self) -> &TickerEmitter;
+    fn ticks(&self) -> u64;
+}
+
+#[no_mangle]
+pub extern "C" fn ticker_new(
+    ticker: *mut TickerQObject,
+    ticker_run_on_gui_thread: fn(*const TickerQObject, *mut c_void),
+    ticks_changed: fn(*const TickerQObject),
+) -> *mut Ticker {
+    let ticker_emit = TickerEmitter {
+        qobject: Arc::new(AtomicPtr::new(ticker)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        cancel: Default::default(),
+        run_on_gui_thread: ticker_run_on_gui_thread,
+        ticks_changed: ticks_changed,
+    };
+    let d_ticker = Ticker::new(ticker_emit);
+    Box::into_raw(Box::new(d_ticker))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ticker_free(ptr: *mut Ticker) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ticker_run_task(ptr: *mut Ticker, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Ticker) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ticker_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Ticker) + Send>));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ticker_ticks_get(ptr: *const Ticker) -> u64 {
+    (&*ptr).ticks()
+}
+
+/// Owns a Ticker for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct TickerFixture {
+    pub ptr: *mut Ticker,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl TickerFixture {
+    pub fn new() -> TickerFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = ticker_new(
+            q as *mut TickerQObject,
+            |_, task| unsafe { ticker_drop_task(task) },
+            |q| TestQObject::emit(q, "ticksChanged"),
+        );
+        TickerFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Ticker {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Ticker {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ticker_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TickerFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -0,0 +1,9 @@
+extern crate libc;
+
+pub mod interface;
+mod implementation;
+
+#[cfg(test)]
+mod tests;
+#[cfg(all(test, feature = "async"))]
+mod async_tests;
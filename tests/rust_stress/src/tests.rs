@@ -0,0 +1,164 @@
+// Frees objects while worker threads emit their signals. Once ticker_free
+// has returned, no callback may be called for the deleted QObject.
+
+use implementation::WORKERS;
+use interface::*;
+use libc::c_void;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 200;
+
+// Stands in for the QObject in the races with ticker_free. Unlike the
+// TestQObject of TickerFixture, its slot is slow and it is never really
+// deleted, so that calls that arrive too late can be counted.
+struct Receiver {
+    alive: AtomicBool,
+    calls: AtomicUsize,
+    late_calls: AtomicUsize,
+}
+
+fn receiver<'a>(qobject: *const TickerQObject) -> &'a Receiver {
+    unsafe { &*(qobject as *const Receiver) }
+}
+
+fn ticks_changed(qobject: *const TickerQObject) {
+    let r = receiver(qobject);
+    r.calls.fetch_add(1, Ordering::SeqCst);
+    // a slow slot makes a race with ticker_free more likely
+    thread::yield_now();
+    if !r.alive.load(Ordering::SeqCst) {
+        r.late_calls.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn run_on_gui_thread(_: *const TickerQObject, task: *mut c_void) {
+    unsafe { ticker_drop_task(task) };
+}
+
+fn new_receiver() -> Box<Receiver> {
+    Box::new(Receiver {
+        alive: AtomicBool::new(true),
+        calls: AtomicUsize::new(0),
+        late_calls: AtomicUsize::new(0),
+    })
+}
+
+#[test]
+fn no_signals_after_free() {
+    let mut receivers = Vec::new();
+    for _ in 0..ROUNDS {
+        let r = new_receiver();
+        let qobject = &*r as *const Receiver as *mut TickerQObject;
+        let ticker = ticker_new(qobject, run_on_gui_thread, ticks_changed);
+        while r.calls.load(Ordering::SeqCst) < WORKERS {
+            thread::yield_now();
+        }
+        unsafe { ticker_free(ticker) };
+        r.alive.store(false, Ordering::SeqCst);
+        receivers.push(r);
+    }
+    // give the workers that are still running a chance to emit
+    thread::sleep(Duration::from_millis(50));
+    for r in &receivers {
+        assert_eq!(r.late_calls.load(Ordering::SeqCst), 0);
+    }
+}
+
+#[test]
+fn workers_fall_silent_after_free() {
+    let r = new_receiver();
+    let qobject = &*r as *const Receiver as *mut TickerQObject;
+    let ticker = ticker_new(qobject, run_on_gui_thread, ticks_changed);
+    while r.calls.load(Ordering::SeqCst) < 10000 {
+        thread::yield_now();
+    }
+    unsafe { ticker_free(ticker) };
+    // the workers only count ticks while the ticker exists
+    let calls = r.calls.load(Ordering::SeqCst);
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(r.calls.load(Ordering::SeqCst), calls);
+}
+
+// The cancel token of an emitter is cancelled by ticker_free.
+
+#[test]
+fn free_cancels_the_token() {
+    let mut t = TickerFixture::new();
+    let token = t.get().emit().cancel_token();
+    assert!(!token.is_cancelled());
+    t.free();
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn free_ends_the_wait() {
+    let mut t = TickerFixture::new();
+    let token = t.get().emit().cancel_token();
+    let start = Instant::now();
+    let waiter = thread::spawn(move || token.wait_timeout(Duration::from_secs(60)));
+    thread::sleep(Duration::from_millis(10));
+    t.free();
+    assert!(waiter.join().unwrap());
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+#[test]
+fn wait_times_out() {
+    let token = CancelToken::default();
+    assert!(!token.wait_timeout(Duration::from_millis(1)));
+}
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// a minimal executor for one future
+fn block_on<F: Future>(mut f: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut f = unsafe { Pin::new_unchecked(&mut f) };
+    loop {
+        if let Poll::Ready(v) = f.as_mut().poll(&mut cx) {
+            return v;
+        }
+        thread::park();
+    }
+}
+
+#[test]
+fn free_wakes_the_future() {
+    let mut t = TickerFixture::new();
+    let token = t.get().emit().cancel_token();
+    let waiter = thread::spawn(move || {
+        block_on(token.cancelled());
+        token.is_cancelled()
+    });
+    thread::sleep(Duration::from_millis(10));
+    t.free();
+    assert!(waiter.join().unwrap());
+}
+
+#[test]
+fn workers_are_joined_on_drop() {
+    let done = Arc::new(AtomicUsize::new(0));
+    let mut workers = Workers::new(CancelToken::default());
+    for _ in 0..WORKERS {
+        let done = done.clone();
+        workers.spawn(move |token| {
+            while !token.wait_timeout(Duration::from_secs(60)) {}
+            done.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    drop(workers);
+    assert_eq!(done.load(Ordering::SeqCst), WORKERS);
+}
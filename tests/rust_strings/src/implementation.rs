@@ -0,0 +1,162 @@
+use interface::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(Debug, PartialEq, Serialize, Deserialize))]
+pub struct StringsItem {
+    line: String,
+    comment: Option<String>,
+    note: String,
+}
+
+pub struct Strings {
+    emit: StringsEmitter,
+    model: StringsList,
+    text: String,
+    title: String,
+    subtitle: Option<String>,
+    buffer: Vec<u16>,
+    bytes: Vec<u8>,
+    list: Vec<StringsItem>,
+    // the values that were rejected because they were not valid UTF-16
+    pub(crate) errors: Vec<Utf16Error>,
+}
+
+impl StringsTrait for Strings {
+    fn new(emit: StringsEmitter, model: StringsList) -> Strings {
+        Strings {
+            emit: emit,
+            model: model,
+            text: String::new(),
+            title: String::new(),
+            subtitle: None,
+            buffer: Vec::new(),
+            bytes: Vec::new(),
+            list: vec![StringsItem {
+                line: "first".into(),
+                comment: None,
+                note: String::new(),
+            }],
+            errors: Vec::new(),
+        }
+    }
+    fn emit(&self) -> &StringsEmitter {
+        &self.emit
+    }
+    fn text(&self) -> &str {
+        &self.text
+    }
+    fn set_text(&mut self, value: String) {
+        self.text = value;
+        self.emit.text_changed();
+    }
+    fn title(&self) -> &str {
+        &self.title
+    }
+    fn set_title(&mut self, value: Result<String, Utf16Error>) {
+        match value {
+            Ok(value) => {
+                self.title = value;
+                self.emit.title_changed();
+            }
+            Err(e) => self.errors.push(e),
+        }
+    }
+    fn subtitle(&self) -> Option<&str> {
+        self.subtitle.as_ref().map(|s| &s[..])
+    }
+    fn set_subtitle(&mut self, value: Result<Option<String>, Utf16Error>) {
+        match value {
+            Ok(value) => {
+                self.subtitle = value;
+                self.emit.subtitle_changed();
+            }
+            Err(e) => self.errors.push(e),
+        }
+    }
+    fn buffer(&self) -> &[u16] {
+        &self.buffer
+    }
+    fn set_buffer(&mut self, value: &[u16]) {
+        self.buffer = value.to_vec();
+        self.emit.buffer_changed();
+    }
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    fn set_bytes(&mut self, value: &[u8]) {
+        self.bytes = value.to_vec();
+        self.emit.bytes_changed();
+    }
+    fn length(&self, text: String) -> u32 {
+        text.chars().count() as u32
+    }
+    fn append(&mut self, line: Result<String, Utf16Error>) -> bool {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                self.errors.push(e);
+                return false;
+            }
+        };
+        let row = self.list.len();
+        self.model.begin_insert_rows(row, row);
+        self.list.push(StringsItem {
+            line: line,
+            comment: None,
+            note: String::new(),
+        });
+        self.model.end_insert_rows();
+        true
+    }
+    fn row_count(&self) -> usize {
+        self.list.len()
+    }
+    fn line(&self, item: usize) -> &str {
+        &self.list[item].line
+    }
+    fn set_line(&mut self, item: usize, v: Result<String, Utf16Error>) -> bool {
+        match v {
+            Ok(v) => {
+                self.list[item].line = v;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+    fn comment(&self, item: usize) -> Option<&str> {
+        self.list[item].comment.as_ref().map(|s| &s[..])
+    }
+    fn set_comment(&mut self, item: usize, v: Result<Option<String>, Utf16Error>) -> bool {
+        match v {
+            Ok(v) => {
+                self.list[item].comment = v;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+    fn note(&self, item: usize) -> &str {
+        &self.list[item].note
+    }
+    fn set_note(&mut self, item: usize, v: String) -> bool {
+        self.list[item].note = v;
+        true
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Strings {
+    /// The rows, for example to serialize them.
+    pub fn rows(&self) -> &[StringsItem] {
+        &self.list
+    }
+    /// Replace all rows, for example with deserialized ones. The model is
+    /// reset.
+    pub fn set_rows(&mut self, rows: Vec<StringsItem>) {
+        self.model.begin_reset_model();
+        self.list = rows;
+        self.model.end_reset_model();
+    }
+}
@@ -0,0 +1,690 @@
+/* generated by rust_qt_binding_generator */
+#![allow(unknown_lints)]
+#![allow(mutex_atomic, needless_pass_by_value)]
+use libc::{c_char, c_ushort, c_int, c_void};
+use std::slice;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
+
+use implementation::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+
+#[repr(C)]
+pub struct COption<T> {
+    data: T,
+    some: bool,
+}
+
+impl<T> From<Option<T>> for COption<T>
+where
+    T: Default,
+{
+    fn from(t: Option<T>) -> COption<T> {
+        if let Some(v) = t {
+            COption {
+                data: v,
+                some: true,
+            }
+        } else {
+            COption {
+                data: T::default(),
+                some: false,
+            }
+        }
+    }
+}
+
+
+pub enum QString {}
+
+fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    let characters = decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
+    s.clear();
+    s.extend(characters);
+}
+
+
+/// A QString that is not valid UTF-16 because it contains an unpaired
+/// surrogate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utf16Error {
+    /// The string with U+FFFD in place of each unpaired surrogate.
+    pub lossy: String,
+}
+
+impl ::std::fmt::Display for Utf16Error {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid UTF-16: unpaired surrogate in {:?}", self.lossy)
+    }
+}
+
+impl ::std::error::Error for Utf16Error {}
+
+fn string_from_utf16_strict(str: *const c_ushort, len: c_int) -> Result<String, Utf16Error> {
+    let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
+    String::from_utf16(utf16).map_err(|_| Utf16Error {
+        lossy: String::from_utf16_lossy(utf16),
+    })
+}
+
+/// Decode the UTF-16 of a borrowed QString. Each unpaired surrogate becomes
+/// U+FFFD.
+pub fn utf16_to_string(utf16: &[u16]) -> String {
+    decode_utf16(utf16.iter().cloned())
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+
+
+pub enum QByteArray {}
+
+
+#[repr(C)]
+pub enum SortOrder {
+    Ascending = 0,
+    Descending = 1,
+}
+
+#[repr(C)]
+pub struct QModelIndex {
+    row: c_int,
+    internal_id: usize,
+}
+
+
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
+fn to_usize(n: c_int) -> usize {
+    if n < 0 {
+        overflow(Overflow::Negative(n));
+        return 0;
+    }
+    n as usize
+}
+
+fn to_c_int(n: usize) -> c_int {
+    if n > c_int::max_value() as usize {
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
+    }
+    n as c_int
+}
+
+
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0xcf9b9e363bb9c25c
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub struct StringsQObject {}
+
+#[derive(Clone)]
+pub struct StringsEmitter {
+    pub(crate) qobject: Arc<AtomicPtr<StringsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const StringsQObject, *mut c_void),
+    pub(crate) text_changed: fn(*const StringsQObject),
+    pub(crate) title_changed: fn(*const StringsQObject),
+    pub(crate) subtitle_changed: fn(*const StringsQObject),
+    pub(crate) buffer_changed: fn(*const StringsQObject),
+    pub(crate) bytes_changed: fn(*const StringsQObject),
+    pub(crate) new_data_ready: fn(*const StringsQObject),
+}
+
+impl StringsEmitter {
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const StringsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by strings_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Strings) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
+    }
+    pub fn text_changed(&self) {
+        self.with_qobject(|ptr| (self.text_changed)(ptr));
+    }
+    pub fn title_changed(&self) {
+        self.with_qobject(|ptr| (self.title_changed)(ptr));
+    }
+    pub fn subtitle_changed(&self) {
+        self.with_qobject(|ptr| (self.subtitle_changed)(ptr));
+    }
+    pub fn buffer_changed(&self) {
+        self.with_qobject(|ptr| (self.buffer_changed)(ptr));
+    }
+    pub fn bytes_changed(&self) {
+        self.with_qobject(|ptr| (self.bytes_changed)(ptr));
+    }
+    pub fn new_data_ready(&self) {
+        self.with_qobject(|ptr| (self.new_data_ready)(ptr));
+    }
+}
+
+pub struct StringsList {
+    pub(crate) qobject: *const StringsQObject,
+    pub(crate) data_changed: fn(*const StringsQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const StringsQObject),
+    pub(crate) end_reset_model: fn(*const StringsQObject),
+    pub(crate) begin_insert_rows: fn(*const StringsQObject, usize, usize),
+    pub(crate) end_insert_rows: fn(*const StringsQObject),
+    pub(crate) begin_remove_rows: fn(*const StringsQObject, usize, usize),
+    pub(crate) end_remove_rows: fn(*const StringsQObject),
+}
+
+impl StringsList {
+    pub fn data_changed(&self, first: usize, last: usize) {
+        (self.data_changed)(self.qobject, first, last);
+    }
+    pub fn begin_reset_model(&self) {
+        (self.begin_reset_model)(self.qobject);
+    }
+    pub fn end_reset_model(&self) {
+        (self.end_reset_model)(self.qobject);
+    }
+    pub fn begin_insert_rows(&self, first: usize, last: usize) {
+        (self.begin_insert_rows)(self.qobject, first, last);
+    }
+    pub fn end_insert_rows(&self) {
+        (self.end_insert_rows)(self.qobject);
+    }
+    pub fn begin_remove_rows(&self, first: usize, last: usize) {
+        (self.begin_remove_rows)(self.qobject, first, last);
+    }
+    pub fn end_remove_rows(&self) {
+        (self.end_remove_rows)(self.qobject);
+    }
+}
+
+pub trait StringsTrait {
+    fn new(emit: StringsEmitter, model: StringsList) -> Self;
+    fn emit(&self) -> &StringsEmitter;
+    fn text(&self) -> &str;
+    fn set_text(&mut self, value: String);
+    fn title(&self) -> &str;
+    fn set_title(&mut self, value: Result<String, Utf16Error>);
+    fn subtitle(&self) -> Option<&str>;
+    fn set_subtitle(&mut self, value: Result<Option<String>, Utf16Error>);
+    fn buffer(&self) -> &[u16];
+    fn set_buffer(&mut self, value: &[u16]);
+    fn bytes(&self) -> &[u8];
+    fn set_bytes(&mut self, value: &[u8]);
+    fn length(&self, text: String) -> u32;
+    fn append(&mut self, line: Result<String, Utf16Error>) -> bool;
+    fn row_count(&self) -> usize;
+    fn insert_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn remove_rows(&mut self, _row: usize, _count: usize) -> bool { false }
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    fn fetch_more(&mut self) {}
+    fn sort(&mut self, u8, SortOrder) {}
+    fn line(&self, item: usize) -> &str;
+    fn set_line(&mut self, item: usize, Result<String, Utf16Error>) -> bool;
+    fn comment(&self, item: usize) -> Option<&str>;
+    fn set_comment(&mut self, item: usize, Result<Option<String>, Utf16Error>) -> bool;
+    fn note(&self, item: usize) -> &str;
+    fn set_note(&mut self, item: usize, String) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn strings_new(
+    strings: *mut StringsQObject,
+    strings_run_on_gui_thread: fn(*const StringsQObject, *mut c_void),
+    text_changed: fn(*const StringsQObject),
+    title_changed: fn(*const StringsQObject),
+    subtitle_changed: fn(*const StringsQObject),
+    buffer_changed: fn(*const StringsQObject),
+    bytes_changed: fn(*const StringsQObject),
+    strings_new_data_ready: fn(*const StringsQObject),
+    strings_data_changed: fn(*const StringsQObject, usize, usize),
+    strings_begin_reset_model: fn(*const StringsQObject),
+    strings_end_reset_model: fn(*const StringsQObject),
+    strings_begin_insert_rows: fn(*const StringsQObject, usize, usize),
+    strings_end_insert_rows: fn(*const StringsQObject),
+    strings_begin_remove_rows: fn(*const StringsQObject, usize, usize),
+    strings_end_remove_rows: fn(*const StringsQObject),
+) -> *mut Strings {
+    let strings_emit = StringsEmitter {
+        qobject: Arc::new(AtomicPtr::new(strings)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: strings_run_on_gui_thread,
+        text_changed: text_changed,
+        title_changed: title_changed,
+        subtitle_changed: subtitle_changed,
+        buffer_changed: buffer_changed,
+        bytes_changed: bytes_changed,
+        new_data_ready: strings_new_data_ready,
+    };
+    let model = StringsList {
+        qobject: strings,
+        data_changed: strings_data_changed,
+        begin_reset_model: strings_begin_reset_model,
+        end_reset_model: strings_end_reset_model,
+        begin_insert_rows: strings_begin_insert_rows,
+        end_insert_rows: strings_end_insert_rows,
+        begin_remove_rows: strings_begin_remove_rows,
+        end_remove_rows: strings_end_remove_rows,
+    };
+    let d_log = Strings::new(strings_emit, model);
+    Box::into_raw(Box::new(d_log))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_free(ptr: *mut Strings) {
+    Box::from_raw(ptr).emit().clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_run_task(ptr: *mut Strings, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Strings) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Strings) + Send>));
+}
+
+#[no_mangle]
+pub extern "C" fn strings_text_get(
+    ptr: *const Strings,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.text();
+    let s: *const c_char = v.as_ptr() as (*const c_char);
+    set(p, s, to_c_int(v.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn strings_text_set(ptr: *mut Strings, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let mut s = String::new();
+    set_string_from_utf16(&mut s, v, len);
+    o.set_text(s);
+}
+
+#[no_mangle]
+pub extern "C" fn strings_title_get(
+    ptr: *const Strings,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.title();
+    let s: *const c_char = v.as_ptr() as (*const c_char);
+    set(p, s, to_c_int(v.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn strings_title_set(ptr: *mut Strings, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let s = string_from_utf16_strict(v, len);
+    o.set_title(s);
+}
+
+#[no_mangle]
+pub extern "C" fn strings_subtitle_get(
+    ptr: *const Strings,
+    p: *mut QString,
+    set: fn(*mut QString, *const c_char, c_int),
+) {
+    let o = unsafe { &*ptr };
+    let v = o.subtitle();
+    if let Some(v) = v {
+        let s: *const c_char = v.as_ptr() as (*const c_char);
+        set(p, s, to_c_int(v.len()));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn strings_subtitle_set(ptr: *mut Strings, v: *const c_ushort, len: c_int) {
+    let o = unsafe { &mut *ptr };
+    let s = string_from_utf16_strict(v, len);
+    o.set_subtitle(s.map(Some));
+}
+
+#[no_mangle]
+pub extern "C" fn strings_subtitle_set_none(ptr: *mut Strings) {
+    let o = unsafe { &mut *ptr };
+    o.set_subtitle(Ok(None));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_buffer_get(ptr: *const Strings, len: *mut c_int) -> *const c_ushort {
+    let v = (&*ptr).buffer();
+    *len = to_c_int(v.len());
+    v.as_ptr() as *const c_ushort
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_buffer_set(ptr: *mut Strings, v: *const c_ushort, len: c_int) {
+    let v = slice::from_raw_parts(v as *const u16, to_usize(len));
+    (&mut *ptr).set_buffer(v);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_bytes_get(ptr: *const Strings, len: *mut c_int) -> *const c_char {
+    let v = (&*ptr).bytes();
+    *len = to_c_int(v.len());
+    v.as_ptr() as *const c_char
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_bytes_set(ptr: *mut Strings, v: *const c_char, len: c_int) {
+    let v = slice::from_raw_parts(v as *const u8, to_usize(len));
+    (&mut *ptr).set_bytes(v);
+}
+
+#[no_mangle]
+pub extern "C" fn strings_length(ptr: *const Strings, text_str: *const c_ushort, text_len: c_int) -> u32 {
+    let mut text = String::new();
+    set_string_from_utf16(&mut text, text_str, text_len);
+    let o = unsafe { &*ptr };
+    let r = o.length(text);
+    r
+}
+
+#[no_mangle]
+pub extern "C" fn strings_append(ptr: *mut Strings, line_str: *const c_ushort, line_len: c_int) -> bool {
+    let line = string_from_utf16_strict(line_str, line_len);
+    let o = unsafe { &mut *ptr };
+    let r = o.append(line);
+    r
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_row_count(ptr: *const Strings) -> c_int {
+    to_c_int((&*ptr).row_count())
+}
+#[no_mangle]
+pub unsafe extern "C" fn strings_insert_rows(ptr: *mut Strings, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is inserted
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).insert_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn strings_remove_rows(ptr: *mut Strings, row: c_int, count: c_int) -> bool {
+    // a negative row or count is reported and nothing is removed
+    let clamped = row < 0 || count < 0;
+    let (row, count) = (to_usize(row), to_usize(count));
+    !clamped && (&mut *ptr).remove_rows(row, count)
+}
+#[no_mangle]
+pub unsafe extern "C" fn strings_can_fetch_more(ptr: *const Strings) -> bool {
+    (&*ptr).can_fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn strings_fetch_more(ptr: *mut Strings) {
+    (&mut *ptr).fetch_more()
+}
+#[no_mangle]
+pub unsafe extern "C" fn strings_sort(
+    ptr: *mut Strings,
+    column: u8,
+    order: SortOrder,
+) {
+    (&mut *ptr).sort(column, order)
+}
+
+#[no_mangle]
+pub extern "C" fn strings_data_line(
+    ptr: *const Strings, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.line(to_usize(row));
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn strings_set_data_line(
+    ptr: *mut Strings, row: c_int,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let v = string_from_utf16_strict(s, len);
+    o.set_line(to_usize(row), v)
+}
+
+#[no_mangle]
+pub extern "C" fn strings_data_comment(
+    ptr: *const Strings, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.comment(to_usize(row));
+    if let Some(data) = data {
+        let s: *const c_char = data.as_ptr() as (*const c_char);
+        set(d, s, to_c_int(data.len()));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn strings_set_data_comment(
+    ptr: *mut Strings, row: c_int,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let v = string_from_utf16_strict(s, len);
+    o.set_comment(to_usize(row), v.map(Some))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strings_set_data_comment_none(ptr: *mut Strings, row: c_int) -> bool {
+    (&mut *ptr).set_comment(to_usize(row), Ok(None))
+}
+
+#[no_mangle]
+pub extern "C" fn strings_data_note(
+    ptr: *const Strings, row: c_int,
+    d: *mut QString,
+    set: fn(*mut QString, *const c_char, len: c_int),
+) {
+    let o = unsafe { &*ptr };
+    let data = o.note(to_usize(row));
+    let s: *const c_char = data.as_ptr() as (*const c_char);
+    set(d, s, to_c_int(data.len()));
+}
+
+#[no_mangle]
+pub extern "C" fn strings_set_data_note(
+    ptr: *mut Strings, row: c_int,
+    s: *const c_ushort, len: c_int,
+) -> bool {
+    let o = unsafe { &mut *ptr };
+    let mut v = String::new();
+    set_string_from_utf16(&mut v, s, len);
+    o.set_note(to_usize(row), v)
+}
+
+/// The values of the writable properties of Strings and of its object
+/// properties. `restore` passes them to the setters, which emit the change
+/// signals.
+/// The rows are read with `rows()` and restored with `set_rows()`, which
+/// resets the model.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StringsSnapshot {
+    pub text: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub buffer: Vec<u16>,
+    pub bytes: Vec<u8>,
+    pub rows: Vec<StringsItem>,
+}
+
+#[cfg(feature = "serde")]
+impl StringsSnapshot {
+    /// Read the values with the getters.
+    pub fn take(o: &Strings) -> StringsSnapshot {
+        StringsSnapshot {
+            text: o.text().to_owned(),
+            title: o.title().to_owned(),
+            subtitle: o.subtitle().map(|v| v.to_owned()),
+            buffer: o.buffer().to_owned(),
+            bytes: o.bytes().to_owned(),
+            rows: o.rows().to_vec(),
+        }
+    }
+    /// Pass the values to the setters.
+    pub fn restore(&self, o: &mut Strings) {
+        o.set_text(self.text.clone());
+        o.set_title(Ok(self.title.clone()));
+        o.set_subtitle(Ok(self.subtitle.clone()));
+        o.set_buffer(&self.buffer);
+        o.set_bytes(&self.bytes);
+        o.set_rows(self.rows.clone());
+    }
+}
+
+/// Owns a Strings for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct StringsFixture {
+    pub ptr: *mut Strings,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl StringsFixture {
+    pub fn new() -> StringsFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = strings_new(
+            q as *mut StringsQObject,
+            |_, task| unsafe { strings_drop_task(task) },
+            |q| TestQObject::emit(q, "textChanged"),
+            |q| TestQObject::emit(q, "titleChanged"),
+            |q| TestQObject::emit(q, "subtitleChanged"),
+            |q| TestQObject::emit(q, "bufferChanged"),
+            |q| TestQObject::emit(q, "bytesChanged"),
+            |q| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        StringsFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Strings {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Strings {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { strings_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for StringsFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -0,0 +1,13 @@
+extern crate libc;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+pub mod interface;
+mod implementation;
+
+#[cfg(test)]
+mod tests;
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests;
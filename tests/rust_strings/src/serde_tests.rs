@@ -0,0 +1,74 @@
+// Saves the properties and rows of an object as JSON and restores them into
+// another object. Restoring goes through the setters, so each property emits
+// its change signal, and replacing the rows resets the model.
+
+use implementation::StringsItem;
+use interface::*;
+use serde_json;
+
+#[test]
+fn snapshot_round_trip() {
+    let mut a = StringsFixture::new();
+    a.get_mut().set_text("text".into());
+    a.get_mut().set_title(Ok("title".into()));
+    a.get_mut().set_subtitle(Ok(Some("subtitle".into())));
+    // a lone surrogate survives in a borrowed string
+    a.get_mut().set_buffer(&[0x61, 0xD800]);
+    a.get_mut().set_bytes(&[0, 0xFF]);
+    let line = utf16("second");
+    assert!(strings_append(a.ptr, line.as_ptr(), line.len() as i32));
+
+    let json = serde_json::to_string(&StringsSnapshot::take(a.get())).unwrap();
+    let snapshot: StringsSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(snapshot, StringsSnapshot::take(a.get()));
+
+    let mut b = StringsFixture::new();
+    snapshot.restore(b.get_mut());
+    assert_eq!(StringsSnapshot::take(b.get()), snapshot);
+    assert_eq!(b.get().subtitle(), Some("subtitle"));
+    assert_eq!(b.get().buffer(), &[0x61, 0xD800]);
+    assert_eq!(b.get().row_count(), 2);
+    assert_eq!(b.get().line(1), "second");
+    assert_eq!(
+        b.qobject.signals(),
+        vec![
+            "textChanged",
+            "titleChanged",
+            "subtitleChanged",
+            "bufferChanged",
+            "bytesChanged",
+            "beginResetModel",
+            "endResetModel",
+        ]
+    );
+}
+
+#[test]
+fn empty_optional_value() {
+    let a = StringsFixture::new();
+    let mut b = StringsFixture::new();
+    b.get_mut().set_subtitle(Ok(Some("old".into())));
+    StringsSnapshot::take(a.get()).restore(b.get_mut());
+    assert_eq!(b.get().subtitle(), None);
+    assert_eq!(StringsSnapshot::take(b.get()), StringsSnapshot::take(a.get()));
+}
+
+#[test]
+fn rows_round_trip() {
+    let a = StringsFixture::new();
+    let line = utf16("second");
+    assert!(strings_append(a.ptr, line.as_ptr(), line.len() as i32));
+    let json = serde_json::to_string(a.get().rows()).unwrap();
+    let rows: Vec<StringsItem> = serde_json::from_str(&json).unwrap();
+
+    let mut b = StringsFixture::new();
+    b.get_mut().set_rows(rows);
+    assert_eq!(b.qobject.signals(), vec!["beginResetModel", "endResetModel"]);
+    assert_eq!(b.get().row_count(), 2);
+    assert_eq!(b.get().line(0), "first");
+    assert_eq!(b.get().line(1), "second");
+}
+
+fn utf16(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
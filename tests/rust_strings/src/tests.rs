@@ -0,0 +1,132 @@
+// Feeds QStrings that are not valid UTF-16 through the generated setters
+// and functions. None of them may panic: by default an unpaired surrogate
+// becomes U+FFFD, a strict value reaches the implementation as an error.
+
+use interface::*;
+use libc::{c_char, c_int, c_ushort};
+
+// 'a', an unpaired high surrogate, 'b'
+const INVALID: &[u16] = &[0x61, 0xD800, 0x62];
+const LOSSY: &str = "a\u{FFFD}b";
+
+fn utf16(s: &str) -> Vec<u16> {
+    s.encode_utf16().collect()
+}
+
+fn ptr(s: &[u16]) -> *const c_ushort {
+    s.as_ptr()
+}
+
+fn len(s: &[u16]) -> c_int {
+    s.len() as c_int
+}
+
+#[test]
+fn property_setter_is_lossy() {
+    let o = StringsFixture::new();
+    strings_text_set(o.ptr, ptr(INVALID), len(INVALID));
+    assert_eq!(o.get().text(), LOSSY);
+    // a lone low surrogate at the start, a lone high one at the end
+    let ends = [0xDC00, 0x61, 0xD83D];
+    strings_text_set(o.ptr, ptr(&ends), len(&ends));
+    assert_eq!(o.get().text(), "\u{FFFD}a\u{FFFD}");
+    let valid = utf16("h\u{e9}llo \u{1F600}");
+    strings_text_set(o.ptr, ptr(&valid), len(&valid));
+    assert_eq!(o.get().text(), "h\u{e9}llo \u{1F600}");
+}
+
+#[test]
+fn strict_property_setter_gets_an_error() {
+    let o = StringsFixture::new();
+    let valid = utf16("title");
+    strings_title_set(o.ptr, ptr(&valid), len(&valid));
+    assert_eq!(o.get().title(), "title");
+    strings_title_set(o.ptr, ptr(INVALID), len(INVALID));
+    assert_eq!(o.get().title(), "title");
+    assert_eq!(o.get().errors, vec![Utf16Error { lossy: LOSSY.into() }]);
+    assert_eq!(
+        o.get().errors[0].to_string(),
+        "invalid UTF-16: unpaired surrogate in \"a\u{FFFD}b\""
+    );
+}
+
+#[test]
+fn strict_optional_property_setter_gets_an_error() {
+    let o = StringsFixture::new();
+    strings_subtitle_set(o.ptr, ptr(INVALID), len(INVALID));
+    assert_eq!(o.get().subtitle(), None);
+    assert_eq!(o.get().errors.len(), 1);
+    let valid = utf16("sub");
+    strings_subtitle_set(o.ptr, ptr(&valid), len(&valid));
+    assert_eq!(o.get().subtitle(), Some("sub"));
+    strings_subtitle_set_none(o.ptr);
+    assert_eq!(o.get().subtitle(), None);
+    assert_eq!(o.get().errors.len(), 1);
+}
+
+#[test]
+fn function_arguments() {
+    let o = StringsFixture::new();
+    assert_eq!(strings_length(o.ptr, ptr(INVALID), len(INVALID)), 3);
+    assert!(!strings_append(o.ptr, ptr(INVALID), len(INVALID)));
+    assert_eq!(o.get().row_count(), 1);
+    assert_eq!(o.get().errors, vec![Utf16Error { lossy: LOSSY.into() }]);
+    let valid = utf16("second");
+    assert!(strings_append(o.ptr, ptr(&valid), len(&valid)));
+    assert_eq!(o.get().row_count(), 2);
+    assert_eq!(o.get().line(1), "second");
+}
+
+#[test]
+fn item_setters() {
+    let o = StringsFixture::new();
+    assert!(!strings_set_data_line(o.ptr, 0, ptr(INVALID), len(INVALID)));
+    assert_eq!(o.get().line(0), "first");
+    assert!(!strings_set_data_comment(o.ptr, 0, ptr(INVALID), len(INVALID)));
+    assert_eq!(o.get().comment(0), None);
+    let valid = utf16("comment");
+    assert!(strings_set_data_comment(o.ptr, 0, ptr(&valid), len(&valid)));
+    assert_eq!(o.get().comment(0), Some("comment"));
+    assert!(unsafe { strings_set_data_comment_none(o.ptr, 0) });
+    assert_eq!(o.get().comment(0), None);
+    assert!(strings_set_data_note(o.ptr, 0, ptr(INVALID), len(INVALID)));
+    assert_eq!(o.get().note(0), LOSSY);
+}
+
+#[test]
+fn empty_string() {
+    let o = StringsFixture::new();
+    let empty: Vec<u16> = Vec::new();
+    strings_text_set(o.ptr, ptr(&empty), 0);
+    assert_eq!(o.get().text(), "");
+    strings_title_set(o.ptr, ptr(&empty), 0);
+    assert!(o.get().errors.is_empty());
+}
+
+// A borrowed value is passed as it is, including unpaired surrogates, and
+// the getter hands out the buffer of the implementation.
+#[test]
+fn borrowed_string_is_not_copied() {
+    let o = StringsFixture::new();
+    unsafe { strings_buffer_set(o.ptr, ptr(INVALID), len(INVALID)) };
+    assert_eq!(o.get().buffer(), INVALID);
+    let mut n: c_int = -1;
+    let p = unsafe { strings_buffer_get(o.ptr, &mut n) };
+    assert_eq!(p, o.get().buffer().as_ptr());
+    assert_eq!(n, len(INVALID));
+    assert_eq!(utf16_to_string(o.get().buffer()), LOSSY);
+}
+
+#[test]
+fn borrowed_bytes_are_not_copied() {
+    let o = StringsFixture::new();
+    let bytes = [0u8, 0xFF, 0x80, b'a'];
+    unsafe { strings_bytes_set(o.ptr, bytes.as_ptr() as *const c_char, 4) };
+    assert_eq!(o.get().bytes(), &bytes[..]);
+    let mut n: c_int = -1;
+    let p = unsafe { strings_bytes_get(o.ptr, &mut n) };
+    assert_eq!(p as *const u8, o.get().bytes().as_ptr());
+    assert_eq!(n, 4);
+    unsafe { strings_bytes_set(o.ptr, p, 0) };
+    assert!(o.get().bytes().is_empty());
+}
@@ -1,12 +1,14 @@
 /* generated by rust_qt_binding_generator */
 #![allow(unknown_lints)]
 #![allow(mutex_atomic, needless_pass_by_value)]
-use libc::{c_char, c_ushort, c_int};
+use libc::{c_char, c_ushort, c_int, c_void};
 use std::slice;
-use std::char::decode_utf16;
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
 
 use std::sync::{Arc, Mutex};
-use std::ptr::null;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::ptr::null_mut;
+use std::thread;
 
 use implementation::*;
 
@@ -42,8 +44,7 @@ pub enum QString {}
 fn set_string_from_utf16(s: &mut String, str: *const c_ushort, len: c_int) {
     let utf16 = unsafe { slice::from_raw_parts(str, to_usize(len)) };
     let characters = decode_utf16(utf16.iter().cloned())
-        .into_iter()
-        .map(|r| r.unwrap());
+        .map(|r| r.unwrap_or(REPLACEMENT_CHARACTER));
     s.clear();
     s.extend(characters);
 }
@@ -63,53 +64,148 @@ pub struct QModelIndex {
 }
 
 
+/// A count, row or length that does not fit on the other side of the
+/// binding. It is clamped and passed to the overflow hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// A value from Rust that is larger than `c_int::max_value()`. C++ gets
+    /// `c_int::max_value()` instead.
+    TooLarge(usize),
+    /// A negative value from C++. Rust gets 0 instead.
+    Negative(c_int),
+}
+
+impl ::std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Overflow::TooLarge(n) => {
+                write!(f, "{} does not fit in a c_int, using {}", n, c_int::max_value())
+            }
+            Overflow::Negative(n) => write!(f, "{} is negative, using 0", n),
+        }
+    }
+}
+
+static OVERFLOW_HOOK: Mutex<Option<fn(Overflow)>> = Mutex::new(None);
+
+/// Pass each overflow to `hook` instead of logging it to stderr. `None`
+/// logs again.
+pub fn set_overflow_hook(hook: Option<fn(Overflow)>) {
+    *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+fn overflow(o: Overflow) {
+    // copied, so the hook can replace itself
+    let hook = *OVERFLOW_HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    match hook {
+        Some(hook) => hook(o),
+        None => eprintln!("rust_qt_binding_generator: {}", o),
+    }
+}
+
 fn to_usize(n: c_int) -> usize {
     if n < 0 {
-        panic!("Cannot cast {} to usize", n);
+        overflow(Overflow::Negative(n));
+        return 0;
     }
     n as usize
 }
 
-
 fn to_c_int(n: usize) -> c_int {
     if n > c_int::max_value() as usize {
-        panic!("Cannot cast {} to c_int", n);
+        overflow(Overflow::TooLarge(n));
+        return c_int::max_value();
     }
     n as c_int
 }
 
 
+/// A hash of the configuration that this code was generated from. The C++
+/// code checks it before it creates an object.
+#[no_mangle]
+pub extern "C" fn interface_binding_hash() -> u64 {
+    0x601c2b7b4ff3e676
+}
+
+/// Stands in for the QObjects of the fixtures in the tests of the
+/// implementation. It records the names of the signals that are emitted.
+#[cfg(test)]
+#[derive(Default)]
+pub struct TestQObject {
+    signals: ::std::sync::Mutex<Vec<&'static str>>,
+}
+
+#[cfg(test)]
+impl TestQObject {
+    fn emit<Q>(qobject: *const Q, signal: &'static str) {
+        let q = unsafe { &*(qobject as *const TestQObject) };
+        q.signals.lock().unwrap().push(signal);
+    }
+    /// Return the signals that were emitted since the last call.
+    pub fn signals(&self) -> Vec<&'static str> {
+        self.signals.lock().unwrap().drain(..).collect()
+    }
+}
+
 pub struct PersonsQObject {}
 
 #[derive(Clone)]
 pub struct PersonsEmitter {
-    qobject: Arc<Mutex<*const PersonsQObject>>,
-    new_data_ready: fn(*const PersonsQObject, item: usize, valid: bool),
+    pub(crate) qobject: Arc<AtomicPtr<PersonsQObject>>,
+    pub(crate) emitting: Arc<AtomicUsize>,
+    pub(crate) run_on_gui_thread: fn(*const PersonsQObject, *mut c_void),
+    pub(crate) new_data_ready: fn(*const PersonsQObject, item: usize, valid: bool),
 }
 
-unsafe impl Send for PersonsEmitter {}
-
 impl PersonsEmitter {
-    fn clear(&self) {
-        *self.qobject.lock().unwrap() = null();
+    // Call `f` with the QObject unless it was deleted. `clear()` waits for
+    // the calls that are running, so the QObject outlives each call.
+    fn with_qobject<F: FnOnce(*const PersonsQObject)>(&self, f: F) {
+        if self.qobject.load(Ordering::SeqCst).is_null() {
+            // cleared, do not keep clear() waiting
+            return;
+        }
+        self.emitting.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.qobject.load(Ordering::SeqCst);
+        if !ptr.is_null() {
+            f(ptr);
+        }
+        self.emitting.fetch_sub(1, Ordering::SeqCst);
+    }
+    // Called by persons_free before the QObject is deleted. A slot that deletes
+    // the QObject must not be connected directly to a signal from Rust: it
+    // would wait here for its own call.
+    pub(crate) fn clear(&self) {
+        self.qobject.store(null_mut(), Ordering::SeqCst);
+        while self.emitting.load(Ordering::SeqCst) != 0 {
+            thread::yield_now();
+        }
+    }
+    /// Run `f` in the thread of the QObject, usually the GUI thread. Signals
+    /// that `f` emits arrive in that thread. `f` is dropped without running
+    /// when the QObject is deleted first.
+    pub fn run_on_gui_thread(&self, f: Box<dyn FnOnce(&mut Persons) + Send>) {
+        self.with_qobject(|ptr| {
+            let task = Box::into_raw(Box::new(f));
+            (self.run_on_gui_thread)(ptr, task as *mut c_void);
+        });
     }
     pub fn new_data_ready(&self, item: Option<usize>) {
-        let ptr = *self.qobject.lock().unwrap();
-        if !ptr.is_null() {
+        self.with_qobject(|ptr| {
             (self.new_data_ready)(ptr, item.unwrap_or(13), item.is_some());
-        }
+        });
     }
 }
 
 pub struct PersonsTree {
-    qobject: *const PersonsQObject,
-    data_changed: fn(*const PersonsQObject, usize, usize),
-    begin_reset_model: fn(*const PersonsQObject),
-    end_reset_model: fn(*const PersonsQObject),
-    begin_insert_rows: fn(*const PersonsQObject, item: usize, valid: bool, usize, usize),
-    end_insert_rows: fn(*const PersonsQObject),
-    begin_remove_rows: fn(*const PersonsQObject, item: usize, valid: bool, usize, usize),
-    end_remove_rows: fn(*const PersonsQObject),
+    pub(crate) qobject: *const PersonsQObject,
+    pub(crate) data_changed: fn(*const PersonsQObject, usize, usize),
+    pub(crate) begin_reset_model: fn(*const PersonsQObject),
+    pub(crate) end_reset_model: fn(*const PersonsQObject),
+    pub(crate) begin_insert_rows: fn(*const PersonsQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_insert_rows: fn(*const PersonsQObject),
+    pub(crate) begin_remove_rows: fn(*const PersonsQObject, item: usize, valid: bool, usize, usize),
+    pub(crate) end_remove_rows: fn(*const PersonsQObject),
 }
 
 impl PersonsTree {
@@ -155,6 +251,7 @@ pub trait PersonsTrait {
 #[no_mangle]
 pub extern "C" fn persons_new(
     persons: *mut PersonsQObject,
+    persons_run_on_gui_thread: fn(*const PersonsQObject, *mut c_void),
     persons_new_data_ready: fn(*const PersonsQObject, item: usize, valid: bool),
     persons_data_changed: fn(*const PersonsQObject, usize, usize),
     persons_begin_reset_model: fn(*const PersonsQObject),
@@ -165,7 +262,9 @@ pub extern "C" fn persons_new(
     persons_end_remove_rows: fn(*const PersonsQObject),
 ) -> *mut Persons {
     let persons_emit = PersonsEmitter {
-        qobject: Arc::new(Mutex::new(persons)),
+        qobject: Arc::new(AtomicPtr::new(persons)),
+        emitting: Arc::new(AtomicUsize::new(0)),
+        run_on_gui_thread: persons_run_on_gui_thread,
         new_data_ready: persons_new_data_ready,
     };
     let model = PersonsTree {
@@ -187,6 +286,17 @@ pub unsafe extern "C" fn persons_free(ptr: *mut Persons) {
     Box::from_raw(ptr).emit().clear();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn persons_run_task(ptr: *mut Persons, task: *mut c_void) {
+    let f = Box::from_raw(task as *mut Box<dyn FnOnce(&mut Persons) + Send>);
+    f(&mut *ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn persons_drop_task(task: *mut c_void) {
+    drop(Box::from_raw(task as *mut Box<dyn FnOnce(&mut Persons) + Send>));
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn persons_row_count(
     ptr: *const Persons,
@@ -281,3 +391,52 @@ pub extern "C" fn persons_set_data_user_name(
     set_string_from_utf16(&mut v, s, len);
     o.set_user_name(item, v)
 }
+
+/// Owns a Persons for the tests of the implementation. `qobject` stands in for
+/// its QObject and records the signals.
+#[cfg(test)]
+pub struct PersonsFixture {
+    pub ptr: *mut Persons,
+    pub qobject: Box<TestQObject>,
+}
+
+#[cfg(test)]
+impl PersonsFixture {
+    pub fn new() -> PersonsFixture {
+        let qobject = Box::new(TestQObject::default());
+        let q = &*qobject as *const TestQObject;
+        let ptr = persons_new(
+            q as *mut PersonsQObject,
+            |_, task| unsafe { persons_drop_task(task) },
+            |q, _, _| TestQObject::emit(q, "newDataReady"),
+            |q, _, _| TestQObject::emit(q, "dataChanged"),
+            |q| TestQObject::emit(q, "beginResetModel"),
+            |q| TestQObject::emit(q, "endResetModel"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginInsertRows"),
+            |q| TestQObject::emit(q, "endInsertRows"),
+            |q, _, _, _, _| TestQObject::emit(q, "beginRemoveRows"),
+            |q| TestQObject::emit(q, "endRemoveRows"),
+        );
+        PersonsFixture { ptr: ptr, qobject: qobject }
+    }
+    pub fn get(&self) -> &Persons {
+        unsafe { &*self.ptr }
+    }
+    pub fn get_mut(&mut self) -> &mut Persons {
+        unsafe { &mut *self.ptr }
+    }
+    /// Delete the object. The QObject stays and would record a late signal.
+    pub fn free(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { persons_free(self.ptr) };
+            self.ptr = null_mut();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for PersonsFixture {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include "test_bulk_rust.h"
+#include <QTest>
+#include <atomic>
+#include <thread>
+
+class TestRustBulk : public QObject
+{
+    Q_OBJECT
+private slots:
+    void testSameAsGetters();
+    void testFetchInBlocks();
+    void testSetDataDropsRow();
+    void testDataChangedDropsRows();
+    void testInsertDropsRows();
+    void testDropFromThread();
+};
+
+void TestRustBulk::testSameAsGetters()
+{
+    // GIVEN
+    Log log;
+    const int note = log.role("note");
+    const int tag = log.role("tag");
+    const int duration = log.role("duration");
+
+    // WHEN
+    for (int row = 0; row < 300; ++row) {
+        const QModelIndex index = log.index(row, 0);
+
+        // THEN
+        QCOMPARE(log.data(index).toString(), log.line(row));
+        QCOMPARE(log.data(index, log.role("level")).value<quint8>(), log.level(row));
+        QCOMPARE(log.data(index, note).isValid(), !log.note(row).isNull());
+        QCOMPARE(log.data(index, note).toString(), log.note(row));
+        QCOMPARE(log.data(index, tag).isValid(), !log.tag(row).isNull());
+        QCOMPARE(log.data(index, tag).toByteArray(), log.tag(row));
+        QCOMPARE(log.data(index, duration), log.duration(row));
+    }
+    QCOMPARE(log.data(log.index(1, 0), note), QVariant());
+    QCOMPARE(log.data(log.index(1, 0), tag).toByteArray(), QByteArray(""));
+    QCOMPARE(log.data(log.index(3, 0), duration).value<quint64>(), quint64(3000));
+}
+
+void TestRustBulk::testFetchInBlocks()
+{
+    // GIVEN
+    Log log;
+
+    // WHEN
+    for (int row = 0; row < 300; ++row) {
+        log.data(log.index(row, 0));
+    }
+
+    // THEN
+    // two blocks of 256 rows
+    QCOMPARE(log.reads(), 512u);
+}
+
+void TestRustBulk::testSetDataDropsRow()
+{
+    // GIVEN
+    Log log;
+    const QModelIndex index = log.index(5, 0);
+    QCOMPARE(log.data(index).toString(), QString("line 5"));
+
+    // WHEN
+    const bool set = log.setData(index, "changed");
+
+    // THEN
+    QVERIFY(set);
+    QCOMPARE(log.data(index).toString(), QString("changed"));
+}
+
+void TestRustBulk::testDataChangedDropsRows()
+{
+    // GIVEN
+    Log log;
+    QCOMPARE(log.data(log.index(0, 0)).toString(), QString("line 0"));
+
+    // WHEN
+    // Rust reverses the rows and reports them as changed
+    log.sort(0);
+
+    // THEN
+    QCOMPARE(log.data(log.index(0, 0)).toString(), QString("line 999"));
+}
+
+void TestRustBulk::testInsertDropsRows()
+{
+    // GIVEN
+    Log log;
+    QCOMPARE(log.data(log.index(0, 0)).toString(), QString("line 0"));
+
+    // WHEN
+    const bool inserted = log.insertRows(0, 1);
+
+    // THEN
+    QVERIFY(inserted);
+    QCOMPARE(log.rowCount(), 1001);
+    QCOMPARE(log.data(log.index(0, 0)).toString(), QString("new"));
+    QCOMPARE(log.data(log.index(1, 0)).toString(), QString("line 0"));
+}
+
+void TestRustBulk::testDropFromThread()
+{
+    // GIVEN
+    Log log;
+    const int level = log.role("level");
+    std::atomic<bool> done(false);
+
+    // WHEN
+    // Rust changes the levels in another thread while they are read
+    std::thread writer([&log, &done]() {
+        for (int i = 1; i <= 20; ++i) {
+            log.changeLevels(quint8(i));
+        }
+        done = true;
+    });
+    while (!done) {
+        for (int row = 0; row < log.rowCount(); row += 100) {
+            log.data(log.index(row, 0), level);
+        }
+    }
+    writer.join();
+
+    // THEN
+    for (int row = 0; row < log.rowCount(); ++row) {
+        QCOMPARE(log.data(log.index(row, 0), level).value<quint8>(), quint8(20));
+    }
+}
+
+QTEST_MAIN(TestRustBulk)
+#include "test_bulk.moc"
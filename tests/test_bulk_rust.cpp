@@ -0,0 +1,469 @@
+/* generated by rust_qt_binding_generator */
+#include "test_bulk_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    struct option_quint64 {
+    public:
+        quint64 value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_pod<option_quint64>::value, "option_quint64 must be a POD type.");
+
+    struct option_quintptr {
+    public:
+        quintptr value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_pod<option_quintptr>::value, "option_quintptr must be a POD type.");
+
+    typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
+    void set_qstring(QString* val, const char* utf8, int nbytes) {
+        *val = QString::fromUtf8(utf8, nbytes);
+    }
+
+    typedef void (*qbytearray_set)(QByteArray* val, const char* bytes, int nbytes);
+    void set_qbytearray(QByteArray* v, const char* bytes, int nbytes) {
+        if (v->isNull() && nbytes == 0) {
+            *v = QByteArray(bytes, nbytes);
+        } else {
+            v->truncate(0);
+            v->append(bytes, nbytes);
+        }
+    }
+
+    // The strings of several rows, one after the other. An empty optional
+    // string has length -1.
+    typedef void (*qstring_rows_set)(QString* vals, const char* utf8, const int* nbytes, int count);
+    void set_qstring_rows(QString* vals, const char* utf8, const int* nbytes, int count) {
+        for (int i = 0; i < count; ++i) {
+            if (nbytes[i] >= 0) {
+                vals[i] = QString::fromUtf8(utf8, nbytes[i]);
+                utf8 += nbytes[i];
+            }
+        }
+    }
+
+    // The bytes of several rows, one after the other. An empty optional
+    // value has length -1.
+    typedef void (*qbytearray_rows_set)(QByteArray* vals, const char* bytes, const int* nbytes, int count);
+    void set_qbytearray_rows(QByteArray* vals, const char* bytes, const int* nbytes, int count) {
+        for (int i = 0; i < count; ++i) {
+            if (nbytes[i] >= 0) {
+                vals[i] = QByteArray(bytes, nbytes[i]);
+                bytes += nbytes[i];
+            }
+        }
+    }
+
+    struct qmodelindex_t {
+        int row;
+        quintptr id;
+    };
+    inline QVariant cleanNullQVariant(const QVariant& v) {
+        return (v.isNull()) ?QVariant() :v;
+    }
+}
+extern "C" {
+    void log_data_line(const Log::Private*, int, QString*, qstring_set);
+    bool log_set_data_line(Log::Private*, int, const ushort* s, int len);
+    quint8 log_data_level(const Log::Private*, int);
+    void log_data_note(const Log::Private*, int, QString*, qstring_set);
+    void log_data_tag(const Log::Private*, int, QByteArray*, qbytearray_set);
+    option_quint64 log_data_duration(const Log::Private*, int);
+    void log_data_rows(const Log::Private*, int first, int count, QString*, qstring_rows_set, quint8*, QString*, qstring_rows_set, QByteArray*, qbytearray_rows_set, option_quint64*);
+    void log_sort(Log::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int log_row_count(const Log::Private*);
+    bool log_insert_rows(Log::Private*, int, int);
+    bool log_remove_rows(Log::Private*, int, int);
+    bool log_can_fetch_more(const Log::Private*);
+    void log_fetch_more(Log::Private*);
+}
+int Log::columnCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : 1;
+}
+
+bool Log::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Log::rowCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : log_row_count(m_d);
+}
+
+bool Log::insertRows(int row, int count, const QModelIndex &)
+{
+    return log_insert_rows(m_d, row, count);
+}
+
+bool Log::removeRows(int row, int count, const QModelIndex &)
+{
+    return log_remove_rows(m_d, row, count);
+}
+
+QModelIndex Log::index(int row, int column, const QModelIndex &parent) const
+{
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
+        return createIndex(row, column, (quintptr)row);
+    }
+    return QModelIndex();
+}
+
+QModelIndex Log::parent(const QModelIndex &) const
+{
+    return QModelIndex();
+}
+
+bool Log::canFetchMore(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : log_can_fetch_more(m_d);
+}
+
+void Log::fetchMore(const QModelIndex &parent)
+{
+    if (!parent.isValid()) {
+        log_fetch_more(m_d);
+    }
+}
+
+void Log::sort(int column, Qt::SortOrder order)
+{
+    log_sort(m_d, column, order);
+}
+Qt::ItemFlags Log::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    if (i.column() == 0) {
+        flags |= Qt::ItemIsEditable;
+    }
+    return flags;
+}
+
+QString Log::line(int row) const
+{
+    QString s;
+    log_data_line(m_d, row, &s, set_qstring);
+    return s;
+}
+
+bool Log::setLine(int row, const QString& value)
+{
+    bool set = false;
+    set = log_set_data_line(m_d, row, value.utf16(), value.length());
+    if (set) {
+        dropCachedRows(this, row, row);
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+quint8 Log::level(int row) const
+{
+    return log_data_level(m_d, row);
+}
+
+QString Log::note(int row) const
+{
+    QString s;
+    log_data_note(m_d, row, &s, set_qstring);
+    return s;
+}
+
+QByteArray Log::tag(int row) const
+{
+    QByteArray b;
+    log_data_tag(m_d, row, &b, set_qbytearray);
+    return b;
+}
+
+QVariant Log::duration(int row) const
+{
+    QVariant v;
+    v = log_data_duration(m_d, row);
+    return v;
+}
+
+QVariant Log::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
+        case Qt::UserRole + 0:
+            return cached(index.row(), 0);
+        case Qt::UserRole + 1:
+            return cached(index.row(), 1);
+        case Qt::UserRole + 2:
+            return cached(index.row(), 2);
+        case Qt::UserRole + 3:
+            return cached(index.row(), 3);
+        case Qt::UserRole + 4:
+            return cached(index.row(), 4);
+        }
+    }
+    return QVariant();
+}
+
+QVariant Log::cached(int row, int property) const
+{
+    QMutexLocker lock(&m_cacheMutex);
+    if (row >= m_cacheFirst && row < m_cacheFirst + m_cacheRows) {
+        return m_cache.at((row - m_cacheFirst) * 5 + property);
+    }
+    // Rust is called without the lock, so that its threads can drop rows
+    // meanwhile
+    const quint32 generation = m_cacheGeneration;
+    lock.unlock();
+    const int first = row - row % 256;
+    const int count = qMin(256, rowCount() - first);
+    QVector<QString> lineRows(count);
+    QVector<quint8> levelRows(count);
+    QVector<QString> noteRows(count);
+    QVector<QByteArray> tagRows(count);
+    QVector<option_quint64> durationRows(count);
+    log_data_rows(m_d, first, count, lineRows.data(), set_qstring_rows, levelRows.data(), noteRows.data(), set_qstring_rows, tagRows.data(), set_qbytearray_rows, durationRows.data());
+    QVector<QVariant> cache(count * 5);
+    for (int i = 0; i < count; ++i) {
+        cache[i * 5 + 0] = QVariant::fromValue(lineRows[i]);
+        cache[i * 5 + 1] = QVariant::fromValue(levelRows[i]);
+        cache[i * 5 + 2] = cleanNullQVariant(QVariant::fromValue(noteRows[i]));
+        cache[i * 5 + 3] = cleanNullQVariant(QVariant::fromValue(tagRows[i]));
+        cache[i * 5 + 4] = durationRows[i];
+    }
+    const QVariant value = cache.at((row - first) * 5 + property);
+    lock.relock();
+    // rows that were dropped during the fetch are fetched again
+    if (generation == m_cacheGeneration) {
+        m_cache.swap(cache);
+        m_cacheFirst = first;
+        m_cacheRows = count;
+    }
+    return value;
+}
+
+void Log::dropCachedRows(Log* o, quintptr first, quintptr last)
+{
+    QMutexLocker lock(&o->m_cacheMutex);
+    ++o->m_cacheGeneration;
+    if (first < quintptr(o->m_cacheFirst + o->m_cacheRows)
+            && last >= quintptr(o->m_cacheFirst)) {
+        o->m_cacheRows = 0;
+    }
+}
+
+void Log::dropCache(Log* o)
+{
+    QMutexLocker lock(&o->m_cacheMutex);
+    ++o->m_cacheGeneration;
+    o->m_cacheRows = 0;
+}
+
+int Log::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Log::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "line");
+    names.insert(Qt::UserRole + 1, "level");
+    names.insert(Qt::UserRole + 2, "note");
+    names.insert(Qt::UserRole + 3, "tag");
+    names.insert(Qt::UserRole + 4, "duration");
+    return names;
+}
+QVariant Log::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Log::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+bool Log::setData(const QModelIndex &index, const QVariant &value, int role)
+{
+    if (index.column() == 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setLine(index.row(), value.value<QString>());
+            }
+        }
+    }
+    return false;
+}
+
+extern "C" {
+    Log::Private* log_new(Log*, void (*)(Log*, void*),
+        void (*)(const Log*),
+        void (*)(Log*, quintptr, quintptr),
+        void (*)(Log*),
+        void (*)(Log*),
+        void (*)(Log*, int, int),
+        void (*)(Log*),
+        void (*)(Log*, int, int),
+        void (*)(Log*));
+    void log_free(Log::Private*);
+    void log_run_task(Log::Private*, void*);
+    void log_drop_task(void*);
+    quint32 log_reads(const Log::Private*);
+    void log_change_levels(const Log::Private*, quint8);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x6d1d769c5372a568)) {
+            qFatal("test_bulk_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Log::Log(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Log::Log(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), log_new(this,
+        Log::postTask,
+        [](const Log* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Log* o, quintptr first, quintptr last) {
+            Log::dropCachedRows(o, first, last);
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 0, last));
+        },
+        [](Log* o) {
+            o->beginResetModel();
+        },
+        [](Log* o) {
+            Log::dropCache(o);
+            o->endResetModel();
+        },
+        [](Log* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](Log* o) {
+            Log::dropCache(o);
+            o->endInsertRows();
+        },
+        [](Log* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](Log* o) {
+            Log::dropCache(o);
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Log::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Log::~Log() {
+    if (m_ownsPrivate) {
+        log_free(m_d);
+    }
+}
+
+bool Log::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        log_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Log::postTask(Log* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, log_drop_task));
+}
+void Log::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("line"));
+}
+quint32 Log::reads() const
+{
+    return log_reads(m_d);
+}
+void Log::changeLevels(quint8 level) const
+{
+    return log_change_levels(m_d, level);
+}
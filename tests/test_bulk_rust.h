@@ -0,0 +1,100 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_BULK_RUST_H
+#define TEST_BULK_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+#include <QMutex>
+
+class Log;
+
+class Log : public QAbstractItemModel
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Log for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Log* create(QObject* parent) { return new Log(false, parent); }
+        static Private*& d(Log* o) { return o->m_d; }
+        static Private* d(const Log* o) { return o->m_d; }
+        static void postTask(Log* o, void* task) { Log::postTask(o, task); }
+        static QModelIndex createIndex(const Log* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Log* o) { o->beginResetModel(); }
+        static void endResetModel(Log* o) { o->endResetModel(); }
+        static void beginInsertRows(Log* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Log* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Log* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Log* o) { o->endRemoveRows(); }
+        static void dropCachedRows(Log* o, quintptr first, quintptr last) {
+            Log::dropCachedRows(o, first, last);
+        }
+        static void dropCache(Log* o) { Log::dropCache(o); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit Log(bool owned, QObject *parent);
+public:
+    explicit Log(QObject *parent = nullptr);
+    ~Log();
+    Q_INVOKABLE quint32 reads() const;
+    Q_INVOKABLE void changeLevels(quint8 level) const;
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE QString line(int row) const;
+    Q_INVOKABLE bool setLine(int row, const QString& value);
+    Q_INVOKABLE quint8 level(int row) const;
+    Q_INVOKABLE QString note(int row) const;
+    Q_INVOKABLE QByteArray tag(int row) const;
+    Q_INVOKABLE QVariant duration(int row) const;
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+    // the item properties of a block of rows, fetched from Rust at once and
+    // guarded by m_cacheMutex because Rust may drop rows from any thread
+    mutable QMutex m_cacheMutex;
+    mutable int m_cacheFirst = 0;
+    mutable int m_cacheRows = 0;
+    mutable QVector<QVariant> m_cache;
+    // counts the drops, so that rows which change while they are fetched
+    // are not kept
+    mutable quint32 m_cacheGeneration = 0;
+    QVariant cached(int row, int property) const;
+    static void dropCachedRows(Log* o, quintptr first, quintptr last);
+    static void dropCache(Log* o);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Log* o, void* task);
+signals:
+};
+#endif // TEST_BULK_RUST_H
@@ -0,0 +1,123 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include "test_coalesce_rust.h"
+#include <QTest>
+#include <QSignalSpy>
+
+class TestRustCoalesce : public QObject
+{
+    Q_OBJECT
+private slots:
+    void testPropertyOncePerIteration();
+    void testDataChangedOncePerIteration();
+    void testDataChangedBeforeRemove();
+    void testDataChangedFromThreads();
+};
+
+void TestRustCoalesce::testPropertyOncePerIteration()
+{
+    // GIVEN
+    Meter meter;
+    QSignalSpy spy(&meter, &Meter::levelChanged);
+
+    // WHEN
+    meter.setLevel(1);
+    meter.setLevel(2);
+    meter.setLevel(3);
+
+    // THEN
+    QVERIFY(spy.isValid());
+    QCOMPARE(spy.count(), 0);
+    QCoreApplication::processEvents();
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(meter.level(), 3u);
+    meter.setLevel(4);
+    QCoreApplication::processEvents();
+    QCOMPARE(spy.count(), 2);
+}
+
+void TestRustCoalesce::testDataChangedOncePerIteration()
+{
+    // GIVEN
+    Cells cells;
+    QSignalSpy spy(&cells, &Cells::dataChanged);
+
+    // WHEN
+    // Rust reports each of the 10 rows
+    cells.sort(0, Qt::DescendingOrder);
+
+    // THEN
+    QVERIFY(spy.isValid());
+    QCOMPARE(spy.count(), 0);
+    QCoreApplication::processEvents();
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(spy.at(0).at(0).toModelIndex().row(), 0);
+    QCOMPARE(spy.at(0).at(1).toModelIndex().row(), 9);
+    QCOMPARE(cells.value(0), 9u);
+}
+
+void TestRustCoalesce::testDataChangedBeforeRemove()
+{
+    // GIVEN
+    Cells cells;
+    QList<QString> order;
+    connect(&cells, &Cells::dataChanged, [&order]() {
+        order.append("dataChanged");
+    });
+    connect(&cells, &Cells::rowsAboutToBeRemoved, [&order]() {
+        order.append("rowsAboutToBeRemoved");
+    });
+
+    // WHEN
+    cells.sort(0, Qt::DescendingOrder);
+    const bool removed = cells.removeRows(0, 5);
+    QCoreApplication::processEvents();
+
+    // THEN
+    QVERIFY(removed);
+    QCOMPARE(order, QList<QString>() << "dataChanged" << "rowsAboutToBeRemoved");
+    QCOMPARE(cells.rowCount(), 5);
+}
+
+void TestRustCoalesce::testDataChangedFromThreads()
+{
+    // GIVEN
+    Cells cells;
+    QSignalSpy spy(&cells, &Cells::dataChanged);
+
+    // WHEN
+    // four Rust threads report every row a hundred times
+    const quint32 reports = cells.reportFromThreads();
+
+    // THEN
+    QVERIFY(spy.isValid());
+    QCOMPARE(reports, 4000u);
+    QCOMPARE(spy.count(), 0);
+    QCoreApplication::processEvents();
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(spy.at(0).at(0).toModelIndex().row(), 0);
+    QCOMPARE(spy.at(0).at(1).toModelIndex().row(), 9);
+    QCoreApplication::processEvents();
+    QCOMPARE(spy.count(), 1);
+}
+
+QTEST_MAIN(TestRustCoalesce)
+#include "test_coalesce.moc"
@@ -0,0 +1,403 @@
+/* generated by rust_qt_binding_generator */
+#include "test_coalesce_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    // The event that delivers the coalesced signals of an object.
+    QEvent::Type pendingSignalsEvent() {
+        static const QEvent::Type t
+            = static_cast<QEvent::Type>(QEvent::registerEventType());
+        return t;
+    }
+
+    struct option_quintptr {
+    public:
+        quintptr value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_pod<option_quintptr>::value, "option_quintptr must be a POD type.");
+
+    struct qmodelindex_t {
+        int row;
+        quintptr id;
+    };
+    inline QVariant cleanNullQVariant(const QVariant& v) {
+        return (v.isNull()) ?QVariant() :v;
+    }
+    inline void meterLevelChanged(Meter* o)
+    {
+        Meter::Binding::emitLater(o, 1);
+    }
+}
+extern "C" {
+    quint32 cells_data_value(const Cells::Private*, int);
+    void cells_sort(Cells::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int cells_row_count(const Cells::Private*);
+    bool cells_insert_rows(Cells::Private*, int, int);
+    bool cells_remove_rows(Cells::Private*, int, int);
+    bool cells_can_fetch_more(const Cells::Private*);
+    void cells_fetch_more(Cells::Private*);
+}
+int Cells::columnCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : 1;
+}
+
+bool Cells::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Cells::rowCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : cells_row_count(m_d);
+}
+
+bool Cells::insertRows(int row, int count, const QModelIndex &)
+{
+    return cells_insert_rows(m_d, row, count);
+}
+
+bool Cells::removeRows(int row, int count, const QModelIndex &)
+{
+    return cells_remove_rows(m_d, row, count);
+}
+
+QModelIndex Cells::index(int row, int column, const QModelIndex &parent) const
+{
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
+        return createIndex(row, column, (quintptr)row);
+    }
+    return QModelIndex();
+}
+
+QModelIndex Cells::parent(const QModelIndex &) const
+{
+    return QModelIndex();
+}
+
+bool Cells::canFetchMore(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : cells_can_fetch_more(m_d);
+}
+
+void Cells::fetchMore(const QModelIndex &parent)
+{
+    if (!parent.isValid()) {
+        cells_fetch_more(m_d);
+    }
+}
+
+void Cells::sort(int column, Qt::SortOrder order)
+{
+    cells_sort(m_d, column, order);
+}
+Qt::ItemFlags Cells::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    return flags;
+}
+
+quint32 Cells::value(int row) const
+{
+    return cells_data_value(m_d, row);
+}
+
+QVariant Cells::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(value(index.row()));
+        }
+    }
+    return QVariant();
+}
+
+int Cells::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Cells::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "value");
+    return names;
+}
+QVariant Cells::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Cells::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+extern "C" {
+    Cells::Private* cells_new(Cells*, void (*)(Cells*, void*),
+        void (*)(const Cells*),
+        void (*)(Cells*, quintptr, quintptr),
+        void (*)(Cells*),
+        void (*)(Cells*),
+        void (*)(Cells*, int, int),
+        void (*)(Cells*),
+        void (*)(Cells*, int, int),
+        void (*)(Cells*));
+    void cells_free(Cells::Private*);
+    void cells_run_task(Cells::Private*, void*);
+    void cells_drop_task(void*);
+    quint32 cells_report_from_threads(const Cells::Private*);
+};
+
+extern "C" {
+    Meter::Private* meter_new(Meter*, void (*)(Meter*, void*), void (*)(Meter*));
+    void meter_free(Meter::Private*);
+    void meter_run_task(Meter::Private*, void*);
+    void meter_drop_task(void*);
+    quint32 meter_level_get(const Meter::Private*);
+    void meter_level_set(Meter::Private*, quint32);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xe3d66af312919603)) {
+            qFatal("test_coalesce_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Cells::Cells(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Cells::Cells(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), cells_new(this,
+        Cells::postTask,
+        [](const Cells* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Cells* o, quintptr first, quintptr last) {
+            Cells::dataChangedLater(o, first, last);
+        },
+        [](Cells* o) {
+            Cells::flushDataChanged(o);
+            o->beginResetModel();
+        },
+        [](Cells* o) {
+            o->endResetModel();
+        },
+        [](Cells* o, int first, int last) {
+            Cells::flushDataChanged(o);
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](Cells* o) {
+            o->endInsertRows();
+        },
+        [](Cells* o, int first, int last) {
+            Cells::flushDataChanged(o);
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](Cells* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Cells::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Cells::~Cells() {
+    if (m_ownsPrivate) {
+        cells_free(m_d);
+    }
+}
+
+bool Cells::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        cells_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    if (e->type() == pendingSignalsEvent()) {
+        const int pending = m_pendingSignals.fetchAndStoreOrdered(0);
+        if (pending & 1) {
+            flushDataChanged(this);
+        }
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Cells::postTask(Cells* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, cells_drop_task));
+}
+
+void Cells::emitLater(Cells* o, int signal)
+{
+    // only the first signal since the last delivery posts an event
+    if (o->m_pendingSignals.fetchAndOrOrdered(1 << signal) == 0) {
+        QCoreApplication::postEvent(o, new QEvent(pendingSignalsEvent()));
+    }
+}
+
+void Cells::dataChangedLater(Cells* o, quintptr first, quintptr last)
+{
+    QMutexLocker lock(&o->m_dirtyMutex);
+    if (o->m_dirty) {
+        o->m_dirtyFirst = qMin(o->m_dirtyFirst, first);
+        o->m_dirtyLast = qMax(o->m_dirtyLast, last);
+    } else {
+        o->m_dirty = true;
+        o->m_dirtyFirst = first;
+        o->m_dirtyLast = last;
+        emitLater(o, 0);
+    }
+}
+
+// Called in the thread of the object, before its rows move and when the
+// posted event is delivered.
+void Cells::flushDataChanged(Cells* o)
+{
+    QMutexLocker lock(&o->m_dirtyMutex);
+    if (!o->m_dirty) {
+        return;
+    }
+    o->m_dirty = false;
+    const quintptr first = o->m_dirtyFirst;
+    const quintptr last = o->m_dirtyLast;
+    lock.unlock();
+    emit o->dataChanged(o->createIndex(first, 0, first),
+            o->createIndex(last, 0, last));
+}
+void Cells::initHeaderData() {
+}
+quint32 Cells::reportFromThreads() const
+{
+    return cells_report_from_threads(m_d);
+}
+Meter::Meter(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Meter::Meter(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), meter_new(this,
+        Meter::postTask,
+        meterLevelChanged))),
+    m_ownsPrivate(true)
+{
+}
+
+Meter::~Meter() {
+    if (m_ownsPrivate) {
+        meter_free(m_d);
+    }
+}
+
+bool Meter::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        meter_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    if (e->type() == pendingSignalsEvent()) {
+        const int pending = m_pendingSignals.fetchAndStoreOrdered(0);
+        if (pending & (1 << 1)) {
+            emit levelChanged();
+        }
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Meter::postTask(Meter* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, meter_drop_task));
+}
+
+void Meter::emitLater(Meter* o, int signal)
+{
+    // only the first signal since the last delivery posts an event
+    if (o->m_pendingSignals.fetchAndOrOrdered(1 << signal) == 0) {
+        QCoreApplication::postEvent(o, new QEvent(pendingSignalsEvent()));
+    }
+}
+quint32 Meter::level() const
+{
+    return meter_level_get(m_d);
+}
+void Meter::setLevel(quint32 v) {
+    meter_level_set(m_d, v);
+}
@@ -0,0 +1,132 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_COALESCE_RUST_H
+#define TEST_COALESCE_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+#include <QMutex>
+
+class Cells;
+class Meter;
+
+class Cells : public QAbstractItemModel
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Cells for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Cells* create(QObject* parent) { return new Cells(false, parent); }
+        static Private*& d(Cells* o) { return o->m_d; }
+        static Private* d(const Cells* o) { return o->m_d; }
+        static void postTask(Cells* o, void* task) { Cells::postTask(o, task); }
+        static void emitLater(Cells* o, int signal) { Cells::emitLater(o, signal); }
+        static QModelIndex createIndex(const Cells* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Cells* o) { o->beginResetModel(); }
+        static void endResetModel(Cells* o) { o->endResetModel(); }
+        static void beginInsertRows(Cells* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Cells* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Cells* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Cells* o) { o->endRemoveRows(); }
+        static void dataChangedLater(Cells* o, quintptr first, quintptr last) {
+            Cells::dataChangedLater(o, first, last);
+        }
+        static void flushDataChanged(Cells* o) { Cells::flushDataChanged(o); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit Cells(bool owned, QObject *parent);
+public:
+    explicit Cells(QObject *parent = nullptr);
+    ~Cells();
+    Q_INVOKABLE quint32 reportFromThreads() const;
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE quint32 value(int row) const;
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread and delivers
+    // the coalesced signals
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Cells* o, void* task);
+    // a bit for each coalesced signal that waits for delivery
+    QAtomicInt m_pendingSignals;
+    static void emitLater(Cells* o, int signal);
+    // the rows that changed since dataChanged was last emitted, guarded by
+    // m_dirtyMutex because Rust may report them from any thread
+    QMutex m_dirtyMutex;
+    bool m_dirty = false;
+    quintptr m_dirtyFirst = 0;
+    quintptr m_dirtyLast = 0;
+    static void dataChangedLater(Cells* o, quintptr first, quintptr last);
+    static void flushDataChanged(Cells* o);
+signals:
+};
+
+class Meter : public QObject
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Meter for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Meter* create(QObject* parent) { return new Meter(false, parent); }
+        static Private*& d(Meter* o) { return o->m_d; }
+        static Private* d(const Meter* o) { return o->m_d; }
+        static void postTask(Meter* o, void* task) { Meter::postTask(o, task); }
+        static void emitLater(Meter* o, int signal) { Meter::emitLater(o, signal); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(quint32 level READ level WRITE setLevel NOTIFY levelChanged FINAL)
+    explicit Meter(bool owned, QObject *parent);
+public:
+    explicit Meter(QObject *parent = nullptr);
+    ~Meter();
+    quint32 level() const;
+    void setLevel(quint32 v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread and delivers
+    // the coalesced signals
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Meter* o, void* task);
+    // a bit for each coalesced signal that waits for delivery
+    QAtomicInt m_pendingSignals;
+    static void emitLater(Meter* o, int signal);
+signals:
+    void levelChanged();
+};
+#endif // TEST_COALESCE_RUST_H
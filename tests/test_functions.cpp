@@ -22,6 +22,9 @@
 #include <QTest>
 #include <QSignalSpy>
 
+// the classes are generated in the namespace from test_functions.json
+using namespace test::functions;
+
 class TestRustObject : public QObject
 {
     Q_OBJECT
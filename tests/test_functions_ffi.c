@@ -0,0 +1,94 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+
+/* Calls the Rust code of test_functions.json from C, without Qt. */
+
+#include "test_functions_rust_ffi.h"
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+static int changes = 0;
+
+static void user_name_changed(const struct PersonQObject* o) {
+    (void)o;
+    changes += 1;
+}
+
+/* There is no event loop that could run tasks later, so they are dropped. */
+static void run_on_gui_thread(const struct PersonQObject* o, void* task) {
+    (void)o;
+    test_person_drop_task(task);
+}
+
+/* Copy a string from Rust into a buffer of 256 bytes. */
+static void set_string(void* val, const char* utf8, int nbytes) {
+    char* buffer = (char*)val;
+    if (nbytes > 255) {
+        nbytes = 255;
+    }
+    memcpy(buffer, utf8, nbytes);
+    buffer[nbytes] = 0;
+}
+
+static int failures = 0;
+
+static void compare(const char* actual, const char* expected) {
+    if (strcmp(actual, expected) != 0) {
+        fprintf(stderr, "expected \"%s\" but got \"%s\"\n", expected, actual);
+        failures += 1;
+    }
+}
+
+int main(void) {
+    const uint16_t konqi[] = { 'K', 'o', 'n', 'q', 'i' };
+    const uint16_t john[] = { 'J', 'o', 'h', 'n' };
+    char buffer[256];
+    struct PersonQObject* qobject = (struct PersonQObject*)&changes;
+    struct PersonPrivate* person;
+
+    if (test_interface_binding_hash() != TEST_INTERFACE_BINDING_HASH) {
+        fprintf(stderr, "the header does not match the Rust code\n");
+        return EXIT_FAILURE;
+    }
+    person = test_person_new(qobject, run_on_gui_thread, user_name_changed);
+
+    test_person_user_name_set(person, konqi, 5);
+    test_person_user_name_get(person, buffer, set_string);
+    compare(buffer, "Konqi");
+    if (changes != 1) {
+        fprintf(stderr, "expected one change but got %d\n", changes);
+        failures += 1;
+    }
+    if (test_person_vowels_in_name(person) != 2) {
+        fprintf(stderr, "expected two vowels\n");
+        failures += 1;
+    }
+
+    test_person_greet(person, john, 4, buffer, set_string);
+    compare(buffer, "Hello John, my name is Konqi, how is it going?");
+
+    test_person_quote_bytes(person, "<", 1, ">", 1, buffer, set_string);
+    compare(buffer, "<Konqi>");
+
+    test_person_free(person);
+    return failures ? EXIT_FAILURE : EXIT_SUCCESS;
+}
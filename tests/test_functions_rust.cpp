@@ -1,7 +1,36 @@
 /* generated by rust_qt_binding_generator */
 #include "test_functions_rust.h"
+#include <QCoreApplication>
 
+namespace test {
+namespace functions {
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
     void set_qstring(QString* val, const char* utf8, int nbytes) {
@@ -13,74 +42,110 @@ namespace {
     }
 }
 extern "C" {
-    Person::Private* person_new(Person*, void (*)(Person*));
-    void person_free(Person::Private*);
-    void person_user_name_get(const Person::Private*, QString*, qstring_set);
-    void person_user_name_set(Person::Private*, const ushort *str, int len);
-    void person_append(Person::Private*, const ushort*, int, quint32);
-    void person_double_name(Person::Private*);
-    void person_greet(const Person::Private*, const ushort*, int, QString*, qstring_set);
-    void person_quote(const Person::Private*, const ushort*, int, const ushort*, int, QString*, qstring_set);
-    void person_quote_bytes(const Person::Private*, const char*, int, const char*, int, QString*, qstring_set);
-    quint8 person_vowels_in_name(const Person::Private*);
+    Person::Private* test_person_new(Person*, void (*)(Person*, void*), void (*)(Person*));
+    void test_person_free(Person::Private*);
+    void test_person_run_task(Person::Private*, void*);
+    void test_person_drop_task(void*);
+    void test_person_user_name_get(const Person::Private*, QString*, qstring_set);
+    void test_person_user_name_set(Person::Private*, const ushort *str, int len);
+    void test_person_greet(const Person::Private*, const ushort*, int, QString*, qstring_set);
+    void test_person_double_name(Person::Private*);
+    void test_person_append(Person::Private*, const ushort*, int, quint32);
+    quint8 test_person_vowels_in_name(const Person::Private*);
+    void test_person_quote(const Person::Private*, const ushort*, int, const ushort*, int, QString*, qstring_set);
+    void test_person_quote_bytes(const Person::Private*, const char*, int, const char*, int, QString*, qstring_set);
 };
 
+extern "C" {
+    quint64 test_interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = test_interface_binding_hash();
+        if (hash != Q_UINT64_C(0x8e70ef51af15ff76)) {
+            qFatal("test_functions_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Person::Person(bool /*owned*/, QObject *parent):
     QObject(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 Person::Person(QObject *parent):
     QObject(parent),
-    m_d(person_new(this,
-        personUserNameChanged)),
+    m_d((checkBindingHash(), test_person_new(this,
+        Person::postTask,
+        personUserNameChanged))),
     m_ownsPrivate(true)
 {
 }
 
 Person::~Person() {
     if (m_ownsPrivate) {
-        person_free(m_d);
+        test_person_free(m_d);
     }
 }
+
+bool Person::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        test_person_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Person::postTask(Person* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, test_person_drop_task));
+}
 QString Person::userName() const
 {
     QString v;
-    person_user_name_get(m_d, &v, set_qstring);
+    test_person_user_name_get(m_d, &v, set_qstring);
     return v;
 }
 void Person::setUserName(const QString& v) {
-    person_user_name_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+    test_person_user_name_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
 }
-void Person::append(const QString& suffix, quint32 amount)
+QString Person::greet(const QString& name) const
 {
-    return person_append(m_d, suffix.utf16(), suffix.size(), amount);
+    QString s;
+    test_person_greet(m_d, name.utf16(), name.size(), &s, set_qstring);
+    return s;
 }
 void Person::doubleName()
 {
-    return person_double_name(m_d);
+    return test_person_double_name(m_d);
 }
-QString Person::greet(const QString& name) const
+void Person::append(const QString& suffix, quint32 amount)
 {
-    QString s;
-    person_greet(m_d, name.utf16(), name.size(), &s, set_qstring);
-    return s;
+    return test_person_append(m_d, suffix.utf16(), suffix.size(), amount);
+}
+quint8 Person::vowelsInName() const
+{
+    return test_person_vowels_in_name(m_d);
 }
 QString Person::quote(const QString& prefix, const QString& suffix) const
 {
     QString s;
-    person_quote(m_d, prefix.utf16(), prefix.size(), suffix.utf16(), suffix.size(), &s, set_qstring);
+    test_person_quote(m_d, prefix.utf16(), prefix.size(), suffix.utf16(), suffix.size(), &s, set_qstring);
     return s;
 }
 QString Person::quoteBytes(const QByteArray& prefix, const QByteArray& suffix) const
 {
     QString s;
-    person_quote_bytes(m_d, prefix.data(), prefix.size(), suffix.data(), suffix.size(), &s, set_qstring);
+    test_person_quote_bytes(m_d, prefix.data(), prefix.size(), suffix.data(), suffix.size(), &s, set_qstring);
     return s;
 }
-quint8 Person::vowelsInName() const
-{
-    return person_vowels_in_name(m_d);
-}
+} // namespace functions
+} // namespace test
@@ -5,13 +5,26 @@
 #include <QObject>
 #include <QAbstractItemModel>
 
+namespace test {
+namespace functions {
 class Person;
 
+/**
+ * Someone with a name that can be greeted.
+ */
 class Person : public QObject
 {
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Person for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Person* create(QObject* parent) { return new Person(false, parent); }
+        static Private*& d(Person* o) { return o->m_d; }
+        static Private* d(const Person* o) { return o->m_d; }
+        static void postTask(Person* o, void* task) { Person::postTask(o, task); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -20,15 +33,37 @@ private:
 public:
     explicit Person(QObject *parent = nullptr);
     ~Person();
+    /**
+     * The name that greet() and quote() use.
+     */
     QString userName() const;
+    /**
+     * The name that greet() and quote() use.
+     */
     void setUserName(const QString& v);
-    Q_INVOKABLE void append(const QString& suffix, quint32 amount);
-    Q_INVOKABLE void doubleName();
+    /**
+     * @param name Who is greeted.
+     */
     Q_INVOKABLE QString greet(const QString& name) const;
+    Q_INVOKABLE void doubleName();
+    Q_INVOKABLE void append(const QString& suffix, quint32 amount);
+    Q_INVOKABLE quint8 vowelsInName() const;
+    /**
+     * The name between `prefix` and `suffix`.
+     * With /* and *\/ it becomes a C comment.
+     * @param prefix Put before the name.
+     * @param suffix Put after the name.
+     */
     Q_INVOKABLE QString quote(const QString& prefix, const QString& suffix) const;
     Q_INVOKABLE QString quoteBytes(const QByteArray& prefix, const QByteArray& suffix) const;
-    Q_INVOKABLE quint8 vowelsInName() const;
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Person* o, void* task);
 signals:
     void userNameChanged();
 };
+} // namespace functions
+} // namespace test
 #endif // TEST_FUNCTIONS_RUST_H
@@ -0,0 +1,119 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_FUNCTIONS_RUST_FFI_H
+#define TEST_FUNCTIONS_RUST_FFI_H
+
+/*
+ * The C functions that the Rust module interface exports for test_functions_rust.cpp.
+ *
+ * Each object is created with <name>_new and freed with <name>_free. The
+ * callbacks that are passed to <name>_new are called by Rust to emit signals.
+ * Strings are passed to Rust as UTF-16 and bytes as a pointer and a length.
+ * Strings and bytes from Rust are passed as UTF-8 to a setter function
+ * together with the pointer that was given by the caller. The data is only
+ * valid during that call.
+ */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#ifndef RUST_QT_QSTRING_SET
+#define RUST_QT_QSTRING_SET
+/** Receives a UTF-8 string from Rust and copies it into `val`. */
+typedef void (*qstring_set)(void* val, const char* utf8, int nbytes);
+#endif
+
+struct PersonPrivate;
+struct PersonQObject;
+
+/** The value that test_interface_binding_hash() returns for this header. */
+#define TEST_INTERFACE_BINDING_HASH UINT64_C(0x8e70ef51af15ff76)
+/**
+ * A hash of the configuration that the Rust code was generated from. When it
+ * differs from TEST_INTERFACE_BINDING_HASH,
+ * the functions in this header do not match the Rust code.
+ */
+uint64_t test_interface_binding_hash(void);
+
+/* Person */
+
+/**
+ * Create the Rust object for the Person object.
+ *
+ * The QObject pointers are passed back to the callbacks when the
+ * Rust object emits a signal. Objects that are properties come with
+ * their own QObject pointer and callbacks.
+ */
+struct PersonPrivate* test_person_new(
+    struct PersonQObject* person,
+    void (*person_run_on_gui_thread)(const struct PersonQObject*, void* task),
+    void (*user_name_changed)(const struct PersonQObject*));
+/**
+ * Free the Rust object for Person.
+ */
+void test_person_free(struct PersonPrivate* ptr);
+/**
+ * Run a task that Person passed to its run_on_gui_thread callback.
+ * This is called in the thread of the QObject and consumes the task.
+ */
+void test_person_run_task(struct PersonPrivate* ptr, void* task);
+/**
+ * Free a task of Person without running it.
+ */
+void test_person_drop_task(void* task);
+/**
+ * Get the property userName by passing its value to `set`.
+ *
+ * The name that greet() and quote() use.
+ */
+void test_person_user_name_get(const struct PersonPrivate* ptr, void* p, qstring_set set);
+/**
+ * Set the property userName.
+ */
+void test_person_user_name_set(struct PersonPrivate* ptr, const uint16_t* v, int len);
+/**
+ * Call Person.greet().
+ * @param name Who is greeted.
+ *
+ * The result is passed to `set`.
+ */
+void test_person_greet(const struct PersonPrivate* ptr, const uint16_t* name_str, int name_len, void* d, qstring_set set);
+/**
+ * Call Person.doubleName().
+ */
+void test_person_double_name(struct PersonPrivate* ptr);
+/**
+ * Call Person.append().
+ */
+void test_person_append(struct PersonPrivate* ptr, const uint16_t* suffix_str, int suffix_len, uint32_t amount);
+/**
+ * Call Person.vowelsInName().
+ */
+uint8_t test_person_vowels_in_name(const struct PersonPrivate* ptr);
+/**
+ * Call Person.quote().
+ *
+ * The name between `prefix` and `suffix`.
+ * With /* and *\/ it becomes a C comment.
+ * @param prefix Put before the name.
+ * @param suffix Put after the name.
+ *
+ * The result is passed to `set`.
+ */
+void test_person_quote(const struct PersonPrivate* ptr, const uint16_t* prefix_str, int prefix_len, const uint16_t* suffix_str, int suffix_len, void* d, qstring_set set);
+/**
+ * Call Person.quoteBytes().
+ *
+ * The result is passed to `set`.
+ */
+void test_person_quote_bytes(const struct PersonPrivate* ptr, const char* prefix_str, int prefix_len, const char* suffix_str, int suffix_len, void* d, qstring_set set);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // TEST_FUNCTIONS_RUST_FFI_H
@@ -0,0 +1,72 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include "test_imports_rust.h"
+#include <QTest>
+#include <QSignalSpy>
+
+class TestRustImports : public QObject
+{
+    Q_OBJECT
+private slots:
+    void testConstructor();
+    void testImportedObject();
+    void testImportedList();
+};
+
+void TestRustImports::testConstructor()
+{
+    Library library;
+}
+
+void TestRustImports::testImportedObject()
+{
+    // GIVEN
+    Library library;
+    QSignalSpy spy(library.author(), &Person::userNameChanged);
+
+    // WHEN
+    library.author()->setUserName("Konqi");
+
+    // THEN
+    QVERIFY(spy.isValid());
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(library.author()->userName(), QString("Konqi"));
+}
+
+void TestRustImports::testImportedList()
+{
+    // GIVEN
+    Library library;
+    QSignalSpy spy(library.readers(), &Readers::dataChanged);
+
+    // WHEN
+    const bool set = library.readers()->setUserName(1, "Konqi");
+
+    // THEN
+    QVERIFY(set);
+    QVERIFY(spy.isValid());
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(library.readers()->rowCount(), 3);
+    QCOMPARE(library.readers()->userName(1), QString("Konqi"));
+}
+
+QTEST_MAIN(TestRustImports)
+#include "test_imports.moc"
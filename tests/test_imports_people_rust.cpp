@@ -0,0 +1,368 @@
+/* generated by rust_qt_binding_generator */
+#include "test_imports_people_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    struct option_quintptr {
+    public:
+        quintptr value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_pod<option_quintptr>::value, "option_quintptr must be a POD type.");
+
+    typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
+    void set_qstring(QString* val, const char* utf8, int nbytes) {
+        *val = QString::fromUtf8(utf8, nbytes);
+    }
+
+    struct qmodelindex_t {
+        int row;
+        quintptr id;
+    };
+    inline QVariant cleanNullQVariant(const QVariant& v) {
+        return (v.isNull()) ?QVariant() :v;
+    }
+    inline void personUserNameChanged(Person* o)
+    {
+        emit o->userNameChanged();
+    }
+}
+extern "C" {
+    Person::Private* person_new(Person*, void (*)(Person*, void*), void (*)(Person*));
+    void person_free(Person::Private*);
+    void person_run_task(Person::Private*, void*);
+    void person_drop_task(void*);
+    void person_user_name_get(const Person::Private*, QString*, qstring_set);
+    void person_user_name_set(Person::Private*, const ushort *str, int len);
+};
+
+extern "C" {
+    void readers_data_user_name(const Readers::Private*, int, QString*, qstring_set);
+    bool readers_set_data_user_name(Readers::Private*, int, const ushort* s, int len);
+    void readers_sort(Readers::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int readers_row_count(const Readers::Private*);
+    bool readers_insert_rows(Readers::Private*, int, int);
+    bool readers_remove_rows(Readers::Private*, int, int);
+    bool readers_can_fetch_more(const Readers::Private*);
+    void readers_fetch_more(Readers::Private*);
+}
+int Readers::columnCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : 1;
+}
+
+bool Readers::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Readers::rowCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : readers_row_count(m_d);
+}
+
+bool Readers::insertRows(int row, int count, const QModelIndex &)
+{
+    return readers_insert_rows(m_d, row, count);
+}
+
+bool Readers::removeRows(int row, int count, const QModelIndex &)
+{
+    return readers_remove_rows(m_d, row, count);
+}
+
+QModelIndex Readers::index(int row, int column, const QModelIndex &parent) const
+{
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
+        return createIndex(row, column, (quintptr)row);
+    }
+    return QModelIndex();
+}
+
+QModelIndex Readers::parent(const QModelIndex &) const
+{
+    return QModelIndex();
+}
+
+bool Readers::canFetchMore(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : readers_can_fetch_more(m_d);
+}
+
+void Readers::fetchMore(const QModelIndex &parent)
+{
+    if (!parent.isValid()) {
+        readers_fetch_more(m_d);
+    }
+}
+
+void Readers::sort(int column, Qt::SortOrder order)
+{
+    readers_sort(m_d, column, order);
+}
+Qt::ItemFlags Readers::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    if (i.column() == 0) {
+        flags |= Qt::ItemIsEditable;
+    }
+    return flags;
+}
+
+QString Readers::userName(int row) const
+{
+    QString s;
+    readers_data_user_name(m_d, row, &s, set_qstring);
+    return s;
+}
+
+bool Readers::setUserName(int row, const QString& value)
+{
+    bool set = false;
+    set = readers_set_data_user_name(m_d, row, value.utf16(), value.length());
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QVariant Readers::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(userName(index.row()));
+        }
+    }
+    return QVariant();
+}
+
+int Readers::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Readers::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "userName");
+    return names;
+}
+QVariant Readers::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Readers::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+bool Readers::setData(const QModelIndex &index, const QVariant &value, int role)
+{
+    if (index.column() == 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setUserName(index.row(), value.value<QString>());
+            }
+        }
+    }
+    return false;
+}
+
+extern "C" {
+    Readers::Private* readers_new(Readers*, void (*)(Readers*, void*),
+        void (*)(const Readers*),
+        void (*)(Readers*, quintptr, quintptr),
+        void (*)(Readers*),
+        void (*)(Readers*),
+        void (*)(Readers*, int, int),
+        void (*)(Readers*),
+        void (*)(Readers*, int, int),
+        void (*)(Readers*));
+    void readers_free(Readers::Private*);
+    void readers_run_task(Readers::Private*, void*);
+    void readers_drop_task(void*);
+};
+
+extern "C" {
+    quint64 people_interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = people_interface_binding_hash();
+        if (hash != Q_UINT64_C(0x99bde2f1ef11a87a)) {
+            qFatal("test_imports_people_rust.cpp and the Rust module people_interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Person::Person(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Person::Person(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), person_new(this,
+        Person::postTask,
+        personUserNameChanged))),
+    m_ownsPrivate(true)
+{
+}
+
+Person::~Person() {
+    if (m_ownsPrivate) {
+        person_free(m_d);
+    }
+}
+
+bool Person::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        person_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Person::postTask(Person* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, person_drop_task));
+}
+QString Person::userName() const
+{
+    QString v;
+    person_user_name_get(m_d, &v, set_qstring);
+    return v;
+}
+void Person::setUserName(const QString& v) {
+    person_user_name_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+Readers::Readers(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Readers::Readers(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), readers_new(this,
+        Readers::postTask,
+        [](const Readers* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Readers* o, quintptr first, quintptr last) {
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 0, last));
+        },
+        [](Readers* o) {
+            o->beginResetModel();
+        },
+        [](Readers* o) {
+            o->endResetModel();
+        },
+        [](Readers* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](Readers* o) {
+            o->endInsertRows();
+        },
+        [](Readers* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](Readers* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Readers::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Readers::~Readers() {
+    if (m_ownsPrivate) {
+        readers_free(m_d);
+    }
+}
+
+bool Readers::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        readers_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Readers::postTask(Readers* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, readers_drop_task));
+}
+void Readers::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("userName"));
+}
@@ -0,0 +1,110 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_IMPORTS_PEOPLE_RUST_H
+#define TEST_IMPORTS_PEOPLE_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+
+class Person;
+class Readers;
+
+class Person : public QObject
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Person for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Person* create(QObject* parent) { return new Person(false, parent); }
+        static Private*& d(Person* o) { return o->m_d; }
+        static Private* d(const Person* o) { return o->m_d; }
+        static void postTask(Person* o, void* task) { Person::postTask(o, task); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(QString userName READ userName WRITE setUserName NOTIFY userNameChanged FINAL)
+    explicit Person(bool owned, QObject *parent);
+public:
+    explicit Person(QObject *parent = nullptr);
+    ~Person();
+    QString userName() const;
+    void setUserName(const QString& v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Person* o, void* task);
+signals:
+    void userNameChanged();
+};
+
+class Readers : public QAbstractItemModel
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Readers for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Readers* create(QObject* parent) { return new Readers(false, parent); }
+        static Private*& d(Readers* o) { return o->m_d; }
+        static Private* d(const Readers* o) { return o->m_d; }
+        static void postTask(Readers* o, void* task) { Readers::postTask(o, task); }
+        static QModelIndex createIndex(const Readers* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Readers* o) { o->beginResetModel(); }
+        static void endResetModel(Readers* o) { o->endResetModel(); }
+        static void beginInsertRows(Readers* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Readers* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Readers* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Readers* o) { o->endRemoveRows(); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit Readers(bool owned, QObject *parent);
+public:
+    explicit Readers(QObject *parent = nullptr);
+    ~Readers();
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE QString userName(int row) const;
+    Q_INVOKABLE bool setUserName(int row, const QString& value);
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Readers* o, void* task);
+signals:
+};
+#endif // TEST_IMPORTS_PEOPLE_RUST_H
@@ -0,0 +1,158 @@
+/* generated by rust_qt_binding_generator */
+#include "test_imports_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+    inline void personUserNameChanged(Person* o)
+    {
+        emit o->userNameChanged();
+    }
+}
+extern "C" {
+    Library::Private* library_new(Library*, void (*)(Library*, void*), Person*, void (*)(Person*, void*), void (*)(Person*), Readers*, void (*)(Readers*, void*),
+        void (*)(const Readers*),
+        void (*)(Readers*, quintptr, quintptr),
+        void (*)(Readers*),
+        void (*)(Readers*),
+        void (*)(Readers*, int, int),
+        void (*)(Readers*),
+        void (*)(Readers*, int, int),
+        void (*)(Readers*));
+    void library_free(Library::Private*);
+    void library_run_task(Library::Private*, void*);
+    void library_drop_task(void*);
+    Person::Private* library_author_get(const Library::Private*);
+    Readers::Private* library_readers_get(const Library::Private*);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x575e5cc1208b1175)) {
+            qFatal("test_imports_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Library::Library(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_author(Person::Binding::create(this)),
+    m_readers(Readers::Binding::create(this)),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Library::Library(QObject *parent):
+    QObject(parent),
+    m_author(Person::Binding::create(this)),
+    m_readers(Readers::Binding::create(this)),
+    m_d((checkBindingHash(), library_new(this,
+        Library::postTask, m_author,
+        Person::Binding::postTask,
+        personUserNameChanged, m_readers,
+        Readers::Binding::postTask,
+        [](const Readers* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Readers* o, quintptr first, quintptr last) {
+            o->dataChanged(Readers::Binding::createIndex(o, first, 0, first),
+                       Readers::Binding::createIndex(o, last, 0, last));
+        },
+        [](Readers* o) {
+            Readers::Binding::beginResetModel(o);
+        },
+        [](Readers* o) {
+            Readers::Binding::endResetModel(o);
+        },
+        [](Readers* o, int first, int last) {
+            Readers::Binding::beginInsertRows(o, QModelIndex(), first, last);
+        },
+        [](Readers* o) {
+            Readers::Binding::endInsertRows(o);
+        },
+        [](Readers* o, int first, int last) {
+            Readers::Binding::beginRemoveRows(o, QModelIndex(), first, last);
+        },
+        [](Readers* o) {
+            Readers::Binding::endRemoveRows(o);
+        }
+))),
+    m_ownsPrivate(true)
+{
+    Person::Binding::d(m_author) = library_author_get(m_d);
+    Readers::Binding::d(m_readers) = library_readers_get(m_d);
+    connect(this->m_readers, &Readers::newDataReady, this->m_readers, [this](const QModelIndex& i) {
+        this->m_readers->fetchMore(i);
+    }, Qt::QueuedConnection);
+}
+
+Library::~Library() {
+    if (m_ownsPrivate) {
+        library_free(m_d);
+    }
+}
+
+bool Library::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        library_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Library::postTask(Library* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, library_drop_task));
+}
+const Person* Library::author() const
+{
+    return m_author;
+}
+Person* Library::author()
+{
+    return m_author;
+}
+const Readers* Library::readers() const
+{
+    return m_readers;
+}
+Readers* Library::readers()
+{
+    return m_readers;
+}
@@ -0,0 +1,48 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_IMPORTS_RUST_H
+#define TEST_IMPORTS_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+#include "test_imports_people_rust.h"
+
+class Library;
+
+class Library : public QObject
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Library for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Library* create(QObject* parent) { return new Library(false, parent); }
+        static Private*& d(Library* o) { return o->m_d; }
+        static Private* d(const Library* o) { return o->m_d; }
+        static void postTask(Library* o, void* task) { Library::postTask(o, task); }
+    };
+private:
+    Person* const m_author;
+    Readers* const m_readers;
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(Person* author READ author NOTIFY authorChanged FINAL)
+    Q_PROPERTY(Readers* readers READ readers NOTIFY readersChanged FINAL)
+    explicit Library(bool owned, QObject *parent);
+public:
+    explicit Library(QObject *parent = nullptr);
+    ~Library();
+    const Person* author() const;
+    Person* author();
+    const Readers* readers() const;
+    Readers* readers();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Library* o, void* task);
+signals:
+    void authorChanged();
+    void readersChanged();
+};
+#endif // TEST_IMPORTS_RUST_H
@@ -30,6 +30,7 @@ private slots:
     void testStringGetter();
     void testStringSetter();
     void testAccessByDefaultRole();
+    void testInsertFromThread();
 };
 
 void TestRustList::testConstructor()
@@ -92,5 +93,24 @@ void TestRustList::testAccessByDefaultRole()
     QCOMPARE(age.value<quint8>(), (quint8)21);
 }
 
+void TestRustList::testInsertFromThread()
+{
+    // GIVEN
+    Persons persons;
+    QSignalSpy spy(&persons, &Persons::rowsInserted);
+
+    // WHEN
+    const bool inserted = persons.insertRows(10, 2);
+
+    // THEN
+    QVERIFY(inserted);
+    QVERIFY(spy.isValid());
+    // a Rust thread sends the insertion to this thread
+    QTRY_COMPARE(spy.count(), 1);
+    QCOMPARE(persons.rowCount(), 12);
+    QCOMPARE(spy.at(0).at(1).toInt(), 10);
+    QCOMPARE(spy.at(0).at(2).toInt(), 11);
+}
+
 QTEST_MAIN(TestRustList)
 #include "test_list.moc"
@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "test_list_rust.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     struct option_quintptr {
     public:
@@ -30,44 +57,42 @@ namespace {
     }
 }
 extern "C" {
-    quint8 no_role_data_user_age(const NoRole::Private*, int);
-    bool no_role_set_data_user_age(NoRole::Private*, int, quint8);
-    void no_role_data_user_name(const NoRole::Private*, int, QString*, qstring_set);
-    bool no_role_set_data_user_name(NoRole::Private*, int, const ushort* s, int len);
-    void no_role_sort(NoRole::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+    void persons_data_user_name(const Persons::Private*, int, QString*, qstring_set);
+    bool persons_set_data_user_name(Persons::Private*, int, const ushort* s, int len);
+    void persons_sort(Persons::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
-    int no_role_row_count(const NoRole::Private*);
-    bool no_role_insert_rows(NoRole::Private*, int, int);
-    bool no_role_remove_rows(NoRole::Private*, int, int);
-    bool no_role_can_fetch_more(const NoRole::Private*);
-    void no_role_fetch_more(NoRole::Private*);
+    int persons_row_count(const Persons::Private*);
+    bool persons_insert_rows(Persons::Private*, int, int);
+    bool persons_remove_rows(Persons::Private*, int, int);
+    bool persons_can_fetch_more(const Persons::Private*);
+    void persons_fetch_more(Persons::Private*);
 }
-int NoRole::columnCount(const QModelIndex &parent) const
+int Persons::columnCount(const QModelIndex &parent) const
 {
     return (parent.isValid()) ? 0 : 1;
 }
 
-bool NoRole::hasChildren(const QModelIndex &parent) const
+bool Persons::hasChildren(const QModelIndex &parent) const
 {
     return rowCount(parent) > 0;
 }
 
-int NoRole::rowCount(const QModelIndex &parent) const
+int Persons::rowCount(const QModelIndex &parent) const
 {
-    return (parent.isValid()) ? 0 : no_role_row_count(m_d);
+    return (parent.isValid()) ? 0 : persons_row_count(m_d);
 }
 
-bool NoRole::insertRows(int row, int count, const QModelIndex &)
+bool Persons::insertRows(int row, int count, const QModelIndex &)
 {
-    return no_role_insert_rows(m_d, row, count);
+    return persons_insert_rows(m_d, row, count);
 }
 
-bool NoRole::removeRows(int row, int count, const QModelIndex &)
+bool Persons::removeRows(int row, int count, const QModelIndex &)
 {
-    return no_role_remove_rows(m_d, row, count);
+    return persons_remove_rows(m_d, row, count);
 }
 
-QModelIndex NoRole::index(int row, int column, const QModelIndex &parent) const
+QModelIndex Persons::index(int row, int column, const QModelIndex &parent) const
 {
     if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
         return createIndex(row, column, (quintptr)row);
@@ -75,28 +100,28 @@ QModelIndex NoRole::index(int row, int column, const QModelIndex &parent) const
     return QModelIndex();
 }
 
-QModelIndex NoRole::parent(const QModelIndex &) const
+QModelIndex Persons::parent(const QModelIndex &) const
 {
     return QModelIndex();
 }
 
-bool NoRole::canFetchMore(const QModelIndex &parent) const
+bool Persons::canFetchMore(const QModelIndex &parent) const
 {
-    return (parent.isValid()) ? 0 : no_role_can_fetch_more(m_d);
+    return (parent.isValid()) ? 0 : persons_can_fetch_more(m_d);
 }
 
-void NoRole::fetchMore(const QModelIndex &parent)
+void Persons::fetchMore(const QModelIndex &parent)
 {
     if (!parent.isValid()) {
-        no_role_fetch_more(m_d);
+        persons_fetch_more(m_d);
     }
 }
 
-void NoRole::sort(int column, Qt::SortOrder order)
+void Persons::sort(int column, Qt::SortOrder order)
 {
-    no_role_sort(m_d, column, order);
+    persons_sort(m_d, column, order);
 }
-Qt::ItemFlags NoRole::flags(const QModelIndex &i) const
+Qt::ItemFlags Persons::flags(const QModelIndex &i) const
 {
     auto flags = QAbstractItemModel::flags(i);
     if (i.column() == 0) {
@@ -105,33 +130,17 @@ Qt::ItemFlags NoRole::flags(const QModelIndex &i) const
     return flags;
 }
 
-quint8 NoRole::userAge(int row) const
-{
-    return no_role_data_user_age(m_d, row);
-}
-
-bool NoRole::setUserAge(int row, quint8 value)
-{
-    bool set = false;
-    set = no_role_set_data_user_age(m_d, row, value);
-    if (set) {
-        QModelIndex index = createIndex(row, 0, row);
-        emit dataChanged(index, index);
-    }
-    return set;
-}
-
-QString NoRole::userName(int row) const
+QString Persons::userName(int row) const
 {
     QString s;
-    no_role_data_user_name(m_d, row, &s, set_qstring);
+    persons_data_user_name(m_d, row, &s, set_qstring);
     return s;
 }
 
-bool NoRole::setUserName(int row, const QString& value)
+bool Persons::setUserName(int row, const QString& value)
 {
     bool set = false;
-    set = no_role_set_data_user_name(m_d, row, value.utf16(), value.length());
+    set = persons_set_data_user_name(m_d, row, value.utf16(), value.length());
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -139,22 +148,22 @@ bool NoRole::setUserName(int row, const QString& value)
     return set;
 }
 
-QVariant NoRole::data(const QModelIndex &index, int role) const
+QVariant Persons::data(const QModelIndex &index, int role) const
 {
     Q_ASSERT(rowCount(index.parent()) > index.row());
     switch (index.column()) {
     case 0:
         switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
         case Qt::UserRole + 0:
-            return QVariant::fromValue(userAge(index.row()));
-        case Qt::UserRole + 1:
             return QVariant::fromValue(userName(index.row()));
         }
     }
     return QVariant();
 }
 
-int NoRole::role(const char* name) const {
+int Persons::role(const char* name) const {
     auto names = roleNames();
     auto i = names.constBegin();
     while (i != names.constEnd()) {
@@ -165,13 +174,12 @@ int NoRole::role(const char* name) const {
     }
     return -1;
 }
-QHash<int, QByteArray> NoRole::roleNames() const {
+QHash<int, QByteArray> Persons::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
-    names.insert(Qt::UserRole + 0, "userAge");
-    names.insert(Qt::UserRole + 1, "userName");
+    names.insert(Qt::UserRole + 0, "userName");
     return names;
 }
-QVariant NoRole::headerData(int section, Qt::Orientation orientation, int role) const
+QVariant Persons::headerData(int section, Qt::Orientation orientation, int role) const
 {
     if (orientation != Qt::Horizontal) {
         return QVariant();
@@ -179,7 +187,7 @@ QVariant NoRole::headerData(int section, Qt::Orientation orientation, int role)
     return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
 }
 
-bool NoRole::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+bool Persons::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
 {
     if (orientation != Qt::Horizontal) {
         return false;
@@ -188,15 +196,10 @@ bool NoRole::setHeaderData(int section, Qt::Orientation orientation, const QVari
     return true;
 }
 
-bool NoRole::setData(const QModelIndex &index, const QVariant &value, int role)
+bool Persons::setData(const QModelIndex &index, const QVariant &value, int role)
 {
     if (index.column() == 0) {
-        if (role == Qt::UserRole + 0) {
-            if (value.canConvert(qMetaTypeId<quint8>())) {
-                return setUserAge(index.row(), value.value<quint8>());
-            }
-        }
-        if (role == Qt::UserRole + 1) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
             if (value.canConvert(qMetaTypeId<QString>())) {
                 return setUserName(index.row(), value.value<QString>());
             }
@@ -206,55 +209,59 @@ bool NoRole::setData(const QModelIndex &index, const QVariant &value, int role)
 }
 
 extern "C" {
-    NoRole::Private* no_role_new(NoRole*,
-        void (*)(const NoRole*),
-        void (*)(NoRole*, quintptr, quintptr),
-        void (*)(NoRole*),
-        void (*)(NoRole*),
-        void (*)(NoRole*, int, int),
-        void (*)(NoRole*),
-        void (*)(NoRole*, int, int),
-        void (*)(NoRole*));
-    void no_role_free(NoRole::Private*);
+    Persons::Private* persons_new(Persons*, void (*)(Persons*, void*),
+        void (*)(const Persons*),
+        void (*)(Persons*, quintptr, quintptr),
+        void (*)(Persons*),
+        void (*)(Persons*),
+        void (*)(Persons*, int, int),
+        void (*)(Persons*),
+        void (*)(Persons*, int, int),
+        void (*)(Persons*));
+    void persons_free(Persons::Private*);
+    void persons_run_task(Persons::Private*, void*);
+    void persons_drop_task(void*);
 };
 
 extern "C" {
-    void persons_data_user_name(const Persons::Private*, int, QString*, qstring_set);
-    bool persons_set_data_user_name(Persons::Private*, int, const ushort* s, int len);
-    void persons_sort(Persons::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+    void no_role_data_user_name(const NoRole::Private*, int, QString*, qstring_set);
+    bool no_role_set_data_user_name(NoRole::Private*, int, const ushort* s, int len);
+    quint8 no_role_data_user_age(const NoRole::Private*, int);
+    bool no_role_set_data_user_age(NoRole::Private*, int, quint8);
+    void no_role_sort(NoRole::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
-    int persons_row_count(const Persons::Private*);
-    bool persons_insert_rows(Persons::Private*, int, int);
-    bool persons_remove_rows(Persons::Private*, int, int);
-    bool persons_can_fetch_more(const Persons::Private*);
-    void persons_fetch_more(Persons::Private*);
+    int no_role_row_count(const NoRole::Private*);
+    bool no_role_insert_rows(NoRole::Private*, int, int);
+    bool no_role_remove_rows(NoRole::Private*, int, int);
+    bool no_role_can_fetch_more(const NoRole::Private*);
+    void no_role_fetch_more(NoRole::Private*);
 }
-int Persons::columnCount(const QModelIndex &parent) const
+int NoRole::columnCount(const QModelIndex &parent) const
 {
     return (parent.isValid()) ? 0 : 1;
 }
 
-bool Persons::hasChildren(const QModelIndex &parent) const
+bool NoRole::hasChildren(const QModelIndex &parent) const
 {
     return rowCount(parent) > 0;
 }
 
-int Persons::rowCount(const QModelIndex &parent) const
+int NoRole::rowCount(const QModelIndex &parent) const
 {
-    return (parent.isValid()) ? 0 : persons_row_count(m_d);
+    return (parent.isValid()) ? 0 : no_role_row_count(m_d);
 }
 
-bool Persons::insertRows(int row, int count, const QModelIndex &)
+bool NoRole::insertRows(int row, int count, const QModelIndex &)
 {
-    return persons_insert_rows(m_d, row, count);
+    return no_role_insert_rows(m_d, row, count);
 }
 
-bool Persons::removeRows(int row, int count, const QModelIndex &)
+bool NoRole::removeRows(int row, int count, const QModelIndex &)
 {
-    return persons_remove_rows(m_d, row, count);
+    return no_role_remove_rows(m_d, row, count);
 }
 
-QModelIndex Persons::index(int row, int column, const QModelIndex &parent) const
+QModelIndex NoRole::index(int row, int column, const QModelIndex &parent) const
 {
     if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
         return createIndex(row, column, (quintptr)row);
@@ -262,28 +269,28 @@ QModelIndex Persons::index(int row, int column, const QModelIndex &parent) const
     return QModelIndex();
 }
 
-QModelIndex Persons::parent(const QModelIndex &) const
+QModelIndex NoRole::parent(const QModelIndex &) const
 {
     return QModelIndex();
 }
 
-bool Persons::canFetchMore(const QModelIndex &parent) const
+bool NoRole::canFetchMore(const QModelIndex &parent) const
 {
-    return (parent.isValid()) ? 0 : persons_can_fetch_more(m_d);
+    return (parent.isValid()) ? 0 : no_role_can_fetch_more(m_d);
 }
 
-void Persons::fetchMore(const QModelIndex &parent)
+void NoRole::fetchMore(const QModelIndex &parent)
 {
     if (!parent.isValid()) {
-        persons_fetch_more(m_d);
+        no_role_fetch_more(m_d);
     }
 }
 
-void Persons::sort(int column, Qt::SortOrder order)
+void NoRole::sort(int column, Qt::SortOrder order)
 {
-    persons_sort(m_d, column, order);
+    no_role_sort(m_d, column, order);
 }
-Qt::ItemFlags Persons::flags(const QModelIndex &i) const
+Qt::ItemFlags NoRole::flags(const QModelIndex &i) const
 {
     auto flags = QAbstractItemModel::flags(i);
     if (i.column() == 0) {
@@ -292,17 +299,17 @@ Qt::ItemFlags Persons::flags(const QModelIndex &i) const
     return flags;
 }
 
-QString Persons::userName(int row) const
+QString NoRole::userName(int row) const
 {
     QString s;
-    persons_data_user_name(m_d, row, &s, set_qstring);
+    no_role_data_user_name(m_d, row, &s, set_qstring);
     return s;
 }
 
-bool Persons::setUserName(int row, const QString& value)
+bool NoRole::setUserName(int row, const QString& value)
 {
     bool set = false;
-    set = persons_set_data_user_name(m_d, row, value.utf16(), value.length());
+    set = no_role_set_data_user_name(m_d, row, value.utf16(), value.length());
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -310,22 +317,38 @@ bool Persons::setUserName(int row, const QString& value)
     return set;
 }
 
-QVariant Persons::data(const QModelIndex &index, int role) const
+quint8 NoRole::userAge(int row) const
+{
+    return no_role_data_user_age(m_d, row);
+}
+
+bool NoRole::setUserAge(int row, quint8 value)
+{
+    bool set = false;
+    set = no_role_set_data_user_age(m_d, row, value);
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QVariant NoRole::data(const QModelIndex &index, int role) const
 {
     Q_ASSERT(rowCount(index.parent()) > index.row());
     switch (index.column()) {
     case 0:
         switch (role) {
-        case Qt::DisplayRole:
-        case Qt::EditRole:
         case Qt::UserRole + 0:
             return QVariant::fromValue(userName(index.row()));
+        case Qt::UserRole + 1:
+            return QVariant::fromValue(userAge(index.row()));
         }
     }
     return QVariant();
 }
 
-int Persons::role(const char* name) const {
+int NoRole::role(const char* name) const {
     auto names = roleNames();
     auto i = names.constBegin();
     while (i != names.constEnd()) {
@@ -336,12 +359,13 @@ int Persons::role(const char* name) const {
     }
     return -1;
 }
-QHash<int, QByteArray> Persons::roleNames() const {
+QHash<int, QByteArray> NoRole::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
     names.insert(Qt::UserRole + 0, "userName");
+    names.insert(Qt::UserRole + 1, "userAge");
     return names;
 }
-QVariant Persons::headerData(int section, Qt::Orientation orientation, int role) const
+QVariant NoRole::headerData(int section, Qt::Orientation orientation, int role) const
 {
     if (orientation != Qt::Horizontal) {
         return QVariant();
@@ -349,7 +373,7 @@ QVariant Persons::headerData(int section, Qt::Orientation orientation, int role)
     return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
 }
 
-bool Persons::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+bool NoRole::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
 {
     if (orientation != Qt::Horizontal) {
         return false;
@@ -358,133 +382,188 @@ bool Persons::setHeaderData(int section, Qt::Orientation orientation, const QVar
     return true;
 }
 
-bool Persons::setData(const QModelIndex &index, const QVariant &value, int role)
+bool NoRole::setData(const QModelIndex &index, const QVariant &value, int role)
 {
     if (index.column() == 0) {
-        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+        if (role == Qt::UserRole + 0) {
             if (value.canConvert(qMetaTypeId<QString>())) {
                 return setUserName(index.row(), value.value<QString>());
             }
         }
+        if (role == Qt::UserRole + 1) {
+            if (value.canConvert(qMetaTypeId<quint8>())) {
+                return setUserAge(index.row(), value.value<quint8>());
+            }
+        }
     }
     return false;
 }
 
 extern "C" {
-    Persons::Private* persons_new(Persons*,
-        void (*)(const Persons*),
-        void (*)(Persons*, quintptr, quintptr),
-        void (*)(Persons*),
-        void (*)(Persons*),
-        void (*)(Persons*, int, int),
-        void (*)(Persons*),
-        void (*)(Persons*, int, int),
-        void (*)(Persons*));
-    void persons_free(Persons::Private*);
+    NoRole::Private* no_role_new(NoRole*, void (*)(NoRole*, void*),
+        void (*)(const NoRole*),
+        void (*)(NoRole*, quintptr, quintptr),
+        void (*)(NoRole*),
+        void (*)(NoRole*),
+        void (*)(NoRole*, int, int),
+        void (*)(NoRole*),
+        void (*)(NoRole*, int, int),
+        void (*)(NoRole*));
+    void no_role_free(NoRole::Private*);
+    void no_role_run_task(NoRole::Private*, void*);
+    void no_role_drop_task(void*);
 };
 
-NoRole::NoRole(bool /*owned*/, QObject *parent):
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x5b4e9b65a8ba4139)) {
+            qFatal("test_list_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Persons::Persons(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
-NoRole::NoRole(QObject *parent):
+Persons::Persons(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(no_role_new(this,
-        [](const NoRole* o) {
+    m_d((checkBindingHash(), persons_new(this,
+        Persons::postTask,
+        [](const Persons* o) {
             emit o->newDataReady(QModelIndex());
         },
-        [](NoRole* o, quintptr first, quintptr last) {
+        [](Persons* o, quintptr first, quintptr last) {
             o->dataChanged(o->createIndex(first, 0, first),
                        o->createIndex(last, 0, last));
         },
-        [](NoRole* o) {
+        [](Persons* o) {
             o->beginResetModel();
         },
-        [](NoRole* o) {
+        [](Persons* o) {
             o->endResetModel();
         },
-        [](NoRole* o, int first, int last) {
+        [](Persons* o, int first, int last) {
             o->beginInsertRows(QModelIndex(), first, last);
         },
-        [](NoRole* o) {
+        [](Persons* o) {
             o->endInsertRows();
         },
-        [](NoRole* o, int first, int last) {
+        [](Persons* o, int first, int last) {
             o->beginRemoveRows(QModelIndex(), first, last);
         },
-        [](NoRole* o) {
+        [](Persons* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
-    connect(this, &NoRole::newDataReady, this, [this](const QModelIndex& i) {
+    connect(this, &Persons::newDataReady, this, [this](const QModelIndex& i) {
         this->fetchMore(i);
     }, Qt::QueuedConnection);
     initHeaderData();
 }
 
-NoRole::~NoRole() {
+Persons::~Persons() {
     if (m_ownsPrivate) {
-        no_role_free(m_d);
+        persons_free(m_d);
     }
 }
-void NoRole::initHeaderData() {
+
+bool Persons::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        persons_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
 }
-Persons::Persons(bool /*owned*/, QObject *parent):
+
+void Persons::postTask(Persons* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, persons_drop_task));
+}
+void Persons::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("userName"));
+}
+NoRole::NoRole(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
-Persons::Persons(QObject *parent):
+NoRole::NoRole(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(persons_new(this,
-        [](const Persons* o) {
+    m_d((checkBindingHash(), no_role_new(this,
+        NoRole::postTask,
+        [](const NoRole* o) {
             emit o->newDataReady(QModelIndex());
         },
-        [](Persons* o, quintptr first, quintptr last) {
+        [](NoRole* o, quintptr first, quintptr last) {
             o->dataChanged(o->createIndex(first, 0, first),
                        o->createIndex(last, 0, last));
         },
-        [](Persons* o) {
+        [](NoRole* o) {
             o->beginResetModel();
         },
-        [](Persons* o) {
+        [](NoRole* o) {
             o->endResetModel();
         },
-        [](Persons* o, int first, int last) {
+        [](NoRole* o, int first, int last) {
             o->beginInsertRows(QModelIndex(), first, last);
         },
-        [](Persons* o) {
+        [](NoRole* o) {
             o->endInsertRows();
         },
-        [](Persons* o, int first, int last) {
+        [](NoRole* o, int first, int last) {
             o->beginRemoveRows(QModelIndex(), first, last);
         },
-        [](Persons* o) {
+        [](NoRole* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
-    connect(this, &Persons::newDataReady, this, [this](const QModelIndex& i) {
+    connect(this, &NoRole::newDataReady, this, [this](const QModelIndex& i) {
         this->fetchMore(i);
     }, Qt::QueuedConnection);
     initHeaderData();
 }
 
-Persons::~Persons() {
+NoRole::~NoRole() {
     if (m_ownsPrivate) {
-        persons_free(m_d);
+        no_role_free(m_d);
     }
 }
-void Persons::initHeaderData() {
-    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("userName"));
+
+bool NoRole::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        no_role_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void NoRole::postTask(NoRole* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, no_role_drop_task));
+}
+void NoRole::initHeaderData() {
 }
@@ -5,21 +5,42 @@
 #include <QObject>
 #include <QAbstractItemModel>
 
-class NoRole;
 class Persons;
+class NoRole;
 
-class NoRole : public QAbstractItemModel
+class Persons : public QAbstractItemModel
 {
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Persons for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Persons* create(QObject* parent) { return new Persons(false, parent); }
+        static Private*& d(Persons* o) { return o->m_d; }
+        static Private* d(const Persons* o) { return o->m_d; }
+        static void postTask(Persons* o, void* task) { Persons::postTask(o, task); }
+        static QModelIndex createIndex(const Persons* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Persons* o) { o->beginResetModel(); }
+        static void endResetModel(Persons* o) { o->endResetModel(); }
+        static void beginInsertRows(Persons* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Persons* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Persons* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Persons* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
-    explicit NoRole(bool owned, QObject *parent);
+    explicit Persons(bool owned, QObject *parent);
 public:
-    explicit NoRole(QObject *parent = nullptr);
-    ~NoRole();
+    explicit Persons(QObject *parent = nullptr);
+    ~Persons();
 
     int columnCount(const QModelIndex &parent = QModelIndex()) const override;
     QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
@@ -38,8 +59,6 @@ public:
     Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
     bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
-    Q_INVOKABLE quint8 userAge(int row) const;
-    Q_INVOKABLE bool setUserAge(int row, quint8 value);
     Q_INVOKABLE QString userName(int row) const;
     Q_INVOKABLE bool setUserName(int row, const QString& value);
 
@@ -49,21 +68,47 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Persons* o, void* task);
 signals:
 };
 
-class Persons : public QAbstractItemModel
+class NoRole : public QAbstractItemModel
 {
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects NoRole for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static NoRole* create(QObject* parent) { return new NoRole(false, parent); }
+        static Private*& d(NoRole* o) { return o->m_d; }
+        static Private* d(const NoRole* o) { return o->m_d; }
+        static void postTask(NoRole* o, void* task) { NoRole::postTask(o, task); }
+        static QModelIndex createIndex(const NoRole* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(NoRole* o) { o->beginResetModel(); }
+        static void endResetModel(NoRole* o) { o->endResetModel(); }
+        static void beginInsertRows(NoRole* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(NoRole* o) { o->endInsertRows(); }
+        static void beginRemoveRows(NoRole* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(NoRole* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
-    explicit Persons(bool owned, QObject *parent);
+    explicit NoRole(bool owned, QObject *parent);
 public:
-    explicit Persons(QObject *parent = nullptr);
-    ~Persons();
+    explicit NoRole(QObject *parent = nullptr);
+    ~NoRole();
 
     int columnCount(const QModelIndex &parent = QModelIndex()) const override;
     QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
@@ -84,6 +129,8 @@ public:
     bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
     Q_INVOKABLE QString userName(int row) const;
     Q_INVOKABLE bool setUserName(int row, const QString& value);
+    Q_INVOKABLE quint8 userAge(int row) const;
+    Q_INVOKABLE bool setUserAge(int row, quint8 value);
 
 signals:
     // new data is ready to be made available to the model with fetchMore()
@@ -91,6 +138,11 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(NoRole* o, void* task);
 signals:
 };
 #endif // TEST_LIST_RUST_H
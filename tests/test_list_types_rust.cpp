@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "test_list_types_rust.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     struct option_bool {
     public:
@@ -55,39 +82,39 @@ namespace {
 extern "C" {
     bool list_data_boolean(const List::Private*, int);
     bool list_set_data_boolean(List::Private*, int, bool);
-    void list_data_bytearray(const List::Private*, int, QByteArray*, qbytearray_set);
-    bool list_set_data_bytearray(List::Private*, int, const char* s, int len);
-    float list_data_f32(const List::Private*, int);
-    bool list_set_data_f32(List::Private*, int, float);
-    double list_data_f64(const List::Private*, int);
-    bool list_set_data_f64(List::Private*, int, double);
-    qint16 list_data_i16(const List::Private*, int);
-    bool list_set_data_i16(List::Private*, int, qint16);
-    qint32 list_data_i32(const List::Private*, int);
-    bool list_set_data_i32(List::Private*, int, qint32);
-    qint64 list_data_i64(const List::Private*, int);
-    bool list_set_data_i64(List::Private*, int, qint64);
-    qint8 list_data_i8(const List::Private*, int);
-    bool list_set_data_i8(List::Private*, int, qint8);
     option_bool list_data_optional_boolean(const List::Private*, int);
     bool list_set_data_optional_boolean(List::Private*, int, bool);
     bool list_set_data_optional_boolean_none(List::Private*, int);
-    void list_data_optional_bytearray(const List::Private*, int, QByteArray*, qbytearray_set);
-    bool list_set_data_optional_bytearray(List::Private*, int, const char* s, int len);
-    bool list_set_data_optional_bytearray_none(List::Private*, int);
-    void list_data_optional_string(const List::Private*, int, QString*, qstring_set);
-    bool list_set_data_optional_string(List::Private*, int, const ushort* s, int len);
-    bool list_set_data_optional_string_none(List::Private*, int);
-    void list_data_string(const List::Private*, int, QString*, qstring_set);
-    bool list_set_data_string(List::Private*, int, const ushort* s, int len);
+    qint8 list_data_i8(const List::Private*, int);
+    bool list_set_data_i8(List::Private*, int, qint8);
+    quint8 list_data_u8(const List::Private*, int);
+    bool list_set_data_u8(List::Private*, int, quint8);
+    qint16 list_data_i16(const List::Private*, int);
+    bool list_set_data_i16(List::Private*, int, qint16);
     quint16 list_data_u16(const List::Private*, int);
     bool list_set_data_u16(List::Private*, int, quint16);
+    qint32 list_data_i32(const List::Private*, int);
+    bool list_set_data_i32(List::Private*, int, qint32);
     quint32 list_data_u32(const List::Private*, int);
     bool list_set_data_u32(List::Private*, int, quint32);
+    qint64 list_data_i64(const List::Private*, int);
+    bool list_set_data_i64(List::Private*, int, qint64);
     quint64 list_data_u64(const List::Private*, int);
     bool list_set_data_u64(List::Private*, int, quint64);
-    quint8 list_data_u8(const List::Private*, int);
-    bool list_set_data_u8(List::Private*, int, quint8);
+    float list_data_f32(const List::Private*, int);
+    bool list_set_data_f32(List::Private*, int, float);
+    double list_data_f64(const List::Private*, int);
+    bool list_set_data_f64(List::Private*, int, double);
+    void list_data_string(const List::Private*, int, QString*, qstring_set);
+    bool list_set_data_string(List::Private*, int, const ushort* s, int len);
+    void list_data_optional_string(const List::Private*, int, QString*, qstring_set);
+    bool list_set_data_optional_string(List::Private*, int, const ushort* s, int len);
+    bool list_set_data_optional_string_none(List::Private*, int);
+    void list_data_bytearray(const List::Private*, int, QByteArray*, qbytearray_set);
+    bool list_set_data_bytearray(List::Private*, int, const char* s, int len);
+    void list_data_optional_bytearray(const List::Private*, int, QByteArray*, qbytearray_set);
+    bool list_set_data_optional_bytearray(List::Private*, int, const char* s, int len);
+    bool list_set_data_optional_bytearray_none(List::Private*, int);
     void list_sort(List::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
 
     int list_row_count(const List::Private*);
@@ -175,17 +202,24 @@ bool List::setBoolean(int row, bool value)
     return set;
 }
 
-QByteArray List::bytearray(int row) const
+QVariant List::optionalBoolean(int row) const
 {
-    QByteArray b;
-    list_data_bytearray(m_d, row, &b, set_qbytearray);
-    return b;
+    QVariant v;
+    v = list_data_optional_boolean(m_d, row);
+    return v;
 }
 
-bool List::setBytearray(int row, const QByteArray& value)
+bool List::setOptionalBoolean(int row, const QVariant& value)
 {
     bool set = false;
-    set = list_set_data_bytearray(m_d, row, value.data(), value.length());
+    if (value.isNull() || !value.isValid()) {
+        set = list_set_data_optional_boolean_none(m_d, row);
+    } else {
+    if (!value.canConvert(qMetaTypeId<bool>())) {
+        return false;
+    }
+    set = list_set_data_optional_boolean(m_d, row, value.value<bool>());
+    }
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -193,15 +227,15 @@ bool List::setBytearray(int row, const QByteArray& value)
     return set;
 }
 
-float List::f32(int row) const
+qint8 List::i8(int row) const
 {
-    return list_data_f32(m_d, row);
+    return list_data_i8(m_d, row);
 }
 
-bool List::setF32(int row, float value)
+bool List::setI8(int row, qint8 value)
 {
     bool set = false;
-    set = list_set_data_f32(m_d, row, value);
+    set = list_set_data_i8(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -209,15 +243,15 @@ bool List::setF32(int row, float value)
     return set;
 }
 
-double List::f64(int row) const
+quint8 List::u8(int row) const
 {
-    return list_data_f64(m_d, row);
+    return list_data_u8(m_d, row);
 }
 
-bool List::setF64(int row, double value)
+bool List::setU8(int row, quint8 value)
 {
     bool set = false;
-    set = list_set_data_f64(m_d, row, value);
+    set = list_set_data_u8(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -241,15 +275,15 @@ bool List::setI16(int row, qint16 value)
     return set;
 }
 
-qint32 List::i32(int row) const
+quint16 List::u16(int row) const
 {
-    return list_data_i32(m_d, row);
+    return list_data_u16(m_d, row);
 }
 
-bool List::setI32(int row, qint32 value)
+bool List::setU16(int row, quint16 value)
 {
     bool set = false;
-    set = list_set_data_i32(m_d, row, value);
+    set = list_set_data_u16(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -257,15 +291,15 @@ bool List::setI32(int row, qint32 value)
     return set;
 }
 
-qint64 List::i64(int row) const
+qint32 List::i32(int row) const
 {
-    return list_data_i64(m_d, row);
+    return list_data_i32(m_d, row);
 }
 
-bool List::setI64(int row, qint64 value)
+bool List::setI32(int row, qint32 value)
 {
     bool set = false;
-    set = list_set_data_i64(m_d, row, value);
+    set = list_set_data_i32(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -273,15 +307,15 @@ bool List::setI64(int row, qint64 value)
     return set;
 }
 
-qint8 List::i8(int row) const
+quint32 List::u32(int row) const
 {
-    return list_data_i8(m_d, row);
+    return list_data_u32(m_d, row);
 }
 
-bool List::setI8(int row, qint8 value)
+bool List::setU32(int row, quint32 value)
 {
     bool set = false;
-    set = list_set_data_i8(m_d, row, value);
+    set = list_set_data_u32(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -289,24 +323,15 @@ bool List::setI8(int row, qint8 value)
     return set;
 }
 
-QVariant List::optionalBoolean(int row) const
+qint64 List::i64(int row) const
 {
-    QVariant v;
-    v = list_data_optional_boolean(m_d, row);
-    return v;
+    return list_data_i64(m_d, row);
 }
 
-bool List::setOptionalBoolean(int row, const QVariant& value)
+bool List::setI64(int row, qint64 value)
 {
     bool set = false;
-    if (value.isNull() || !value.isValid()) {
-        set = list_set_data_optional_boolean_none(m_d, row);
-    } else {
-    if (!value.canConvert(qMetaTypeId<bool>())) {
-        return false;
-    }
-    set = list_set_data_optional_boolean(m_d, row, value.value<bool>());
-    }
+    set = list_set_data_i64(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -314,21 +339,15 @@ bool List::setOptionalBoolean(int row, const QVariant& value)
     return set;
 }
 
-QByteArray List::optionalBytearray(int row) const
+quint64 List::u64(int row) const
 {
-    QByteArray b;
-    list_data_optional_bytearray(m_d, row, &b, set_qbytearray);
-    return b;
+    return list_data_u64(m_d, row);
 }
 
-bool List::setOptionalBytearray(int row, const QByteArray& value)
+bool List::setU64(int row, quint64 value)
 {
     bool set = false;
-    if (value.isNull()) {
-        set = list_set_data_optional_bytearray_none(m_d, row);
-    } else {
-    set = list_set_data_optional_bytearray(m_d, row, value.data(), value.length());
-    }
+    set = list_set_data_u64(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -336,21 +355,15 @@ bool List::setOptionalBytearray(int row, const QByteArray& value)
     return set;
 }
 
-QString List::optionalString(int row) const
+float List::f32(int row) const
 {
-    QString s;
-    list_data_optional_string(m_d, row, &s, set_qstring);
-    return s;
+    return list_data_f32(m_d, row);
 }
 
-bool List::setOptionalString(int row, const QString& value)
+bool List::setF32(int row, float value)
 {
     bool set = false;
-    if (value.isNull()) {
-        set = list_set_data_optional_string_none(m_d, row);
-    } else {
-    set = list_set_data_optional_string(m_d, row, value.utf16(), value.length());
-    }
+    set = list_set_data_f32(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -358,17 +371,15 @@ bool List::setOptionalString(int row, const QString& value)
     return set;
 }
 
-QString List::string(int row) const
+double List::f64(int row) const
 {
-    QString s;
-    list_data_string(m_d, row, &s, set_qstring);
-    return s;
+    return list_data_f64(m_d, row);
 }
 
-bool List::setString(int row, const QString& value)
+bool List::setF64(int row, double value)
 {
     bool set = false;
-    set = list_set_data_string(m_d, row, value.utf16(), value.length());
+    set = list_set_data_f64(m_d, row, value);
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -376,15 +387,17 @@ bool List::setString(int row, const QString& value)
     return set;
 }
 
-quint16 List::u16(int row) const
+QString List::string(int row) const
 {
-    return list_data_u16(m_d, row);
+    QString s;
+    list_data_string(m_d, row, &s, set_qstring);
+    return s;
 }
 
-bool List::setU16(int row, quint16 value)
+bool List::setString(int row, const QString& value)
 {
     bool set = false;
-    set = list_set_data_u16(m_d, row, value);
+    set = list_set_data_string(m_d, row, value.utf16(), value.length());
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -392,15 +405,21 @@ bool List::setU16(int row, quint16 value)
     return set;
 }
 
-quint32 List::u32(int row) const
+QString List::optionalString(int row) const
 {
-    return list_data_u32(m_d, row);
+    QString s;
+    list_data_optional_string(m_d, row, &s, set_qstring);
+    return s;
 }
 
-bool List::setU32(int row, quint32 value)
+bool List::setOptionalString(int row, const QString& value)
 {
     bool set = false;
-    set = list_set_data_u32(m_d, row, value);
+    if (value.isNull()) {
+        set = list_set_data_optional_string_none(m_d, row);
+    } else {
+    set = list_set_data_optional_string(m_d, row, value.utf16(), value.length());
+    }
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -408,15 +427,17 @@ bool List::setU32(int row, quint32 value)
     return set;
 }
 
-quint64 List::u64(int row) const
+QByteArray List::bytearray(int row) const
 {
-    return list_data_u64(m_d, row);
+    QByteArray b;
+    list_data_bytearray(m_d, row, &b, set_qbytearray);
+    return b;
 }
 
-bool List::setU64(int row, quint64 value)
+bool List::setBytearray(int row, const QByteArray& value)
 {
     bool set = false;
-    set = list_set_data_u64(m_d, row, value);
+    set = list_set_data_bytearray(m_d, row, value.data(), value.length());
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -424,15 +445,21 @@ bool List::setU64(int row, quint64 value)
     return set;
 }
 
-quint8 List::u8(int row) const
+QByteArray List::optionalBytearray(int row) const
 {
-    return list_data_u8(m_d, row);
+    QByteArray b;
+    list_data_optional_bytearray(m_d, row, &b, set_qbytearray);
+    return b;
 }
 
-bool List::setU8(int row, quint8 value)
+bool List::setOptionalBytearray(int row, const QByteArray& value)
 {
     bool set = false;
-    set = list_set_data_u8(m_d, row, value);
+    if (value.isNull()) {
+        set = list_set_data_optional_bytearray_none(m_d, row);
+    } else {
+    set = list_set_data_optional_bytearray(m_d, row, value.data(), value.length());
+    }
     if (set) {
         QModelIndex index = createIndex(row, 0, row);
         emit dataChanged(index, index);
@@ -449,37 +476,37 @@ QVariant List::data(const QModelIndex &index, int role) const
         case Qt::UserRole + 0:
             return QVariant::fromValue(boolean(index.row()));
         case Qt::UserRole + 1:
-            return QVariant::fromValue(bytearray(index.row()));
+            return optionalBoolean(index.row());
         case Qt::UserRole + 2:
-            return QVariant::fromValue(f32(index.row()));
+            return QVariant::fromValue(i8(index.row()));
         case Qt::UserRole + 3:
-            return QVariant::fromValue(f64(index.row()));
+            return QVariant::fromValue(u8(index.row()));
         case Qt::UserRole + 4:
             return QVariant::fromValue(i16(index.row()));
         case Qt::UserRole + 5:
-            return QVariant::fromValue(i32(index.row()));
+            return QVariant::fromValue(u16(index.row()));
         case Qt::UserRole + 6:
-            return QVariant::fromValue(i64(index.row()));
+            return QVariant::fromValue(i32(index.row()));
         case Qt::UserRole + 7:
-            return QVariant::fromValue(i8(index.row()));
+            return QVariant::fromValue(u32(index.row()));
         case Qt::UserRole + 8:
-            return optionalBoolean(index.row());
+            return QVariant::fromValue(i64(index.row()));
         case Qt::UserRole + 9:
-            return cleanNullQVariant(QVariant::fromValue(optionalBytearray(index.row())));
+            return QVariant::fromValue(u64(index.row()));
         case Qt::UserRole + 10:
-            return cleanNullQVariant(QVariant::fromValue(optionalString(index.row())));
+            return QVariant::fromValue(f32(index.row()));
+        case Qt::UserRole + 11:
+            return QVariant::fromValue(f64(index.row()));
         case Qt::DisplayRole:
         case Qt::EditRole:
-        case Qt::UserRole + 11:
-            return QVariant::fromValue(string(index.row()));
         case Qt::UserRole + 12:
-            return QVariant::fromValue(u16(index.row()));
+            return QVariant::fromValue(string(index.row()));
         case Qt::UserRole + 13:
-            return QVariant::fromValue(u32(index.row()));
+            return cleanNullQVariant(QVariant::fromValue(optionalString(index.row())));
         case Qt::UserRole + 14:
-            return QVariant::fromValue(u64(index.row()));
+            return QVariant::fromValue(bytearray(index.row()));
         case Qt::UserRole + 15:
-            return QVariant::fromValue(u8(index.row()));
+            return cleanNullQVariant(QVariant::fromValue(optionalBytearray(index.row())));
         }
     }
     return QVariant();
@@ -499,21 +526,21 @@ int List::role(const char* name) const {
 QHash<int, QByteArray> List::roleNames() const {
     QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
     names.insert(Qt::UserRole + 0, "boolean");
-    names.insert(Qt::UserRole + 1, "bytearray");
-    names.insert(Qt::UserRole + 2, "f32");
-    names.insert(Qt::UserRole + 3, "f64");
+    names.insert(Qt::UserRole + 1, "optionalBoolean");
+    names.insert(Qt::UserRole + 2, "i8");
+    names.insert(Qt::UserRole + 3, "u8");
     names.insert(Qt::UserRole + 4, "i16");
-    names.insert(Qt::UserRole + 5, "i32");
-    names.insert(Qt::UserRole + 6, "i64");
-    names.insert(Qt::UserRole + 7, "i8");
-    names.insert(Qt::UserRole + 8, "optionalBoolean");
-    names.insert(Qt::UserRole + 9, "optionalBytearray");
-    names.insert(Qt::UserRole + 10, "optionalString");
-    names.insert(Qt::UserRole + 11, "string");
-    names.insert(Qt::UserRole + 12, "u16");
-    names.insert(Qt::UserRole + 13, "u32");
-    names.insert(Qt::UserRole + 14, "u64");
-    names.insert(Qt::UserRole + 15, "u8");
+    names.insert(Qt::UserRole + 5, "u16");
+    names.insert(Qt::UserRole + 6, "i32");
+    names.insert(Qt::UserRole + 7, "u32");
+    names.insert(Qt::UserRole + 8, "i64");
+    names.insert(Qt::UserRole + 9, "u64");
+    names.insert(Qt::UserRole + 10, "f32");
+    names.insert(Qt::UserRole + 11, "f64");
+    names.insert(Qt::UserRole + 12, "string");
+    names.insert(Qt::UserRole + 13, "optionalString");
+    names.insert(Qt::UserRole + 14, "bytearray");
+    names.insert(Qt::UserRole + 15, "optionalBytearray");
     return names;
 }
 QVariant List::headerData(int section, Qt::Orientation orientation, int role) const
@@ -542,18 +569,16 @@ bool List::setData(const QModelIndex &index, const QVariant &value, int role)
             }
         }
         if (role == Qt::UserRole + 1) {
-            if (value.canConvert(qMetaTypeId<QByteArray>())) {
-                return setBytearray(index.row(), value.value<QByteArray>());
-            }
+            return setOptionalBoolean(index.row(), value);
         }
         if (role == Qt::UserRole + 2) {
-            if (value.canConvert(qMetaTypeId<float>())) {
-                return setF32(index.row(), value.value<float>());
+            if (value.canConvert(qMetaTypeId<qint8>())) {
+                return setI8(index.row(), value.value<qint8>());
             }
         }
         if (role == Qt::UserRole + 3) {
-            if (value.canConvert(qMetaTypeId<double>())) {
-                return setF64(index.row(), value.value<double>());
+            if (value.canConvert(qMetaTypeId<quint8>())) {
+                return setU8(index.row(), value.value<quint8>());
             }
         }
         if (role == Qt::UserRole + 4) {
@@ -562,56 +587,58 @@ bool List::setData(const QModelIndex &index, const QVariant &value, int role)
             }
         }
         if (role == Qt::UserRole + 5) {
-            if (value.canConvert(qMetaTypeId<qint32>())) {
-                return setI32(index.row(), value.value<qint32>());
+            if (value.canConvert(qMetaTypeId<quint16>())) {
+                return setU16(index.row(), value.value<quint16>());
             }
         }
         if (role == Qt::UserRole + 6) {
-            if (value.canConvert(qMetaTypeId<qint64>())) {
-                return setI64(index.row(), value.value<qint64>());
+            if (value.canConvert(qMetaTypeId<qint32>())) {
+                return setI32(index.row(), value.value<qint32>());
             }
         }
         if (role == Qt::UserRole + 7) {
-            if (value.canConvert(qMetaTypeId<qint8>())) {
-                return setI8(index.row(), value.value<qint8>());
+            if (value.canConvert(qMetaTypeId<quint32>())) {
+                return setU32(index.row(), value.value<quint32>());
             }
         }
         if (role == Qt::UserRole + 8) {
-            return setOptionalBoolean(index.row(), value);
+            if (value.canConvert(qMetaTypeId<qint64>())) {
+                return setI64(index.row(), value.value<qint64>());
+            }
         }
         if (role == Qt::UserRole + 9) {
-            if (!value.isValid() || value.isNull() ||value.canConvert(qMetaTypeId<QByteArray>())) {
-                return setOptionalBytearray(index.row(), value.value<QByteArray>());
+            if (value.canConvert(qMetaTypeId<quint64>())) {
+                return setU64(index.row(), value.value<quint64>());
             }
         }
         if (role == Qt::UserRole + 10) {
-            if (!value.isValid() || value.isNull() ||value.canConvert(qMetaTypeId<QString>())) {
-                return setOptionalString(index.row(), value.value<QString>());
+            if (value.canConvert(qMetaTypeId<float>())) {
+                return setF32(index.row(), value.value<float>());
             }
         }
-        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 11) {
-            if (value.canConvert(qMetaTypeId<QString>())) {
-                return setString(index.row(), value.value<QString>());
+        if (role == Qt::UserRole + 11) {
+            if (value.canConvert(qMetaTypeId<double>())) {
+                return setF64(index.row(), value.value<double>());
             }
         }
-        if (role == Qt::UserRole + 12) {
-            if (value.canConvert(qMetaTypeId<quint16>())) {
-                return setU16(index.row(), value.value<quint16>());
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 12) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setString(index.row(), value.value<QString>());
             }
         }
         if (role == Qt::UserRole + 13) {
-            if (value.canConvert(qMetaTypeId<quint32>())) {
-                return setU32(index.row(), value.value<quint32>());
+            if (!value.isValid() || value.isNull() ||value.canConvert(qMetaTypeId<QString>())) {
+                return setOptionalString(index.row(), value.value<QString>());
             }
         }
         if (role == Qt::UserRole + 14) {
-            if (value.canConvert(qMetaTypeId<quint64>())) {
-                return setU64(index.row(), value.value<quint64>());
+            if (value.canConvert(qMetaTypeId<QByteArray>())) {
+                return setBytearray(index.row(), value.value<QByteArray>());
             }
         }
         if (role == Qt::UserRole + 15) {
-            if (value.canConvert(qMetaTypeId<quint8>())) {
-                return setU8(index.row(), value.value<quint8>());
+            if (!value.isValid() || value.isNull() ||value.canConvert(qMetaTypeId<QByteArray>())) {
+                return setOptionalBytearray(index.row(), value.value<QByteArray>());
             }
         }
     }
@@ -619,7 +646,7 @@ bool List::setData(const QModelIndex &index, const QVariant &value, int role)
 }
 
 extern "C" {
-    List::Private* list_new(List*,
+    List::Private* list_new(List*, void (*)(List*, void*),
         void (*)(const List*),
         void (*)(List*, quintptr, quintptr),
         void (*)(List*),
@@ -629,19 +656,39 @@ extern "C" {
         void (*)(List*, int, int),
         void (*)(List*));
     void list_free(List::Private*);
+    void list_run_task(List::Private*, void*);
+    void list_drop_task(void*);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
 };
 
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xe00d27b7b2b00a91)) {
+            qFatal("test_list_types_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 List::List(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
 List::List(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(list_new(this,
+    m_d((checkBindingHash(), list_new(this,
+        List::postTask,
         [](const List* o) {
             emit o->newDataReady(QModelIndex());
         },
@@ -667,7 +714,7 @@ List::List(QObject *parent):
         [](List* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
     connect(this, &List::newDataReady, this, [this](const QModelIndex& i) {
@@ -681,6 +728,20 @@ List::~List() {
         list_free(m_d);
     }
 }
+
+bool List::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        list_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void List::postTask(List* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, list_drop_task));
+}
 void List::initHeaderData() {
     m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("string"));
 }
@@ -12,6 +12,27 @@ class List : public QAbstractItemModel
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects List for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static List* create(QObject* parent) { return new List(false, parent); }
+        static Private*& d(List* o) { return o->m_d; }
+        static Private* d(const List* o) { return o->m_d; }
+        static void postTask(List* o, void* task) { List::postTask(o, task); }
+        static QModelIndex createIndex(const List* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(List* o) { o->beginResetModel(); }
+        static void endResetModel(List* o) { o->endResetModel(); }
+        static void beginInsertRows(List* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(List* o) { o->endInsertRows(); }
+        static void beginRemoveRows(List* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(List* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -39,36 +60,36 @@ public:
     bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
     Q_INVOKABLE bool boolean(int row) const;
     Q_INVOKABLE bool setBoolean(int row, bool value);
-    Q_INVOKABLE QByteArray bytearray(int row) const;
-    Q_INVOKABLE bool setBytearray(int row, const QByteArray& value);
-    Q_INVOKABLE float f32(int row) const;
-    Q_INVOKABLE bool setF32(int row, float value);
-    Q_INVOKABLE double f64(int row) const;
-    Q_INVOKABLE bool setF64(int row, double value);
-    Q_INVOKABLE qint16 i16(int row) const;
-    Q_INVOKABLE bool setI16(int row, qint16 value);
-    Q_INVOKABLE qint32 i32(int row) const;
-    Q_INVOKABLE bool setI32(int row, qint32 value);
-    Q_INVOKABLE qint64 i64(int row) const;
-    Q_INVOKABLE bool setI64(int row, qint64 value);
-    Q_INVOKABLE qint8 i8(int row) const;
-    Q_INVOKABLE bool setI8(int row, qint8 value);
     Q_INVOKABLE QVariant optionalBoolean(int row) const;
     Q_INVOKABLE bool setOptionalBoolean(int row, const QVariant& value);
-    Q_INVOKABLE QByteArray optionalBytearray(int row) const;
-    Q_INVOKABLE bool setOptionalBytearray(int row, const QByteArray& value);
-    Q_INVOKABLE QString optionalString(int row) const;
-    Q_INVOKABLE bool setOptionalString(int row, const QString& value);
-    Q_INVOKABLE QString string(int row) const;
-    Q_INVOKABLE bool setString(int row, const QString& value);
+    Q_INVOKABLE qint8 i8(int row) const;
+    Q_INVOKABLE bool setI8(int row, qint8 value);
+    Q_INVOKABLE quint8 u8(int row) const;
+    Q_INVOKABLE bool setU8(int row, quint8 value);
+    Q_INVOKABLE qint16 i16(int row) const;
+    Q_INVOKABLE bool setI16(int row, qint16 value);
     Q_INVOKABLE quint16 u16(int row) const;
     Q_INVOKABLE bool setU16(int row, quint16 value);
+    Q_INVOKABLE qint32 i32(int row) const;
+    Q_INVOKABLE bool setI32(int row, qint32 value);
     Q_INVOKABLE quint32 u32(int row) const;
     Q_INVOKABLE bool setU32(int row, quint32 value);
+    Q_INVOKABLE qint64 i64(int row) const;
+    Q_INVOKABLE bool setI64(int row, qint64 value);
     Q_INVOKABLE quint64 u64(int row) const;
     Q_INVOKABLE bool setU64(int row, quint64 value);
-    Q_INVOKABLE quint8 u8(int row) const;
-    Q_INVOKABLE bool setU8(int row, quint8 value);
+    Q_INVOKABLE float f32(int row) const;
+    Q_INVOKABLE bool setF32(int row, float value);
+    Q_INVOKABLE double f64(int row) const;
+    Q_INVOKABLE bool setF64(int row, double value);
+    Q_INVOKABLE QString string(int row) const;
+    Q_INVOKABLE bool setString(int row, const QString& value);
+    Q_INVOKABLE QString optionalString(int row) const;
+    Q_INVOKABLE bool setOptionalString(int row, const QString& value);
+    Q_INVOKABLE QByteArray bytearray(int row) const;
+    Q_INVOKABLE bool setBytearray(int row, const QByteArray& value);
+    Q_INVOKABLE QByteArray optionalBytearray(int row) const;
+    Q_INVOKABLE bool setOptionalBytearray(int row, const QByteArray& value);
 
 signals:
     // new data is ready to be made available to the model with fetchMore()
@@ -76,6 +97,11 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(List* o, void* task);
 signals:
 };
 #endif // TEST_LIST_TYPES_RUST_H
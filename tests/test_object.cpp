@@ -21,6 +21,7 @@
 #include "test_object_rust.h"
 #include <QTest>
 #include <QSignalSpy>
+#include <QMetaProperty>
 
 class TestRustObject : public QObject
 {
@@ -29,6 +30,8 @@ private slots:
     void testConstructor();
     void testStringGetter();
     void testStringSetter();
+    void testReset();
+    void testConstant();
 };
 
 void TestRustObject::testConstructor()
@@ -57,5 +60,35 @@ void TestRustObject::testStringSetter()
     QCOMPARE(person.userName(), QString("Konqi"));
 }
 
+void TestRustObject::testReset()
+{
+    // GIVEN
+    Person person;
+    person.setAge(42);
+    QSignalSpy spy(&person, &Person::ageChanged);
+
+    // WHEN
+    QVERIFY(person.metaObject()->property(
+        person.metaObject()->indexOfProperty("age")).reset(&person));
+
+    // THEN
+    QVERIFY(spy.isValid());
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(person.age(), (quint8)0);
+}
+
+void TestRustObject::testConstant()
+{
+    // GIVEN
+    Person person;
+    auto property = person.metaObject()->property(
+        person.metaObject()->indexOfProperty("species"));
+
+    // THEN
+    QVERIFY(property.isConstant());
+    QVERIFY(!property.hasNotifySignal());
+    QCOMPARE(person.species(), QString("Konqi"));
+}
+
 QTEST_MAIN(TestRustObject)
 #include "test_object.moc"
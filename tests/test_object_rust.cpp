@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "test_object_rust.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
     void set_qstring(QString* val, const char* utf8, int nbytes) {
@@ -11,27 +38,57 @@ namespace {
     {
         emit o->userNameChanged();
     }
+    inline void personAgeChanged(Person* o)
+    {
+        emit o->ageChanged();
+    }
 }
 extern "C" {
-    Person::Private* person_new(Person*, void (*)(Person*));
+    Person::Private* person_new(Person*, void (*)(Person*, void*), void (*)(Person*), void (*)(Person*));
     void person_free(Person::Private*);
+    void person_run_task(Person::Private*, void*);
+    void person_drop_task(void*);
     void person_user_name_get(const Person::Private*, QString*, qstring_set);
     void person_user_name_set(Person::Private*, const ushort *str, int len);
+    quint8 person_age_get(const Person::Private*);
+    void person_age_set(Person::Private*, quint8);
+    void person_age_reset(Person::Private*);
+    void person_species_get(const Person::Private*, QString*, qstring_set);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
 };
 
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x6535b948b4df56d9)) {
+            qFatal("test_object_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Person::Person(bool /*owned*/, QObject *parent):
     QObject(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 Person::Person(QObject *parent):
     QObject(parent),
-    m_d(person_new(this,
-        personUserNameChanged)),
+    m_d((checkBindingHash(), person_new(this,
+        Person::postTask,
+        personUserNameChanged,
+        personAgeChanged))),
     m_ownsPrivate(true)
 {
+    readConstants();
 }
 
 Person::~Person() {
@@ -39,6 +96,24 @@ Person::~Person() {
         person_free(m_d);
     }
 }
+
+bool Person::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        person_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Person::postTask(Person* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, person_drop_task));
+}
+void Person::readConstants()
+{
+    person_species_get(m_d, &m_species, set_qstring);
+}
 QString Person::userName() const
 {
     QString v;
@@ -48,3 +123,17 @@ QString Person::userName() const
 void Person::setUserName(const QString& v) {
     person_user_name_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
 }
+quint8 Person::age() const
+{
+    return person_age_get(m_d);
+}
+void Person::setAge(quint8 v) {
+    person_age_set(m_d, v);
+}
+void Person::resetAge() {
+    person_age_reset(m_d);
+}
+QString Person::species() const
+{
+    return m_species;
+}
@@ -12,17 +12,41 @@ class Person : public QObject
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Person for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Person* create(QObject* parent) { return new Person(false, parent); }
+        static Private*& d(Person* o) { return o->m_d; }
+        static Private* d(const Person* o) { return o->m_d; }
+        static void postTask(Person* o, void* task) { Person::postTask(o, task); }
+        static void readConstants(Person* o) { o->readConstants(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
+    // the values of the const properties, read once from Rust
+    QString m_species;
+    void readConstants();
     Q_PROPERTY(QString userName READ userName WRITE setUserName NOTIFY userNameChanged FINAL)
+    Q_PROPERTY(quint8 age READ age WRITE setAge RESET resetAge NOTIFY ageChanged FINAL)
+    Q_PROPERTY(QString species READ species CONSTANT FINAL)
     explicit Person(bool owned, QObject *parent);
 public:
     explicit Person(QObject *parent = nullptr);
     ~Person();
     QString userName() const;
     void setUserName(const QString& v);
+    quint8 age() const;
+    void setAge(quint8 v);
+    void resetAge();
+    QString species() const;
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Person* o, void* task);
 signals:
     void userNameChanged();
+    void ageChanged();
 };
 #endif // TEST_OBJECT_RUST_H
@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "test_object_types_rust.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     struct option_bool {
     public:
@@ -47,141 +74,161 @@ namespace {
     {
         emit o->booleanChanged();
     }
-    inline void objectBytearrayChanged(Object* o)
+    inline void objectOptionalBooleanChanged(Object* o)
     {
-        emit o->bytearrayChanged();
+        emit o->optionalBooleanChanged();
     }
-    inline void objectF32Changed(Object* o)
+    inline void objectI8Changed(Object* o)
     {
-        emit o->f32Changed();
+        emit o->i8Changed();
     }
-    inline void objectF64Changed(Object* o)
+    inline void objectU8Changed(Object* o)
     {
-        emit o->f64Changed();
+        emit o->u8Changed();
     }
     inline void objectI16Changed(Object* o)
     {
         emit o->i16Changed();
     }
+    inline void objectU16Changed(Object* o)
+    {
+        emit o->u16Changed();
+    }
     inline void objectI32Changed(Object* o)
     {
         emit o->i32Changed();
     }
-    inline void objectI64Changed(Object* o)
+    inline void objectU32Changed(Object* o)
     {
-        emit o->i64Changed();
+        emit o->u32Changed();
     }
-    inline void objectI8Changed(Object* o)
+    inline void objectI64Changed(Object* o)
     {
-        emit o->i8Changed();
+        emit o->i64Changed();
     }
-    inline void objectOptionalBooleanChanged(Object* o)
+    inline void objectU64Changed(Object* o)
     {
-        emit o->optionalBooleanChanged();
+        emit o->u64Changed();
     }
-    inline void objectOptionalBytearrayChanged(Object* o)
+    inline void objectOptionalU64Changed(Object* o)
     {
-        emit o->optionalBytearrayChanged();
+        emit o->optionalU64Changed();
     }
-    inline void objectOptionalStringChanged(Object* o)
+    inline void objectF32Changed(Object* o)
     {
-        emit o->optionalStringChanged();
+        emit o->f32Changed();
     }
-    inline void objectOptionalU64Changed(Object* o)
+    inline void objectF64Changed(Object* o)
     {
-        emit o->optionalU64Changed();
+        emit o->f64Changed();
     }
     inline void objectStringChanged(Object* o)
     {
         emit o->stringChanged();
     }
-    inline void objectU16Changed(Object* o)
-    {
-        emit o->u16Changed();
-    }
-    inline void objectU32Changed(Object* o)
+    inline void objectOptionalStringChanged(Object* o)
     {
-        emit o->u32Changed();
+        emit o->optionalStringChanged();
     }
-    inline void objectU64Changed(Object* o)
+    inline void objectBytearrayChanged(Object* o)
     {
-        emit o->u64Changed();
+        emit o->bytearrayChanged();
     }
-    inline void objectU8Changed(Object* o)
+    inline void objectOptionalBytearrayChanged(Object* o)
     {
-        emit o->u8Changed();
+        emit o->optionalBytearrayChanged();
     }
 }
 extern "C" {
-    Object::Private* object_new(Object*, void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*));
+    Object::Private* object_new(Object*, void (*)(Object*, void*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*), void (*)(Object*));
     void object_free(Object::Private*);
+    void object_run_task(Object::Private*, void*);
+    void object_drop_task(void*);
     bool object_boolean_get(const Object::Private*);
     void object_boolean_set(Object::Private*, bool);
-    void object_bytearray_get(const Object::Private*, QByteArray*, qbytearray_set);
-    void object_bytearray_set(Object::Private*, const char* bytes, int len);
-    float object_f32_get(const Object::Private*);
-    void object_f32_set(Object::Private*, float);
-    double object_f64_get(const Object::Private*);
-    void object_f64_set(Object::Private*, double);
+    option_bool object_optional_boolean_get(const Object::Private*);
+    void object_optional_boolean_set(Object::Private*, bool);
+    void object_optional_boolean_set_none(Object::Private*);
+    qint8 object_i8_get(const Object::Private*);
+    void object_i8_set(Object::Private*, qint8);
+    quint8 object_u8_get(const Object::Private*);
+    void object_u8_set(Object::Private*, quint8);
     qint16 object_i16_get(const Object::Private*);
     void object_i16_set(Object::Private*, qint16);
+    quint16 object_u16_get(const Object::Private*);
+    void object_u16_set(Object::Private*, quint16);
     qint32 object_i32_get(const Object::Private*);
     void object_i32_set(Object::Private*, qint32);
+    quint32 object_u32_get(const Object::Private*);
+    void object_u32_set(Object::Private*, quint32);
     qint64 object_i64_get(const Object::Private*);
     void object_i64_set(Object::Private*, qint64);
-    qint8 object_i8_get(const Object::Private*);
-    void object_i8_set(Object::Private*, qint8);
-    option_bool object_optional_boolean_get(const Object::Private*);
-    void object_optional_boolean_set(Object::Private*, bool);
-    void object_optional_boolean_set_none(Object::Private*);
-    void object_optional_bytearray_get(const Object::Private*, QByteArray*, qbytearray_set);
-    void object_optional_bytearray_set(Object::Private*, const char* bytes, int len);
-    void object_optional_bytearray_set_none(Object::Private*);
-    void object_optional_string_get(const Object::Private*, QString*, qstring_set);
-    void object_optional_string_set(Object::Private*, const ushort *str, int len);
-    void object_optional_string_set_none(Object::Private*);
+    quint64 object_u64_get(const Object::Private*);
+    void object_u64_set(Object::Private*, quint64);
     option_quint64 object_optional_u64_get(const Object::Private*);
     void object_optional_u64_set(Object::Private*, quint64);
     void object_optional_u64_set_none(Object::Private*);
+    float object_f32_get(const Object::Private*);
+    void object_f32_set(Object::Private*, float);
+    double object_f64_get(const Object::Private*);
+    void object_f64_set(Object::Private*, double);
     void object_string_get(const Object::Private*, QString*, qstring_set);
     void object_string_set(Object::Private*, const ushort *str, int len);
-    quint16 object_u16_get(const Object::Private*);
-    void object_u16_set(Object::Private*, quint16);
-    quint32 object_u32_get(const Object::Private*);
-    void object_u32_set(Object::Private*, quint32);
-    quint64 object_u64_get(const Object::Private*);
-    void object_u64_set(Object::Private*, quint64);
-    quint8 object_u8_get(const Object::Private*);
-    void object_u8_set(Object::Private*, quint8);
+    void object_optional_string_get(const Object::Private*, QString*, qstring_set);
+    void object_optional_string_set(Object::Private*, const ushort *str, int len);
+    void object_optional_string_set_none(Object::Private*);
+    void object_bytearray_get(const Object::Private*, QByteArray*, qbytearray_set);
+    void object_bytearray_set(Object::Private*, const char* bytes, int len);
+    void object_optional_bytearray_get(const Object::Private*, QByteArray*, qbytearray_set);
+    void object_optional_bytearray_set(Object::Private*, const char* bytes, int len);
+    void object_optional_bytearray_set_none(Object::Private*);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
 };
 
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x6608da53a1939b42)) {
+            qFatal("test_object_types_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Object::Object(bool /*owned*/, QObject *parent):
     QObject(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 Object::Object(QObject *parent):
     QObject(parent),
-    m_d(object_new(this,
+    m_d((checkBindingHash(), object_new(this,
+        Object::postTask,
         objectBooleanChanged,
-        objectBytearrayChanged,
-        objectF32Changed,
-        objectF64Changed,
+        objectOptionalBooleanChanged,
+        objectI8Changed,
+        objectU8Changed,
         objectI16Changed,
+        objectU16Changed,
         objectI32Changed,
+        objectU32Changed,
         objectI64Changed,
-        objectI8Changed,
-        objectOptionalBooleanChanged,
-        objectOptionalBytearrayChanged,
-        objectOptionalStringChanged,
+        objectU64Changed,
         objectOptionalU64Changed,
+        objectF32Changed,
+        objectF64Changed,
         objectStringChanged,
-        objectU16Changed,
-        objectU32Changed,
-        objectU64Changed,
-        objectU8Changed)),
+        objectOptionalStringChanged,
+        objectBytearrayChanged,
+        objectOptionalBytearrayChanged))),
     m_ownsPrivate(true)
 {
 }
@@ -191,6 +238,20 @@ Object::~Object() {
         object_free(m_d);
     }
 }
+
+bool Object::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        object_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Object::postTask(Object* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, object_drop_task));
+}
 bool Object::boolean() const
 {
     return object_boolean_get(m_d);
@@ -198,28 +259,35 @@ bool Object::boolean() const
 void Object::setBoolean(bool v) {
     object_boolean_set(m_d, v);
 }
-QByteArray Object::bytearray() const
+QVariant Object::optionalBoolean() const
 {
-    QByteArray v;
-    object_bytearray_get(m_d, &v, set_qbytearray);
-    return v;
+    QVariant v;
+    auto r = object_optional_boolean_get(m_d);
+    if (r.some) {
+        v.setValue(r.value);
+    }
+    return r;
 }
-void Object::setBytearray(const QByteArray& v) {
-    object_bytearray_set(m_d, v.data(), v.size());
+void Object::setOptionalBoolean(const QVariant& v) {
+    if (v.isNull() || !v.canConvert<bool>()) {
+        object_optional_boolean_set_none(m_d);
+    } else {
+        object_optional_boolean_set(m_d, v.value<bool>());
+    }
 }
-float Object::f32() const
+qint8 Object::i8() const
 {
-    return object_f32_get(m_d);
+    return object_i8_get(m_d);
 }
-void Object::setF32(float v) {
-    object_f32_set(m_d, v);
+void Object::setI8(qint8 v) {
+    object_i8_set(m_d, v);
 }
-double Object::f64() const
+quint8 Object::u8() const
 {
-    return object_f64_get(m_d);
+    return object_u8_get(m_d);
 }
-void Object::setF64(double v) {
-    object_f64_set(m_d, v);
+void Object::setU8(quint8 v) {
+    object_u8_set(m_d, v);
 }
 qint16 Object::i16() const
 {
@@ -228,6 +296,13 @@ qint16 Object::i16() const
 void Object::setI16(qint16 v) {
     object_i16_set(m_d, v);
 }
+quint16 Object::u16() const
+{
+    return object_u16_get(m_d);
+}
+void Object::setU16(quint16 v) {
+    object_u16_set(m_d, v);
+}
 qint32 Object::i32() const
 {
     return object_i32_get(m_d);
@@ -235,6 +310,13 @@ qint32 Object::i32() const
 void Object::setI32(qint32 v) {
     object_i32_set(m_d, v);
 }
+quint32 Object::u32() const
+{
+    return object_u32_get(m_d);
+}
+void Object::setU32(quint32 v) {
+    object_u32_set(m_d, v);
+}
 qint64 Object::i64() const
 {
     return object_i64_get(m_d);
@@ -242,54 +324,12 @@ qint64 Object::i64() const
 void Object::setI64(qint64 v) {
     object_i64_set(m_d, v);
 }
-qint8 Object::i8() const
-{
-    return object_i8_get(m_d);
-}
-void Object::setI8(qint8 v) {
-    object_i8_set(m_d, v);
-}
-QVariant Object::optionalBoolean() const
-{
-    QVariant v;
-    auto r = object_optional_boolean_get(m_d);
-    if (r.some) {
-        v.setValue(r.value);
-    }
-    return r;
-}
-void Object::setOptionalBoolean(const QVariant& v) {
-    if (v.isNull() || !v.canConvert<bool>()) {
-        object_optional_boolean_set_none(m_d);
-    } else {
-        object_optional_boolean_set(m_d, v.value<bool>());
-    }
-}
-QByteArray Object::optionalBytearray() const
-{
-    QByteArray v;
-    object_optional_bytearray_get(m_d, &v, set_qbytearray);
-    return v;
-}
-void Object::setOptionalBytearray(const QByteArray& v) {
-    if (v.isNull()) {
-        object_optional_bytearray_set_none(m_d);
-    } else {
-    object_optional_bytearray_set(m_d, v.data(), v.size());
-    }
-}
-QString Object::optionalString() const
+quint64 Object::u64() const
 {
-    QString v;
-    object_optional_string_get(m_d, &v, set_qstring);
-    return v;
+    return object_u64_get(m_d);
 }
-void Object::setOptionalString(const QString& v) {
-    if (v.isNull()) {
-        object_optional_string_set_none(m_d);
-    } else {
-    object_optional_string_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
-    }
+void Object::setU64(quint64 v) {
+    object_u64_set(m_d, v);
 }
 QVariant Object::optionalU64() const
 {
@@ -307,6 +347,20 @@ void Object::setOptionalU64(const QVariant& v) {
         object_optional_u64_set(m_d, v.value<quint64>());
     }
 }
+float Object::f32() const
+{
+    return object_f32_get(m_d);
+}
+void Object::setF32(float v) {
+    object_f32_set(m_d, v);
+}
+double Object::f64() const
+{
+    return object_f64_get(m_d);
+}
+void Object::setF64(double v) {
+    object_f64_set(m_d, v);
+}
 QString Object::string() const
 {
     QString v;
@@ -316,31 +370,38 @@ QString Object::string() const
 void Object::setString(const QString& v) {
     object_string_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
 }
-quint16 Object::u16() const
-{
-    return object_u16_get(m_d);
-}
-void Object::setU16(quint16 v) {
-    object_u16_set(m_d, v);
-}
-quint32 Object::u32() const
+QString Object::optionalString() const
 {
-    return object_u32_get(m_d);
+    QString v;
+    object_optional_string_get(m_d, &v, set_qstring);
+    return v;
 }
-void Object::setU32(quint32 v) {
-    object_u32_set(m_d, v);
+void Object::setOptionalString(const QString& v) {
+    if (v.isNull()) {
+        object_optional_string_set_none(m_d);
+    } else {
+    object_optional_string_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+    }
 }
-quint64 Object::u64() const
+QByteArray Object::bytearray() const
 {
-    return object_u64_get(m_d);
+    QByteArray v;
+    object_bytearray_get(m_d, &v, set_qbytearray);
+    return v;
 }
-void Object::setU64(quint64 v) {
-    object_u64_set(m_d, v);
+void Object::setBytearray(const QByteArray& v) {
+    object_bytearray_set(m_d, v.data(), v.size());
 }
-quint8 Object::u8() const
+QByteArray Object::optionalBytearray() const
 {
-    return object_u8_get(m_d);
+    QByteArray v;
+    object_optional_bytearray_get(m_d, &v, set_qbytearray);
+    return v;
 }
-void Object::setU8(quint8 v) {
-    object_u8_set(m_d, v);
+void Object::setOptionalBytearray(const QByteArray& v) {
+    if (v.isNull()) {
+        object_optional_bytearray_set_none(m_d);
+    } else {
+    object_optional_bytearray_set(m_d, v.data(), v.size());
+    }
 }
@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "test_objects_rust.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
     void set_qstring(QString* val, const char* utf8, int nbytes) {
@@ -13,67 +40,59 @@ namespace {
     }
 }
 extern "C" {
-    Group::Private* group_new(Group*, Person*, InnerObject*, void (*)(InnerObject*));
-    void group_free(Group::Private*);
-    Person::Private* group_person_get(const Group::Private*);
-};
-
-extern "C" {
-    InnerObject::Private* inner_object_new(InnerObject*, void (*)(InnerObject*));
+    InnerObject::Private* inner_object_new(InnerObject*, void (*)(InnerObject*, void*), void (*)(InnerObject*));
     void inner_object_free(InnerObject::Private*);
+    void inner_object_run_task(InnerObject::Private*, void*);
+    void inner_object_drop_task(void*);
     void inner_object_description_get(const InnerObject::Private*, QString*, qstring_set);
     void inner_object_description_set(InnerObject::Private*, const ushort *str, int len);
 };
 
 extern "C" {
-    Person::Private* person_new(Person*, InnerObject*, void (*)(InnerObject*));
+    Person::Private* person_new(Person*, void (*)(Person*, void*), InnerObject*, void (*)(InnerObject*, void*), void (*)(InnerObject*));
     void person_free(Person::Private*);
+    void person_run_task(Person::Private*, void*);
+    void person_drop_task(void*);
     InnerObject::Private* person_object_get(const Person::Private*);
 };
 
-Group::Group(bool /*owned*/, QObject *parent):
-    QObject(parent),
-    m_person(new Person(false, this)),
-    m_d(0),
-    m_ownsPrivate(false)
-{
-}
+extern "C" {
+    Group::Private* group_new(Group*, void (*)(Group*, void*), Person*, void (*)(Person*, void*), InnerObject*, void (*)(InnerObject*, void*), void (*)(InnerObject*));
+    void group_free(Group::Private*);
+    void group_run_task(Group::Private*, void*);
+    void group_drop_task(void*);
+    Person::Private* group_person_get(const Group::Private*);
+};
 
-Group::Group(QObject *parent):
-    QObject(parent),
-    m_person(new Person(false, this)),
-    m_d(group_new(this, m_person, m_person->m_object,
-        innerObjectDescriptionChanged)),
-    m_ownsPrivate(true)
-{
-    m_person->m_d = group_person_get(m_d);
-    m_person->m_object->m_d = person_object_get(m_person->m_d);
-}
+extern "C" {
+    quint64 interface_binding_hash();
+};
 
-Group::~Group() {
-    if (m_ownsPrivate) {
-        group_free(m_d);
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x4a224064e945469d)) {
+            qFatal("test_objects_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
     }
 }
-const Person* Group::person() const
-{
-    return m_person;
-}
-Person* Group::person()
-{
-    return m_person;
-}
+
 InnerObject::InnerObject(bool /*owned*/, QObject *parent):
     QObject(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 InnerObject::InnerObject(QObject *parent):
     QObject(parent),
-    m_d(inner_object_new(this,
-        innerObjectDescriptionChanged)),
+    m_d((checkBindingHash(), inner_object_new(this,
+        InnerObject::postTask,
+        innerObjectDescriptionChanged))),
     m_ownsPrivate(true)
 {
 }
@@ -83,6 +102,20 @@ InnerObject::~InnerObject() {
         inner_object_free(m_d);
     }
 }
+
+bool InnerObject::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        inner_object_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void InnerObject::postTask(InnerObject* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, inner_object_drop_task));
+}
 QString InnerObject::description() const
 {
     QString v;
@@ -98,13 +131,16 @@ Person::Person(bool /*owned*/, QObject *parent):
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
 }
 
 Person::Person(QObject *parent):
     QObject(parent),
     m_object(new InnerObject(false, this)),
-    m_d(person_new(this, m_object,
-        innerObjectDescriptionChanged)),
+    m_d((checkBindingHash(), person_new(this,
+        Person::postTask, m_object,
+        InnerObject::postTask,
+        innerObjectDescriptionChanged))),
     m_ownsPrivate(true)
 {
     m_object->m_d = person_object_get(m_d);
@@ -115,6 +151,20 @@ Person::~Person() {
         person_free(m_d);
     }
 }
+
+bool Person::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        person_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Person::postTask(Person* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, person_drop_task));
+}
 const InnerObject* Person::object() const
 {
     return m_object;
@@ -123,3 +173,53 @@ InnerObject* Person::object()
 {
     return m_object;
 }
+Group::Group(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_person(new Person(false, this)),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Group::Group(QObject *parent):
+    QObject(parent),
+    m_person(new Person(false, this)),
+    m_d((checkBindingHash(), group_new(this,
+        Group::postTask, m_person,
+        Person::postTask, m_person->m_object,
+        InnerObject::postTask,
+        innerObjectDescriptionChanged))),
+    m_ownsPrivate(true)
+{
+    m_person->m_d = group_person_get(m_d);
+    m_person->m_object->m_d = person_object_get(m_person->m_d);
+}
+
+Group::~Group() {
+    if (m_ownsPrivate) {
+        group_free(m_d);
+    }
+}
+
+bool Group::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        group_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Group::postTask(Group* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, group_drop_task));
+}
+const Person* Group::person() const
+{
+    return m_person;
+}
+Person* Group::person()
+{
+    return m_person;
+}
@@ -5,30 +5,9 @@
 #include <QObject>
 #include <QAbstractItemModel>
 
-class Group;
 class InnerObject;
 class Person;
-
-class Group : public QObject
-{
-    Q_OBJECT
-    friend class Person;
-public:
-    class Private;
-private:
-    Person* const m_person;
-    Private * m_d;
-    bool m_ownsPrivate;
-    Q_PROPERTY(Person* person READ person NOTIFY personChanged FINAL)
-    explicit Group(bool owned, QObject *parent);
-public:
-    explicit Group(QObject *parent = nullptr);
-    ~Group();
-    const Person* person() const;
-    Person* person();
-signals:
-    void personChanged();
-};
+class Group;
 
 class InnerObject : public QObject
 {
@@ -37,6 +16,14 @@ class InnerObject : public QObject
     friend class Person;
 public:
     class Private;
+    // constructs and connects InnerObject for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static InnerObject* create(QObject* parent) { return new InnerObject(false, parent); }
+        static Private*& d(InnerObject* o) { return o->m_d; }
+        static Private* d(const InnerObject* o) { return o->m_d; }
+        static void postTask(InnerObject* o, void* task) { InnerObject::postTask(o, task); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -47,6 +34,11 @@ public:
     ~InnerObject();
     QString description() const;
     void setDescription(const QString& v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(InnerObject* o, void* task);
 signals:
     void descriptionChanged();
 };
@@ -57,6 +49,14 @@ class Person : public QObject
     friend class Group;
 public:
     class Private;
+    // constructs and connects Person for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Person* create(QObject* parent) { return new Person(false, parent); }
+        static Private*& d(Person* o) { return o->m_d; }
+        static Private* d(const Person* o) { return o->m_d; }
+        static void postTask(Person* o, void* task) { Person::postTask(o, task); }
+    };
 private:
     InnerObject* const m_object;
     Private * m_d;
@@ -68,7 +68,46 @@ public:
     ~Person();
     const InnerObject* object() const;
     InnerObject* object();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Person* o, void* task);
 signals:
     void objectChanged();
 };
+
+class Group : public QObject
+{
+    Q_OBJECT
+    friend class Person;
+public:
+    class Private;
+    // constructs and connects Group for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Group* create(QObject* parent) { return new Group(false, parent); }
+        static Private*& d(Group* o) { return o->m_d; }
+        static Private* d(const Group* o) { return o->m_d; }
+        static void postTask(Group* o, void* task) { Group::postTask(o, task); }
+    };
+private:
+    Person* const m_person;
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(Person* person READ person NOTIFY personChanged FINAL)
+    explicit Group(bool owned, QObject *parent);
+public:
+    explicit Group(QObject *parent = nullptr);
+    ~Group();
+    const Person* person() const;
+    Person* person();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Group* o, void* task);
+signals:
+    void personChanged();
+};
 #endif // TEST_OBJECTS_RUST_H
@@ -0,0 +1,578 @@
+/* generated by rust_qt_binding_generator */
+#include "test_overflow_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    struct option_quintptr {
+    public:
+        quintptr value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_pod<option_quintptr>::value, "option_quintptr must be a POD type.");
+
+    typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
+    void set_qstring(QString* val, const char* utf8, int nbytes) {
+        *val = QString::fromUtf8(utf8, nbytes);
+    }
+
+    struct qmodelindex_t {
+        int row;
+        quintptr id;
+    };
+    inline QVariant cleanNullQVariant(const QVariant& v) {
+        return (v.isNull()) ?QVariant() :v;
+    }
+}
+extern "C" {
+    void rows_data_user_name(const Rows::Private*, int, QString*, qstring_set);
+    bool rows_set_data_user_name(Rows::Private*, int, const ushort* s, int len);
+    void rows_sort(Rows::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int rows_row_count(const Rows::Private*);
+    bool rows_insert_rows(Rows::Private*, int, int);
+    bool rows_remove_rows(Rows::Private*, int, int);
+    bool rows_can_fetch_more(const Rows::Private*);
+    void rows_fetch_more(Rows::Private*);
+}
+int Rows::columnCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : 1;
+}
+
+bool Rows::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Rows::rowCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : rows_row_count(m_d);
+}
+
+bool Rows::insertRows(int row, int count, const QModelIndex &)
+{
+    return rows_insert_rows(m_d, row, count);
+}
+
+bool Rows::removeRows(int row, int count, const QModelIndex &)
+{
+    return rows_remove_rows(m_d, row, count);
+}
+
+QModelIndex Rows::index(int row, int column, const QModelIndex &parent) const
+{
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
+        return createIndex(row, column, (quintptr)row);
+    }
+    return QModelIndex();
+}
+
+QModelIndex Rows::parent(const QModelIndex &) const
+{
+    return QModelIndex();
+}
+
+bool Rows::canFetchMore(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : rows_can_fetch_more(m_d);
+}
+
+void Rows::fetchMore(const QModelIndex &parent)
+{
+    if (!parent.isValid()) {
+        rows_fetch_more(m_d);
+    }
+}
+
+void Rows::sort(int column, Qt::SortOrder order)
+{
+    rows_sort(m_d, column, order);
+}
+Qt::ItemFlags Rows::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    if (i.column() == 0) {
+        flags |= Qt::ItemIsEditable;
+    }
+    return flags;
+}
+
+QString Rows::userName(int row) const
+{
+    QString s;
+    rows_data_user_name(m_d, row, &s, set_qstring);
+    return s;
+}
+
+bool Rows::setUserName(int row, const QString& value)
+{
+    bool set = false;
+    set = rows_set_data_user_name(m_d, row, value.utf16(), value.length());
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QVariant Rows::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(userName(index.row()));
+        }
+    }
+    return QVariant();
+}
+
+int Rows::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Rows::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "userName");
+    return names;
+}
+QVariant Rows::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Rows::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+bool Rows::setData(const QModelIndex &index, const QVariant &value, int role)
+{
+    if (index.column() == 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setUserName(index.row(), value.value<QString>());
+            }
+        }
+    }
+    return false;
+}
+
+extern "C" {
+    Rows::Private* rows_new(Rows*, void (*)(Rows*, void*),
+        void (*)(const Rows*),
+        void (*)(Rows*, quintptr, quintptr),
+        void (*)(Rows*),
+        void (*)(Rows*),
+        void (*)(Rows*, int, int),
+        void (*)(Rows*),
+        void (*)(Rows*, int, int),
+        void (*)(Rows*));
+    void rows_free(Rows::Private*);
+    void rows_run_task(Rows::Private*, void*);
+    void rows_drop_task(void*);
+};
+
+extern "C" {
+    void nodes_data_user_name(const Nodes::Private*, quintptr, QString*, qstring_set);
+    bool nodes_set_data_user_name(Nodes::Private*, quintptr, const ushort* s, int len);
+    void nodes_sort(Nodes::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int nodes_row_count(const Nodes::Private*, quintptr, bool);
+    bool nodes_can_fetch_more(const Nodes::Private*, quintptr, bool);
+    void nodes_fetch_more(Nodes::Private*, quintptr, bool);
+    quintptr nodes_index(const Nodes::Private*, quintptr, bool, int);
+    qmodelindex_t nodes_parent(const Nodes::Private*, quintptr);
+    int nodes_row(const Nodes::Private*, quintptr);
+}
+int Nodes::columnCount(const QModelIndex &) const
+{
+    return 1;
+}
+
+bool Nodes::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Nodes::rowCount(const QModelIndex &parent) const
+{
+    if (parent.isValid() && parent.column() != 0) {
+        return 0;
+    }
+    return nodes_row_count(m_d, parent.internalId(), parent.isValid());
+}
+
+bool Nodes::insertRows(int, int, const QModelIndex &)
+{
+    return false; // not supported yet
+}
+
+bool Nodes::removeRows(int, int, const QModelIndex &)
+{
+    return false; // not supported yet
+}
+
+QModelIndex Nodes::index(int row, int column, const QModelIndex &parent) const
+{
+    if (row < 0 || column < 0 || column >= 1) {
+        return QModelIndex();
+    }
+    if (parent.isValid() && parent.column() != 0) {
+        return QModelIndex();
+    }
+    if (row >= rowCount(parent)) {
+        return QModelIndex();
+    }
+    const quintptr id = nodes_index(m_d, parent.internalId(), parent.isValid(), row);
+    return createIndex(row, column, id);
+}
+
+QModelIndex Nodes::parent(const QModelIndex &index) const
+{
+    if (!index.isValid()) {
+        return QModelIndex();
+    }
+    const qmodelindex_t parent = nodes_parent(m_d, index.internalId());
+    return parent.row >= 0 ?createIndex(parent.row, 0, parent.id) :QModelIndex();
+}
+
+bool Nodes::canFetchMore(const QModelIndex &parent) const
+{
+    if (parent.isValid() && parent.column() != 0) {
+        return false;
+    }
+    return nodes_can_fetch_more(m_d, parent.internalId(), parent.isValid());
+}
+
+void Nodes::fetchMore(const QModelIndex &parent)
+{
+    nodes_fetch_more(m_d, parent.internalId(), parent.isValid());
+}
+
+void Nodes::sort(int column, Qt::SortOrder order)
+{
+    nodes_sort(m_d, column, order);
+}
+Qt::ItemFlags Nodes::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    if (i.column() == 0) {
+        flags |= Qt::ItemIsEditable;
+    }
+    return flags;
+}
+
+QString Nodes::userName(const QModelIndex& index) const
+{
+    QString s;
+    nodes_data_user_name(m_d, index.internalId(), &s, set_qstring);
+    return s;
+}
+
+bool Nodes::setUserName(const QModelIndex& index, const QString& value)
+{
+    bool set = false;
+    set = nodes_set_data_user_name(m_d, index.internalId(), value.utf16(), value.length());
+    if (set) {
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QVariant Nodes::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(userName(index));
+        }
+    }
+    return QVariant();
+}
+
+int Nodes::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Nodes::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "userName");
+    return names;
+}
+QVariant Nodes::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Nodes::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+bool Nodes::setData(const QModelIndex &index, const QVariant &value, int role)
+{
+    if (index.column() == 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setUserName(index, value.value<QString>());
+            }
+        }
+    }
+    return false;
+}
+
+extern "C" {
+    Nodes::Private* nodes_new(Nodes*, void (*)(Nodes*, void*),
+        void (*)(const Nodes*, quintptr, bool),
+        void (*)(Nodes*, quintptr, quintptr),
+        void (*)(Nodes*),
+        void (*)(Nodes*),
+        void (*)(Nodes*, option_quintptr, int, int),
+        void (*)(Nodes*),
+        void (*)(Nodes*, option_quintptr, int, int),
+        void (*)(Nodes*));
+    void nodes_free(Nodes::Private*);
+    void nodes_run_task(Nodes::Private*, void*);
+    void nodes_drop_task(void*);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x04517bc2e729aca3)) {
+            qFatal("test_overflow_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Rows::Rows(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Rows::Rows(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), rows_new(this,
+        Rows::postTask,
+        [](const Rows* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Rows* o, quintptr first, quintptr last) {
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 0, last));
+        },
+        [](Rows* o) {
+            o->beginResetModel();
+        },
+        [](Rows* o) {
+            o->endResetModel();
+        },
+        [](Rows* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](Rows* o) {
+            o->endInsertRows();
+        },
+        [](Rows* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](Rows* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Rows::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Rows::~Rows() {
+    if (m_ownsPrivate) {
+        rows_free(m_d);
+    }
+}
+
+bool Rows::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        rows_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Rows::postTask(Rows* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, rows_drop_task));
+}
+void Rows::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("userName"));
+}
+Nodes::Nodes(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Nodes::Nodes(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), nodes_new(this,
+        Nodes::postTask,
+        [](const Nodes* o, quintptr id, bool valid) {
+            if (valid) {
+                int row = nodes_row(o->m_d, id);
+                emit o->newDataReady(o->createIndex(row, 0, id));
+            } else {
+                emit o->newDataReady(QModelIndex());
+            }
+        },
+        [](Nodes* o, quintptr first, quintptr last) {
+            quintptr frow = nodes_row(o->m_d, first);
+            quintptr lrow = nodes_row(o->m_d, first);
+            o->dataChanged(o->createIndex(frow, 0, first),
+                       o->createIndex(lrow, 0, last));
+        },
+        [](Nodes* o) {
+            o->beginResetModel();
+        },
+        [](Nodes* o) {
+            o->endResetModel();
+        },
+        [](Nodes* o, option_quintptr id, int first, int last) {
+            if (id.some) {
+                int row = nodes_row(o->m_d, id.value);
+                o->beginInsertRows(o->createIndex(row, 0, id.value), first, last);
+            } else {
+                o->beginInsertRows(QModelIndex(), first, last);
+            }
+        },
+        [](Nodes* o) {
+            o->endInsertRows();
+        },
+        [](Nodes* o, option_quintptr id, int first, int last) {
+            if (id.some) {
+                int row = nodes_row(o->m_d, id.value);
+                o->beginRemoveRows(o->createIndex(row, 0, id.value), first, last);
+            } else {
+                o->beginRemoveRows(QModelIndex(), first, last);
+            }
+        },
+        [](Nodes* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Nodes::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Nodes::~Nodes() {
+    if (m_ownsPrivate) {
+        nodes_free(m_d);
+    }
+}
+
+bool Nodes::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        nodes_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Nodes::postTask(Nodes* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, nodes_drop_task));
+}
+void Nodes::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("userName"));
+}
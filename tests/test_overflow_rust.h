@@ -0,0 +1,146 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_OVERFLOW_RUST_H
+#define TEST_OVERFLOW_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+
+class Rows;
+class Nodes;
+
+class Rows : public QAbstractItemModel
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Rows for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Rows* create(QObject* parent) { return new Rows(false, parent); }
+        static Private*& d(Rows* o) { return o->m_d; }
+        static Private* d(const Rows* o) { return o->m_d; }
+        static void postTask(Rows* o, void* task) { Rows::postTask(o, task); }
+        static QModelIndex createIndex(const Rows* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Rows* o) { o->beginResetModel(); }
+        static void endResetModel(Rows* o) { o->endResetModel(); }
+        static void beginInsertRows(Rows* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Rows* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Rows* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Rows* o) { o->endRemoveRows(); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit Rows(bool owned, QObject *parent);
+public:
+    explicit Rows(QObject *parent = nullptr);
+    ~Rows();
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE QString userName(int row) const;
+    Q_INVOKABLE bool setUserName(int row, const QString& value);
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Rows* o, void* task);
+signals:
+};
+
+class Nodes : public QAbstractItemModel
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Nodes for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Nodes* create(QObject* parent) { return new Nodes(false, parent); }
+        static Private*& d(Nodes* o) { return o->m_d; }
+        static Private* d(const Nodes* o) { return o->m_d; }
+        static void postTask(Nodes* o, void* task) { Nodes::postTask(o, task); }
+        static QModelIndex createIndex(const Nodes* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Nodes* o) { o->beginResetModel(); }
+        static void endResetModel(Nodes* o) { o->endResetModel(); }
+        static void beginInsertRows(Nodes* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Nodes* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Nodes* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Nodes* o) { o->endRemoveRows(); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit Nodes(bool owned, QObject *parent);
+public:
+    explicit Nodes(QObject *parent = nullptr);
+    ~Nodes();
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE QString userName(const QModelIndex& index) const;
+    Q_INVOKABLE bool setUserName(const QModelIndex& index, const QString& value);
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Nodes* o, void* task);
+signals:
+};
+#endif // TEST_OVERFLOW_RUST_H
@@ -0,0 +1,140 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+
+#include "test_qt6_rust.h"
+#include <QTest>
+#include <QSignalSpy>
+#include <QQmlEngine>
+#include <QQmlComponent>
+
+class TestRustQt6 : public QObject
+{
+    Q_OBJECT
+private slots:
+    void testOptionalItem();
+    void testSingleton();
+    void testBindableInitialValue();
+    void testBindable();
+    void testBindableFromThread();
+    void testQmlRegistration();
+};
+
+void TestRustQt6::testOptionalItem()
+{
+    // GIVEN
+    Flags flags;
+    QSignalSpy spy(&flags, &Flags::dataChanged);
+    const int role = flags.role("flag");
+    const QModelIndex index(flags.index(0, 0));
+
+    // WHEN
+    const bool set = flags.setData(index, true, role);
+
+    // THEN
+    QVERIFY(set);
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(flags.data(index, role).metaType(), QMetaType::fromType<bool>());
+    QCOMPARE(flags.data(index, role).toBool(), true);
+    QVERIFY(flags.setData(index, QVariant(), role));
+    QVERIFY(!flags.data(index, role).isValid());
+}
+
+void TestRustQt6::testSingleton()
+{
+    // GIVEN
+    Settings* settings = Settings::instance();
+    QSignalSpy spy(settings, &Settings::themeChanged);
+
+    // WHEN
+    settings->setTheme("dark");
+
+    // THEN
+    QCOMPARE(Settings::instance(), settings);
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(settings->theme(), QString("dark"));
+}
+
+void TestRustQt6::testBindableInitialValue()
+{
+    // GIVEN
+    Settings* settings = Settings::instance();
+
+    // THEN
+    // new() in Rust pushes the values it starts with
+    QCOMPARE(settings->load(), 0.5f);
+    QCOMPARE(settings->bindableLoad().value(), 0.5f);
+    QCOMPARE(settings->title(), QString("Settings"));
+}
+
+void TestRustQt6::testBindable()
+{
+    // GIVEN
+    Settings* settings = Settings::instance();
+    QSignalSpy spy(settings, &Settings::loadChanged);
+    QProperty<float> doubled;
+    doubled.setBinding([settings]() {
+        return settings->bindableLoad().value() * 2;
+    });
+
+    // WHEN
+    settings->setLoad(0.25f);
+
+    // THEN
+    QCOMPARE(spy.count(), 1);
+    QCOMPARE(settings->load(), 0.25f);
+    QCOMPARE(doubled.value(), 0.5f);
+}
+
+void TestRustQt6::testBindableFromThread()
+{
+    // GIVEN
+    Settings* settings = Settings::instance();
+    QSignalSpy spy(settings, &Settings::titleChanged);
+
+    // WHEN
+    // the Rust code pushes the title from a worker thread
+    settings->setTitle("Rust");
+
+    // THEN
+    QTRY_COMPARE(settings->title(), QString("Rust"));
+    QCOMPARE(spy.count(), 1);
+}
+
+void TestRustQt6::testQmlRegistration()
+{
+    // GIVEN
+    registerQmlTypes();
+    QQmlEngine engine;
+    QQmlComponent component(&engine);
+
+    // WHEN
+    component.setData("import RustQt6\n"
+        "import QtQml\n"
+        "QtObject { property string theme: Settings.theme }", QUrl());
+    QScopedPointer<QObject> object(component.create());
+
+    // THEN
+    QVERIFY2(object, qPrintable(component.errorString()));
+    QCOMPARE(object->property("theme").toString(), Settings::instance()->theme());
+}
+
+QTEST_MAIN(TestRustQt6)
+#include "test_qt6.moc"
@@ -0,0 +1,454 @@
+/* generated by rust_qt_binding_generator */
+#include "test_qt6_rust.h"
+#include <QtQml/qqml.h>
+#include <QQmlEngine>
+#include <QCoreApplication>
+#include <QThread>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    struct option_bool {
+    public:
+        bool value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_trivial<option_bool>::value
+        && std::is_standard_layout<option_bool>::value, "option_bool must be a POD type.");
+
+    struct option_quintptr {
+    public:
+        quintptr value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_trivial<option_quintptr>::value
+        && std::is_standard_layout<option_quintptr>::value, "option_quintptr must be a POD type.");
+
+    typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
+    void set_qstring(QString* val, const char* utf8, int nbytes) {
+        *val = QString::fromUtf8(utf8, nbytes);
+    }
+
+    struct qmodelindex_t {
+        int row;
+        quintptr id;
+    };
+    inline QVariant cleanNullQVariant(const QVariant& v) {
+        return (v.isNull()) ?QVariant() :v;
+    }
+    inline void settingsThemeChanged(Settings* o)
+    {
+        emit o->themeChanged();
+    }
+    inline void settingsLoadUpdate(Settings* o, float v)
+    {
+        if (o->thread() == QThread::currentThread()) {
+            o->bindableLoad().setValue(v);
+        } else {
+            QMetaObject::invokeMethod(o, [o, v]() {
+                o->bindableLoad().setValue(v);
+            }, Qt::QueuedConnection);
+        }
+    }
+    inline void settingsTitleUpdate(Settings* o, const char* s, int len)
+    {
+        const QString v = QString::fromUtf8(s, len);
+        if (o->thread() == QThread::currentThread()) {
+            o->bindableTitle().setValue(v);
+        } else {
+            QMetaObject::invokeMethod(o, [o, v]() {
+                o->bindableTitle().setValue(v);
+            }, Qt::QueuedConnection);
+        }
+    }
+}
+extern "C" {
+    option_bool flags_data_flag(const Flags::Private*, int);
+    bool flags_set_data_flag(Flags::Private*, int, bool);
+    bool flags_set_data_flag_none(Flags::Private*, int);
+    void flags_sort(Flags::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int flags_row_count(const Flags::Private*);
+    bool flags_insert_rows(Flags::Private*, int, int);
+    bool flags_remove_rows(Flags::Private*, int, int);
+    bool flags_can_fetch_more(const Flags::Private*);
+    void flags_fetch_more(Flags::Private*);
+}
+int Flags::columnCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : 1;
+}
+
+bool Flags::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Flags::rowCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : flags_row_count(m_d);
+}
+
+bool Flags::insertRows(int row, int count, const QModelIndex &)
+{
+    return flags_insert_rows(m_d, row, count);
+}
+
+bool Flags::removeRows(int row, int count, const QModelIndex &)
+{
+    return flags_remove_rows(m_d, row, count);
+}
+
+QModelIndex Flags::index(int row, int column, const QModelIndex &parent) const
+{
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
+        return createIndex(row, column, (quintptr)row);
+    }
+    return QModelIndex();
+}
+
+QModelIndex Flags::parent(const QModelIndex &) const
+{
+    return QModelIndex();
+}
+
+bool Flags::canFetchMore(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : flags_can_fetch_more(m_d);
+}
+
+void Flags::fetchMore(const QModelIndex &parent)
+{
+    if (!parent.isValid()) {
+        flags_fetch_more(m_d);
+    }
+}
+
+void Flags::sort(int column, Qt::SortOrder order)
+{
+    flags_sort(m_d, column, order);
+}
+Qt::ItemFlags Flags::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    if (i.column() == 0) {
+        flags |= Qt::ItemIsEditable;
+    }
+    return flags;
+}
+
+QVariant Flags::flag(int row) const
+{
+    QVariant v;
+    v = flags_data_flag(m_d, row);
+    return v;
+}
+
+bool Flags::setFlag(int row, const QVariant& value)
+{
+    bool set = false;
+    if (value.isNull() || !value.isValid()) {
+        set = flags_set_data_flag_none(m_d, row);
+    } else {
+    if (!value.canConvert(QMetaType::fromType<bool>())) {
+        return false;
+    }
+    set = flags_set_data_flag(m_d, row, value.value<bool>());
+    }
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QVariant Flags::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::UserRole + 0:
+            return flag(index.row());
+        }
+    }
+    return QVariant();
+}
+
+int Flags::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Flags::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "flag");
+    return names;
+}
+QVariant Flags::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Flags::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+bool Flags::setData(const QModelIndex &index, const QVariant &value, int role)
+{
+    if (index.column() == 0) {
+        if (role == Qt::UserRole + 0) {
+            return setFlag(index.row(), value);
+        }
+    }
+    return false;
+}
+
+extern "C" {
+    Flags::Private* flags_new(Flags*, void (*)(Flags*, void*),
+        void (*)(const Flags*),
+        void (*)(Flags*, quintptr, quintptr),
+        void (*)(Flags*),
+        void (*)(Flags*),
+        void (*)(Flags*, int, int),
+        void (*)(Flags*),
+        void (*)(Flags*, int, int),
+        void (*)(Flags*));
+    void flags_free(Flags::Private*);
+    void flags_run_task(Flags::Private*, void*);
+    void flags_drop_task(void*);
+};
+
+extern "C" {
+    Settings::Private* settings_new(Settings*, void (*)(Settings*, void*), void (*)(Settings*), void (*)(Settings*, float), void (*)(Settings*, const char*, int));
+    void settings_free(Settings::Private*);
+    void settings_run_task(Settings::Private*, void*);
+    void settings_drop_task(void*);
+    void settings_theme_get(const Settings::Private*, QString*, qstring_set);
+    void settings_theme_set(Settings::Private*, const ushort *str, int len);
+    void settings_load_set(Settings::Private*, float);
+    void settings_title_set(Settings::Private*, const ushort *str, int len);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xe46381206ea990dd)) {
+            qFatal("test_qt6_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Flags::Flags(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Flags::Flags(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), flags_new(this,
+        Flags::postTask,
+        [](const Flags* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Flags* o, quintptr first, quintptr last) {
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 0, last));
+        },
+        [](Flags* o) {
+            o->beginResetModel();
+        },
+        [](Flags* o) {
+            o->endResetModel();
+        },
+        [](Flags* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](Flags* o) {
+            o->endInsertRows();
+        },
+        [](Flags* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](Flags* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Flags::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Flags::~Flags() {
+    if (m_ownsPrivate) {
+        flags_free(m_d);
+    }
+}
+
+bool Flags::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        flags_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Flags::postTask(Flags* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, flags_drop_task));
+}
+void Flags::initHeaderData() {
+}
+Settings::Settings(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Settings::Settings(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), settings_new(this,
+        Settings::postTask,
+        settingsThemeChanged,
+        settingsLoadUpdate,
+        settingsTitleUpdate))),
+    m_ownsPrivate(true)
+{
+}
+
+Settings::~Settings() {
+    if (m_ownsPrivate) {
+        settings_free(m_d);
+    }
+}
+
+bool Settings::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        settings_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Settings::postTask(Settings* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, settings_drop_task));
+}
+
+Settings* Settings::instance()
+{
+    static Settings* const instance = new Settings();
+    return instance;
+}
+
+Settings* Settings::create(QQmlEngine*, QJSEngine*)
+{
+    QQmlEngine::setObjectOwnership(instance(), QQmlEngine::CppOwnership);
+    return instance();
+}
+QString Settings::theme() const
+{
+    QString v;
+    settings_theme_get(m_d, &v, set_qstring);
+    return v;
+}
+void Settings::setTheme(const QString& v) {
+    settings_theme_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+float Settings::load() const
+{
+    return m_load;
+}
+QBindable<float> Settings::bindableLoad()
+{
+    return &m_load;
+}
+void Settings::setLoad(float v) {
+    settings_load_set(m_d, v);
+}
+QString Settings::title() const
+{
+    return m_title;
+}
+QBindable<QString> Settings::bindableTitle()
+{
+    return &m_title;
+}
+void Settings::setTitle(const QString& v) {
+    settings_title_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+
+void registerQmlTypes()
+{
+    const char* uri = "RustQt6";
+    qmlRegisterTypesAndRevisions<Flags, Settings>(uri, 1);
+}
@@ -0,0 +1,143 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_QT6_RUST_H
+#define TEST_QT6_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+#include <QtQml/qqmlregistration.h>
+#include <QProperty>
+
+#if QT_VERSION < QT_VERSION_CHECK(6, 2, 0)
+#error "This file was generated for Qt 6.2 or newer."
+#endif
+
+class QQmlEngine;
+class QJSEngine;
+
+class Flags;
+class Settings;
+
+class Flags : public QAbstractItemModel
+{
+    Q_OBJECT
+    QML_ELEMENT
+public:
+    class Private;
+    // constructs and connects Flags for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Flags* create(QObject* parent) { return new Flags(false, parent); }
+        static Private*& d(Flags* o) { return o->m_d; }
+        static Private* d(const Flags* o) { return o->m_d; }
+        static void postTask(Flags* o, void* task) { Flags::postTask(o, task); }
+        static QModelIndex createIndex(const Flags* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Flags* o) { o->beginResetModel(); }
+        static void endResetModel(Flags* o) { o->endResetModel(); }
+        static void beginInsertRows(Flags* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Flags* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Flags* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Flags* o) { o->endRemoveRows(); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    explicit Flags(bool owned, QObject *parent);
+public:
+    explicit Flags(QObject *parent = nullptr);
+    ~Flags();
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE QVariant flag(int row) const;
+    Q_INVOKABLE bool setFlag(int row, const QVariant& value);
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Flags* o, void* task);
+signals:
+};
+
+class Settings : public QObject
+{
+    Q_OBJECT
+    QML_ELEMENT
+    QML_SINGLETON
+public:
+    class Private;
+    // constructs and connects Settings for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Settings* create(QObject* parent) { return new Settings(false, parent); }
+        static Private*& d(Settings* o) { return o->m_d; }
+        static Private* d(const Settings* o) { return o->m_d; }
+        static void postTask(Settings* o, void* task) { Settings::postTask(o, task); }
+    };
+signals:
+    void loadChanged();
+    void titleChanged();
+private:
+    Q_OBJECT_BINDABLE_PROPERTY(Settings, float, m_load, &Settings::loadChanged)
+    Q_OBJECT_BINDABLE_PROPERTY(Settings, QString, m_title, &Settings::titleChanged)
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(QString theme READ theme WRITE setTheme NOTIFY themeChanged FINAL)
+    Q_PROPERTY(float load READ load WRITE setLoad NOTIFY loadChanged BINDABLE bindableLoad FINAL)
+    Q_PROPERTY(QString title READ title WRITE setTitle NOTIFY titleChanged BINDABLE bindableTitle FINAL)
+    explicit Settings(bool owned, QObject *parent);
+    explicit Settings(QObject *parent = nullptr);
+    // the instance is never deleted
+    ~Settings();
+public:
+    // the one instance of Settings, it lives as long as the program
+    static Settings* instance();
+    // called by QML to get the instance
+    static Settings* create(QQmlEngine*, QJSEngine*);
+    QString theme() const;
+    void setTheme(const QString& v);
+    float load() const;
+    QBindable<float> bindableLoad();
+    void setLoad(float v);
+    QString title() const;
+    QBindable<QString> bindableTitle();
+    void setTitle(const QString& v);
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Settings* o, void* task);
+signals:
+    void themeChanged();
+};
+
+// register all types with QML under the module RustQt6 1.0
+void registerQmlTypes();
+#endif // TEST_QT6_RUST_H
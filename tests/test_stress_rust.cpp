@@ -0,0 +1,100 @@
+/* generated by rust_qt_binding_generator */
+#include "test_stress_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+    inline void tickerTicksChanged(Ticker* o)
+    {
+        emit o->ticksChanged();
+    }
+}
+extern "C" {
+    Ticker::Private* ticker_new(Ticker*, void (*)(Ticker*, void*), void (*)(Ticker*));
+    void ticker_free(Ticker::Private*);
+    void ticker_run_task(Ticker::Private*, void*);
+    void ticker_drop_task(void*);
+    quint64 ticker_ticks_get(const Ticker::Private*);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xd47326da137cf13d)) {
+            qFatal("test_stress_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Ticker::Ticker(bool /*owned*/, QObject *parent):
+    QObject(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+}
+
+Ticker::Ticker(QObject *parent):
+    QObject(parent),
+    m_d((checkBindingHash(), ticker_new(this,
+        Ticker::postTask,
+        tickerTicksChanged))),
+    m_ownsPrivate(true)
+{
+}
+
+Ticker::~Ticker() {
+    if (m_ownsPrivate) {
+        ticker_free(m_d);
+    }
+}
+
+bool Ticker::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        ticker_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QObject::event(e);
+}
+
+void Ticker::postTask(Ticker* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, ticker_drop_task));
+}
+quint64 Ticker::ticks() const
+{
+    return ticker_ticks_get(m_d);
+}
@@ -0,0 +1,40 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_STRESS_RUST_H
+#define TEST_STRESS_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+
+class Ticker;
+
+class Ticker : public QObject
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Ticker for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Ticker* create(QObject* parent) { return new Ticker(false, parent); }
+        static Private*& d(Ticker* o) { return o->m_d; }
+        static Private* d(const Ticker* o) { return o->m_d; }
+        static void postTask(Ticker* o, void* task) { Ticker::postTask(o, task); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(quint64 ticks READ ticks NOTIFY ticksChanged FINAL)
+    explicit Ticker(bool owned, QObject *parent);
+public:
+    explicit Ticker(QObject *parent = nullptr);
+    ~Ticker();
+    quint64 ticks() const;
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Ticker* o, void* task);
+signals:
+    void ticksChanged();
+};
+#endif // TEST_STRESS_RUST_H
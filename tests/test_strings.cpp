@@ -0,0 +1,95 @@
+/*
+ *   Copyright 2017  Jos van den Oever <jos@vandenoever.info>
+ *
+ *   This program is free software; you can redistribute it and/or
+ *   modify it under the terms of the GNU General Public License as
+ *   published by the Free Software Foundation; either version 2 of
+ *   the License or (at your option) version 3 or any later version
+ *   accepted by the membership of KDE e.V. (or its successor approved
+ *   by the membership of KDE e.V.), which shall act as a proxy
+ *   defined in Section 14 of version 3 of the license.
+ *
+ *   This program is distributed in the hope that it will be useful,
+ *   but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *   GNU General Public License for more details.
+ *
+ *   You should have received a copy of the GNU General Public License
+ *   along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include "test_strings_rust.h"
+#include <QTest>
+
+class TestRustStrings : public QObject
+{
+    Q_OBJECT
+private slots:
+    void testBufferGetterCopies();
+    void testBufferViewWrapsRust();
+    void testBytesGetterCopies();
+    void testBytesViewWrapsRust();
+};
+
+void TestRustStrings::testBufferGetterCopies()
+{
+    // GIVEN
+    Strings strings;
+    strings.setBuffer("first");
+    const QString buffer = strings.buffer();
+
+    // WHEN
+    strings.setBuffer("second");
+
+    // THEN
+    QCOMPARE(buffer, QString("first"));
+    QCOMPARE(strings.buffer(), QString("second"));
+    QVERIFY(strings.buffer().constData() != strings.bufferView().constData());
+}
+
+void TestRustStrings::testBufferViewWrapsRust()
+{
+    // GIVEN
+    Strings strings;
+
+    // WHEN
+    strings.setBuffer("text");
+
+    // THEN
+    QCOMPARE(strings.bufferView(), QString("text"));
+    QCOMPARE(strings.bufferView().constData(), strings.bufferView().constData());
+    QCOMPARE(strings.property("buffer").toString(), QString("text"));
+}
+
+void TestRustStrings::testBytesGetterCopies()
+{
+    // GIVEN
+    Strings strings;
+    strings.setBytes("first");
+    const QByteArray bytes = strings.bytes();
+
+    // WHEN
+    strings.setBytes("second");
+
+    // THEN
+    QCOMPARE(bytes, QByteArray("first"));
+    QCOMPARE(strings.bytes(), QByteArray("second"));
+    QVERIFY(strings.bytes().constData() != strings.bytesView().constData());
+}
+
+void TestRustStrings::testBytesViewWrapsRust()
+{
+    // GIVEN
+    Strings strings;
+
+    // WHEN
+    strings.setBytes("bytes");
+
+    // THEN
+    QCOMPARE(strings.bytesView(), QByteArray("bytes"));
+    QCOMPARE(strings.bytesView().constData(), strings.bytesView().constData());
+    QCOMPARE(strings.property("bytes").toByteArray(), QByteArray("bytes"));
+}
+
+QTEST_MAIN(TestRustStrings)
+#include "test_strings.moc"
@@ -0,0 +1,488 @@
+/* generated by rust_qt_binding_generator */
+#include "test_strings_rust.h"
+#include <QCoreApplication>
+
+namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
+
+    struct option_quintptr {
+    public:
+        quintptr value;
+        bool some;
+        operator QVariant() const {
+            if (some) {
+                return QVariant::fromValue(value);
+            }
+            return QVariant();
+        }
+    };
+    static_assert(std::is_pod<option_quintptr>::value, "option_quintptr must be a POD type.");
+
+    typedef void (*qstring_set)(QString* val, const char* utf8, int nbytes);
+    void set_qstring(QString* val, const char* utf8, int nbytes) {
+        *val = QString::fromUtf8(utf8, nbytes);
+    }
+
+    typedef void (*qbytearray_set)(QByteArray* val, const char* bytes, int nbytes);
+    void set_qbytearray(QByteArray* v, const char* bytes, int nbytes) {
+        if (v->isNull() && nbytes == 0) {
+            *v = QByteArray(bytes, nbytes);
+        } else {
+            v->truncate(0);
+            v->append(bytes, nbytes);
+        }
+    }
+
+    struct qmodelindex_t {
+        int row;
+        quintptr id;
+    };
+    inline QVariant cleanNullQVariant(const QVariant& v) {
+        return (v.isNull()) ?QVariant() :v;
+    }
+    inline void stringsTextChanged(Strings* o)
+    {
+        emit o->textChanged();
+    }
+    inline void stringsTitleChanged(Strings* o)
+    {
+        emit o->titleChanged();
+    }
+    inline void stringsSubtitleChanged(Strings* o)
+    {
+        emit o->subtitleChanged();
+    }
+    inline void stringsBufferChanged(Strings* o)
+    {
+        emit o->bufferChanged();
+    }
+    inline void stringsBytesChanged(Strings* o)
+    {
+        emit o->bytesChanged();
+    }
+}
+extern "C" {
+    void strings_data_line(const Strings::Private*, int, QString*, qstring_set);
+    bool strings_set_data_line(Strings::Private*, int, const ushort* s, int len);
+    void strings_data_comment(const Strings::Private*, int, QString*, qstring_set);
+    bool strings_set_data_comment(Strings::Private*, int, const ushort* s, int len);
+    bool strings_set_data_comment_none(Strings::Private*, int);
+    void strings_data_note(const Strings::Private*, int, QString*, qstring_set);
+    bool strings_set_data_note(Strings::Private*, int, const ushort* s, int len);
+    void strings_sort(Strings::Private*, unsigned char column, Qt::SortOrder order = Qt::AscendingOrder);
+
+    int strings_row_count(const Strings::Private*);
+    bool strings_insert_rows(Strings::Private*, int, int);
+    bool strings_remove_rows(Strings::Private*, int, int);
+    bool strings_can_fetch_more(const Strings::Private*);
+    void strings_fetch_more(Strings::Private*);
+}
+int Strings::columnCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : 1;
+}
+
+bool Strings::hasChildren(const QModelIndex &parent) const
+{
+    return rowCount(parent) > 0;
+}
+
+int Strings::rowCount(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : strings_row_count(m_d);
+}
+
+bool Strings::insertRows(int row, int count, const QModelIndex &)
+{
+    return strings_insert_rows(m_d, row, count);
+}
+
+bool Strings::removeRows(int row, int count, const QModelIndex &)
+{
+    return strings_remove_rows(m_d, row, count);
+}
+
+QModelIndex Strings::index(int row, int column, const QModelIndex &parent) const
+{
+    if (!parent.isValid() && row >= 0 && row < rowCount(parent) && column >= 0 && column < 1) {
+        return createIndex(row, column, (quintptr)row);
+    }
+    return QModelIndex();
+}
+
+QModelIndex Strings::parent(const QModelIndex &) const
+{
+    return QModelIndex();
+}
+
+bool Strings::canFetchMore(const QModelIndex &parent) const
+{
+    return (parent.isValid()) ? 0 : strings_can_fetch_more(m_d);
+}
+
+void Strings::fetchMore(const QModelIndex &parent)
+{
+    if (!parent.isValid()) {
+        strings_fetch_more(m_d);
+    }
+}
+
+void Strings::sort(int column, Qt::SortOrder order)
+{
+    strings_sort(m_d, column, order);
+}
+Qt::ItemFlags Strings::flags(const QModelIndex &i) const
+{
+    auto flags = QAbstractItemModel::flags(i);
+    if (i.column() == 0) {
+        flags |= Qt::ItemIsEditable;
+    }
+    return flags;
+}
+
+QString Strings::line(int row) const
+{
+    QString s;
+    strings_data_line(m_d, row, &s, set_qstring);
+    return s;
+}
+
+bool Strings::setLine(int row, const QString& value)
+{
+    bool set = false;
+    set = strings_set_data_line(m_d, row, value.utf16(), value.length());
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QString Strings::comment(int row) const
+{
+    QString s;
+    strings_data_comment(m_d, row, &s, set_qstring);
+    return s;
+}
+
+bool Strings::setComment(int row, const QString& value)
+{
+    bool set = false;
+    if (value.isNull()) {
+        set = strings_set_data_comment_none(m_d, row);
+    } else {
+    set = strings_set_data_comment(m_d, row, value.utf16(), value.length());
+    }
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QString Strings::note(int row) const
+{
+    QString s;
+    strings_data_note(m_d, row, &s, set_qstring);
+    return s;
+}
+
+bool Strings::setNote(int row, const QString& value)
+{
+    bool set = false;
+    set = strings_set_data_note(m_d, row, value.utf16(), value.length());
+    if (set) {
+        QModelIndex index = createIndex(row, 0, row);
+        emit dataChanged(index, index);
+    }
+    return set;
+}
+
+QVariant Strings::data(const QModelIndex &index, int role) const
+{
+    Q_ASSERT(rowCount(index.parent()) > index.row());
+    switch (index.column()) {
+    case 0:
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
+        case Qt::UserRole + 0:
+            return QVariant::fromValue(line(index.row()));
+        case Qt::UserRole + 1:
+            return cleanNullQVariant(QVariant::fromValue(comment(index.row())));
+        case Qt::UserRole + 2:
+            return QVariant::fromValue(note(index.row()));
+        }
+    }
+    return QVariant();
+}
+
+int Strings::role(const char* name) const {
+    auto names = roleNames();
+    auto i = names.constBegin();
+    while (i != names.constEnd()) {
+        if (i.value() == name) {
+            return i.key();
+        }
+        ++i;
+    }
+    return -1;
+}
+QHash<int, QByteArray> Strings::roleNames() const {
+    QHash<int, QByteArray> names = QAbstractItemModel::roleNames();
+    names.insert(Qt::UserRole + 0, "line");
+    names.insert(Qt::UserRole + 1, "comment");
+    names.insert(Qt::UserRole + 2, "note");
+    return names;
+}
+QVariant Strings::headerData(int section, Qt::Orientation orientation, int role) const
+{
+    if (orientation != Qt::Horizontal) {
+        return QVariant();
+    }
+    return m_headerData.value(qMakePair(section, (Qt::ItemDataRole)role), role == Qt::DisplayRole ?QString::number(section + 1) :QVariant());
+}
+
+bool Strings::setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role)
+{
+    if (orientation != Qt::Horizontal) {
+        return false;
+    }
+    m_headerData.insert(qMakePair(section, (Qt::ItemDataRole)role), value);
+    return true;
+}
+
+bool Strings::setData(const QModelIndex &index, const QVariant &value, int role)
+{
+    if (index.column() == 0) {
+        if (role == Qt::DisplayRole || role == Qt::EditRole || role == Qt::UserRole + 0) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setLine(index.row(), value.value<QString>());
+            }
+        }
+        if (role == Qt::UserRole + 1) {
+            if (!value.isValid() || value.isNull() ||value.canConvert(qMetaTypeId<QString>())) {
+                return setComment(index.row(), value.value<QString>());
+            }
+        }
+        if (role == Qt::UserRole + 2) {
+            if (value.canConvert(qMetaTypeId<QString>())) {
+                return setNote(index.row(), value.value<QString>());
+            }
+        }
+    }
+    return false;
+}
+
+extern "C" {
+    Strings::Private* strings_new(Strings*, void (*)(Strings*, void*), void (*)(Strings*), void (*)(Strings*), void (*)(Strings*), void (*)(Strings*), void (*)(Strings*),
+        void (*)(const Strings*),
+        void (*)(Strings*, quintptr, quintptr),
+        void (*)(Strings*),
+        void (*)(Strings*),
+        void (*)(Strings*, int, int),
+        void (*)(Strings*),
+        void (*)(Strings*, int, int),
+        void (*)(Strings*));
+    void strings_free(Strings::Private*);
+    void strings_run_task(Strings::Private*, void*);
+    void strings_drop_task(void*);
+    void strings_text_get(const Strings::Private*, QString*, qstring_set);
+    void strings_text_set(Strings::Private*, const ushort *str, int len);
+    void strings_title_get(const Strings::Private*, QString*, qstring_set);
+    void strings_title_set(Strings::Private*, const ushort *str, int len);
+    void strings_subtitle_get(const Strings::Private*, QString*, qstring_set);
+    void strings_subtitle_set(Strings::Private*, const ushort *str, int len);
+    void strings_subtitle_set_none(Strings::Private*);
+    const ushort* strings_buffer_get(const Strings::Private*, int*);
+    void strings_buffer_set(Strings::Private*, const ushort *str, int len);
+    const char* strings_bytes_get(const Strings::Private*, int*);
+    void strings_bytes_set(Strings::Private*, const char* bytes, int len);
+    quint32 strings_length(const Strings::Private*, const ushort*, int);
+    bool strings_append(Strings::Private*, const ushort*, int);
+};
+
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0xcf9b9e363bb9c25c)) {
+            qFatal("test_strings_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
+Strings::Strings(bool /*owned*/, QObject *parent):
+    QAbstractItemModel(parent),
+    m_d(0),
+    m_ownsPrivate(false)
+{
+    checkBindingHash();
+    initHeaderData();
+}
+
+Strings::Strings(QObject *parent):
+    QAbstractItemModel(parent),
+    m_d((checkBindingHash(), strings_new(this,
+        Strings::postTask,
+        stringsTextChanged,
+        stringsTitleChanged,
+        stringsSubtitleChanged,
+        stringsBufferChanged,
+        stringsBytesChanged,
+        [](const Strings* o) {
+            emit o->newDataReady(QModelIndex());
+        },
+        [](Strings* o, quintptr first, quintptr last) {
+            o->dataChanged(o->createIndex(first, 0, first),
+                       o->createIndex(last, 0, last));
+        },
+        [](Strings* o) {
+            o->beginResetModel();
+        },
+        [](Strings* o) {
+            o->endResetModel();
+        },
+        [](Strings* o, int first, int last) {
+            o->beginInsertRows(QModelIndex(), first, last);
+        },
+        [](Strings* o) {
+            o->endInsertRows();
+        },
+        [](Strings* o, int first, int last) {
+            o->beginRemoveRows(QModelIndex(), first, last);
+        },
+        [](Strings* o) {
+            o->endRemoveRows();
+        }
+))),
+    m_ownsPrivate(true)
+{
+    connect(this, &Strings::newDataReady, this, [this](const QModelIndex& i) {
+        this->fetchMore(i);
+    }, Qt::QueuedConnection);
+    initHeaderData();
+}
+
+Strings::~Strings() {
+    if (m_ownsPrivate) {
+        strings_free(m_d);
+    }
+}
+
+bool Strings::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        strings_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Strings::postTask(Strings* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, strings_drop_task));
+}
+void Strings::initHeaderData() {
+    m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("line"));
+}
+QString Strings::text() const
+{
+    QString v;
+    strings_text_get(m_d, &v, set_qstring);
+    return v;
+}
+void Strings::setText(const QString& v) {
+    strings_text_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+QString Strings::title() const
+{
+    QString v;
+    strings_title_get(m_d, &v, set_qstring);
+    return v;
+}
+void Strings::setTitle(const QString& v) {
+    strings_title_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+QString Strings::subtitle() const
+{
+    QString v;
+    strings_subtitle_get(m_d, &v, set_qstring);
+    return v;
+}
+void Strings::setSubtitle(const QString& v) {
+    if (v.isNull()) {
+        strings_subtitle_set_none(m_d);
+    } else {
+    strings_subtitle_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+    }
+}
+QString Strings::buffer() const
+{
+    int len = 0;
+    const ushort* v = strings_buffer_get(m_d, &len);
+    return QString(reinterpret_cast<const QChar*>(v), len);
+}
+QString Strings::bufferView() const
+{
+    int len = 0;
+    const ushort* v = strings_buffer_get(m_d, &len);
+    return QString::fromRawData(reinterpret_cast<const QChar*>(v), len);
+}
+void Strings::setBuffer(const QString& v) {
+    strings_buffer_set(m_d, reinterpret_cast<const ushort*>(v.data()), v.size());
+}
+QByteArray Strings::bytes() const
+{
+    int len = 0;
+    const char* v = strings_bytes_get(m_d, &len);
+    return QByteArray(v, len);
+}
+QByteArray Strings::bytesView() const
+{
+    int len = 0;
+    const char* v = strings_bytes_get(m_d, &len);
+    return QByteArray::fromRawData(v, len);
+}
+void Strings::setBytes(const QByteArray& v) {
+    strings_bytes_set(m_d, v.data(), v.size());
+}
+quint32 Strings::length(const QString& text) const
+{
+    return strings_length(m_d, text.utf16(), text.size());
+}
+bool Strings::append(const QString& line)
+{
+    return strings_append(m_d, line.utf16(), line.size());
+}
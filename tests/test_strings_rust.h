@@ -0,0 +1,109 @@
+/* generated by rust_qt_binding_generator */
+#ifndef TEST_STRINGS_RUST_H
+#define TEST_STRINGS_RUST_H
+
+#include <QObject>
+#include <QAbstractItemModel>
+
+class Strings;
+
+class Strings : public QAbstractItemModel
+{
+    Q_OBJECT
+public:
+    class Private;
+    // constructs and connects Strings for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Strings* create(QObject* parent) { return new Strings(false, parent); }
+        static Private*& d(Strings* o) { return o->m_d; }
+        static Private* d(const Strings* o) { return o->m_d; }
+        static void postTask(Strings* o, void* task) { Strings::postTask(o, task); }
+        static QModelIndex createIndex(const Strings* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Strings* o) { o->beginResetModel(); }
+        static void endResetModel(Strings* o) { o->endResetModel(); }
+        static void beginInsertRows(Strings* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Strings* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Strings* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Strings* o) { o->endRemoveRows(); }
+    };
+private:
+    Private * m_d;
+    bool m_ownsPrivate;
+    Q_PROPERTY(QString text READ text WRITE setText NOTIFY textChanged FINAL)
+    Q_PROPERTY(QString title READ title WRITE setTitle NOTIFY titleChanged FINAL)
+    Q_PROPERTY(QString subtitle READ subtitle WRITE setSubtitle NOTIFY subtitleChanged FINAL)
+    Q_PROPERTY(QString buffer READ buffer WRITE setBuffer NOTIFY bufferChanged FINAL)
+    Q_PROPERTY(QByteArray bytes READ bytes WRITE setBytes NOTIFY bytesChanged FINAL)
+    explicit Strings(bool owned, QObject *parent);
+public:
+    explicit Strings(QObject *parent = nullptr);
+    ~Strings();
+    QString text() const;
+    void setText(const QString& v);
+    QString title() const;
+    void setTitle(const QString& v);
+    QString subtitle() const;
+    void setSubtitle(const QString& v);
+    QString buffer() const;
+    // C++ only: wraps the buffer of Rust without a copy and is valid
+    // until buffer changes
+    QString bufferView() const;
+    void setBuffer(const QString& v);
+    QByteArray bytes() const;
+    // C++ only: wraps the buffer of Rust without a copy and is valid
+    // until bytes changes
+    QByteArray bytesView() const;
+    void setBytes(const QByteArray& v);
+    Q_INVOKABLE quint32 length(const QString& text) const;
+    Q_INVOKABLE bool append(const QString& line);
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const override;
+    QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const override;
+    QModelIndex parent(const QModelIndex &index) const override;
+    bool hasChildren(const QModelIndex &parent = QModelIndex()) const override;
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override;
+    bool canFetchMore(const QModelIndex &parent) const override;
+    void fetchMore(const QModelIndex &parent) override;
+    Qt::ItemFlags flags(const QModelIndex &index) const override;
+    void sort(int column, Qt::SortOrder order = Qt::AscendingOrder) override;
+    int role(const char* name) const;
+    QHash<int, QByteArray> roleNames() const override;
+    QVariant headerData(int section, Qt::Orientation orientation, int role = Qt::DisplayRole) const override;
+    bool setHeaderData(int section, Qt::Orientation orientation, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE bool insertRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    Q_INVOKABLE bool removeRows(int row, int count, const QModelIndex &parent = QModelIndex()) override;
+    bool setData(const QModelIndex &index, const QVariant &value, int role = Qt::EditRole) override;
+    Q_INVOKABLE QString line(int row) const;
+    Q_INVOKABLE bool setLine(int row, const QString& value);
+    Q_INVOKABLE QString comment(int row) const;
+    Q_INVOKABLE bool setComment(int row, const QString& value);
+    Q_INVOKABLE QString note(int row) const;
+    Q_INVOKABLE bool setNote(int row, const QString& value);
+
+signals:
+    // new data is ready to be made available to the model with fetchMore()
+    void newDataReady(const QModelIndex &parent) const;
+private:
+    QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
+    void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Strings* o, void* task);
+signals:
+    void textChanged();
+    void titleChanged();
+    void subtitleChanged();
+    void bufferChanged();
+    void bytesChanged();
+};
+#endif // TEST_STRINGS_RUST_H
@@ -1,7 +1,34 @@
 /* generated by rust_qt_binding_generator */
 #include "test_tree_rust.h"
+#include <QCoreApplication>
 
 namespace {
+    // A closure that Rust sends with run_on_gui_thread. It is dropped without
+    // running when the receiver is deleted before the event is delivered.
+    class RustTaskEvent : public QEvent {
+    public:
+        RustTaskEvent(void* task, void (*drop)(void*))
+            :QEvent(type()), m_task(task), m_drop(drop) {}
+        ~RustTaskEvent() {
+            if (m_task) {
+                m_drop(m_task);
+            }
+        }
+        static QEvent::Type type() {
+            static const QEvent::Type t
+                = static_cast<QEvent::Type>(QEvent::registerEventType());
+            return t;
+        }
+        // the closure is no longer dropped with the event after this
+        void* take() {
+            void* task = m_task;
+            m_task = nullptr;
+            return task;
+        }
+    private:
+        void* m_task;
+        void (*m_drop)(void*);
+    };
 
     struct option_quintptr {
     public:
@@ -197,7 +224,7 @@ bool Persons::setData(const QModelIndex &index, const QVariant &value, int role)
 }
 
 extern "C" {
-    Persons::Private* persons_new(Persons*,
+    Persons::Private* persons_new(Persons*, void (*)(Persons*, void*),
         void (*)(const Persons*, quintptr, bool),
         void (*)(Persons*, quintptr, quintptr),
         void (*)(Persons*),
@@ -207,19 +234,39 @@ extern "C" {
         void (*)(Persons*, option_quintptr, int, int),
         void (*)(Persons*));
     void persons_free(Persons::Private*);
+    void persons_run_task(Persons::Private*, void*);
+    void persons_drop_task(void*);
 };
 
+extern "C" {
+    quint64 interface_binding_hash();
+};
+
+namespace {
+    // Abort if the Rust code was generated from a different configuration.
+    // Its functions would be called with the wrong arguments.
+    void checkBindingHash() {
+        static const quint64 hash = interface_binding_hash();
+        if (hash != Q_UINT64_C(0x601c2b7b4ff3e676)) {
+            qFatal("test_tree_rust.cpp and the Rust module interface were generated from different "
+                "configurations. Run rust_qt_binding_generator again.");
+        }
+    }
+}
+
 Persons::Persons(bool /*owned*/, QObject *parent):
     QAbstractItemModel(parent),
     m_d(0),
     m_ownsPrivate(false)
 {
+    checkBindingHash();
     initHeaderData();
 }
 
 Persons::Persons(QObject *parent):
     QAbstractItemModel(parent),
-    m_d(persons_new(this,
+    m_d((checkBindingHash(), persons_new(this,
+        Persons::postTask,
         [](const Persons* o, quintptr id, bool valid) {
             if (valid) {
                 int row = persons_row(o->m_d, id);
@@ -262,7 +309,7 @@ Persons::Persons(QObject *parent):
         [](Persons* o) {
             o->endRemoveRows();
         }
-)),
+))),
     m_ownsPrivate(true)
 {
     connect(this, &Persons::newDataReady, this, [this](const QModelIndex& i) {
@@ -276,6 +323,20 @@ Persons::~Persons() {
         persons_free(m_d);
     }
 }
+
+bool Persons::event(QEvent* e)
+{
+    if (e->type() == RustTaskEvent::type()) {
+        persons_run_task(m_d, static_cast<RustTaskEvent*>(e)->take());
+        return true;
+    }
+    return QAbstractItemModel::event(e);
+}
+
+void Persons::postTask(Persons* o, void* task)
+{
+    QCoreApplication::postEvent(o, new RustTaskEvent(task, persons_drop_task));
+}
 void Persons::initHeaderData() {
     m_headerData.insert(qMakePair(0, Qt::DisplayRole), QVariant("userName"));
 }
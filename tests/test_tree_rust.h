@@ -12,6 +12,27 @@ class Persons : public QAbstractItemModel
     Q_OBJECT
 public:
     class Private;
+    // constructs and connects Persons for the generated code, also in the
+    // files that import this one
+    struct Binding {
+        static Persons* create(QObject* parent) { return new Persons(false, parent); }
+        static Private*& d(Persons* o) { return o->m_d; }
+        static Private* d(const Persons* o) { return o->m_d; }
+        static void postTask(Persons* o, void* task) { Persons::postTask(o, task); }
+        static QModelIndex createIndex(const Persons* o, int row, int column, quintptr id) {
+            return o->createIndex(row, column, id);
+        }
+        static void beginResetModel(Persons* o) { o->beginResetModel(); }
+        static void endResetModel(Persons* o) { o->endResetModel(); }
+        static void beginInsertRows(Persons* o, const QModelIndex& parent, int first, int last) {
+            o->beginInsertRows(parent, first, last);
+        }
+        static void endInsertRows(Persons* o) { o->endInsertRows(); }
+        static void beginRemoveRows(Persons* o, const QModelIndex& parent, int first, int last) {
+            o->beginRemoveRows(parent, first, last);
+        }
+        static void endRemoveRows(Persons* o) { o->endRemoveRows(); }
+    };
 private:
     Private * m_d;
     bool m_ownsPrivate;
@@ -46,6 +67,11 @@ signals:
 private:
     QHash<QPair<int,Qt::ItemDataRole>, QVariant> m_headerData;
     void initHeaderData();
+protected:
+    // runs the closures that Rust sends with run_on_gui_thread
+    bool event(QEvent* e) override;
+private:
+    static void postTask(Persons* o, void* task);
 signals:
 };
 #endif // TEST_TREE_RUST_H